@@ -0,0 +1,889 @@
+/*!
+Bidirectional text of unicode code points (UAX #9).
+
+# Embedding levels
+The Unicode Bidirectional Algorithm finds the direction of every code point of a text that mixes left-to-right (e.g. Latin) and right-to-left (e.g. Hebrew, Arabic) scripts.
+The direction is given by an embedding level: the even levels are left-to-right, and the odd levels are right-to-left.
+
+The text is split into paragraphs after every paragraph separator (a `CR LF` pair is a single separator), and the levels of every paragraph are resolved with the rules of the algorithm:
+* The paragraph level (P2, P3), from the [`ParagraphDirection`]: left-to-right (0), right-to-left (1) or the direction of the first strong character.
+* The explicit levels of the embeddings, overrides and isolates (X1 to X8), up to the maximum depth of 125.
+* The isolating run sequences (X9, X10), where the weak types (W1 to W7), the paired brackets (N0), the neutrals (N1, N2) and the implicit levels (I1, I2) are resolved.
+* The levels of the separators and of the whitespaces at the end of the paragraph are reset to the paragraph level (L1), as if every paragraph were displayed in a single line.
+
+The characters removed by the rule X9 (the explicit formatting characters and the boundary neutrals) take the level of the character before them.
+
+# Reordering
+[`visual_order`] reverses the runs of characters of a line from the highest level to the lowest odd level (L2), giving the order in which the characters are displayed from left to right.
+[`reorder`] also replaces the characters of the right-to-left runs with their mirrored glyph (L4), like `(` with `)`.
+
+**Note**:
+
+* The combining marks are not moved after their base in the right-to-left runs (the rule L3 is not applied).
+* A line of a paragraph broken in several lines is reordered with the levels of its code points, without the whitespaces at its end (e.g. the lines of [`crate::linebreak::wrap`]).
+*/
+
+use std::ops::Range;
+
+use crate::tables;
+
+/// The maximum explicit embedding level (BD2).
+const MAX_DEPTH: u8 = 125;
+
+/// The maximum number of opening brackets waiting for their closing bracket (BD16).
+const MAX_BRACKETS: usize = 63;
+
+/// The bidirectional classes of the unicode code points (values of the `Bidi_Class` property).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BidiClass {
+    /// A strong left-to-right character, like the Latin letters (`L`).
+    LeftToRight,
+    /// A strong right-to-left character, like the Hebrew letters (`R`).
+    RightToLeft,
+    /// A strong right-to-left Arabic letter (`AL`).
+    ArabicLetter,
+    /// A European digit (`EN`).
+    EuropeanNumber,
+    /// A plus or minus sign (`ES`).
+    EuropeanSeparator,
+    /// A number terminator, like the degree or the currency symbols (`ET`).
+    EuropeanTerminator,
+    /// An Arabic-Indic digit (`AN`).
+    ArabicNumber,
+    /// A number separator, like the comma and the colon (`CS`).
+    CommonSeparator,
+    /// A nonspacing mark, which takes the type of the character before it (`NSM`).
+    NonspacingMark,
+    /// A default ignorable character, like the zero width joiner (`BN`).
+    BoundaryNeutral,
+    /// A paragraph separator, like the line feed (`B`).
+    ParagraphSeparator,
+    /// A segment separator, like the tab (`S`).
+    SegmentSeparator,
+    /// A whitespace (`WS`).
+    WhiteSpace,
+    /// Another neutral character, like the punctuation (`ON`).
+    OtherNeutral,
+    /// The left-to-right embedding U+202A (`LRE`).
+    LeftToRightEmbedding,
+    /// The left-to-right override U+202D (`LRO`).
+    LeftToRightOverride,
+    /// The right-to-left embedding U+202B (`RLE`).
+    RightToLeftEmbedding,
+    /// The right-to-left override U+202E (`RLO`).
+    RightToLeftOverride,
+    /// The pop directional formatting U+202C (`PDF`).
+    PopDirectionalFormat,
+    /// The left-to-right isolate U+2066 (`LRI`).
+    LeftToRightIsolate,
+    /// The right-to-left isolate U+2067 (`RLI`).
+    RightToLeftIsolate,
+    /// The first strong isolate U+2068 (`FSI`).
+    FirstStrongIsolate,
+    /// The pop directional isolate U+2069 (`PDI`).
+    PopDirectionalIsolate,
+}
+
+/// The direction of a paragraph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParagraphDirection {
+    /// Left-to-right (level 0).
+    LeftToRight,
+    /// Right-to-left (level 1).
+    RightToLeft,
+    /// The direction of the first strong character of the paragraph, outside of the isolates, or left-to-right if there is none.
+    Auto,
+}
+
+/// Check if a class is removed by the rule X9: the explicit embeddings and overrides, and the boundary neutrals.
+fn is_removed(class: BidiClass) -> bool {
+    matches!(
+        class,
+        BidiClass::LeftToRightEmbedding
+            | BidiClass::RightToLeftEmbedding
+            | BidiClass::LeftToRightOverride
+            | BidiClass::RightToLeftOverride
+            | BidiClass::PopDirectionalFormat
+            | BidiClass::BoundaryNeutral
+    )
+}
+
+/// Check if a class is an isolate initiator (LRI, RLI, FSI).
+fn is_isolate_initiator(class: BidiClass) -> bool {
+    matches!(
+        class,
+        BidiClass::LeftToRightIsolate
+            | BidiClass::RightToLeftIsolate
+            | BidiClass::FirstStrongIsolate
+    )
+}
+
+/// Check if a class is a neutral or an isolate formatting character (`NI`).
+fn is_neutral_or_isolate(class: BidiClass) -> bool {
+    matches!(
+        class,
+        BidiClass::ParagraphSeparator
+            | BidiClass::SegmentSeparator
+            | BidiClass::WhiteSpace
+            | BidiClass::OtherNeutral
+            | BidiClass::PopDirectionalIsolate
+    ) || is_isolate_initiator(class)
+}
+
+/// Get the strong direction of a class for the rules N0 to N2, where the numbers are right-to-left.
+fn strong_direction(class: BidiClass) -> Option<BidiClass> {
+    match class {
+        BidiClass::LeftToRight => Some(BidiClass::LeftToRight),
+        BidiClass::RightToLeft
+        | BidiClass::ArabicLetter
+        | BidiClass::EuropeanNumber
+        | BidiClass::ArabicNumber => Some(BidiClass::RightToLeft),
+        _ => None,
+    }
+}
+
+/// Get the direction of an embedding level: left-to-right if even, right-to-left if odd.
+fn level_direction(level: u8) -> BidiClass {
+    if level % 2 == 1 {
+        BidiClass::RightToLeft
+    } else {
+        BidiClass::LeftToRight
+    }
+}
+
+/// Get the least level greater than a level, odd for the right-to-left embeddings and even otherwise (X2 to X5).
+fn next_level(level: u8, rtl: bool) -> u8 {
+    if rtl {
+        (level + 1) | 1
+    } else {
+        (level + 2) & !1
+    }
+}
+
+/// Get the paired bracket of a code point and if it is an opening bracket (`BidiBrackets.txt`).
+fn paired_bracket(code_point: u32) -> Option<(u32, bool)> {
+    let table: &[(u32, u32, bool)] = tables::bidi::BIDI_PAIRED_BRACKETS;
+    table
+        .binary_search_by_key(&code_point, |(cp, _, _)| *cp)
+        .ok()
+        .map(|i| (table[i].1, table[i].2))
+}
+
+/// Get the canonical equivalent of a bracket, so that U+2329 and U+232A pair with U+3008 and U+3009 (BD16).
+fn canonical_bracket(code_point: u32) -> u32 {
+    match code_point {
+        0x2329 => 0x3008,
+        0x232A => 0x3009,
+        code_point => code_point,
+    }
+}
+
+/// Find the direction of the first strong character of a range of a paragraph, skipping the isolates (P2).
+///
+/// # Parameters
+/// * `classes`: [`&[BidiClass]`] - The classes of the code points of the paragraph.
+/// * `matching_pdi`: [`&[Option<usize>]`] - The index of the matching PDI of every isolate initiator.
+/// * `range`: [`Range<usize>`] - The range of the paragraph to search.
+///
+/// # Returns
+/// [`BidiClass::LeftToRight`] or [`BidiClass::RightToLeft`], or [`None`] if there is no strong character.
+fn first_strong(
+    classes: &[BidiClass],
+    matching_pdi: &[Option<usize>],
+    range: Range<usize>,
+) -> Option<BidiClass> {
+    let mut i: usize = range.start;
+    while i < range.end {
+        match classes[i] {
+            BidiClass::LeftToRight => return Some(BidiClass::LeftToRight),
+            BidiClass::RightToLeft | BidiClass::ArabicLetter => {
+                return Some(BidiClass::RightToLeft)
+            }
+            class if is_isolate_initiator(class) => i = matching_pdi[i]?,
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Split a slice of unicode code points into paragraphs, after every paragraph separator (P1).
+fn paragraphs(unicode_cp: &[u32]) -> Vec<Range<usize>> {
+    let mut paragraphs: Vec<Range<usize>> = Vec::new();
+    let mut start: usize = 0;
+    for (i, code_point) in unicode_cp.iter().enumerate() {
+        let crlf: bool = *code_point == 0x0D && unicode_cp.get(i + 1) == Some(&0x0A);
+        if bidi_class(*code_point) == BidiClass::ParagraphSeparator && !crlf {
+            paragraphs.push(start..i + 1);
+            start = i + 1;
+        }
+    }
+    if start < unicode_cp.len() {
+        paragraphs.push(start..unicode_cp.len());
+    }
+    paragraphs
+}
+
+/// An isolating run sequence (BD13): the indexes of its code points in the paragraph, with its level and the types of its boundaries.
+struct RunSequence {
+    indexes: Vec<usize>,
+    level: u8,
+    sos: BidiClass,
+    eos: BidiClass,
+}
+
+/// A paragraph being resolved.
+struct Paragraph<'a> {
+    /// The code points of the paragraph.
+    code_points: &'a [u32],
+    /// The original classes of the code points.
+    original: Vec<BidiClass>,
+    /// The classes of the code points, changed by the rules.
+    classes: Vec<BidiClass>,
+    /// The index of the matching PDI of every isolate initiator (BD9).
+    matching_pdi: Vec<Option<usize>>,
+    /// The paragraph embedding level.
+    level: u8,
+    /// The embedding levels of the code points.
+    levels: Vec<u8>,
+}
+
+impl<'a> Paragraph<'a> {
+    /// Create a paragraph and find its level (P2, P3).
+    fn new(code_points: &'a [u32], direction: ParagraphDirection) -> Self {
+        let original: Vec<BidiClass> = code_points.iter().map(|cp| bidi_class(*cp)).collect();
+        let mut matching_pdi: Vec<Option<usize>> = vec![None; original.len()];
+        let mut initiators: Vec<usize> = Vec::new();
+        for (i, class) in original.iter().enumerate() {
+            if is_isolate_initiator(*class) {
+                initiators.push(i);
+            } else if *class == BidiClass::PopDirectionalIsolate {
+                if let Some(initiator) = initiators.pop() {
+                    matching_pdi[initiator] = Some(i);
+                }
+            }
+        }
+        let level: u8 = match direction {
+            ParagraphDirection::LeftToRight => 0,
+            ParagraphDirection::RightToLeft => 1,
+            ParagraphDirection::Auto => {
+                match first_strong(&original, &matching_pdi, 0..original.len()) {
+                    Some(BidiClass::RightToLeft) => 1,
+                    _ => 0,
+                }
+            }
+        };
+        Self {
+            code_points,
+            classes: original.clone(),
+            levels: vec![level; original.len()],
+            original,
+            matching_pdi,
+            level,
+        }
+    }
+
+    /// Resolve the explicit levels and the directional overrides (X1 to X8).
+    fn resolve_explicit(&mut self) {
+        // The directional status stack: the level, the override and if the entry is an isolate
+        let mut stack: Vec<(u8, Option<BidiClass>, bool)> = vec![(self.level, None, false)];
+        let mut overflow_isolates: usize = 0;
+        let mut overflow_embeddings: usize = 0;
+        let mut valid_isolates: usize = 0;
+        for i in 0..self.original.len() {
+            let (level, override_class, isolate) = *stack.last().unwrap();
+            let class: BidiClass = self.original[i];
+            match class {
+                BidiClass::RightToLeftEmbedding
+                | BidiClass::LeftToRightEmbedding
+                | BidiClass::RightToLeftOverride
+                | BidiClass::LeftToRightOverride => {
+                    self.levels[i] = level;
+                    let rtl: bool = matches!(
+                        class,
+                        BidiClass::RightToLeftEmbedding | BidiClass::RightToLeftOverride
+                    );
+                    let new_level: u8 = next_level(level, rtl);
+                    if new_level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0
+                    {
+                        let override_class: Option<BidiClass> = match class {
+                            BidiClass::RightToLeftOverride => Some(BidiClass::RightToLeft),
+                            BidiClass::LeftToRightOverride => Some(BidiClass::LeftToRight),
+                            _ => None,
+                        };
+                        stack.push((new_level, override_class, false));
+                    } else if overflow_isolates == 0 {
+                        overflow_embeddings += 1;
+                    }
+                }
+                BidiClass::RightToLeftIsolate
+                | BidiClass::LeftToRightIsolate
+                | BidiClass::FirstStrongIsolate => {
+                    self.levels[i] = level;
+                    if let Some(override_class) = override_class {
+                        self.classes[i] = override_class;
+                    }
+                    let rtl: bool = match class {
+                        BidiClass::RightToLeftIsolate => true,
+                        BidiClass::LeftToRightIsolate => false,
+                        // The direction of the first strong character up to the matching PDI (X5c)
+                        _ => {
+                            let end: usize = self.matching_pdi[i].unwrap_or(self.original.len());
+                            first_strong(&self.original, &self.matching_pdi, i + 1..end)
+                                == Some(BidiClass::RightToLeft)
+                        }
+                    };
+                    let new_level: u8 = next_level(level, rtl);
+                    if new_level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0
+                    {
+                        valid_isolates += 1;
+                        stack.push((new_level, None, true));
+                    } else {
+                        overflow_isolates += 1;
+                    }
+                }
+                BidiClass::PopDirectionalIsolate => {
+                    if overflow_isolates > 0 {
+                        overflow_isolates -= 1;
+                    } else if valid_isolates > 0 {
+                        overflow_embeddings = 0;
+                        while !stack.last().unwrap().2 {
+                            stack.pop();
+                        }
+                        stack.pop();
+                        valid_isolates -= 1;
+                    }
+                    let (level, override_class, _) = *stack.last().unwrap();
+                    self.levels[i] = level;
+                    if let Some(override_class) = override_class {
+                        self.classes[i] = override_class;
+                    }
+                }
+                BidiClass::PopDirectionalFormat => {
+                    self.levels[i] = level;
+                    if overflow_isolates == 0 && overflow_embeddings > 0 {
+                        overflow_embeddings -= 1;
+                    } else if overflow_isolates == 0 && !isolate && stack.len() >= 2 {
+                        stack.pop();
+                    }
+                }
+                BidiClass::ParagraphSeparator => self.levels[i] = self.level,
+                BidiClass::BoundaryNeutral => self.levels[i] = level,
+                _ => {
+                    self.levels[i] = level;
+                    if let Some(override_class) = override_class {
+                        self.classes[i] = override_class;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Find the isolating run sequences of the code points not removed by the rule X9 (X10, BD13).
+    fn run_sequences(&self) -> Vec<RunSequence> {
+        // The level runs, and the run of every code point
+        let mut runs: Vec<Vec<usize>> = Vec::new();
+        let mut run_of: Vec<usize> = vec![0; self.original.len()];
+        let mut previous_level: Option<u8> = None;
+        for (i, class) in self.original.iter().enumerate() {
+            if is_removed(*class) {
+                continue;
+            }
+            if previous_level != Some(self.levels[i]) {
+                runs.push(Vec::new());
+                previous_level = Some(self.levels[i]);
+            }
+            runs.last_mut().unwrap().push(i);
+            run_of[i] = runs.len() - 1;
+        }
+        let mut sequences: Vec<RunSequence> = Vec::new();
+        let mut continued: Vec<bool> = vec![false; runs.len()];
+        for (r, run) in runs.iter().enumerate() {
+            if continued[r] {
+                continue;
+            }
+            let mut indexes: Vec<usize> = run.clone();
+            // Continue with the run of the matching PDI of an isolate initiator at the end of the run
+            while let Some(pdi) = self.matching_pdi[*indexes.last().unwrap()] {
+                let next: usize = run_of[pdi];
+                if runs[next][0] != pdi {
+                    break;
+                }
+                continued[next] = true;
+                indexes.extend_from_slice(&runs[next]);
+            }
+            let first: usize = indexes[0];
+            let last: usize = *indexes.last().unwrap();
+            let level: u8 = self.levels[first];
+            let before: u8 = (0..first)
+                .rev()
+                .find(|i| !is_removed(self.original[*i]))
+                .map_or(self.level, |i| self.levels[i]);
+            let after: u8 = if is_isolate_initiator(self.original[last]) {
+                self.level
+            } else {
+                (last + 1..self.original.len())
+                    .find(|i| !is_removed(self.original[*i]))
+                    .map_or(self.level, |i| self.levels[i])
+            };
+            sequences.push(RunSequence {
+                indexes,
+                level,
+                sos: level_direction(level.max(before)),
+                eos: level_direction(level.max(after)),
+            });
+        }
+        sequences
+    }
+
+    /// Resolve the weak types of an isolating run sequence (W1 to W7).
+    fn resolve_weak(&mut self, sequence: &RunSequence) {
+        let indexes: &[usize] = &sequence.indexes;
+        // W1: the nonspacing marks take the type of the character before them
+        let mut previous: BidiClass = sequence.sos;
+        for &i in indexes {
+            if self.classes[i] == BidiClass::NonspacingMark {
+                self.classes[i] = if is_isolate_initiator(previous)
+                    || previous == BidiClass::PopDirectionalIsolate
+                {
+                    BidiClass::OtherNeutral
+                } else {
+                    previous
+                };
+            }
+            previous = self.classes[i];
+        }
+        // W2: the European numbers after an Arabic letter are Arabic numbers, W3: the Arabic letters are right-to-left
+        let mut strong: BidiClass = sequence.sos;
+        for &i in indexes {
+            match self.classes[i] {
+                class @ (BidiClass::LeftToRight
+                | BidiClass::RightToLeft
+                | BidiClass::ArabicLetter) => strong = class,
+                BidiClass::EuropeanNumber if strong == BidiClass::ArabicLetter => {
+                    self.classes[i] = BidiClass::ArabicNumber
+                }
+                _ => {}
+            }
+        }
+        for &i in indexes {
+            if self.classes[i] == BidiClass::ArabicLetter {
+                self.classes[i] = BidiClass::RightToLeft;
+            }
+        }
+        // W4: a single separator between two numbers of the same type
+        for k in 1..indexes.len().saturating_sub(1) {
+            let previous: BidiClass = self.classes[indexes[k - 1]];
+            let next: BidiClass = self.classes[indexes[k + 1]];
+            match self.classes[indexes[k]] {
+                BidiClass::EuropeanSeparator
+                    if previous == BidiClass::EuropeanNumber
+                        && next == BidiClass::EuropeanNumber =>
+                {
+                    self.classes[indexes[k]] = BidiClass::EuropeanNumber
+                }
+                BidiClass::CommonSeparator
+                    if previous == next
+                        && matches!(
+                            previous,
+                            BidiClass::EuropeanNumber | BidiClass::ArabicNumber
+                        ) =>
+                {
+                    self.classes[indexes[k]] = previous
+                }
+                _ => {}
+            }
+        }
+        // W5: the terminators next to a European number
+        let mut k: usize = 0;
+        while k < indexes.len() {
+            if self.classes[indexes[k]] != BidiClass::EuropeanTerminator {
+                k += 1;
+                continue;
+            }
+            let start: usize = k;
+            while k < indexes.len() && self.classes[indexes[k]] == BidiClass::EuropeanTerminator {
+                k += 1;
+            }
+            let number = |k: Option<usize>| {
+                k.and_then(|k| indexes.get(k))
+                    .is_some_and(|i| self.classes[*i] == BidiClass::EuropeanNumber)
+            };
+            if number(start.checked_sub(1)) || number(Some(k)) {
+                for &i in &indexes[start..k] {
+                    self.classes[i] = BidiClass::EuropeanNumber;
+                }
+            }
+        }
+        // W6: the other separators and terminators are neutral
+        for &i in indexes {
+            if matches!(
+                self.classes[i],
+                BidiClass::EuropeanSeparator
+                    | BidiClass::EuropeanTerminator
+                    | BidiClass::CommonSeparator
+            ) {
+                self.classes[i] = BidiClass::OtherNeutral;
+            }
+        }
+        // W7: the European numbers after a left-to-right character are left-to-right
+        let mut strong: BidiClass = sequence.sos;
+        for &i in indexes {
+            match self.classes[i] {
+                class @ (BidiClass::LeftToRight | BidiClass::RightToLeft) => strong = class,
+                BidiClass::EuropeanNumber if strong == BidiClass::LeftToRight => {
+                    self.classes[i] = BidiClass::LeftToRight
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Find the bracket pairs of an isolating run sequence (BD16).
+    ///
+    /// # Returns
+    /// A [`Vec<(usize, usize)>`] containing the positions in the sequence of the opening and the closing brackets, sorted by the opening bracket.
+    fn bracket_pairs(&self, sequence: &RunSequence) -> Vec<(usize, usize)> {
+        let mut pairs: Vec<(usize, usize)> = Vec::new();
+        // The closing brackets expected by the opening brackets, with their positions
+        let mut stack: Vec<(u32, usize)> = Vec::new();
+        for (position, &i) in sequence.indexes.iter().enumerate() {
+            if self.classes[i] != BidiClass::OtherNeutral {
+                continue;
+            }
+            match paired_bracket(self.code_points[i]) {
+                Some((closing, true)) => {
+                    if stack.len() == MAX_BRACKETS {
+                        break;
+                    }
+                    stack.push((canonical_bracket(closing), position));
+                }
+                Some((_, false)) => {
+                    let closing: u32 = canonical_bracket(self.code_points[i]);
+                    if let Some(k) = stack.iter().rposition(|(c, _)| *c == closing) {
+                        pairs.push((stack[k].1, position));
+                        stack.truncate(k);
+                    }
+                }
+                None => {}
+            }
+        }
+        pairs.sort_unstable();
+        pairs
+    }
+
+    /// Resolve the paired brackets of an isolating run sequence (N0).
+    fn resolve_brackets(&mut self, sequence: &RunSequence) {
+        let indexes: &[usize] = &sequence.indexes;
+        let embedding: BidiClass = level_direction(sequence.level);
+        for (open, close) in self.bracket_pairs(sequence) {
+            let mut found_embedding: bool = false;
+            let mut found_opposite: bool = false;
+            for &i in &indexes[open + 1..close] {
+                match strong_direction(self.classes[i]) {
+                    Some(direction) if direction == embedding => found_embedding = true,
+                    Some(_) => found_opposite = true,
+                    None => {}
+                }
+            }
+            let direction: BidiClass = if found_embedding {
+                embedding
+            } else if found_opposite {
+                // The opposite direction only if it is also the direction of the context before the brackets
+                let context: BidiClass = indexes[..open]
+                    .iter()
+                    .rev()
+                    .find_map(|i| strong_direction(self.classes[*i]))
+                    .unwrap_or(sequence.sos);
+                if context != embedding {
+                    context
+                } else {
+                    embedding
+                }
+            } else {
+                continue;
+            };
+            for position in [open, close] {
+                self.classes[indexes[position]] = direction;
+                // The nonspacing marks after a bracket take its direction
+                for &i in &indexes[position + 1..] {
+                    if self.original[i] != BidiClass::NonspacingMark {
+                        break;
+                    }
+                    self.classes[i] = direction;
+                }
+            }
+        }
+    }
+
+    /// Resolve the neutrals and the isolate formatting characters of an isolating run sequence (N1, N2).
+    fn resolve_neutrals(&mut self, sequence: &RunSequence) {
+        let indexes: &[usize] = &sequence.indexes;
+        let embedding: BidiClass = level_direction(sequence.level);
+        let mut k: usize = 0;
+        while k < indexes.len() {
+            if !is_neutral_or_isolate(self.classes[indexes[k]]) {
+                k += 1;
+                continue;
+            }
+            let start: usize = k;
+            while k < indexes.len() && is_neutral_or_isolate(self.classes[indexes[k]]) {
+                k += 1;
+            }
+            let before: BidiClass = match start {
+                0 => sequence.sos,
+                start => strong_direction(self.classes[indexes[start - 1]]).unwrap_or(embedding),
+            };
+            let after: BidiClass = match indexes.get(k) {
+                Some(i) => strong_direction(self.classes[*i]).unwrap_or(embedding),
+                None => sequence.eos,
+            };
+            let direction: BidiClass = if before == after { before } else { embedding };
+            for &i in &indexes[start..k] {
+                self.classes[i] = direction;
+            }
+        }
+    }
+
+    /// Resolve the implicit levels of an isolating run sequence (I1, I2).
+    fn resolve_implicit(&mut self, sequence: &RunSequence) {
+        for &i in &sequence.indexes {
+            let level: u8 = self.levels[i];
+            self.levels[i] = match (level % 2, self.classes[i]) {
+                (0, BidiClass::RightToLeft) => level + 1,
+                (0, BidiClass::ArabicNumber | BidiClass::EuropeanNumber) => level + 2,
+                (
+                    1,
+                    BidiClass::LeftToRight | BidiClass::EuropeanNumber | BidiClass::ArabicNumber,
+                ) => level + 1,
+                _ => level,
+            };
+        }
+    }
+
+    /// Resolve the levels of the paragraph, displayed in a single line.
+    ///
+    /// # Returns
+    /// A [`Vec<u8>`] containing the embedding levels of the code points.
+    fn resolve(mut self) -> Vec<u8> {
+        self.resolve_explicit();
+        for sequence in self.run_sequences() {
+            self.resolve_weak(&sequence);
+            self.resolve_brackets(&sequence);
+            self.resolve_neutrals(&sequence);
+            self.resolve_implicit(&sequence);
+        }
+        // The removed characters take the level of the character before them
+        for i in 0..self.original.len() {
+            if is_removed(self.original[i]) {
+                self.levels[i] = if i == 0 {
+                    self.level
+                } else {
+                    self.levels[i - 1]
+                };
+            }
+        }
+        // L1: the separators, and the whitespaces before them and at the end of the line
+        let mut trailing: bool = true;
+        for i in (0..self.original.len()).rev() {
+            match self.original[i] {
+                BidiClass::SegmentSeparator | BidiClass::ParagraphSeparator => {
+                    self.levels[i] = self.level;
+                    trailing = true;
+                }
+                BidiClass::WhiteSpace | BidiClass::PopDirectionalIsolate if trailing => {
+                    self.levels[i] = self.level
+                }
+                class if trailing && (is_isolate_initiator(class) || is_removed(class)) => {
+                    self.levels[i] = self.level
+                }
+                _ => trailing = false,
+            }
+        }
+        self.levels
+    }
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// Get the bidirectional class of a unicode code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The [`BidiClass`] of the code point (`DerivedBidiClass.txt`).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// assert_eq!(bidi_class(0x41), BidiClass::LeftToRight);
+/// assert_eq!(bidi_class(0x5D0), BidiClass::RightToLeft);
+/// assert_eq!(bidi_class(0x627), BidiClass::ArabicLetter);
+/// assert_eq!(bidi_class(0x31), BidiClass::EuropeanNumber);
+/// assert_eq!(bidi_class(0x20), BidiClass::WhiteSpace);
+/// ```
+pub fn bidi_class(code_point: u32) -> BidiClass {
+    tables::lookup_range(tables::bidi::BIDI_CLASS, code_point).unwrap_or(BidiClass::LeftToRight)
+}
+
+/// Get the mirrored glyph of a unicode code point, displayed in the right-to-left runs.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The code point with the mirrored glyph (`BidiMirroring.txt`), or [`None`] if there is none.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// assert_eq!(bidi_mirroring_glyph(0x28), Some(0x29));
+/// assert_eq!(bidi_mirroring_glyph(0x226E), Some(0x226F));
+/// assert_eq!(bidi_mirroring_glyph(0x41), None);
+/// ```
+pub fn bidi_mirroring_glyph(code_point: u32) -> Option<u32> {
+    let table: &[(u32, u32)] = tables::bidi::BIDI_MIRRORING_GLYPH;
+    table
+        .binary_search_by_key(&code_point, |(cp, _)| *cp)
+        .ok()
+        .map(|i| table[i].1)
+}
+
+/// Get the embedding level of the first paragraph of a vector of unicode code points (P2, P3).
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `direction`: [`ParagraphDirection`] - The direction of the paragraph.
+///
+/// # Returns
+/// The paragraph embedding level: 0 if left-to-right, 1 if right-to-left.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "123 שלום world".chars().map(|c| c as u32).collect();
+/// assert_eq!(paragraph_level(&v, ParagraphDirection::Auto), 1);
+/// assert_eq!(paragraph_level(&v, ParagraphDirection::LeftToRight), 0);
+/// ```
+pub fn paragraph_level<T: AsRef<Vec<u32>>>(unicode_cp: T, direction: ParagraphDirection) -> u8 {
+    let v: &[u32] = unicode_cp.as_ref();
+    let paragraph: Range<usize> = paragraphs(v).into_iter().next().unwrap_or(0..0);
+    Paragraph::new(&v[paragraph], direction).level
+}
+
+/// Resolve the embedding levels of a vector of unicode code points, with every paragraph in a single line.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `direction`: [`ParagraphDirection`] - The direction of the paragraphs.
+///
+/// # Returns
+/// A [`Vec<u8>`] containing the embedding level of every code point: even if left-to-right, odd if right-to-left.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "car is אבג (10)!".chars().map(|c| c as u32).collect();
+/// let levels: Vec<u8> = bidi_levels(&v, ParagraphDirection::LeftToRight);
+/// assert_eq!(levels, vec![0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 2, 2, 1, 0]);
+/// let levels: Vec<u8> = bidi_levels(&v, ParagraphDirection::RightToLeft);
+/// assert_eq!(levels, vec![2, 2, 2, 2, 2, 2, 1, 1, 1, 1, 1, 1, 2, 2, 1, 1]);
+/// ```
+pub fn bidi_levels<T: AsRef<Vec<u32>>>(unicode_cp: T, direction: ParagraphDirection) -> Vec<u8> {
+    let v: &[u32] = unicode_cp.as_ref();
+    paragraphs(v)
+        .into_iter()
+        .flat_map(|paragraph| Paragraph::new(&v[paragraph], direction).resolve())
+        .collect()
+}
+
+/// Get the visual order of the code points of a line from their embedding levels (L2).
+///
+/// # Parameters
+/// * `levels`: [`&[u8]`] - The embedding levels of the code points of a line (see [`bidi_levels`]).
+///
+/// # Returns
+/// A [`Vec<usize>`] containing the indexes of the code points of the line, in the order in which they are displayed from left to right.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// assert_eq!(visual_order(&[0, 0, 1, 1, 1, 0]), vec![0, 1, 4, 3, 2, 5]);
+/// assert_eq!(visual_order(&[1, 1, 2, 2, 1]), vec![4, 2, 3, 1, 0]);
+/// ```
+pub fn visual_order(levels: &[u8]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..levels.len()).collect();
+    let highest: u8 = levels.iter().copied().max().unwrap_or(0);
+    let lowest_odd: u8 = levels
+        .iter()
+        .copied()
+        .filter(|l| l % 2 == 1)
+        .min()
+        .unwrap_or(highest + 1);
+    for level in (lowest_odd..=highest).rev() {
+        let mut i: usize = 0;
+        while i < levels.len() {
+            if levels[order[i]] < level {
+                i += 1;
+                continue;
+            }
+            let start: usize = i;
+            while i < levels.len() && levels[order[i]] >= level {
+                i += 1;
+            }
+            order[start..i].reverse();
+        }
+    }
+    order
+}
+
+/// Reorder a vector of unicode code points for display, with every paragraph in a single line.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `direction`: [`ParagraphDirection`] - The direction of the paragraphs.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the code points in visual order (L2), with the mirrored glyphs in the right-to-left runs (L4) and the paragraph separators at the end of their paragraph.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "abc (אבג) 123".chars().map(|c| c as u32).collect();
+/// let s: String = reorder(&v, ParagraphDirection::RightToLeft)
+///     .iter()
+///     .map(|cp| char::from_u32(*cp).unwrap())
+///     .collect();
+/// assert_eq!(s, "123 (גבא) abc");
+/// ```
+pub fn reorder<T: AsRef<Vec<u32>>>(unicode_cp: T, direction: ParagraphDirection) -> Vec<u32> {
+    let v: &[u32] = unicode_cp.as_ref();
+    let levels: Vec<u8> = bidi_levels(unicode_cp.as_ref(), direction);
+    let mut reordered: Vec<u32> = Vec::with_capacity(v.len());
+    for paragraph in paragraphs(v) {
+        // The paragraph separator stays at the end of the paragraph
+        let mut end: usize = paragraph.end;
+        while end > paragraph.start && bidi_class(v[end - 1]) == BidiClass::ParagraphSeparator {
+            end -= 1;
+        }
+        let levels: &[u8] = &levels[paragraph.start..end];
+        for i in visual_order(levels) {
+            let code_point: u32 = v[paragraph.start + i];
+            reordered.push(match levels[i] % 2 {
+                1 => bidi_mirroring_glyph(code_point).unwrap_or(code_point),
+                _ => code_point,
+            });
+        }
+        reordered.extend_from_slice(&v[end..paragraph.end]);
+    }
+    reordered
+}
@@ -0,0 +1,1360 @@
+/*!
+Generate the tables of the `tables` module from the files of the Unicode Character Database.
+
+Every table is a sorted list of ranges of code points (an inversion list with the values of the ranges), searched by binary search.
+To update the tables to a new version of Unicode, download the files the tables are generated from into a directory (`make ucd` lists them: files of `ucd/`, `ucd/auxiliary/`, `ucd/extracted/` and `ucd/emoji/`, `security/confusables.txt`, `security/IdentifierStatus.txt` and the DUCET `UCA/allkeys.txt`), update the enums of the new property values, and run:
+
+```text
+cargo run --release --bin gen_tables -- <ucd directory> src/tables
+```
+
+The version of Unicode is read from the header of `DerivedAge.txt` (e.g. `# DerivedAge-14.0.0.txt`), and the generator panics if the header of another file (or the `@version` of `allkeys.txt`) names a different version.
+*/
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The long names of the general categories, indexed by their abbreviation.
+const GENERAL_CATEGORIES: &[(&str, &str)] = &[
+    ("Lu", "UppercaseLetter"),
+    ("Ll", "LowercaseLetter"),
+    ("Lt", "TitlecaseLetter"),
+    ("Lm", "ModifierLetter"),
+    ("Lo", "OtherLetter"),
+    ("Mn", "NonspacingMark"),
+    ("Mc", "SpacingMark"),
+    ("Me", "EnclosingMark"),
+    ("Nd", "DecimalNumber"),
+    ("Nl", "LetterNumber"),
+    ("No", "OtherNumber"),
+    ("Pc", "ConnectorPunctuation"),
+    ("Pd", "DashPunctuation"),
+    ("Ps", "OpenPunctuation"),
+    ("Pe", "ClosePunctuation"),
+    ("Pi", "InitialPunctuation"),
+    ("Pf", "FinalPunctuation"),
+    ("Po", "OtherPunctuation"),
+    ("Sm", "MathSymbol"),
+    ("Sc", "CurrencySymbol"),
+    ("Sk", "ModifierSymbol"),
+    ("So", "OtherSymbol"),
+    ("Zs", "SpaceSeparator"),
+    ("Zl", "LineSeparator"),
+    ("Zp", "ParagraphSeparator"),
+    ("Cc", "Control"),
+    ("Cf", "Format"),
+    ("Cs", "Surrogate"),
+    ("Co", "PrivateUse"),
+];
+
+/// The enum variants of the line break classes, indexed by their abbreviation.
+const LINE_BREAKS: &[(&str, &str)] = &[
+    ("BK", "MandatoryBreak"),
+    ("CR", "CarriageReturn"),
+    ("LF", "LineFeed"),
+    ("NL", "NextLine"),
+    ("SP", "Space"),
+    ("ZW", "ZwSpace"),
+    ("ZWJ", "Zwj"),
+    ("CM", "CombiningMark"),
+    ("WJ", "WordJoiner"),
+    ("GL", "Glue"),
+    ("BA", "BreakAfter"),
+    ("BB", "BreakBefore"),
+    ("B2", "BreakBoth"),
+    ("HY", "Hyphen"),
+    ("CB", "ContingentBreak"),
+    ("CL", "ClosePunctuation"),
+    ("CP", "CloseParenthesis"),
+    ("EX", "Exclamation"),
+    ("IN", "Inseparable"),
+    ("NS", "Nonstarter"),
+    ("OP", "OpenPunctuation"),
+    ("QU", "Quotation"),
+    ("IS", "InfixNumeric"),
+    ("NU", "Numeric"),
+    ("PO", "PostfixNumeric"),
+    ("PR", "PrefixNumeric"),
+    ("SY", "BreakSymbols"),
+    ("AI", "Ambiguous"),
+    ("AL", "Alphabetic"),
+    ("CJ", "ConditionalJapaneseStarter"),
+    ("EB", "EBase"),
+    ("EM", "EModifier"),
+    ("H2", "H2"),
+    ("H3", "H3"),
+    ("HL", "HebrewLetter"),
+    ("ID", "Ideographic"),
+    ("JL", "Jl"),
+    ("JV", "Jv"),
+    ("JT", "Jt"),
+    ("RI", "RegionalIndicator"),
+    ("SA", "ComplexContext"),
+    ("XX", "Unknown"),
+    ("SG", "Surrogate"),
+];
+
+/// The enum variants of the East Asian widths, indexed by their abbreviation.
+const EAST_ASIAN_WIDTHS: &[(&str, &str)] = &[
+    ("A", "Ambiguous"),
+    ("F", "Fullwidth"),
+    ("H", "Halfwidth"),
+    ("N", "Neutral"),
+    ("Na", "Narrow"),
+    ("W", "Wide"),
+];
+
+/// The variants of the bidirectional classes, by short name.
+const BIDI_CLASSES: &[(&str, &str)] = &[
+    ("L", "LeftToRight"),
+    ("R", "RightToLeft"),
+    ("AL", "ArabicLetter"),
+    ("EN", "EuropeanNumber"),
+    ("ES", "EuropeanSeparator"),
+    ("ET", "EuropeanTerminator"),
+    ("AN", "ArabicNumber"),
+    ("CS", "CommonSeparator"),
+    ("NSM", "NonspacingMark"),
+    ("BN", "BoundaryNeutral"),
+    ("B", "ParagraphSeparator"),
+    ("S", "SegmentSeparator"),
+    ("WS", "WhiteSpace"),
+    ("ON", "OtherNeutral"),
+    ("LRE", "LeftToRightEmbedding"),
+    ("LRO", "LeftToRightOverride"),
+    ("RLE", "RightToLeftEmbedding"),
+    ("RLO", "RightToLeftOverride"),
+    ("PDF", "PopDirectionalFormat"),
+    ("LRI", "LeftToRightIsolate"),
+    ("RLI", "RightToLeftIsolate"),
+    ("FSI", "FirstStrongIsolate"),
+    ("PDI", "PopDirectionalIsolate"),
+];
+
+/// The long names of the scripts, by ISO 15924 code.
+const SCRIPTS: &[(&str, &str)] = &[
+    ("Adlm", "Adlam"),
+    ("Ahom", "Ahom"),
+    ("Hluw", "Anatolian_Hieroglyphs"),
+    ("Arab", "Arabic"),
+    ("Armn", "Armenian"),
+    ("Avst", "Avestan"),
+    ("Bali", "Balinese"),
+    ("Bamu", "Bamum"),
+    ("Bass", "Bassa_Vah"),
+    ("Batk", "Batak"),
+    ("Beng", "Bengali"),
+    ("Bhks", "Bhaiksuki"),
+    ("Bopo", "Bopomofo"),
+    ("Brah", "Brahmi"),
+    ("Brai", "Braille"),
+    ("Bugi", "Buginese"),
+    ("Buhd", "Buhid"),
+    ("Cans", "Canadian_Aboriginal"),
+    ("Cari", "Carian"),
+    ("Aghb", "Caucasian_Albanian"),
+    ("Cakm", "Chakma"),
+    ("Cham", "Cham"),
+    ("Cher", "Cherokee"),
+    ("Chrs", "Chorasmian"),
+    ("Zyyy", "Common"),
+    ("Copt", "Coptic"),
+    ("Xsux", "Cuneiform"),
+    ("Cprt", "Cypriot"),
+    ("Cpmn", "Cypro_Minoan"),
+    ("Cyrl", "Cyrillic"),
+    ("Dsrt", "Deseret"),
+    ("Deva", "Devanagari"),
+    ("Diak", "Dives_Akuru"),
+    ("Dogr", "Dogra"),
+    ("Dupl", "Duployan"),
+    ("Egyp", "Egyptian_Hieroglyphs"),
+    ("Elba", "Elbasan"),
+    ("Elym", "Elymaic"),
+    ("Ethi", "Ethiopic"),
+    ("Geor", "Georgian"),
+    ("Glag", "Glagolitic"),
+    ("Goth", "Gothic"),
+    ("Gran", "Grantha"),
+    ("Grek", "Greek"),
+    ("Gujr", "Gujarati"),
+    ("Gong", "Gunjala_Gondi"),
+    ("Guru", "Gurmukhi"),
+    ("Hani", "Han"),
+    ("Hang", "Hangul"),
+    ("Rohg", "Hanifi_Rohingya"),
+    ("Hano", "Hanunoo"),
+    ("Hatr", "Hatran"),
+    ("Hebr", "Hebrew"),
+    ("Hira", "Hiragana"),
+    ("Armi", "Imperial_Aramaic"),
+    ("Zinh", "Inherited"),
+    ("Phli", "Inscriptional_Pahlavi"),
+    ("Prti", "Inscriptional_Parthian"),
+    ("Java", "Javanese"),
+    ("Kthi", "Kaithi"),
+    ("Knda", "Kannada"),
+    ("Kana", "Katakana"),
+    ("Kali", "Kayah_Li"),
+    ("Khar", "Kharoshthi"),
+    ("Kits", "Khitan_Small_Script"),
+    ("Khmr", "Khmer"),
+    ("Khoj", "Khojki"),
+    ("Sind", "Khudawadi"),
+    ("Laoo", "Lao"),
+    ("Latn", "Latin"),
+    ("Lepc", "Lepcha"),
+    ("Limb", "Limbu"),
+    ("Lina", "Linear_A"),
+    ("Linb", "Linear_B"),
+    ("Lisu", "Lisu"),
+    ("Lyci", "Lycian"),
+    ("Lydi", "Lydian"),
+    ("Mahj", "Mahajani"),
+    ("Maka", "Makasar"),
+    ("Mlym", "Malayalam"),
+    ("Mand", "Mandaic"),
+    ("Mani", "Manichaean"),
+    ("Marc", "Marchen"),
+    ("Gonm", "Masaram_Gondi"),
+    ("Medf", "Medefaidrin"),
+    ("Mtei", "Meetei_Mayek"),
+    ("Mend", "Mende_Kikakui"),
+    ("Merc", "Meroitic_Cursive"),
+    ("Mero", "Meroitic_Hieroglyphs"),
+    ("Plrd", "Miao"),
+    ("Modi", "Modi"),
+    ("Mong", "Mongolian"),
+    ("Mroo", "Mro"),
+    ("Mult", "Multani"),
+    ("Mymr", "Myanmar"),
+    ("Nbat", "Nabataean"),
+    ("Nand", "Nandinagari"),
+    ("Talu", "New_Tai_Lue"),
+    ("Newa", "Newa"),
+    ("Nkoo", "Nko"),
+    ("Nshu", "Nushu"),
+    ("Hmnp", "Nyiakeng_Puachue_Hmong"),
+    ("Ogam", "Ogham"),
+    ("Olck", "Ol_Chiki"),
+    ("Hung", "Old_Hungarian"),
+    ("Ital", "Old_Italic"),
+    ("Narb", "Old_North_Arabian"),
+    ("Perm", "Old_Permic"),
+    ("Xpeo", "Old_Persian"),
+    ("Sogo", "Old_Sogdian"),
+    ("Sarb", "Old_South_Arabian"),
+    ("Orkh", "Old_Turkic"),
+    ("Ougr", "Old_Uyghur"),
+    ("Orya", "Oriya"),
+    ("Osge", "Osage"),
+    ("Osma", "Osmanya"),
+    ("Hmng", "Pahawh_Hmong"),
+    ("Palm", "Palmyrene"),
+    ("Pauc", "Pau_Cin_Hau"),
+    ("Phag", "Phags_Pa"),
+    ("Phnx", "Phoenician"),
+    ("Phlp", "Psalter_Pahlavi"),
+    ("Rjng", "Rejang"),
+    ("Runr", "Runic"),
+    ("Samr", "Samaritan"),
+    ("Saur", "Saurashtra"),
+    ("Shrd", "Sharada"),
+    ("Shaw", "Shavian"),
+    ("Sidd", "Siddham"),
+    ("Sgnw", "SignWriting"),
+    ("Sinh", "Sinhala"),
+    ("Sogd", "Sogdian"),
+    ("Sora", "Sora_Sompeng"),
+    ("Soyo", "Soyombo"),
+    ("Sund", "Sundanese"),
+    ("Sylo", "Syloti_Nagri"),
+    ("Syrc", "Syriac"),
+    ("Tglg", "Tagalog"),
+    ("Tagb", "Tagbanwa"),
+    ("Tale", "Tai_Le"),
+    ("Lana", "Tai_Tham"),
+    ("Tavt", "Tai_Viet"),
+    ("Takr", "Takri"),
+    ("Taml", "Tamil"),
+    ("Tnsa", "Tangsa"),
+    ("Tang", "Tangut"),
+    ("Telu", "Telugu"),
+    ("Thaa", "Thaana"),
+    ("Thai", "Thai"),
+    ("Tibt", "Tibetan"),
+    ("Tfng", "Tifinagh"),
+    ("Tirh", "Tirhuta"),
+    ("Toto", "Toto"),
+    ("Ugar", "Ugaritic"),
+    ("Vaii", "Vai"),
+    ("Vith", "Vithkuqi"),
+    ("Wcho", "Wancho"),
+    ("Wara", "Warang_Citi"),
+    ("Yezi", "Yezidi"),
+    ("Yiii", "Yi"),
+    ("Zanb", "Zanabazar_Square"),
+    ("Zzzz", "Unknown"),
+];
+
+/// The maximum number of code points in a run of the names table.
+const NAME_RUN: u32 = 32;
+
+/// A collation element of allkeys.txt: the primary, secondary and tertiary weights, and the variable flag (`*`).
+type CollationElement = (u16, u16, u8, bool);
+
+/// The ranges of the names derived from a prefix (e.g. `CJK UNIFIED IDEOGRAPH-`), and the other names.
+type Names = (Vec<(u32, u32, String)>, BTreeMap<u32, String>);
+
+/// A record of UnicodeData.txt.
+struct UnicodeDataEntry {
+    code_point: u32,
+    fields: Vec<String>,
+}
+
+/// A version of Unicode (major, minor, update).
+type Version = (u8, u8, u8);
+
+/// The generator state.
+struct Generator {
+    ucd_dir: PathBuf,
+    out_dir: PathBuf,
+    /// The version of the Unicode Character Database the tables are generated from.
+    version: Version,
+    unicode_data: Vec<UnicodeDataEntry>,
+}
+
+/// Parse a hexadecimal code point.
+fn parse_cp(s: &str) -> u32 {
+    u32::from_str_radix(s.trim(), 16).unwrap_or_else(|_| panic!("Invalid code point {}", s))
+}
+
+/// Parse a code point or a range of code points (`XXXX..YYYY`).
+fn parse_range(s: &str) -> (u32, u32) {
+    match s.trim().split_once("..") {
+        Some((first, last)) => (parse_cp(first), parse_cp(last)),
+        None => {
+            let cp: u32 = parse_cp(s);
+            (cp, cp)
+        }
+    }
+}
+
+/// Parse a version of Unicode (e.g. `14.0.0`, or `14.0` for the files without an update number).
+fn parse_version(s: &str) -> Version {
+    let parts: Vec<u8> = s
+        .trim()
+        .split('.')
+        .map(|p| {
+            p.parse()
+                .unwrap_or_else(|_| panic!("Invalid version {}", s))
+        })
+        .collect();
+    match parts[..] {
+        [major, minor] => (major, minor, 0),
+        [major, minor, update] => (major, minor, update),
+        _ => panic!("Invalid version {}", s),
+    }
+}
+
+/// Find the version of Unicode in the header of a file: the file name line (e.g. `# DerivedAge-14.0.0.txt`) or the `# Version:` line.
+fn header_version(file: &str, content: &str) -> Option<Version> {
+    let stem: &str = Path::new(file).file_stem()?.to_str()?;
+    content
+        .lines()
+        .take_while(|line| line.starts_with('#') || line.trim().is_empty())
+        .find_map(|line| {
+            let line: &str = line.trim_start_matches('#').trim();
+            if let Some(version) = line.strip_prefix("Version:") {
+                return Some(parse_version(version));
+            }
+            let version: &str = line
+                .strip_prefix(stem)?
+                .strip_prefix('-')?
+                .strip_suffix(".txt")?;
+            Some(parse_version(version))
+        })
+}
+
+/// Merge the adjacent ranges with the same value.
+fn merge_ranges<V: PartialEq + Clone>(mut ranges: Vec<(u32, u32, V)>) -> Vec<(u32, u32, V)> {
+    ranges.sort_by_key(|r| r.0);
+    let mut merged: Vec<(u32, u32, V)> = Vec::new();
+    for (first, last, value) in ranges {
+        match merged.last_mut() {
+            Some(prev) if prev.1 + 1 == first && prev.2 == value => prev.1 = last,
+            _ => merged.push((first, last, value)),
+        }
+    }
+    merged
+}
+
+/// Convert a property value of the Unicode Character Database into the name of an enum variant (e.g. `Regional_Indicator` into `RegionalIndicator`, `LVT` into `Lvt`).
+fn variant_name(value: &str) -> String {
+    value
+        .split('_')
+        .map(|part| {
+            if part.len() > 1 && part.chars().all(|c| c.is_ascii_uppercase()) {
+                part[..1].to_string() + &part[1..].to_ascii_lowercase()
+            } else {
+                part.to_string()
+            }
+        })
+        .collect()
+}
+
+/// Escape a string as a Rust string literal.
+fn string_literal(s: &str) -> String {
+    format!("{:?}", s)
+}
+
+impl Generator {
+    fn new(ucd_dir: PathBuf, out_dir: PathBuf) -> Self {
+        let path: PathBuf = ucd_dir.join("DerivedAge.txt");
+        let content: String =
+            fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+        let version: Version = header_version("DerivedAge.txt", &content)
+            .unwrap_or_else(|| panic!("{}: missing version", path.display()));
+        let mut generator = Generator {
+            ucd_dir,
+            out_dir,
+            version,
+            unicode_data: Vec::new(),
+        };
+        generator.unicode_data = generator.load_unicode_data();
+        generator
+    }
+
+    /// Check that a file is of the version of the Unicode Character Database the tables are generated from.
+    fn check_version(&self, file: &str, version: Version) {
+        assert!(
+            version == self.version,
+            "{} is version {}.{}.{}, but the Unicode Character Database is version {}.{}.{}",
+            file,
+            version.0,
+            version.1,
+            version.2,
+            self.version.0,
+            self.version.1,
+            self.version.2
+        );
+    }
+
+    /// Read a file of the Unicode Character Database, checking the version in its header, if any.
+    fn read(&self, file: &str) -> String {
+        let path: PathBuf = self.ucd_dir.join(file);
+        let content: String =
+            fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+        if let Some(version) = header_version(file, &content) {
+            self.check_version(file, version);
+        }
+        content
+    }
+
+    /// Read the data lines of a file, without comments and blank lines, split into trimmed fields.
+    fn records(&self, file: &str) -> Vec<Vec<String>> {
+        self.read(file)
+            .lines()
+            .map(|line| line.split('#').next().unwrap().trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| line.split(';').map(|f| f.trim().to_string()).collect())
+            .collect()
+    }
+
+    /// Read a file of `range ; value` records.
+    fn property_ranges(&self, file: &str) -> Vec<(u32, u32, String)> {
+        self.records(file)
+            .into_iter()
+            .map(|r| {
+                let (first, last) = parse_range(&r[0]);
+                (first, last, r[1].clone())
+            })
+            .collect()
+    }
+
+    /// Load UnicodeData.txt, with the `First`/`Last` ranges expanded into their first and last entries.
+    fn load_unicode_data(&self) -> Vec<UnicodeDataEntry> {
+        self.read("UnicodeData.txt")
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let fields: Vec<String> = line.split(';').map(|f| f.to_string()).collect();
+                UnicodeDataEntry {
+                    code_point: parse_cp(&fields[0]),
+                    fields,
+                }
+            })
+            .collect()
+    }
+
+    /// Iterate the ranges of UnicodeData.txt: every entry is a range of one code point, except the `First`/`Last` pairs.
+    fn unicode_data_ranges(&self) -> Vec<(u32, u32, &UnicodeDataEntry)> {
+        let mut ranges: Vec<(u32, u32, &UnicodeDataEntry)> = Vec::new();
+        let mut i: usize = 0;
+        while i < self.unicode_data.len() {
+            let entry: &UnicodeDataEntry = &self.unicode_data[i];
+            if entry.fields[1].ends_with(", First>") {
+                ranges.push((entry.code_point, self.unicode_data[i + 1].code_point, entry));
+                i += 2;
+            } else {
+                ranges.push((entry.code_point, entry.code_point, entry));
+                i += 1;
+            }
+        }
+        ranges
+    }
+
+    /// Write a generated file in the output directory, with a header naming the files it is generated from, if any.
+    fn write(&self, file: &str, sources: &[&str], body: &str) {
+        let (major, minor, update) = self.version;
+        let mut content: String = format!(
+            "// Generated from the Unicode Character Database {}.{}.{}",
+            major, minor, update
+        );
+        if !sources.is_empty() {
+            write!(content, " ({})", sources.join(", ")).unwrap();
+        }
+        content.push_str(". Do not edit.\n");
+        content.push('\n');
+        content.push_str(body);
+        let path: PathBuf = self.out_dir.join(file);
+        fs::write(&path, content).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+    }
+
+    /// Format a table of ranges as a `const` slice.
+    fn range_table<V>(
+        name: &str,
+        ty: &str,
+        ranges: &[(u32, u32, V)],
+        value: impl Fn(&V) -> String,
+    ) -> String {
+        let mut s: String = String::new();
+        writeln!(s, "#[rustfmt::skip]").unwrap();
+        writeln!(s, "pub(crate) const {}: &[(u32, u32, {})] = &[", name, ty).unwrap();
+        for (first, last, v) in ranges {
+            writeln!(s, "    (0x{:04X}, 0x{:04X}, {}),", first, last, value(v)).unwrap();
+        }
+        writeln!(s, "];").unwrap();
+        s
+    }
+
+    fn version(&self) {
+        let (major, minor, update) = self.version;
+        let body: String = format!(
+            "pub(crate) const UNICODE_VERSION: (u8, u8, u8) = ({}, {}, {});\n",
+            major, minor, update
+        );
+        self.write("version.rs", &[], &body);
+    }
+
+    fn general_category(&self) {
+        let long: HashMap<&str, &str> = GENERAL_CATEGORIES.iter().copied().collect();
+        let ranges: Vec<(u32, u32, String)> = merge_ranges(
+            self.unicode_data_ranges()
+                .into_iter()
+                .map(|(first, last, e)| (first, last, e.fields[2].clone()))
+                .collect(),
+        );
+        let mut body: String = String::from("use crate::unicode::GeneralCategory as G;\n\n");
+        body.push_str(&Self::range_table("GENERAL_CATEGORY", "G", &ranges, |gc| {
+            format!("G::{}", long[gc.as_str()])
+        }));
+        self.write("general_category.rs", &["UnicodeData.txt"], &body);
+    }
+
+    fn age(&self) {
+        let ranges: Vec<(u32, u32, (u8, u8))> = merge_ranges(
+            self.property_ranges("DerivedAge.txt")
+                .into_iter()
+                .map(|(first, last, age)| {
+                    let (major, minor) = age.split_once('.').unwrap();
+                    (
+                        first,
+                        last,
+                        (major.parse().unwrap(), minor.parse().unwrap()),
+                    )
+                })
+                .collect(),
+        );
+        let body: String = Self::range_table("AGE", "(u8, u8)", &ranges, |(major, minor)| {
+            format!("({}, {})", major, minor)
+        });
+        self.write("age.rs", &["DerivedAge.txt"], &body);
+    }
+
+    fn block(&self) {
+        let ranges: Vec<(u32, u32, String)> = self.property_ranges("Blocks.txt");
+        let body: String = Self::range_table("BLOCK", "&str", &ranges, |b| string_literal(b));
+        self.write("block.rs", &["Blocks.txt"], &body);
+    }
+
+    /// Format a table of mappings from a code point to a sequence of code points as a `const` slice.
+    fn mapping_table(name: &str, mappings: &[(u32, Vec<u32>)]) -> String {
+        let mut s: String = String::new();
+        writeln!(s, "#[rustfmt::skip]").unwrap();
+        writeln!(s, "pub(crate) const {}: &[(u32, &[u32])] = &[", name).unwrap();
+        for (cp, mapping) in mappings {
+            let values: Vec<String> = mapping.iter().map(|m| format!("0x{:04X}", m)).collect();
+            writeln!(s, "    (0x{:04X}, &[{}]),", cp, values.join(", ")).unwrap();
+        }
+        writeln!(s, "];").unwrap();
+        s
+    }
+
+    fn normalization(&self) {
+        let ccc: Vec<(u32, u32, u8)> = merge_ranges(
+            self.unicode_data_ranges()
+                .into_iter()
+                .map(|(first, last, e)| (first, last, e.fields[3].parse().unwrap()))
+                .filter(|(_, _, ccc)| *ccc != 0)
+                .collect(),
+        );
+
+        let mut canonical: Vec<(u32, Vec<u32>)> = Vec::new();
+        let mut compatibility: Vec<(u32, Vec<u32>)> = Vec::new();
+        for entry in &self.unicode_data {
+            let decomposition: &str = &entry.fields[5];
+            if decomposition.is_empty() {
+                continue;
+            }
+            let compat: bool = decomposition.starts_with('<');
+            let mapping: Vec<u32> = decomposition
+                .split(' ')
+                .filter(|d| !d.starts_with('<'))
+                .map(parse_cp)
+                .collect();
+            if compat {
+                compatibility.push((entry.code_point, mapping));
+            } else {
+                canonical.push((entry.code_point, mapping));
+            }
+        }
+
+        let mut exclusions: Vec<(u32, u32)> = Vec::new();
+        let mut quick_checks: BTreeMap<String, Vec<(u32, u32, String)>> = BTreeMap::new();
+        for record in self.records("DerivedNormalizationProps.txt") {
+            let (first, last) = parse_range(&record[0]);
+            match record[1].as_str() {
+                "Full_Composition_Exclusion" => exclusions.push((first, last)),
+                qc @ ("NFC_QC" | "NFD_QC" | "NFKC_QC" | "NFKD_QC") => quick_checks
+                    .entry(qc.to_string())
+                    .or_default()
+                    .push((first, last, record[2].clone())),
+                _ => {}
+            }
+        }
+        let excluded = |cp: u32| exclusions.iter().any(|(f, l)| (*f..=*l).contains(&cp));
+        let mut composition: Vec<(u32, u32, u32)> = canonical
+            .iter()
+            .filter(|(cp, mapping)| mapping.len() == 2 && !excluded(*cp))
+            .map(|(cp, mapping)| (mapping[0], mapping[1], *cp))
+            .collect();
+        composition.sort();
+
+        let mut body: String = String::from("use crate::normalize::IsNormalized as Q;\n\n");
+        body.push_str(&Self::range_table(
+            "CANONICAL_COMBINING_CLASS",
+            "u8",
+            &ccc,
+            |c| c.to_string(),
+        ));
+        body.push('\n');
+        body.push_str(&Self::mapping_table("CANONICAL_DECOMPOSITION", &canonical));
+        body.push('\n');
+        body.push_str(&Self::mapping_table(
+            "COMPATIBILITY_DECOMPOSITION",
+            &compatibility,
+        ));
+        body.push('\n');
+        writeln!(body, "#[rustfmt::skip]").unwrap();
+        writeln!(
+            body,
+            "pub(crate) const COMPOSITION: &[(u32, u32, u32)] = &["
+        )
+        .unwrap();
+        for (first, second, composite) in &composition {
+            writeln!(
+                body,
+                "    (0x{:04X}, 0x{:04X}, 0x{:04X}),",
+                first, second, composite
+            )
+            .unwrap();
+        }
+        writeln!(body, "];").unwrap();
+        for (qc, ranges) in &quick_checks {
+            let name: String = format!("{}_QUICK_CHECK", qc.trim_end_matches("_QC"));
+            body.push('\n');
+            body.push_str(&Self::range_table(
+                &name,
+                "Q",
+                &merge_ranges(ranges.clone()),
+                |v| match v.as_str() {
+                    "N" => String::from("Q::No"),
+                    "M" => String::from("Q::Maybe"),
+                    _ => panic!("Invalid quick check value {}", v),
+                },
+            ));
+        }
+        self.write(
+            "normalization.rs",
+            &["UnicodeData.txt", "DerivedNormalizationProps.txt"],
+            &body,
+        );
+    }
+
+    /// Read the ranges of a binary property from a file of `range ; property` records.
+    fn binary_property(&self, file: &str, property: &str) -> Vec<(u32, u32)> {
+        let ranges: Vec<(u32, u32, ())> = self
+            .property_ranges(file)
+            .into_iter()
+            .filter(|(_, _, p)| p == property)
+            .map(|(first, last, _)| (first, last, ()))
+            .collect();
+        merge_ranges(ranges)
+            .into_iter()
+            .map(|(first, last, _)| (first, last))
+            .collect()
+    }
+
+    /// Format a set of ranges as a `const` slice.
+    fn range_set(name: &str, ranges: &[(u32, u32)]) -> String {
+        let mut s: String = String::new();
+        writeln!(s, "#[rustfmt::skip]").unwrap();
+        writeln!(s, "pub(crate) const {}: &[(u32, u32)] = &[", name).unwrap();
+        for (first, last) in ranges {
+            writeln!(s, "    (0x{:04X}, 0x{:04X}),", first, last).unwrap();
+        }
+        writeln!(s, "];").unwrap();
+        s
+    }
+
+    fn case(&self) {
+        // Simple mappings of UnicodeData.txt (uppercase, lowercase, titlecase)
+        let mut mappings: [BTreeMap<u32, Vec<u32>>; 3] = Default::default();
+        for entry in &self.unicode_data {
+            for (i, field) in [12, 13, 14].iter().enumerate() {
+                let mut mapping: &str = entry.fields[*field].trim();
+                // A missing titlecase mapping is the same as the uppercase one
+                if *field == 14 && mapping.is_empty() {
+                    mapping = entry.fields[12].trim();
+                }
+                if !mapping.is_empty() {
+                    mappings[i].insert(entry.code_point, vec![parse_cp(mapping)]);
+                }
+            }
+        }
+        // Unconditional full mappings of SpecialCasing.txt (lowercase, titlecase, uppercase)
+        for record in self.records("SpecialCasing.txt") {
+            if record.len() > 4 && !record[4].is_empty() {
+                continue;
+            }
+            let cp: u32 = parse_cp(&record[0]);
+            for (i, field) in [2, 0, 1].iter().enumerate() {
+                let mapping: Vec<u32> =
+                    record[field + 1].split_whitespace().map(parse_cp).collect();
+                if mapping == [cp] {
+                    mappings[i].remove(&cp);
+                } else {
+                    mappings[i].insert(cp, mapping);
+                }
+            }
+        }
+        let mut folding: Vec<(u32, Vec<u32>)> = Vec::new();
+        for record in self.records("CaseFolding.txt") {
+            if record[1] == "C" || record[1] == "F" {
+                let mapping: Vec<u32> = record[2].split_whitespace().map(parse_cp).collect();
+                folding.push((parse_cp(&record[0]), mapping));
+            }
+        }
+        let table = |map: &BTreeMap<u32, Vec<u32>>| -> Vec<(u32, Vec<u32>)> {
+            map.iter()
+                .filter(|(cp, mapping)| mapping.as_slice() != [**cp])
+                .map(|(cp, mapping)| (*cp, mapping.clone()))
+                .collect()
+        };
+
+        let mut body: String = String::new();
+        body.push_str(&Self::mapping_table("UPPERCASE", &table(&mappings[0])));
+        body.push('\n');
+        body.push_str(&Self::mapping_table("LOWERCASE", &table(&mappings[1])));
+        body.push('\n');
+        body.push_str(&Self::mapping_table("TITLECASE", &table(&mappings[2])));
+        body.push('\n');
+        body.push_str(&Self::mapping_table("CASE_FOLDING", &folding));
+        body.push('\n');
+        body.push_str(&Self::range_set(
+            "CASED",
+            &self.binary_property("DerivedCoreProperties.txt", "Cased"),
+        ));
+        body.push('\n');
+        body.push_str(&Self::range_set(
+            "CASE_IGNORABLE",
+            &self.binary_property("DerivedCoreProperties.txt", "Case_Ignorable"),
+        ));
+        body.push('\n');
+        body.push_str(&Self::range_set(
+            "SOFT_DOTTED",
+            &self.binary_property("PropList.txt", "Soft_Dotted"),
+        ));
+        self.write(
+            "case.rs",
+            &[
+                "UnicodeData.txt",
+                "SpecialCasing.txt",
+                "CaseFolding.txt",
+                "DerivedCoreProperties.txt",
+                "PropList.txt",
+            ],
+            &body,
+        );
+    }
+
+    fn segment(&self) {
+        let mut body: String = String::from(
+            "use crate::segment::GraphemeClusterBreak as G;\n\
+             use crate::segment::SentenceBreak as S;\n\
+             use crate::segment::WordBreak as W;\n",
+        );
+        for (name, ty, file) in [
+            ("GRAPHEME_CLUSTER_BREAK", "G", "GraphemeBreakProperty.txt"),
+            ("WORD_BREAK", "W", "WordBreakProperty.txt"),
+            ("SENTENCE_BREAK", "S", "SentenceBreakProperty.txt"),
+        ] {
+            let ranges: Vec<(u32, u32, String)> = merge_ranges(self.property_ranges(file));
+            body.push('\n');
+            body.push_str(&Self::range_table(name, ty, &ranges, |v| {
+                format!("{}::{}", ty, variant_name(v))
+            }));
+        }
+        body.push('\n');
+        body.push_str(&Self::range_set(
+            "EXTENDED_PICTOGRAPHIC",
+            &self.binary_property("emoji-data.txt", "Extended_Pictographic"),
+        ));
+        self.write(
+            "segment.rs",
+            &[
+                "GraphemeBreakProperty.txt",
+                "WordBreakProperty.txt",
+                "SentenceBreakProperty.txt",
+                "emoji-data.txt",
+            ],
+            &body,
+        );
+    }
+
+    /// Generate a table of ranges from a file of `range ; value` records, without the ranges of the default value.
+    fn enumerated_property(
+        &self,
+        file: &str,
+        name: &str,
+        ty: &str,
+        variants: &[(&str, &str)],
+        default: &str,
+    ) -> String {
+        let variants: HashMap<&str, &str> = variants.iter().copied().collect();
+        let ranges: Vec<(u32, u32, String)> = merge_ranges(
+            self.property_ranges(file)
+                .into_iter()
+                .filter(|(_, _, v)| v != default)
+                .collect(),
+        );
+        Self::range_table(name, ty, &ranges, |v| {
+            format!("{}::{}", ty, variants[v.as_str()])
+        })
+    }
+
+    fn line_break(&self) {
+        let mut body: String = String::from("use crate::linebreak::LineBreak as L;\n\n");
+        body.push_str(&self.enumerated_property(
+            "LineBreak.txt",
+            "LINE_BREAK",
+            "L",
+            LINE_BREAKS,
+            "XX",
+        ));
+        self.write("line_break.rs", &["LineBreak.txt"], &body);
+    }
+
+    fn east_asian_width(&self) {
+        let mut body: String = String::from("use crate::unicode::EastAsianWidth as E;\n\n");
+        body.push_str(&self.enumerated_property(
+            "EastAsianWidth.txt",
+            "EAST_ASIAN_WIDTH",
+            "E",
+            EAST_ASIAN_WIDTHS,
+            "N",
+        ));
+        self.write("east_asian_width.rs", &["EastAsianWidth.txt"], &body);
+    }
+
+    fn numeric(&self) {
+        let mut body: String = String::from("use crate::unicode::NumericType as N;\n\n");
+        body.push_str(&self.enumerated_property(
+            "DerivedNumericType.txt",
+            "NUMERIC_TYPE",
+            "N",
+            &[
+                ("Decimal", "Decimal"),
+                ("Digit", "Digit"),
+                ("Numeric", "Numeric"),
+            ],
+            "None",
+        ));
+        // The values as fractions (numerator, denominator), from the last field of the records
+        let values: Vec<(u32, u32, (i64, u32))> = self
+            .records("DerivedNumericValues.txt")
+            .into_iter()
+            .map(|r| {
+                let (first, last) = parse_range(&r[0]);
+                let (numerator, denominator) = r[3].split_once('/').unwrap_or((&r[3], "1"));
+                (
+                    first,
+                    last,
+                    (numerator.parse().unwrap(), denominator.parse().unwrap()),
+                )
+            })
+            .collect();
+        body.push('\n');
+        body.push_str(&Self::range_table(
+            "NUMERIC_VALUE",
+            "(i64, u32)",
+            &merge_ranges(values),
+            |(numerator, denominator)| format!("({}, {})", numerator, denominator),
+        ));
+        self.write(
+            "numeric.rs",
+            &["DerivedNumericType.txt", "DerivedNumericValues.txt"],
+            &body,
+        );
+    }
+
+    fn emoji(&self) {
+        let mut body: String = String::new();
+        for (name, property) in [
+            ("EMOJI", "Emoji"),
+            ("EMOJI_PRESENTATION", "Emoji_Presentation"),
+            ("EMOJI_MODIFIER", "Emoji_Modifier"),
+            ("EMOJI_MODIFIER_BASE", "Emoji_Modifier_Base"),
+            ("EMOJI_COMPONENT", "Emoji_Component"),
+        ] {
+            if !body.is_empty() {
+                body.push('\n');
+            }
+            body.push_str(&Self::range_set(
+                name,
+                &self.binary_property("emoji-data.txt", property),
+            ));
+        }
+        self.write("emoji.rs", &["emoji-data.txt"], &body);
+    }
+
+    fn identifier(&self) {
+        let mut body: String = String::new();
+        for (name, property) in [("XID_START", "XID_Start"), ("XID_CONTINUE", "XID_Continue")] {
+            if !body.is_empty() {
+                body.push('\n');
+            }
+            body.push_str(&Self::range_set(
+                name,
+                &self.binary_property("DerivedCoreProperties.txt", property),
+            ));
+        }
+        self.write("identifier.rs", &["DerivedCoreProperties.txt"], &body);
+    }
+
+    fn whitespace(&self) {
+        let mut body: String = String::new();
+        for (name, file, property) in [
+            ("WHITE_SPACE", "PropList.txt", "White_Space"),
+            ("PATTERN_WHITE_SPACE", "PropList.txt", "Pattern_White_Space"),
+            (
+                "DEFAULT_IGNORABLE_CODE_POINT",
+                "DerivedCoreProperties.txt",
+                "Default_Ignorable_Code_Point",
+            ),
+        ] {
+            if !body.is_empty() {
+                body.push('\n');
+            }
+            body.push_str(&Self::range_set(
+                name,
+                &self.binary_property(file, property),
+            ));
+        }
+        self.write(
+            "whitespace.rs",
+            &["PropList.txt", "DerivedCoreProperties.txt"],
+            &body,
+        );
+    }
+
+    fn script(&self) {
+        let variants: HashMap<&str, String> = SCRIPTS
+            .iter()
+            .map(|(_, long)| (*long, variant_name(long)))
+            .collect();
+        let by_code: HashMap<&str, String> = SCRIPTS
+            .iter()
+            .map(|(short, long)| (*short, variant_name(long)))
+            .collect();
+        let ranges: Vec<(u32, u32, String)> = merge_ranges(
+            self.property_ranges("Scripts.txt")
+                .into_iter()
+                .filter(|(_, _, v)| v != "Unknown")
+                .collect(),
+        );
+        let mut body: String = String::from("use crate::unicode::Script as S;\n\n");
+        body.push_str(&Self::range_table("SCRIPT", "S", &ranges, |v| {
+            format!("S::{}", variants[v.as_str()])
+        }));
+        body.push('\n');
+        let extensions: Vec<(u32, u32, String)> =
+            merge_ranges(self.property_ranges("ScriptExtensions.txt"));
+        body.push_str(&Self::range_table(
+            "SCRIPT_EXTENSIONS",
+            "&[S]",
+            &extensions,
+            |v| {
+                let scripts: Vec<String> = v
+                    .split_whitespace()
+                    .map(|s| format!("S::{}", by_code[s]))
+                    .collect();
+                format!("&[{}]", scripts.join(", "))
+            },
+        ));
+        self.write("script.rs", &["Scripts.txt", "ScriptExtensions.txt"], &body);
+    }
+
+    fn security(&self) {
+        let confusables: Vec<(u32, Vec<u32>)> = self
+            .records("confusables.txt")
+            .into_iter()
+            .map(|r| {
+                (
+                    parse_cp(&r[0]),
+                    r[1].split_whitespace().map(parse_cp).collect(),
+                )
+            })
+            .collect();
+        let mut body: String = Self::mapping_table("CONFUSABLES", &confusables);
+        body.push('\n');
+        body.push_str(&Self::range_set(
+            "IDENTIFIER_ALLOWED",
+            &self.binary_property("IdentifierStatus.txt", "Allowed"),
+        ));
+        self.write(
+            "security.rs",
+            &["confusables.txt", "IdentifierStatus.txt"],
+            &body,
+        );
+    }
+
+    fn collation(&self) {
+        let mut version: String = String::new();
+        let mut implicit: Vec<(u32, u32, u16)> = Vec::new();
+        let mut singles: Vec<(u32, Vec<CollationElement>)> = Vec::new();
+        let mut contractions: Vec<(Vec<u32>, Vec<CollationElement>)> = Vec::new();
+        for line in self.read("allkeys.txt").lines() {
+            let line: &str = line.split('#').next().unwrap().trim();
+            if let Some(v) = line.strip_prefix("@version") {
+                version = v.trim().to_string();
+            } else if let Some(w) = line.strip_prefix("@implicitweights") {
+                let (range, base) = w.split_once(';').unwrap();
+                let (first, last) = parse_range(range);
+                implicit.push((first, last, u16::from_str_radix(base.trim(), 16).unwrap()));
+            } else if let Some((cps, elements)) = line.split_once(';') {
+                let cps: Vec<u32> = cps.split_whitespace().map(parse_cp).collect();
+                let elements: Vec<CollationElement> = elements
+                    .trim()
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .split("][")
+                    .map(|e| {
+                        let weights: Vec<&str> = e[1..].split('.').collect();
+                        (
+                            u16::from_str_radix(weights[0], 16).unwrap(),
+                            u16::from_str_radix(weights[1], 16).unwrap(),
+                            u8::from_str_radix(weights[2], 16).unwrap(),
+                            e.starts_with('*'),
+                        )
+                    })
+                    .collect();
+                match cps.len() {
+                    1 => singles.push((cps[0], elements)),
+                    _ => contractions.push((cps, elements)),
+                }
+            }
+        }
+        singles.sort();
+        contractions.sort();
+        implicit.sort();
+        self.check_version("allkeys.txt", parse_version(&version));
+
+        // The variable collation elements are the ones with the lowest primary weights
+        let all = || {
+            singles
+                .iter()
+                .map(|s| &s.1)
+                .chain(contractions.iter().map(|c| &c.1))
+                .flatten()
+        };
+        let max_variable: u16 = all().filter(|e| e.3).map(|e| e.0).max().unwrap();
+        assert!(
+            all().all(|e| e.3 == (e.0 != 0 && e.0 <= max_variable)),
+            "The variable collation elements are not a range of primary weights"
+        );
+
+        let elements = |elements: &[(u16, u16, u8, bool)]| -> String {
+            let elements: Vec<String> = elements
+                .iter()
+                .map(|(p, s, t, _)| format!("(0x{:04X}, 0x{:04X}, 0x{:02X})", p, s, t))
+                .collect();
+            format!("&[{}]", elements.join(", "))
+        };
+        let mut body: String = String::from("use crate::collate::CollationElement as C;\n\n");
+        writeln!(
+            body,
+            "// The Default Unicode Collation Element Table, version {}.",
+            version
+        )
+        .unwrap();
+        body.push('\n');
+        writeln!(
+            body,
+            "/// The highest primary weight of the variable collation elements."
+        )
+        .unwrap();
+        writeln!(
+            body,
+            "pub(crate) const MAX_VARIABLE: u16 = 0x{:04X};",
+            max_variable
+        )
+        .unwrap();
+        body.push('\n');
+        writeln!(body, "#[rustfmt::skip]").unwrap();
+        writeln!(
+            body,
+            "pub(crate) const COLLATION_ELEMENTS: &[(u32, &[C])] = &["
+        )
+        .unwrap();
+        for (cp, e) in &singles {
+            writeln!(body, "    (0x{:04X}, {}),", cp, elements(e)).unwrap();
+        }
+        writeln!(body, "];").unwrap();
+        body.push('\n');
+        writeln!(body, "#[rustfmt::skip]").unwrap();
+        writeln!(
+            body,
+            "pub(crate) const CONTRACTIONS: &[(&[u32], &[C])] = &["
+        )
+        .unwrap();
+        for (cps, e) in &contractions {
+            let cps: Vec<String> = cps.iter().map(|cp| format!("0x{:04X}", cp)).collect();
+            writeln!(body, "    (&[{}], {}),", cps.join(", "), elements(e)).unwrap();
+        }
+        writeln!(body, "];").unwrap();
+        body.push('\n');
+        body.push_str(&Self::range_table(
+            "IMPLICIT_WEIGHTS",
+            "u16",
+            &implicit,
+            |base| format!("0x{:04X}", base),
+        ));
+        body.push('\n');
+        body.push_str(&Self::range_set(
+            "UNIFIED_IDEOGRAPH",
+            &self.binary_property("PropList.txt", "Unified_Ideograph"),
+        ));
+        self.write("collate.rs", &["allkeys.txt", "PropList.txt"], &body);
+    }
+
+    fn bidi(&self) {
+        let mut body: String = String::from("use crate::bidi::BidiClass as B;\n\n");
+        body.push_str(&self.enumerated_property(
+            "DerivedBidiClass.txt",
+            "BIDI_CLASS",
+            "B",
+            BIDI_CLASSES,
+            "L",
+        ));
+        body.push('\n');
+        writeln!(body, "#[rustfmt::skip]").unwrap();
+        writeln!(
+            body,
+            "pub(crate) const BIDI_PAIRED_BRACKETS: &[(u32, u32, bool)] = &["
+        )
+        .unwrap();
+        for r in self.records("BidiBrackets.txt") {
+            let opening: bool = r[2] == "o";
+            writeln!(
+                body,
+                "    (0x{:04X}, 0x{:04X}, {}),",
+                parse_cp(&r[0]),
+                parse_cp(&r[1]),
+                opening
+            )
+            .unwrap();
+        }
+        writeln!(body, "];").unwrap();
+        body.push('\n');
+        writeln!(body, "#[rustfmt::skip]").unwrap();
+        writeln!(
+            body,
+            "pub(crate) const BIDI_MIRRORING_GLYPH: &[(u32, u32)] = &["
+        )
+        .unwrap();
+        for r in self.records("BidiMirroring.txt") {
+            writeln!(
+                body,
+                "    (0x{:04X}, 0x{:04X}),",
+                parse_cp(&r[0]),
+                parse_cp(&r[1])
+            )
+            .unwrap();
+        }
+        writeln!(body, "];").unwrap();
+        self.write(
+            "bidi.rs",
+            &[
+                "DerivedBidiClass.txt",
+                "BidiBrackets.txt",
+                "BidiMirroring.txt",
+            ],
+            &body,
+        );
+    }
+
+    /// Split the names into the derived ones (a prefix followed by the code point in hexadecimal) and the others.
+    fn names(&self) -> Names {
+        let mut derived: Vec<(u32, u32, String)> = Vec::new();
+        let mut names: BTreeMap<u32, String> = BTreeMap::new();
+        for (first, last, entry) in self.unicode_data_ranges() {
+            let name: &str = &entry.fields[1];
+            if let Some(range_name) = name.strip_prefix('<') {
+                // Only the ideographs ranges have a derived name (NR2), the Hangul syllables are derived by algorithm (NR1)
+                if range_name.starts_with("CJK Ideograph") {
+                    derived.push((first, last, "CJK UNIFIED IDEOGRAPH-".to_string()));
+                } else if range_name.starts_with("Tangut Ideograph") {
+                    derived.push((first, last, "TANGUT IDEOGRAPH-".to_string()));
+                }
+                continue;
+            }
+            let hex: String = format!("-{:04X}", first);
+            match name.strip_suffix(&hex) {
+                Some(prefix) => derived.push((first, last, format!("{}-", prefix))),
+                None => {
+                    names.insert(first, name.to_string());
+                }
+            }
+        }
+        (merge_ranges(derived), names)
+    }
+
+    fn name(&self) {
+        let (derived, names) = self.names();
+
+        // Words sorted by frequency, so that the most common ones are first
+        let mut frequency: HashMap<&str, usize> = HashMap::new();
+        for name in names.values() {
+            for word in name.split(' ') {
+                *frequency.entry(word).or_default() += 1;
+            }
+        }
+        let mut words: Vec<&str> = frequency.keys().copied().collect();
+        words.sort_by(|a, b| frequency[b].cmp(&frequency[a]).then(a.cmp(b)));
+        assert!(words.len() < 0x8000, "Too many words in the names");
+        let index: HashMap<&str, usize> = words.iter().enumerate().map(|(i, w)| (*w, i)).collect();
+
+        // Runs of consecutive named code points, each pointing to the data of its first name
+        let mut data: Vec<u16> = Vec::new();
+        let mut runs: Vec<(u32, u32, u32)> = Vec::new();
+        for (cp, name) in &names {
+            match runs.last_mut() {
+                Some(run) if run.1 + 1 == *cp && run.1 - run.0 + 1 < NAME_RUN => run.1 = *cp,
+                _ => runs.push((*cp, *cp, data.len() as u32)),
+            }
+            let name_words: Vec<&str> = name.split(' ').collect();
+            for (i, word) in name_words.iter().enumerate() {
+                let mut w: u16 = index[word] as u16;
+                if i == name_words.len() - 1 {
+                    w |= 0x8000;
+                }
+                data.push(w);
+            }
+        }
+
+        let mut body: String = String::new();
+        writeln!(body, "#[rustfmt::skip]").unwrap();
+        writeln!(body, "pub(crate) const NAME_WORDS: &[&str] = &[").unwrap();
+        for word in &words {
+            writeln!(body, "    {},", string_literal(word)).unwrap();
+        }
+        writeln!(body, "];\n").unwrap();
+        writeln!(body, "#[rustfmt::skip]").unwrap();
+        writeln!(body, "pub(crate) const NAME_DATA: &[u16] = &[").unwrap();
+        for chunk in data.chunks(16) {
+            let line: Vec<String> = chunk.iter().map(|w| format!("0x{:04X}", w)).collect();
+            writeln!(body, "    {},", line.join(", ")).unwrap();
+        }
+        writeln!(body, "];\n").unwrap();
+        let run_values: Vec<(u32, u32, u32)> = runs;
+        body.push_str(&Self::range_table("NAME_RANGES", "u32", &run_values, |o| {
+            o.to_string()
+        }));
+        body.push('\n');
+        body.push_str(&Self::range_table(
+            "DERIVED_NAME_RANGES",
+            "&str",
+            &derived,
+            |p| string_literal(p),
+        ));
+        body.push('\n');
+        writeln!(body, "#[rustfmt::skip]").unwrap();
+        writeln!(body, "pub(crate) const NAME_ALIASES: &[(u32, &str)] = &[").unwrap();
+        for record in self.records("NameAliases.txt") {
+            writeln!(
+                body,
+                "    (0x{:04X}, {}),",
+                parse_cp(&record[0]),
+                string_literal(&record[1])
+            )
+            .unwrap();
+        }
+        writeln!(body, "];").unwrap();
+        self.write("names.rs", &["UnicodeData.txt", "NameAliases.txt"], &body);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() != 3 {
+        eprintln!("Usage: {} <ucd directory> <output directory>", args[0]);
+        std::process::exit(1);
+    }
+    let generator = Generator::new(PathBuf::from(&args[1]), PathBuf::from(&args[2]));
+    assert!(
+        Path::new(&generator.out_dir).is_dir(),
+        "Invalid output directory"
+    );
+    generator.version();
+    generator.general_category();
+    generator.age();
+    generator.block();
+    generator.name();
+    generator.normalization();
+    generator.case();
+    generator.segment();
+    generator.line_break();
+    generator.east_asian_width();
+    generator.numeric();
+    generator.emoji();
+    generator.bidi();
+    generator.identifier();
+    generator.script();
+    generator.security();
+    generator.collation();
+    generator.whitespace();
+}
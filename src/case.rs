@@ -0,0 +1,443 @@
+/*!
+Full case mapping and case folding of unicode code points.
+
+# Case mapping
+A vector of unicode code points is mapped to uppercase, lowercase or titlecase using the full case mappings of the Unicode Standard:
+* The mappings can change the length of the vector (e.g. `ß` is uppercased to `SS`, `ŉ` to `ʼN`).
+* The unconditional mappings of `SpecialCasing.txt` take precedence over the simple mappings of `UnicodeData.txt`.
+* The conditional mappings of `SpecialCasing.txt` depend on the context of the code point and on the [locale](CaseLocale).
+
+## Conditions
+
+| Condition           | Mapping                                                                                          |
+|---------------------|--------------------------------------------------------------------------------------------------|
+| `Final_Sigma`       | `Σ` is lowercased to `ς` at the end of a word (a cased letter before it, none after it).          |
+| `After_Soft_Dotted` | Lithuanian: `U+0307` is removed in uppercase and titlecase after a soft dotted letter (e.g. `i`). |
+| `More_Above`        | Lithuanian: `I`, `J` and `Į` keep their dot (`U+0307`) in lowercase before an accent above.       |
+| `After_I`           | Turkish, Azerbaijani: `U+0307` is removed in lowercase after `I`.                                 |
+| `Not_Before_Dot`    | Turkish, Azerbaijani: `I` is lowercased to `ı`, unless it is followed by `U+0307`.               |
+
+Besides, in Turkish and Azerbaijani `i` is uppercased to `İ` and `İ` is lowercased to `i`, and in Lithuanian `Ì`, `Í` and `Ĩ` keep their dot in lowercase.
+
+## Titlecase
+The words are found with the word boundaries of [`crate::segment`]. In every word, the first letter or number is mapped to titlecase (if it is cased) and the code points after it to lowercase, so that `o'neil` is titlecased as `O'neil` and `1st place` as `1st Place`.
+
+# Case folding
+The case folding maps the code points to a form without case differences, for case-insensitive comparisons. It uses the full (`C` and `F`) mappings of `CaseFolding.txt`, or the Turkic (`T`) ones for Turkish and Azerbaijani.
+
+**Note**:
+
+* The case folding is not a lowercase mapping: for instance `ß` is folded to `ss`, and the Cherokee letters are folded to uppercase.
+* [`caseless_eq`] compares two vectors with the canonical caseless matching of the Unicode Standard, so that the precomposed and decomposed forms match too.
+*/
+
+use crate::normalize;
+use crate::segment;
+use crate::tables;
+use crate::unicode;
+
+/// The COMBINING DOT ABOVE.
+const DOT_ABOVE: u32 = 0x0307;
+
+/// The canonical combining class of the accents above.
+const CCC_ABOVE: u8 = 230;
+
+/// The full case mappings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CaseMapping {
+    Upper,
+    Lower,
+    Title,
+}
+
+/// Find the mapping of a code point in a table of mappings.
+///
+/// # Parameters
+/// * `table`: [`&[(u32, &[u32])]`] - A table of mappings, sorted by code point.
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The mapping of the code point, if any.
+fn find_mapping(
+    table: &'static [(u32, &'static [u32])],
+    code_point: u32,
+) -> Option<&'static [u32]> {
+    table
+        .binary_search_by_key(&code_point, |(cp, _)| *cp)
+        .ok()
+        .map(|i| table[i].1)
+}
+
+/// Check if a code point is cased (`Cased` property).
+fn is_cased(code_point: u32) -> bool {
+    tables::in_ranges(tables::case::CASED, code_point)
+}
+
+/// Check if a code point is case-ignorable (`Case_Ignorable` property).
+fn is_case_ignorable(code_point: u32) -> bool {
+    tables::in_ranges(tables::case::CASE_IGNORABLE, code_point)
+}
+
+/// Check the `Final_Sigma` condition: the code point is preceded by a cased letter (and case-ignorable code points), and it is not followed by a cased letter (after case-ignorable code points).
+fn is_final_sigma(unicode_cp: &[u32], i: usize) -> bool {
+    let before: bool = unicode_cp[..i]
+        .iter()
+        .rev()
+        .find(|cp| !is_case_ignorable(**cp))
+        .is_some_and(|cp| is_cased(*cp));
+    let after: bool = unicode_cp[i + 1..]
+        .iter()
+        .find(|cp| !is_case_ignorable(**cp))
+        .is_some_and(|cp| is_cased(*cp));
+    before && !after
+}
+
+/// Find the code point before the code point at index `i`, skipping the code points with a canonical combining class different from `0` and `230`.
+fn previous_base(unicode_cp: &[u32], i: usize) -> Option<u32> {
+    unicode_cp[..i]
+        .iter()
+        .rev()
+        .find(|cp| matches!(normalize::canonical_combining_class(**cp), 0 | CCC_ABOVE))
+        .copied()
+}
+
+/// Check the `After_Soft_Dotted` condition: there is a soft dotted code point before, with no code point with a canonical combining class `0` or `230` between them.
+fn is_after_soft_dotted(unicode_cp: &[u32], i: usize) -> bool {
+    previous_base(unicode_cp, i).is_some_and(|cp| tables::in_ranges(tables::case::SOFT_DOTTED, cp))
+}
+
+/// Check the `After_I` condition: there is an `I` before, with no code point with a canonical combining class `0` or `230` between them.
+fn is_after_i(unicode_cp: &[u32], i: usize) -> bool {
+    previous_base(unicode_cp, i) == Some(0x49)
+}
+
+/// Check the `More_Above` condition: the code point is followed by an accent above, with no code point with a canonical combining class `0` between them.
+fn is_more_above(unicode_cp: &[u32], i: usize) -> bool {
+    unicode_cp[i + 1..]
+        .iter()
+        .map(|cp| normalize::canonical_combining_class(*cp))
+        .take_while(|ccc| *ccc != 0)
+        .any(|ccc| ccc == CCC_ABOVE)
+}
+
+/// Check the `Before_Dot` condition: the code point is followed by `U+0307`, with no code point with a canonical combining class `0` or `230` between them.
+fn is_before_dot(unicode_cp: &[u32], i: usize) -> bool {
+    unicode_cp[i + 1..]
+        .iter()
+        .find(|cp| {
+            **cp == DOT_ABOVE || matches!(normalize::canonical_combining_class(**cp), 0 | CCC_ABOVE)
+        })
+        .is_some_and(|cp| *cp == DOT_ABOVE)
+}
+
+/// Map the code point at index `i`, appending the mapping to a vector.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points, the context of the code point.
+/// * `i`: [`usize`] - The index of the code point.
+/// * `mapping`: [`CaseMapping`] - The case mapping.
+/// * `locale`: [`CaseLocale`] - The locale of the conditional mappings.
+/// * `mapped`: [`&mut Vec<u32>`] - The vector the mapping is appended to.
+fn map_code_point(
+    unicode_cp: &[u32],
+    i: usize,
+    mapping: CaseMapping,
+    locale: CaseLocale,
+    mapped: &mut Vec<u32>,
+) {
+    let code_point: u32 = unicode_cp[i];
+    let special: Option<&[u32]> = match (mapping, locale, code_point) {
+        (CaseMapping::Lower, _, 0x3A3) if is_final_sigma(unicode_cp, i) => Some(&[0x3C2]),
+        // Turkish and Azerbaijani
+        (CaseMapping::Lower, CaseLocale::Turkish | CaseLocale::Azerbaijani, 0x130) => Some(&[0x69]),
+        (CaseMapping::Lower, CaseLocale::Turkish | CaseLocale::Azerbaijani, DOT_ABOVE)
+            if is_after_i(unicode_cp, i) =>
+        {
+            Some(&[])
+        }
+        (CaseMapping::Lower, CaseLocale::Turkish | CaseLocale::Azerbaijani, 0x49)
+            if !is_before_dot(unicode_cp, i) =>
+        {
+            Some(&[0x131])
+        }
+        (
+            CaseMapping::Upper | CaseMapping::Title,
+            CaseLocale::Turkish | CaseLocale::Azerbaijani,
+            0x69,
+        ) => Some(&[0x130]),
+        // Lithuanian
+        (CaseMapping::Lower, CaseLocale::Lithuanian, 0x49) if is_more_above(unicode_cp, i) => {
+            Some(&[0x69, DOT_ABOVE])
+        }
+        (CaseMapping::Lower, CaseLocale::Lithuanian, 0x4A) if is_more_above(unicode_cp, i) => {
+            Some(&[0x6A, DOT_ABOVE])
+        }
+        (CaseMapping::Lower, CaseLocale::Lithuanian, 0x12E) if is_more_above(unicode_cp, i) => {
+            Some(&[0x12F, DOT_ABOVE])
+        }
+        (CaseMapping::Lower, CaseLocale::Lithuanian, 0xCC) => Some(&[0x69, DOT_ABOVE, 0x300]),
+        (CaseMapping::Lower, CaseLocale::Lithuanian, 0xCD) => Some(&[0x69, DOT_ABOVE, 0x301]),
+        (CaseMapping::Lower, CaseLocale::Lithuanian, 0x128) => Some(&[0x69, DOT_ABOVE, 0x303]),
+        (CaseMapping::Upper | CaseMapping::Title, CaseLocale::Lithuanian, DOT_ABOVE)
+            if is_after_soft_dotted(unicode_cp, i) =>
+        {
+            Some(&[])
+        }
+        _ => None,
+    };
+    let table: &[(u32, &[u32])] = match mapping {
+        CaseMapping::Upper => tables::case::UPPERCASE,
+        CaseMapping::Lower => tables::case::LOWERCASE,
+        CaseMapping::Title => tables::case::TITLECASE,
+    };
+    match special.or_else(|| find_mapping(table, code_point)) {
+        Some(m) => mapped.extend_from_slice(m),
+        None => mapped.push(code_point),
+    }
+}
+
+/// Map a vector of unicode code points to uppercase or lowercase.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+/// * `mapping`: [`CaseMapping`] - The case mapping.
+/// * `locale`: [`CaseLocale`] - The locale of the conditional mappings.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the mapped unicode code points.
+fn map_case(unicode_cp: &[u32], mapping: CaseMapping, locale: CaseLocale) -> Vec<u32> {
+    let mut mapped: Vec<u32> = Vec::with_capacity(unicode_cp.len());
+    for i in 0..unicode_cp.len() {
+        map_code_point(unicode_cp, i, mapping, locale, &mut mapped);
+    }
+    mapped
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// The locale of the language-sensitive case mappings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseLocale {
+    /// The mappings of the Unicode Standard, without language-sensitive tailoring.
+    Root,
+    /// Turkish (`tr`): dotted and dotless `i`.
+    Turkish,
+    /// Azerbaijani (`az`): dotted and dotless `i`, like Turkish.
+    Azerbaijani,
+    /// Lithuanian (`lt`): `i` keeps its dot under accents above.
+    Lithuanian,
+}
+
+/// Map a vector of unicode code points to uppercase.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the uppercase unicode code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x73, 0x74, 0x72, 0x61, 0xdf, 0x65]; // "straße"
+/// assert_eq!(to_upper(&v), vec![0x53, 0x54, 0x52, 0x41, 0x53, 0x53, 0x45]); // "STRASSE"
+/// ```
+pub fn to_upper<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    to_upper_locale(unicode_cp, CaseLocale::Root)
+}
+
+/// Map a vector of unicode code points to uppercase, with the mappings of a locale.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `locale`: [`CaseLocale`] - The locale.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the uppercase unicode code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x69]; // "i"
+/// assert_eq!(to_upper_locale(&v, CaseLocale::Turkish), vec![0x130]); // "İ"
+/// ```
+pub fn to_upper_locale<T: AsRef<Vec<u32>>>(unicode_cp: T, locale: CaseLocale) -> Vec<u32> {
+    map_case(unicode_cp.as_ref(), CaseMapping::Upper, locale)
+}
+
+/// Map a vector of unicode code points to lowercase.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the lowercase unicode code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x39f, 0x394, 0x3a5, 0x3a3, 0x3a3, 0x395, 0x3a5, 0x3a3]; // "ΟΔΥΣΣΕΥΣ"
+/// assert_eq!(to_lower(&v), vec![0x3bf, 0x3b4, 0x3c5, 0x3c3, 0x3c3, 0x3b5, 0x3c5, 0x3c2]); // "οδυσσευς"
+/// ```
+pub fn to_lower<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    to_lower_locale(unicode_cp, CaseLocale::Root)
+}
+
+/// Map a vector of unicode code points to lowercase, with the mappings of a locale.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `locale`: [`CaseLocale`] - The locale.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the lowercase unicode code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x49, 0x130]; // "Iİ"
+/// assert_eq!(to_lower_locale(&v, CaseLocale::Turkish), vec![0x131, 0x69]); // "ıi"
+/// ```
+pub fn to_lower_locale<T: AsRef<Vec<u32>>>(unicode_cp: T, locale: CaseLocale) -> Vec<u32> {
+    map_case(unicode_cp.as_ref(), CaseMapping::Lower, locale)
+}
+
+/// Map a vector of unicode code points to titlecase: the first letter or number of every word (see [`segment::words`]) to titlecase if it is cased, the code points after it to lowercase.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the titlecase unicode code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x1c6, 0x75, 0x4d, 0x20, 0xdf, 0x61]; // "ǆuM ßa"
+/// assert_eq!(to_title(&v), vec![0x1c5, 0x75, 0x6d, 0x20, 0x53, 0x73, 0x61]); // "ǅum Ssa"
+/// ```
+pub fn to_title<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    to_title_locale(unicode_cp, CaseLocale::Root)
+}
+
+/// Map a vector of unicode code points to titlecase, with the mappings of a locale.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `locale`: [`CaseLocale`] - The locale.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the titlecase unicode code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x69, 0x73, 0x74, 0x61, 0x6e, 0x62, 0x75, 0x6c]; // "istanbul"
+/// assert_eq!(to_title_locale(&v, CaseLocale::Turkish)[0], 0x130); // "İstanbul"
+/// ```
+pub fn to_title_locale<T: AsRef<Vec<u32>>>(unicode_cp: T, locale: CaseLocale) -> Vec<u32> {
+    let v: &Vec<u32> = unicode_cp.as_ref();
+    let mut mapped: Vec<u32> = Vec::with_capacity(v.len());
+    // The first letter or number of a word, so that only the words starting with a cased letter are titlecased
+    let is_head = |cp: u32| {
+        is_cased(cp)
+            || matches!(
+                &unicode::general_category(cp).abbreviation()[..1],
+                "L" | "N"
+            )
+    };
+    for word in segment::words(v) {
+        match word.clone().find(|i| is_head(v[*i])) {
+            Some(first) => {
+                mapped.extend_from_slice(&v[word.start..first]);
+                if is_cased(v[first]) {
+                    map_code_point(v, first, CaseMapping::Title, locale, &mut mapped);
+                } else {
+                    mapped.push(v[first]);
+                }
+                for i in first + 1..word.end {
+                    map_code_point(v, i, CaseMapping::Lower, locale, &mut mapped);
+                }
+            }
+            None => mapped.extend_from_slice(&v[word]),
+        }
+    }
+    mapped
+}
+
+/// Fold the case of a vector of unicode code points, for case-insensitive comparisons.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the case folded unicode code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x53, 0x74, 0x72, 0x61, 0x1e9e, 0x65]; // "StraẞE"
+/// assert_eq!(case_fold(&v), vec![0x73, 0x74, 0x72, 0x61, 0x73, 0x73, 0x65]); // "strasse"
+/// ```
+pub fn case_fold<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    case_fold_locale(unicode_cp, CaseLocale::Root)
+}
+
+/// Fold the case of a vector of unicode code points, with the mappings of a locale (the Turkic mappings for Turkish and Azerbaijani).
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `locale`: [`CaseLocale`] - The locale.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the case folded unicode code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x49, 0x130]; // "Iİ"
+/// assert_eq!(case_fold_locale(&v, CaseLocale::Turkish), vec![0x131, 0x69]); // "ıi"
+/// assert_eq!(case_fold(&v), vec![0x69, 0x69, 0x307]); // "ii̇"
+/// ```
+pub fn case_fold_locale<T: AsRef<Vec<u32>>>(unicode_cp: T, locale: CaseLocale) -> Vec<u32> {
+    let turkic: bool = matches!(locale, CaseLocale::Turkish | CaseLocale::Azerbaijani);
+    let mut folded: Vec<u32> = Vec::with_capacity(unicode_cp.as_ref().len());
+    for code_point in unicode_cp.as_ref() {
+        match (turkic, code_point) {
+            (true, 0x49) => folded.push(0x131),
+            (true, 0x130) => folded.push(0x69),
+            _ => match find_mapping(tables::case::CASE_FOLDING, *code_point) {
+                Some(m) => folded.extend_from_slice(m),
+                None => folded.push(*code_point),
+            },
+        }
+    }
+    folded
+}
+
+/// Compare two vectors of unicode code points ignoring the case, with the canonical caseless matching of the Unicode Standard (`NFD(case_fold(NFD(x)))`).
+///
+/// # Parameters
+/// * `a`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `b`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// `true` if the two vectors match ignoring the case.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let a: Vec<u32> = vec![0x4d, 0x41, 0xdf, 0x45]; // "MAßE"
+/// let b: Vec<u32> = vec![0x6d, 0x61, 0x73, 0x73, 0x65]; // "masse"
+/// assert!(caseless_eq(&a, &b));
+///
+/// let a: Vec<u32> = vec![0xc5]; // "Å"
+/// let b: Vec<u32> = vec![0x61, 0x30a]; // "å" decomposed
+/// assert!(caseless_eq(&a, &b));
+/// ```
+pub fn caseless_eq<T: AsRef<Vec<u32>>, U: AsRef<Vec<u32>>>(a: T, b: U) -> bool {
+    let key = |v: &Vec<u32>| normalize::nfd(case_fold(normalize::nfd(v)));
+    key(a.as_ref()) == key(b.as_ref())
+}
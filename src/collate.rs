@@ -0,0 +1,327 @@
+/*!
+Collation of unicode code points (UTS #10, Unicode Collation Algorithm).
+
+# Collation
+Sorting unicode text by code point puts the accented and the uppercase letters far from their base letters (e.g. `Émile` after `Zoe`).
+The collation compares texts level by level, with the weights of the Default Unicode Collation Element Table (DUCET, `allkeys.txt`):
+1. The **primary** level compares the base characters (`a` < `b`, `e` = `é` = `E`).
+2. The **secondary** level compares the accents (`e` < `é`).
+3. The **tertiary** level compares the case and the variants (`e` < `E`).
+4. The **quaternary** level compares the variable characters (spaces, punctuation and symbols) when they are shifted.
+5. The **identical** level compares the code points of the NFD of the texts.
+
+A difference at a level is only taken into account if the levels before it are equal, so that `role` < `Role` < `rôle` at the tertiary strength (`o` < `ô` at the secondary level wins over `r` < `R` at the tertiary one).
+
+# Collation elements
+The text is normalized to NFD and every code point, or sequence of code points with a weight of its own (a contraction, like `L·` in Catalan), is mapped to collation elements:
+* The contractions are matched greedily, including the discontiguous ones with unblocked combining marks (e.g. `и` and its breve `U+0306` after a dot below `U+0323`, which is `й` with a dot below).
+* The code points missing from the table get implicit weights: the Han ideographs are sorted by code point after the other characters, and the unassigned code points after them.
+
+# Variable weighting
+The variable characters (spaces, punctuation and most symbols) are either:
+* [`VariableWeighting::NonIgnorable`]: compared like the letters, at every level, so that `de luge` < `de-luge` < `deluge`.
+* [`VariableWeighting::Shifted`]: ignored up to the tertiary level and compared at the quaternary level, so that `deluge` = `de-luge` = `de luge` at the tertiary strength.
+
+**Note**:
+
+* The collation is locale-independent: the tailorings of the languages (e.g. `ch` after `h` in Slovak) are not supported.
+* The comparison of two sort keys is the same as the comparison of their texts with [`collate`], so that the keys can be computed once to sort or index large collections.
+*/
+
+use std::cmp::Ordering;
+
+use crate::normalize;
+use crate::tables;
+use crate::unicode;
+
+/// A collation element: the primary, secondary and tertiary weights.
+pub(crate) type CollationElement = (u16, u16, u8);
+
+/// Get the collation elements of a contraction.
+///
+/// # Parameters
+/// * `sequence`: [`&[u32]`] - A sequence of unicode code points.
+///
+/// # Returns
+/// A [`&[(u16, u16, u8)]`] containing the collation elements, or [`None`] if the sequence is not a contraction.
+fn contraction(sequence: &[u32]) -> Option<&'static [CollationElement]> {
+    let table: &[(&[u32], &[CollationElement])] = tables::collate::CONTRACTIONS;
+    table
+        .binary_search_by(|(s, _)| (*s).cmp(sequence))
+        .ok()
+        .map(|i| table[i].1)
+}
+
+/// Check if a sequence of code points is a contraction or the beginning of one.
+///
+/// # Parameters
+/// * `sequence`: [`&[u32]`] - A sequence of unicode code points.
+///
+/// # Returns
+/// A [`bool`] indicating if a contraction starts with the sequence.
+fn is_contraction_prefix(sequence: &[u32]) -> bool {
+    let table: &[(&[u32], &[CollationElement])] = tables::collate::CONTRACTIONS;
+    match table.binary_search_by(|(s, _)| (*s).cmp(sequence)) {
+        Ok(_) => true,
+        Err(i) => i < table.len() && table[i].0.starts_with(sequence),
+    }
+}
+
+/// Get the implicit collation elements of a code point missing from the table.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The two collation elements of the code point: the Tangut, Nushu and Khitan characters are sorted by their offset in their script, the Han ideographs and the other code points by code point.
+fn implicit_elements(code_point: u32) -> [CollationElement; 2] {
+    let (first, second): (u16, u32) =
+        match tables::find_range(tables::collate::IMPLICIT_WEIGHTS, code_point) {
+            Some(i) => {
+                // The offset from the first range of the script (e.g. the Tangut Supplement after the Tangut block)
+                let base: u16 = tables::collate::IMPLICIT_WEIGHTS[i].2;
+                let start: u32 = tables::collate::IMPLICIT_WEIGHTS
+                    .iter()
+                    .filter(|(_, _, b)| *b == base)
+                    .map(|(first, _, _)| *first)
+                    .min()
+                    .unwrap();
+                (base, code_point - start)
+            }
+            None => {
+                let base: u16 =
+                    if !tables::in_ranges(tables::collate::UNIFIED_IDEOGRAPH, code_point) {
+                        0xFBC0
+                    } else if matches!(
+                        unicode::block(code_point),
+                        Some("CJK Unified Ideographs" | "CJK Compatibility Ideographs")
+                    ) {
+                        0xFB40
+                    } else {
+                        0xFB80
+                    };
+                (base + (code_point >> 15) as u16, code_point & 0x7FFF)
+            }
+        };
+    [
+        (first, 0x0020, 0x02),
+        ((second | 0x8000) as u16, 0x0000, 0x00),
+    ]
+}
+
+/// Get the collation elements of a vector of unicode code points in NFD.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points, normalized to NFD.
+///
+/// # Returns
+/// A [`Vec<(u16, u16, u8)>`] containing the collation elements of the longest matches of the table, in order.
+fn collation_elements(unicode_cp: &[u32]) -> Vec<CollationElement> {
+    let v: &[u32] = unicode_cp;
+    let ccc: Vec<u8> = v
+        .iter()
+        .map(|cp| normalize::canonical_combining_class(*cp))
+        .collect();
+    // The end of the run of code points with the same combining class starting at every index
+    let mut run_end: Vec<usize> = vec![v.len(); v.len()];
+    for k in (0..v.len().saturating_sub(1)).rev() {
+        if ccc[k] == ccc[k + 1] {
+            run_end[k] = run_end[k + 1];
+        } else {
+            run_end[k] = k + 1;
+        }
+    }
+    // The combining marks already matched by a discontiguous contraction
+    let mut skipped: Vec<bool> = vec![false; v.len()];
+    let mut elements: Vec<CollationElement> = Vec::new();
+    let mut i: usize = 0;
+    while i < v.len() {
+        if skipped[i] {
+            i += 1;
+            continue;
+        }
+        // The longest contiguous match
+        let mut matched: Vec<u32> = vec![v[i]];
+        let mut end: usize = i + 1;
+        let mut candidate: Vec<u32> = vec![v[i]];
+        let mut j: usize = i + 1;
+        while j < v.len() {
+            if skipped[j] {
+                j += 1;
+                continue;
+            }
+            candidate.push(v[j]);
+            if !is_contraction_prefix(&candidate) {
+                break;
+            }
+            j += 1;
+            if contraction(&candidate).is_some() {
+                matched.clone_from(&candidate);
+                end = j;
+            }
+        }
+        // Extended with the unblocked combining marks after it, if a contraction starts with the match
+        if is_contraction_prefix(&matched) {
+            let mut k: usize = end;
+            let mut skipped_ccc: u8 = 0;
+            while k < v.len() {
+                if skipped[k] {
+                    k += 1;
+                    continue;
+                }
+                if ccc[k] == 0 {
+                    break;
+                }
+                if skipped_ccc < ccc[k] {
+                    matched.push(v[k]);
+                    if contraction(&matched).is_some() {
+                        skipped[k] = true;
+                        k += 1;
+                        continue;
+                    }
+                    matched.pop();
+                }
+                // The following marks with the same combining class are blocked
+                skipped_ccc = ccc[k];
+                k = run_end[k];
+            }
+        }
+        if matched.len() > 1 {
+            elements.extend_from_slice(contraction(&matched).unwrap());
+        } else {
+            let table: &[(u32, &[CollationElement])] = tables::collate::COLLATION_ELEMENTS;
+            match table.binary_search_by_key(&matched[0], |(cp, _)| *cp) {
+                Ok(index) => elements.extend_from_slice(table[index].1),
+                Err(_) => elements.extend_from_slice(&implicit_elements(matched[0])),
+            }
+        }
+        i = end;
+    }
+    elements
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// The number of levels compared by the collation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Strength {
+    /// The base characters only.
+    Primary,
+    /// The base characters and the accents.
+    Secondary,
+    /// The base characters, the accents and the case.
+    Tertiary,
+    /// The tertiary level and the shifted variable characters.
+    Quaternary,
+    /// The quaternary level and the code points.
+    Identical,
+}
+
+/// The weighting of the variable characters (spaces, punctuation and most symbols).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VariableWeighting {
+    /// The variable characters are compared like the other characters.
+    NonIgnorable,
+    /// The variable characters are only compared at the quaternary level.
+    Shifted,
+}
+
+/// Get the sort key of a vector of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `strength`: [`Strength`] - The number of levels of the key.
+/// * `weighting`: [`VariableWeighting`] - The weighting of the variable characters.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the non-zero weights of every level, with a 0 between the levels.
+/// The quaternary level is only included with [`VariableWeighting::Shifted`], and the identical level contains the code points of the NFD of the vector.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let key = |s: &str| sort_key(s.chars().map(|c| c as u32).collect::<Vec<u32>>(), Strength::Tertiary, VariableWeighting::NonIgnorable);
+/// assert!(key("Zoe") > key("Émile"));
+/// assert!(key("Émile") > key("emile"));
+/// ```
+pub fn sort_key<T: AsRef<Vec<u32>>>(
+    unicode_cp: T,
+    strength: Strength,
+    weighting: VariableWeighting,
+) -> Vec<u32> {
+    let nfd: Vec<u32> = normalize::nfd(unicode_cp);
+    let mut levels: [Vec<u32>; 4] = Default::default();
+    let mut after_variable: bool = false;
+    for (primary, secondary, tertiary) in collation_elements(&nfd) {
+        let mut weights: [u32; 4] = [primary as u32, secondary as u32, tertiary as u32, 0xFFFF];
+        if weighting == VariableWeighting::Shifted {
+            if primary != 0 && primary <= tables::collate::MAX_VARIABLE {
+                weights = [0, 0, 0, primary as u32];
+                after_variable = true;
+            } else if primary == 0 && (secondary == 0 || after_variable) {
+                // The completely ignorable elements, and the ignorable elements after a variable one
+                weights = [0, 0, 0, 0];
+            } else if primary != 0 {
+                after_variable = false;
+            }
+        }
+        for (level, weight) in levels.iter_mut().zip(weights) {
+            if weight != 0 {
+                level.push(weight);
+            }
+        }
+    }
+    let count: usize = match (strength, weighting) {
+        (Strength::Primary, _) => 1,
+        (Strength::Secondary, _) => 2,
+        (_, VariableWeighting::NonIgnorable) | (Strength::Tertiary, _) => 3,
+        _ => 4,
+    };
+    let mut key: Vec<u32> = Vec::new();
+    for (i, level) in levels.iter().take(count).enumerate() {
+        if i > 0 {
+            key.push(0);
+        }
+        key.extend(level);
+    }
+    if strength == Strength::Identical {
+        key.push(0);
+        key.extend(nfd);
+    }
+    key
+}
+
+/// Compare two vectors of unicode code points with the Unicode Collation Algorithm.
+///
+/// # Parameters
+/// * `a`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `b`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `strength`: [`Strength`] - The number of levels compared.
+/// * `weighting`: [`VariableWeighting`] - The weighting of the variable characters.
+///
+/// # Returns
+/// The [`Ordering`] of the [`sort_key`]s of the vectors.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let mut names: Vec<Vec<u32>> = ["Zoe", "Émile", "eve", "Adam"]
+///     .iter()
+///     .map(|s| s.chars().map(|c| c as u32).collect())
+///     .collect();
+/// names.sort_by(|a, b| collate(a, b, Strength::Tertiary, VariableWeighting::NonIgnorable));
+/// let names: Vec<String> = names.iter().map(|v| v.iter().map(|cp| char::from_u32(*cp).unwrap()).collect()).collect();
+/// assert_eq!(names, vec!["Adam", "Émile", "eve", "Zoe"]);
+/// ```
+pub fn collate<T: AsRef<Vec<u32>>, U: AsRef<Vec<u32>>>(
+    a: T,
+    b: U,
+    strength: Strength,
+    weighting: VariableWeighting,
+) -> Ordering {
+    sort_key(a, strength, weighting).cmp(&sort_key(b, strength, weighting))
+}
@@ -0,0 +1,326 @@
+/*!
+Emoji properties and emoji sequences (UTS #51).
+
+# Properties
+The emoji properties of the code points come from `emoji-data.txt`:
+* [`is_emoji`]: The characters which can be shown as emoji, including the digits, `#` and `*` of the keycaps and the regional indicators (`Emoji`).
+* [`is_emoji_presentation`]: The characters shown as emoji by default, without a variation selector (`Emoji_Presentation`).
+* [`is_emoji_modifier`]: The skin tone modifiers U+1F3FB to U+1F3FF (`Emoji_Modifier`).
+* [`is_emoji_modifier_base`]: The characters which take a skin tone modifier (`Emoji_Modifier_Base`).
+* [`is_emoji_component`]: The characters used in the emoji sequences, like the zero width joiner and the tags (`Emoji_Component`).
+* [`is_extended_pictographic`]: The pictographs, including the reserved code points for future emoji (`Extended_Pictographic`).
+
+# Emoji sequences
+An emoji shown as a single image can be made of several code points. [`emoji_sequences`] finds the longest emoji sequences of a text:
+
+| Sequence       | Code points                                                            | Example             |
+|----------------|------------------------------------------------------------------------|---------------------|
+| `Basic`        | A character with an emoji presentation by default.                     | `😀`                |
+| `Presentation` | A character followed by U+FE0F VARIATION SELECTOR-16.                  | `❤️` (`2764 FE0F`)  |
+| `Keycap`       | A digit, `#` or `*`, U+FE0F and U+20E3 COMBINING ENCLOSING KEYCAP.     | `1️⃣`                |
+| `Flag`         | Two regional indicators.                                               | `🇮🇹`               |
+| `Modifier`     | A modifier base followed by a skin tone modifier.                      | `👍🏽`               |
+| `Tag`          | A base followed by tags (U+E0020 to U+E007E) and U+E007F CANCEL TAG.   | `🏴󠁧󠁢󠁳󠁣󠁴󠁿` (Scotland)    |
+| `Zwj`          | Emoji joined by U+200D ZERO WIDTH JOINER.                              | `👩‍💻`, `👨‍👩‍👧`          |
+
+**Note**:
+
+* The emoji characters with a text presentation by default (like the digits, `©` or `☺`) are not emoji sequences on their own, unless they are followed by U+FE0F.
+* The keycaps without U+FE0F (e.g. `1⃣`) are recognized too, since they are commonly shown as emoji.
+* The elements of a ZWJ sequence are emoji characters, presentation sequences or modifier sequences. They are not required to have an emoji presentation (e.g. `⚕` in `👩‍⚕`), but the digits, `#` and `*` need U+FE0F. The sequences are not checked against the list of the recommended ones: a ZWJ sequence which is not supported by a font is shown as its elements one after the other.
+*/
+
+use std::ops::Range;
+
+use crate::tables;
+
+/// U+FE0F VARIATION SELECTOR-16, which requests the emoji presentation.
+const EMOJI_VARIATION_SELECTOR: u32 = 0xFE0F;
+
+/// U+20E3 COMBINING ENCLOSING KEYCAP.
+const KEYCAP: u32 = 0x20E3;
+
+/// U+200D ZERO WIDTH JOINER.
+const ZWJ: u32 = 0x200D;
+
+/// U+E007F CANCEL TAG, the end of a tag sequence.
+const CANCEL_TAG: u32 = 0xE007F;
+
+/// Check if a code point is a regional indicator.
+fn is_regional_indicator(code_point: u32) -> bool {
+    (0x1F1E6..=0x1F1FF).contains(&code_point)
+}
+
+/// Check if a code point can be the base of a keycap.
+fn is_keycap_base(code_point: u32) -> bool {
+    matches!(code_point, 0x23 | 0x2A | 0x30..=0x39)
+}
+
+/// Match an element of an emoji sequence: a flag, a keycap, or an emoji character with a presentation selector, a skin tone modifier or tags.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+/// * `start`: [`usize`] - The index of the first code point of the element.
+///
+/// # Returns
+/// The index after the element and its [`EmojiSequence`] (with [`EmojiSequence::Basic`] for an emoji character with a text presentation by default), or [`None`] if there is no emoji at the index.
+fn match_element(unicode_cp: &[u32], start: usize) -> Option<(usize, EmojiSequence)> {
+    let at = |i: usize| unicode_cp.get(i).copied();
+    let first: u32 = at(start)?;
+    if !is_emoji(first) {
+        return None;
+    }
+    if is_regional_indicator(first) && at(start + 1).is_some_and(is_regional_indicator) {
+        return Some((start + 2, EmojiSequence::Flag));
+    }
+    if is_keycap_base(first) {
+        match (at(start + 1), at(start + 2)) {
+            (Some(EMOJI_VARIATION_SELECTOR), Some(KEYCAP)) => {
+                return Some((start + 3, EmojiSequence::Keycap))
+            }
+            (Some(KEYCAP), _) => return Some((start + 2, EmojiSequence::Keycap)),
+            _ => {}
+        }
+    }
+    let (end, sequence): (usize, EmojiSequence) = match at(start + 1) {
+        Some(EMOJI_VARIATION_SELECTOR) => (start + 2, EmojiSequence::Presentation),
+        Some(modifier) if is_emoji_modifier(modifier) && is_emoji_modifier_base(first) => {
+            (start + 2, EmojiSequence::Modifier)
+        }
+        _ => (start + 1, EmojiSequence::Basic),
+    };
+    // The tags after the base
+    let mut tags: usize = end;
+    while at(tags).is_some_and(|cp| (0xE0020..=0xE007E).contains(&cp)) {
+        tags += 1;
+    }
+    if tags > end && at(tags) == Some(CANCEL_TAG) {
+        return Some((tags + 1, EmojiSequence::Tag));
+    }
+    Some((end, sequence))
+}
+
+/// Check if an element of an emoji sequence can be joined by a ZWJ: an emoji character, a presentation sequence or a modifier sequence, but not a digit, `#` or `*` without U+FE0F.
+///
+/// # Parameters
+/// * `first`: [`u32`] - The first code point of the element.
+/// * `sequence`: [`EmojiSequence`] - The kind of the element, as returned by [`match_element`].
+///
+/// # Returns
+/// A [`bool`] indicating if the element can be part of a ZWJ sequence.
+fn is_zwj_element(first: u32, sequence: EmojiSequence) -> bool {
+    match sequence {
+        EmojiSequence::Basic => !is_keycap_base(first),
+        EmojiSequence::Presentation | EmojiSequence::Modifier => true,
+        _ => false,
+    }
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// The kinds of emoji sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmojiSequence {
+    /// A single character with an emoji presentation by default.
+    Basic,
+    /// A character followed by U+FE0F VARIATION SELECTOR-16.
+    Presentation,
+    /// A digit, `#` or `*` followed by U+FE0F and U+20E3 COMBINING ENCLOSING KEYCAP.
+    Keycap,
+    /// A pair of regional indicators.
+    Flag,
+    /// A modifier base followed by a skin tone modifier.
+    Modifier,
+    /// A base followed by tags and U+E007F CANCEL TAG, like the flags of the subdivisions.
+    Tag,
+    /// Emoji joined by U+200D ZERO WIDTH JOINER.
+    Zwj,
+}
+
+/// Check if a unicode code point can be shown as an emoji (`Emoji` property).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is an emoji character.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_emoji(0x1F600)); // 😀
+/// assert!(is_emoji(0x2764)); // ❤
+/// assert!(is_emoji(0x31)); // 1
+/// assert!(!is_emoji(0x41)); // A
+/// ```
+pub fn is_emoji(code_point: u32) -> bool {
+    tables::in_ranges(tables::emoji::EMOJI, code_point)
+}
+
+/// Check if a unicode code point is shown as an emoji by default (`Emoji_Presentation` property).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point has an emoji presentation by default.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_emoji_presentation(0x1F600)); // 😀
+/// assert!(!is_emoji_presentation(0x2764)); // ❤
+/// ```
+pub fn is_emoji_presentation(code_point: u32) -> bool {
+    tables::in_ranges(tables::emoji::EMOJI_PRESENTATION, code_point)
+}
+
+/// Check if a unicode code point is a skin tone modifier (`Emoji_Modifier` property).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is an emoji modifier.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_emoji_modifier(0x1F3FD)); // 🏽
+/// assert!(!is_emoji_modifier(0x1F44D)); // 👍
+/// ```
+pub fn is_emoji_modifier(code_point: u32) -> bool {
+    tables::in_ranges(tables::emoji::EMOJI_MODIFIER, code_point)
+}
+
+/// Check if a unicode code point takes a skin tone modifier (`Emoji_Modifier_Base` property).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is an emoji modifier base.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_emoji_modifier_base(0x1F44D)); // 👍
+/// assert!(!is_emoji_modifier_base(0x1F600)); // 😀
+/// ```
+pub fn is_emoji_modifier_base(code_point: u32) -> bool {
+    tables::in_ranges(tables::emoji::EMOJI_MODIFIER_BASE, code_point)
+}
+
+/// Check if a unicode code point is used in the emoji sequences (`Emoji_Component` property).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is an emoji component.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_emoji_component(0x200D)); // ZERO WIDTH JOINER
+/// assert!(is_emoji_component(0x1F3FB)); // 🏻
+/// assert!(!is_emoji_component(0x1F600)); // 😀
+/// ```
+pub fn is_emoji_component(code_point: u32) -> bool {
+    tables::in_ranges(tables::emoji::EMOJI_COMPONENT, code_point)
+}
+
+/// Check if a unicode code point is a pictograph (`Extended_Pictographic` property).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is an extended pictographic.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_extended_pictographic(0x1F600)); // 😀
+/// assert!(is_extended_pictographic(0xA9)); // ©
+/// assert!(is_extended_pictographic(0x1FAFF)); // reserved
+/// assert!(!is_extended_pictographic(0x31)); // 1
+/// ```
+pub fn is_extended_pictographic(code_point: u32) -> bool {
+    tables::in_ranges(tables::segment::EXTENDED_PICTOGRAPHIC, code_point)
+}
+
+/// Find the emoji sequences of a slice of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// A [`Vec`] containing the [`Range`] of indexes of every emoji sequence, with its kind of [`EmojiSequence`], in order.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "Hi 👋🏽! 👨‍👩‍👧 🇮🇹 ©".chars().map(|c| c as u32).collect();
+/// assert_eq!(
+///     emoji_sequences(&v),
+///     vec![
+///         (3..5, EmojiSequence::Modifier),
+///         (7..12, EmojiSequence::Zwj),
+///         (13..15, EmojiSequence::Flag),
+///     ]
+/// );
+/// ```
+pub fn emoji_sequences(unicode_cp: &[u32]) -> Vec<(Range<usize>, EmojiSequence)> {
+    let mut sequences: Vec<(Range<usize>, EmojiSequence)> = Vec::new();
+    let mut i: usize = 0;
+    while i < unicode_cp.len() {
+        let (mut end, mut sequence) = match match_element(unicode_cp, i) {
+            Some(element) => element,
+            None => {
+                i += 1;
+                continue;
+            }
+        };
+        // The elements joined by a ZWJ
+        if is_zwj_element(unicode_cp[i], sequence) {
+            while unicode_cp.get(end) == Some(&ZWJ) {
+                match match_element(unicode_cp, end + 1) {
+                    Some((next, element)) if is_zwj_element(unicode_cp[end + 1], element) => {
+                        end = next;
+                        sequence = EmojiSequence::Zwj;
+                    }
+                    _ => break,
+                }
+            }
+        }
+        // A character with a text presentation by default is not an emoji on its own
+        if sequence != EmojiSequence::Basic || is_emoji_presentation(unicode_cp[i]) {
+            sequences.push((i..end, sequence));
+        }
+        i = end;
+    }
+    sequences
+}
+
+/// Count the emoji of a slice of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// The number of [`emoji_sequences`], so that a ZWJ sequence, a flag or an emoji with a skin tone counts as one emoji.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "👨‍👩‍👧‍👦 and 👍🏿👍".chars().map(|c| c as u32).collect();
+/// assert_eq!(emoji_count(&v), 3);
+/// ```
+pub fn emoji_count(unicode_cp: &[u32]) -> usize {
+    emoji_sequences(unicode_cp).len()
+}
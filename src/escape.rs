@@ -0,0 +1,456 @@
+/*!
+Escape sequences of JSON, Rust, C, Python and JavaScript string literals.
+
+# Escaping
+A unicode code point is escaped using the following rules:
+* The printable ASCII characters (`0x20` to `0x7E`) are written as they are, except for `"` and `\`, which are always escaped (`\"` and `\\`).
+* The control characters with a short escape in the dialect (e.g. `\n`, `\t`) are written using it.
+* Every other code point is written using the numeric escape of the [dialect](#dialects), with lowercase hexadecimal digits.
+
+# Unescaping
+An escaped text is parsed back into unicode code points using the escapes defined by the dialect. Any other character is decoded as it is.
+An escape that is not defined by the dialect, is truncated or encodes an invalid unicode code point is reported as an [`EscapeError`] with the byte offset of its backslash.
+
+## Dialects
+
+| Dialect    | `U+00E9`    | `U+1F600`        | Control (`U+0001`) |
+|------------|-------------|------------------|--------------------|
+| JSON       | `\u00e9`    | `\ud83d\ude00`   | `\u0001`           |
+| Rust       | `\u{e9}`    | `\u{1f600}`      | `\x01`             |
+| C          | `\u00e9`    | `\U0001f600`     | `\001`             |
+| Python     | `\xe9`      | `\U0001f600`     | `\x01`             |
+| JavaScript | `\u00e9`    | `\u{1f600}`      | `\x01`             |
+
+**Note**:
+
+* JSON only has 4-digit escapes, so the code points greater than `0xFFFF` are written as a [UTF-16 surrogate pair](crate::utf16).
+* JavaScript also accepts the JSON surrogate pair form when unescaping.
+* C forbids the universal character names below `U+00A0`, so the code points from `0x80` to `0x9F` are written as the octal escapes of their UTF-8 bytes (e.g. `\302\205` for `U+0085`), and such sequences are decoded back into a single code point.
+* The Python `\N{...}` named escapes are not supported.
+*/
+
+use std::fmt;
+
+use crate::unicode;
+use crate::utf16;
+
+/// Check if a character is a hexadecimal digit.
+fn is_hex(c: char) -> bool {
+    c.is_ascii_hexdigit()
+}
+
+/// Check if a character is an octal digit.
+fn is_octal(c: char) -> bool {
+    ('0'..='7').contains(&c)
+}
+
+/// Get the short escape of a unicode code point in a dialect.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+/// * `dialect`: [`EscapeDialect`] - The dialect.
+///
+/// # Returns
+/// The short escape (e.g. `\n`), or [`None`] if the dialect does not define one.
+fn short_escape(code_point: u32, dialect: EscapeDialect) -> Option<&'static str> {
+    use EscapeDialect::*;
+    match (code_point, dialect) {
+        (0x22, _) => Some("\\\""),
+        (0x5C, _) => Some("\\\\"),
+        (0x0A, _) => Some("\\n"),
+        (0x0D, _) => Some("\\r"),
+        (0x09, _) => Some("\\t"),
+        (0x00, Rust) => Some("\\0"),
+        (0x07, C | Python) => Some("\\a"),
+        (0x08, Json | C | Python | JavaScript) => Some("\\b"),
+        (0x0C, Json | C | Python | JavaScript) => Some("\\f"),
+        (0x0B, C | Python | JavaScript) => Some("\\v"),
+        _ => None,
+    }
+}
+
+/// Get the code point of a short escape in a dialect.
+///
+/// # Parameters
+/// * `c`: [`char`] - The character following the backslash.
+/// * `dialect`: [`EscapeDialect`] - The dialect.
+///
+/// # Returns
+/// The unicode code point, or [`None`] if the dialect does not define the short escape.
+fn short_unescape(c: char, dialect: EscapeDialect) -> Option<u32> {
+    use EscapeDialect::*;
+    match (c, dialect) {
+        ('"', _) | ('\\', _) => Some(c as u32),
+        ('n', _) => Some(0x0A),
+        ('r', _) => Some(0x0D),
+        ('t', _) => Some(0x09),
+        ('/', Json) => Some(0x2F),
+        ('\'', Rust | C | Python | JavaScript) => Some(0x27),
+        ('?', C) => Some(0x3F),
+        ('a', C | Python) => Some(0x07),
+        ('b', Json | C | Python | JavaScript) => Some(0x08),
+        ('f', Json | C | Python | JavaScript) => Some(0x0C),
+        ('v', C | Python | JavaScript) => Some(0x0B),
+        _ => None,
+    }
+}
+
+/// Escape a unicode code point in a dialect.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+/// * `dialect`: [`EscapeDialect`] - The dialect.
+/// * `out`: [`&mut String`] - The string to append the escaped code point to.
+///
+/// # Panics
+/// * If the input unicode code point is invalid.
+fn escape_code_point(code_point: u32, dialect: EscapeDialect, out: &mut String) {
+    unicode::check_code_point(code_point);
+    if let Some(escape) = short_escape(code_point, dialect) {
+        out.push_str(escape);
+        return;
+    }
+    if (0x20..=0x7E).contains(&code_point) {
+        out.push(char::from_u32(code_point).unwrap());
+        return;
+    }
+
+    let escape: String = match dialect {
+        EscapeDialect::Json => match code_point {
+            0x00..=0xFFFF => format!("\\u{:04x}", code_point),
+            _ => utf16::encode_in_utf16(vec![code_point])
+                .iter()
+                .map(|unit| format!("\\u{:04x}", unit))
+                .collect(),
+        },
+        EscapeDialect::Rust => match code_point {
+            0x00..=0x7F => format!("\\x{:02x}", code_point),
+            _ => format!("\\u{{{:x}}}", code_point),
+        },
+        EscapeDialect::C => match code_point {
+            0x00..=0x7F => format!("\\{:03o}", code_point),
+            // C11 forbids the universal character names below `0xA0` (other than `$`, `@` and `` ` ``)
+            0x80..=0x9F => char::from_u32(code_point)
+                .unwrap()
+                .encode_utf8(&mut [0; 4])
+                .bytes()
+                .map(|byte| format!("\\{:03o}", byte))
+                .collect(),
+            0xA0..=0xFFFF => format!("\\u{:04x}", code_point),
+            _ => format!("\\U{:08x}", code_point),
+        },
+        EscapeDialect::Python => match code_point {
+            0x00..=0xFF => format!("\\x{:02x}", code_point),
+            0x100..=0xFFFF => format!("\\u{:04x}", code_point),
+            _ => format!("\\U{:08x}", code_point),
+        },
+        EscapeDialect::JavaScript => match code_point {
+            0x00..=0x7F => format!("\\x{:02x}", code_point),
+            0x80..=0xFFFF => format!("\\u{:04x}", code_point),
+            _ => format!("\\u{{{:x}}}", code_point),
+        },
+    };
+    out.push_str(&escape);
+}
+
+/// A parser of escaped text.
+struct Unescaper<'a> {
+    /// The escaped text.
+    text: &'a str,
+    /// The byte offset of the next character to read.
+    pos: usize,
+    /// The dialect.
+    dialect: EscapeDialect,
+}
+
+impl Unescaper<'_> {
+    /// Peek the next character without consuming it.
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    /// Consume the next character.
+    fn bump(&mut self) -> Option<char> {
+        let c: char = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// Read a number made of `min` to `max` digits accepted by `accept`, in the given `radix`.
+    ///
+    /// # Returns
+    /// The value of the number, or [`EscapeErrorKind::TruncatedEscape`] if less than `min` digits are found.
+    fn digits(
+        &mut self,
+        min: usize,
+        max: usize,
+        radix: u32,
+        accept: fn(char) -> bool,
+    ) -> Result<u32, EscapeErrorKind> {
+        let mut value: u32 = 0;
+        let mut count: usize = 0;
+        while count < max {
+            match self.peek() {
+                Some(c) if accept(c) => {
+                    self.bump();
+                    // Saturate, so that overlong escapes are reported as invalid code points
+                    value = value
+                        .saturating_mul(radix)
+                        .saturating_add(c.to_digit(radix).unwrap());
+                    count += 1;
+                }
+                _ => break,
+            }
+        }
+        if count < min {
+            return Err(EscapeErrorKind::TruncatedEscape);
+        }
+        Ok(value)
+    }
+
+    /// Read the digits of a braced escape (`\u{...}`), after the `u`.
+    fn braced(&mut self) -> Result<u32, EscapeErrorKind> {
+        if self.bump() != Some('{') {
+            return Err(EscapeErrorKind::TruncatedEscape);
+        }
+        let mut value: u32 = 0;
+        let mut count: usize = 0;
+        loop {
+            match self.bump() {
+                Some('}') if count > 0 => return Ok(value),
+                // Rust allows underscores between the digits
+                Some('_') if count > 0 && self.dialect == EscapeDialect::Rust => {}
+                Some(c) if is_hex(c) && count < 6 => {
+                    value = (value << 4) | c.to_digit(16).unwrap();
+                    count += 1;
+                }
+                _ => return Err(EscapeErrorKind::TruncatedEscape),
+            }
+        }
+    }
+
+    /// Read a `\uXXXX` escape, after the `u`, combining a surrogate pair when it is followed by a low surrogate escape.
+    fn surrogate_pair(&mut self) -> Result<u32, EscapeErrorKind> {
+        let high: u32 = self.digits(4, 4, 16, is_hex)?;
+        if (0xDC00..=0xDFFF).contains(&high) {
+            return Err(EscapeErrorKind::LoneSurrogate(high as u16));
+        }
+        if !(0xD800..=0xDBFF).contains(&high) {
+            return Ok(high);
+        }
+        if !self.text[self.pos..].starts_with("\\u") {
+            return Err(EscapeErrorKind::LoneSurrogate(high as u16));
+        }
+        let start: usize = self.pos;
+        self.pos += 2;
+        let low: u32 = self.digits(4, 4, 16, is_hex)?;
+        if !(0xDC00..=0xDFFF).contains(&low) {
+            self.pos = start;
+            return Err(EscapeErrorKind::LoneSurrogate(high as u16));
+        }
+        Ok(utf16::decode_from_utf16(vec![high as u16, low as u16])[0])
+    }
+
+    /// Read the octal escapes of the continuation bytes of a UTF-8 sequence (e.g. `\302\205`), after the escape of its first byte.
+    ///
+    /// # Returns
+    /// The unicode code point of the sequence, or `first` if it is not the first byte of a valid UTF-8 sequence.
+    fn utf8_sequence(&mut self, first: u32) -> u32 {
+        let len: usize = match first {
+            0xC2..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF4 => 4,
+            _ => return first,
+        };
+        let start: usize = self.pos;
+        let mut bytes: Vec<u8> = vec![first as u8];
+        while bytes.len() < len {
+            if !self.text[self.pos..].starts_with('\\') {
+                break;
+            }
+            self.pos += 1;
+            match self.digits(3, 3, 8, is_octal) {
+                Ok(byte @ 0x80..=0xBF) => bytes.push(byte as u8),
+                _ => break,
+            }
+        }
+        match std::str::from_utf8(&bytes) {
+            Ok(s) if bytes.len() == len => s.chars().next().unwrap() as u32,
+            _ => {
+                self.pos = start;
+                first
+            }
+        }
+    }
+
+    /// Read an escape sequence, after the backslash.
+    fn escape(&mut self) -> Result<u32, EscapeErrorKind> {
+        use EscapeDialect::*;
+        let c: char = self.bump().ok_or(EscapeErrorKind::TruncatedEscape)?;
+        if let Some(code_point) = short_unescape(c, self.dialect) {
+            return Ok(code_point);
+        }
+        let code_point: u32 = match (c, self.dialect) {
+            ('0', Rust) => 0,
+            ('0', JavaScript) if !self.peek().is_some_and(|d| d.is_ascii_digit()) => 0,
+            ('0'..='7', C) => {
+                self.pos -= 1;
+                let value: u32 = self.digits(1, 3, 8, is_octal)?;
+                self.utf8_sequence(value)
+            }
+            ('0'..='7', Python) => {
+                self.pos -= 1;
+                self.digits(1, 3, 8, is_octal)?
+            }
+            ('x', Rust) => {
+                let value: u32 = self.digits(2, 2, 16, is_hex)?;
+                if value > 0x7F {
+                    return Err(EscapeErrorKind::InvalidCodePoint(value));
+                }
+                value
+            }
+            ('x', C) => self.digits(1, usize::MAX, 16, is_hex)?,
+            ('x', Python | JavaScript) => self.digits(2, 2, 16, is_hex)?,
+            ('u', Rust) => self.braced()?,
+            ('u', JavaScript) if self.peek() == Some('{') => self.braced()?,
+            ('u', Json | JavaScript) => self.surrogate_pair()?,
+            ('u', C | Python) => self.digits(4, 4, 16, is_hex)?,
+            ('U', C | Python) => self.digits(8, 8, 16, is_hex)?,
+            ('N', Python) => return Err(EscapeErrorKind::UnsupportedEscape),
+            _ => return Err(EscapeErrorKind::UnknownEscape(c)),
+        };
+        if (0xD800..=0xDFFF).contains(&code_point) {
+            return Err(EscapeErrorKind::LoneSurrogate(code_point as u16));
+        }
+        if code_point > 0x10FFFF {
+            return Err(EscapeErrorKind::InvalidCodePoint(code_point));
+        }
+        Ok(code_point)
+    }
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// The dialect of the escape sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscapeDialect {
+    /// JSON strings (RFC 8259).
+    Json,
+    /// Rust string literals.
+    Rust,
+    /// C string literals (C11).
+    C,
+    /// Python string literals.
+    Python,
+    /// JavaScript string literals (ECMAScript 2015).
+    JavaScript,
+}
+
+/// The kind of an [`EscapeError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscapeErrorKind {
+    /// The escape sequence is not defined by the dialect.
+    UnknownEscape(char),
+    /// The escape sequence is defined by the dialect, but it is not supported (e.g. Python `\N{...}`).
+    UnsupportedEscape,
+    /// The escape sequence has not enough digits, or is not terminated.
+    TruncatedEscape,
+    /// The escape sequence encodes a value greater than `0x10FFFF` (or `0x7F` for Rust `\x` escapes).
+    InvalidCodePoint(u32),
+    /// The escape sequence encodes a surrogate that is not part of a valid surrogate pair.
+    LoneSurrogate(u16),
+}
+
+/// A malformed escape sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EscapeError {
+    /// The kind of the error.
+    pub kind: EscapeErrorKind,
+    /// The byte offset of the backslash starting the escape sequence.
+    pub position: usize,
+}
+
+impl fmt::Display for EscapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            EscapeErrorKind::UnknownEscape(c) => write!(f, "Unknown escape \\{}", c)?,
+            EscapeErrorKind::UnsupportedEscape => write!(f, "Unsupported escape")?,
+            EscapeErrorKind::TruncatedEscape => write!(f, "Truncated escape")?,
+            EscapeErrorKind::InvalidCodePoint(cp) => write!(f, "Invalid code point {:x}", cp)?,
+            EscapeErrorKind::LoneSurrogate(s) => write!(f, "Lone surrogate {:x}", s)?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for EscapeError {}
+
+/// Escape a vector of unicode code points in a dialect.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `dialect`: [`EscapeDialect`] - The dialect.
+///
+/// # Returns
+/// A [`String`] containing only printable ASCII characters, to be put between double quotes.
+///
+/// # Panics
+/// * If the input vector (`unicode_cp`) of unicode code points contains invalid unicode code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x41, 0xe9, 0x1f600]; // "Aé😀"
+/// assert_eq!(escape(&v, EscapeDialect::Json), "A\\u00e9\\ud83d\\ude00");
+/// assert_eq!(escape(&v, EscapeDialect::Rust), "A\\u{e9}\\u{1f600}");
+/// assert_eq!(escape(&v, EscapeDialect::C), "A\\u00e9\\U0001f600");
+/// ```
+pub fn escape<T: AsRef<Vec<u32>>>(unicode_cp: T, dialect: EscapeDialect) -> String {
+    let mut escaped: String = String::new();
+    for cp in unicode_cp.as_ref() {
+        escape_code_point(*cp, dialect, &mut escaped);
+    }
+    escaped
+}
+
+/// Parse an escaped text of a dialect into a vector of unicode code points.
+///
+/// # Parameters
+/// * `text`: [`&str`] - The escaped text, without the surrounding quotes.
+/// * `dialect`: [`EscapeDialect`] - The dialect.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points, or the [`EscapeError`] of the first malformed escape.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = unescape("A\\ud83d\\ude00", EscapeDialect::Json).unwrap();
+/// assert_eq!(v, vec![0x41, 0x1f600]);
+///
+/// let err: EscapeError = unescape("A\\ud83d", EscapeDialect::Json).unwrap_err();
+/// assert_eq!(err.kind, EscapeErrorKind::LoneSurrogate(0xd83d));
+/// assert_eq!(err.position, 1);
+/// ```
+pub fn unescape(text: &str, dialect: EscapeDialect) -> Result<Vec<u32>, EscapeError> {
+    let mut unescaper: Unescaper = Unescaper {
+        text,
+        pos: 0,
+        dialect,
+    };
+    let mut unicode_cp: Vec<u32> = Vec::new();
+    while let Some(c) = unescaper.bump() {
+        if c != '\\' {
+            unicode_cp.push(c as u32);
+            continue;
+        }
+        let position: usize = unescaper.pos - 1;
+        let code_point: u32 = unescaper
+            .escape()
+            .map_err(|kind| EscapeError { kind, position })?;
+        unicode_cp.push(code_point);
+    }
+    Ok(unicode_cp)
+}
@@ -0,0 +1,454 @@
+/*!
+GSM 03.38 (3GPP TS 23.038) 7-bit default alphabet encoding and decoding.
+
+# Encoding
+A unicode code point is represented using one or two septets (7-bit values) in the GSM 7-bit default alphabet, depending on the table it belongs to.
+* If the unicode code point is in the [default table](#default-table), it is represented using one septet.
+* If the unicode code point is in the [extension table](#extension-table), it is represented using two septets: the escape septet `0x1B` followed by the septet of the extension table.
+* Otherwise, the unicode code point can not be represented in the GSM 7-bit default alphabet and the message has to be sent in UCS-2 (see [`crate::ucs2`]).
+
+# Decoding
+A sequence of septets is decoded into unicode code points using the following rules:
+* If the septet is not the escape septet `0x1B`, it is looked up in the [default table](#default-table).
+* If the septet is the escape septet `0x1B`, the next septet is looked up in the [extension table](#extension-table). If it is not in the extension table, it is looked up in the default table, as required by the specification.
+
+## Representation
+
+**Note**:
+
+* The septets are transmitted packed into octets, so that 8 septets fit into 7 octets (see [`pack_septets`] and [`unpack_septets`]).
+* The packing is little-endian: the first septet is stored in the 7 least significant bits of the first octet, and the least significant bit of the second septet is stored in the most significant bit of the first octet.
+
+### Default table
+
+The default table maps the 128 septets `0x00` to `0x7F` to unicode code points. It mostly agrees with ASCII, except for the accented latin letters, the greek capital letters and a few symbols (e.g. `@` is `0x00`, `$` is `0x02` and `£` is `0x01`).
+
+### Extension table
+
+The extension table contains the characters `|`, `^`, `€`, `{`, `}`, `[`, `~`, `]`, `\` and the form feed.
+
+### Packing
+
+* Septets: `0aaaaaaa|0bbbbbbb|0ccccccc|...`
+* Octets: `baaaaaaa|ccbbbbbb|dddccccc|...`
+
+# SMS segments
+A single SMS carries 140 octets of user data, that is 160 septets or 70 UCS-2 code units.
+When a message is longer, it is split into segments, each one carrying a 6 octets user data header, so that only 153 septets or 67 UCS-2 code units fit in each segment.
+An escape sequence (or a surrogate pair) is never split across two segments.
+*/
+
+use crate::ucs2;
+use crate::utf16;
+
+/// The escape septet, which introduces a character of the extension table.
+const ESCAPE: u8 = 0x1B;
+
+/// Number of septets in a single SMS.
+const GSM7_SINGLE_SEGMENT: usize = 160;
+
+/// Number of septets in a segment of a concatenated SMS.
+const GSM7_MULTI_SEGMENT: usize = 153;
+
+/// Number of UCS-2 code units in a single SMS.
+const UCS2_SINGLE_SEGMENT: usize = 70;
+
+/// Number of UCS-2 code units in a segment of a concatenated SMS.
+const UCS2_MULTI_SEGMENT: usize = 67;
+
+/// The GSM 7-bit default table, indexed by septet.
+#[rustfmt::skip]
+const DEFAULT_TABLE: [u32; 128] = [
+    0x0040, 0x00A3, 0x0024, 0x00A5, 0x00E8, 0x00E9, 0x00F9, 0x00EC, // @ £ $ ¥ è é ù ì
+    0x00F2, 0x00C7, 0x000A, 0x00D8, 0x00F8, 0x000D, 0x00C5, 0x00E5, // ò Ç LF Ø ø CR Å å
+    0x0394, 0x005F, 0x03A6, 0x0393, 0x039B, 0x03A9, 0x03A0, 0x03A8, // Δ _ Φ Γ Λ Ω Π Ψ
+    0x03A3, 0x0398, 0x039E, 0x001B, 0x00C6, 0x00E6, 0x00DF, 0x00C9, // Σ Θ Ξ ESC Æ æ ß É
+    0x0020, 0x0021, 0x0022, 0x0023, 0x00A4, 0x0025, 0x0026, 0x0027, // SP ! " # ¤ % & '
+    0x0028, 0x0029, 0x002A, 0x002B, 0x002C, 0x002D, 0x002E, 0x002F, // ( ) * + , - . /
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037, // 0 1 2 3 4 5 6 7
+    0x0038, 0x0039, 0x003A, 0x003B, 0x003C, 0x003D, 0x003E, 0x003F, // 8 9 : ; < = > ?
+    0x00A1, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, // ¡ A B C D E F G
+    0x0048, 0x0049, 0x004A, 0x004B, 0x004C, 0x004D, 0x004E, 0x004F, // H I J K L M N O
+    0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, // P Q R S T U V W
+    0x0058, 0x0059, 0x005A, 0x00C4, 0x00D6, 0x00D1, 0x00DC, 0x00A7, // X Y Z Ä Ö Ñ Ü §
+    0x00BF, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, // ¿ a b c d e f g
+    0x0068, 0x0069, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E, 0x006F, // h i j k l m n o
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, // p q r s t u v w
+    0x0078, 0x0079, 0x007A, 0x00E4, 0x00F6, 0x00F1, 0x00FC, 0x00E0, // x y z ä ö ñ ü à
+];
+
+/// The GSM 7-bit extension table, as pairs of (septet following the escape, unicode code point).
+const EXTENSION_TABLE: [(u8, u32); 10] = [
+    (0x0A, 0x000C), // Form feed
+    (0x14, 0x005E), // ^
+    (0x28, 0x007B), // {
+    (0x29, 0x007D), // }
+    (0x2F, 0x005C), // \
+    (0x3C, 0x005B), // [
+    (0x3D, 0x007E), // ~
+    (0x3E, 0x005D), // ]
+    (0x40, 0x007C), // |
+    (0x65, 0x20AC), // €
+];
+
+/// Encode a unicode code point into a vector of GSM 7-bit septets.
+///
+/// # Parameters
+/// * `unicode_cp`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`Vec<u8>`] containing one septet (default table) or two septets (extension table), or [`None`] if the unicode code point is not in the GSM 7-bit default alphabet.
+fn encode_code_point(unicode_cp: u32) -> Option<Vec<u8>> {
+    // The escape septet never encodes a character on its own
+    if let Some(septet) = DEFAULT_TABLE
+        .iter()
+        .position(|&cp| cp == unicode_cp)
+        .filter(|&septet| septet != ESCAPE as usize)
+    {
+        return Some(vec![septet as u8]);
+    }
+    EXTENSION_TABLE
+        .iter()
+        .find(|&&(_, cp)| cp == unicode_cp)
+        .map(|&(septet, _)| vec![ESCAPE, septet])
+}
+
+/// Decode a GSM 7-bit septet into a unicode code point.
+///
+/// # Parameters
+/// * `septets`: [`&[u8]`] - A slice of GSM 7-bit septets.
+/// * `i`: [`usize`] - The index of the septet to read.
+///
+/// # Returns
+/// A tuple containing the unicode code point and the number of septets read.
+/// * The unicode code point is the decoded GSM 7-bit septet.
+/// * The number of septets read is the number of consumed septets from the vector of GSM 7-bit septets.
+///
+/// # Panics
+/// * If the index `i` is out of bounds.
+/// * If a septet is greater than `0x7F`.
+/// * If the escape septet is the last septet.
+fn decode_symbol(septets: &[u8], i: usize) -> Option<(u32, usize)> {
+    if i > septets.len() {
+        panic!("Index out of bounds");
+    }
+
+    if i == septets.len() {
+        return None;
+    }
+
+    let septet: u8 = septets[i];
+    if septet > 0x7F {
+        panic!("Invalid GSM 7-bit septet {}", septet);
+    }
+    if septet != ESCAPE {
+        return Some((DEFAULT_TABLE[septet as usize], 1));
+    }
+
+    if i + 1 == septets.len() {
+        panic!("Invalid GSM 7-bit sequence");
+    }
+    let extension: u8 = septets[i + 1];
+    if extension > 0x7F {
+        panic!("Invalid GSM 7-bit septet {}", extension);
+    }
+    let code_point: u32 = EXTENSION_TABLE
+        .iter()
+        .find(|&&(ext, _)| ext == extension)
+        .map(|&(_, cp)| cp)
+        .unwrap_or(DEFAULT_TABLE[extension as usize]);
+    Some((code_point, 2))
+}
+
+/// Count the number of segments needed to send a message, given the size (in septets or code units) of each character.
+///
+/// # Parameters
+/// * `sizes`: [`&[usize]`] - The size of each character of the message.
+/// * `single`: [`usize`] - The capacity of a single SMS.
+/// * `multi`: [`usize`] - The capacity of a segment of a concatenated SMS.
+///
+/// # Returns
+/// The number of segments. A character is never split across two segments.
+fn count_segments(sizes: &[usize], single: usize, multi: usize) -> usize {
+    let total: usize = sizes.iter().sum();
+    if total <= single {
+        return 1;
+    }
+    let mut segments: usize = 1;
+    let mut used: usize = 0;
+    for size in sizes {
+        if used + size > multi {
+            segments += 1;
+            used = 0;
+        }
+        used += size;
+    }
+    segments
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// The encoding chosen to send a SMS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmsEncoding {
+    /// The GSM 7-bit default alphabet, including the extension table.
+    Gsm7,
+    /// UCS-2, used when at least one character is not in the GSM 7-bit default alphabet.
+    Ucs2,
+}
+
+/// The encoded payload of a SMS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SmsPayload {
+    /// Unpacked GSM 7-bit septets (see [`pack_septets`]).
+    Gsm7(Vec<u8>),
+    /// UCS-2 code points, or UTF-16 code units if the message contains unicode code points greater than `0xFFFF`.
+    Ucs2(Vec<u16>),
+}
+
+/// The size of a SMS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SmsInfo {
+    /// The encoding used to send the message.
+    pub encoding: SmsEncoding,
+    /// The number of septets (GSM 7-bit) or code units (UCS-2) of the message.
+    pub units: usize,
+    /// The number of segments needed to send the message.
+    pub segments: usize,
+}
+
+/// Check if a vector of unicode code points can be encoded in the GSM 7-bit default alphabet.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// `true` if every unicode code point is in the default table or in the extension table.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_gsm7_encodable(vec![0x48, 0x69, 0x20AC])); // "Hi€"
+/// assert!(!is_gsm7_encodable(vec![0x48, 0x69, 0x1F600])); // "Hi😀"
+/// ```
+pub fn is_gsm7_encodable<T: AsRef<Vec<u32>>>(unicode_cp: T) -> bool {
+    unicode_cp
+        .as_ref()
+        .iter()
+        .all(|cp| encode_code_point(*cp).is_some())
+}
+
+/// Encode a vector of unicode code points into a vector of GSM 7-bit septets.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u8>`] containing the (unpacked) GSM 7-bit septets.
+///
+/// # Panics
+/// * If the input vector (`unicode_cp`) of unicode code points contains code points not in the GSM 7-bit default alphabet.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x40, 0x20AC]; // "@€"
+/// let enc: Vec<u8> = encode_in_gsm7(&v);
+/// assert_eq!(enc, vec![0x00, 0x1B, 0x65]);
+/// ```
+pub fn encode_in_gsm7<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u8> {
+    let mut septets: Vec<u8> = Vec::new();
+    for cp in unicode_cp.as_ref() {
+        match encode_code_point(*cp) {
+            Some(mut s) => septets.append(&mut s),
+            None => panic!("Invalid GSM 7-bit code point {}", cp),
+        }
+    }
+    septets
+}
+
+/// Decode a vector of GSM 7-bit septets into a vector of unicode code points.
+///
+/// # Parameters
+/// * `septets`: [`Vec<u8>`] - A vector of (unpacked) GSM 7-bit septets.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points.
+///
+/// # Panics
+/// * If the input vector (`septets`) contains values greater than `0x7F`.
+/// * If the input vector (`septets`) ends with the escape septet.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u8> = vec![0x00, 0x1B, 0x65];
+/// let dec: Vec<u32> = decode_from_gsm7(&v);
+/// assert_eq!(dec, vec![0x40, 0x20AC]);
+/// ```
+pub fn decode_from_gsm7<T: AsRef<Vec<u8>>>(septets: T) -> Vec<u32> {
+    let septets: &Vec<u8> = septets.as_ref();
+    let len: usize = septets.len();
+    let mut i: usize = 0;
+    let mut unicode_cp: Vec<u32> = Vec::new();
+    while i < len {
+        let (cp, offset) = decode_symbol(septets, i).unwrap();
+        i += offset;
+        unicode_cp.push(cp);
+    }
+    unicode_cp
+}
+
+/// Pack a vector of GSM 7-bit septets into a vector of octets.
+///
+/// # Parameters
+/// * `septets`: [`Vec<u8>`] - A vector of GSM 7-bit septets.
+///
+/// # Returns
+/// A [`Vec<u8>`] containing `ceil(7 * septets.len() / 8)` octets.
+///
+/// # Panics
+/// * If the input vector (`septets`) contains values greater than `0x7F`.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u8> = encode_in_gsm7(vec![0x68, 0x65, 0x6C, 0x6C, 0x6F]); // "hello"
+/// let packed: Vec<u8> = pack_septets(&v);
+/// assert_eq!(packed, vec![0xE8, 0x32, 0x9B, 0xFD, 0x06]);
+/// ```
+pub fn pack_septets<T: AsRef<Vec<u8>>>(septets: T) -> Vec<u8> {
+    let septets: &Vec<u8> = septets.as_ref();
+    let mut octets: Vec<u8> = vec![0; (septets.len() * 7).div_ceil(8)];
+    for (i, septet) in septets.iter().enumerate() {
+        if *septet > 0x7F {
+            panic!("Invalid GSM 7-bit septet {}", septet);
+        }
+        // septets: 0b0110_1000 (h), 0b0110_0101 (e)
+        // The first septet is stored in bits 0..7 of octet 0, the second septet in bits 7..14
+        //
+        // 0b0110_0101 << 7 -> 0b0011_0010_1000_0000
+        //                            ^^^^^^^^^^^^^__ Bits 7..14
+        // octet 0: 0b1000_0000 | 0b0110_1000 -> 0b1110_1000
+        // octet 1: 0b0011_0010
+        let bit: usize = i * 7;
+        let shifted: u16 = (*septet as u16) << (bit % 8);
+        octets[bit / 8] |= (shifted & 0xFF) as u8;
+        if shifted > 0xFF {
+            octets[bit / 8 + 1] |= (shifted >> 8) as u8;
+        }
+    }
+    octets
+}
+
+/// Unpack a vector of octets into a vector of GSM 7-bit septets.
+///
+/// # Parameters
+/// * `octets`: [`Vec<u8>`] - A vector of packed octets.
+/// * `septet_count`: [`usize`] - The number of septets to unpack. It is required because the last octet may have seven spare bits, which would be read as an extra `@`.
+///
+/// # Returns
+/// A [`Vec<u8>`] containing the GSM 7-bit septets.
+///
+/// # Panics
+/// * If the input vector (`octets`) is too short to contain `septet_count` septets.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u8> = vec![0xE8, 0x32, 0x9B, 0xFD, 0x06];
+/// let septets: Vec<u8> = unpack_septets(&v, 5);
+/// assert_eq!(decode_from_gsm7(septets), vec![0x68, 0x65, 0x6C, 0x6C, 0x6F]); // "hello"
+/// ```
+pub fn unpack_septets<T: AsRef<Vec<u8>>>(octets: T, septet_count: usize) -> Vec<u8> {
+    let octets: &Vec<u8> = octets.as_ref();
+    if (septet_count * 7).div_ceil(8) > octets.len() {
+        panic!("Index out of bounds");
+    }
+    let mut septets: Vec<u8> = Vec::with_capacity(septet_count);
+    for i in 0..septet_count {
+        let bit: usize = i * 7;
+        let mut value: u16 = octets[bit / 8] as u16;
+        if bit / 8 + 1 < octets.len() {
+            value |= (octets[bit / 8 + 1] as u16) << 8;
+        }
+        septets.push(((value >> (bit % 8)) & 0x7F) as u8);
+    }
+    septets
+}
+
+/// Compute the encoding, the size and the number of segments of a SMS.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`SmsInfo`] describing the message.
+/// * If every unicode code point is in the GSM 7-bit default alphabet, the size is in septets (160 in a single SMS, 153 per segment).
+/// * Otherwise the size is in UTF-16 code units (70 in a single SMS, 67 per segment).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let info: SmsInfo = sms_info(vec![0x41; 161]);
+/// assert_eq!(info.encoding, SmsEncoding::Gsm7);
+/// assert_eq!(info.units, 161);
+/// assert_eq!(info.segments, 2);
+/// ```
+pub fn sms_info<T: AsRef<Vec<u32>>>(unicode_cp: T) -> SmsInfo {
+    let unicode_cp: &Vec<u32> = unicode_cp.as_ref();
+    let gsm7_sizes: Option<Vec<usize>> = unicode_cp
+        .iter()
+        .map(|cp| encode_code_point(*cp).map(|s| s.len()))
+        .collect();
+    let (encoding, sizes, single, multi) = match gsm7_sizes {
+        Some(sizes) => (
+            SmsEncoding::Gsm7,
+            sizes,
+            GSM7_SINGLE_SEGMENT,
+            GSM7_MULTI_SEGMENT,
+        ),
+        None => (
+            SmsEncoding::Ucs2,
+            unicode_cp
+                .iter()
+                .map(|cp| if *cp > 0xFFFF { 2 } else { 1 })
+                .collect(),
+            UCS2_SINGLE_SEGMENT,
+            UCS2_MULTI_SEGMENT,
+        ),
+    };
+    SmsInfo {
+        encoding,
+        units: sizes.iter().sum(),
+        segments: count_segments(&sizes, single, multi),
+    }
+}
+
+/// Encode a vector of unicode code points as a SMS payload.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`SmsPayload`] containing the GSM 7-bit septets if every unicode code point is in the GSM 7-bit default alphabet, otherwise the UCS-2 code points (see [`ucs2::encode_in_ucs2`]).
+/// * If the message contains unicode code points greater than `0xFFFF` (e.g. emoji), they are encoded as surrogate pairs (see [`utf16::encode_in_utf16`]), as counted by [`sms_info`].
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(encode_sms(vec![0x48, 0x69]), SmsPayload::Gsm7(vec![0x48, 0x69]));
+/// assert_eq!(encode_sms(vec![0x48, 0x3B1]), SmsPayload::Ucs2(vec![0x48, 0x3B1]));
+/// ```
+pub fn encode_sms<T: AsRef<Vec<u32>>>(unicode_cp: T) -> SmsPayload {
+    let unicode_cp: &Vec<u32> = unicode_cp.as_ref();
+    if is_gsm7_encodable(unicode_cp) {
+        SmsPayload::Gsm7(encode_in_gsm7(unicode_cp))
+    } else if unicode_cp.iter().any(|cp| *cp > 0xFFFF) {
+        SmsPayload::Ucs2(utf16::encode_in_utf16(unicode_cp))
+    } else {
+        SmsPayload::Ucs2(ucs2::encode_in_ucs2(unicode_cp))
+    }
+}
@@ -0,0 +1,238 @@
+/*!
+Validation of identifiers (UAX #31, Unicode Identifiers and Syntax).
+
+# Default identifiers
+The default identifier syntax of UAX #31 lets the identifiers be written in any language, not only with ASCII letters:
+* The first code point of an identifier must be an [`is_xid_start`] code point (`XID_Start`): the letters, the ideographs and the letter numbers (e.g. `Ⅻ`).
+* The following code points must be [`is_xid_continue`] code points (`XID_Continue`): the start code points, the combining marks, the digits and the connector punctuation (e.g. `_`).
+
+The `XID_Start` and `XID_Continue` properties are closed under NFKC: the NFKC of an identifier is still an identifier.
+
+# Profiles
+[`validate_identifier`] checks an identifier with an [`IdentifierProfile`]:
+* [`IdentifierProfile::Default`]: The default identifier syntax.
+* [`IdentifierProfile::Nfkc`]: The default identifier syntax, and the identifier must be in NFKC, so that the identifiers which look the same and are equal once normalized (e.g. `ﬁle` with the ligature U+FB01 and `file`) cannot be mixed.
+
+A disallowed identifier is reported as an [`IdentifierError`] with the first disallowed code point and its index.
+
+**Note**:
+
+* The profiles do not allow the `_` at the start of an identifier, or the `$` of some languages: a language with its own syntax can check the first code point itself and the rest of the identifier with [`is_xid_continue`].
+* The ZERO WIDTH JOINER and ZERO WIDTH NON-JOINER, which some scripts need inside words, are not allowed.
+* The mixing of scripts and the confusable identifiers are checked by the [`crate::security`] module.
+*/
+
+use std::fmt;
+
+use crate::normalize::{self, IsNormalized, NormalizationForm};
+use crate::tables;
+
+/// Find the first code point of an identifier which is not in NFKC.
+///
+/// The identifier is split into segments before every stable code point (a starter which is `Yes` in the NFKC quick
+/// check), since nothing before it is changed by what follows. Only the segment of a `Maybe` code point is normalized
+/// again, so that the search is linear in the length of the identifier for the segments of bounded length.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points, which is not in NFKC.
+///
+/// # Returns
+/// The index of the first code point such that the identifier up to it is not in NFKC.
+fn first_not_nfkc(unicode_cp: &[u32]) -> usize {
+    let table: &[(u32, u32, IsNormalized)] = normalize::quick_check_table(NormalizationForm::Nfkc);
+    // The start of the last segment, and the combining class of the previous code point
+    let mut start: usize = 0;
+    let mut last_ccc: u8 = 0;
+    for (i, code_point) in unicode_cp.iter().enumerate() {
+        let ccc: u8 = normalize::canonical_combining_class(*code_point);
+        if ccc != 0 && last_ccc > ccc {
+            return i;
+        }
+        match tables::lookup_range(table, *code_point) {
+            Some(IsNormalized::No) => return i,
+            // It may compose with the segment, including the decomposition of its starter
+            Some(IsNormalized::Maybe) => {
+                if !normalize::is_nfkc(unicode_cp[start..=i].to_vec()) {
+                    return i;
+                }
+            }
+            _ => {
+                if ccc == 0 {
+                    start = i;
+                }
+            }
+        }
+        last_ccc = ccc;
+    }
+    unicode_cp.len() - 1
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// The rules an identifier is checked with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdentifierProfile {
+    /// The default identifier syntax: an `XID_Start` code point followed by `XID_Continue` code points.
+    Default,
+    /// The default identifier syntax, in NFKC.
+    Nfkc,
+}
+
+/// The kind of an [`IdentifierError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentifierErrorKind {
+    /// The identifier is empty.
+    Empty,
+    /// The first code point is not an `XID_Start` code point.
+    InvalidStart(u32),
+    /// A code point after the first one is not an `XID_Continue` code point.
+    InvalidContinue(u32),
+    /// The identifier is not in NFKC from this code point (with [`IdentifierProfile::Nfkc`]).
+    NotNfkc(u32),
+}
+
+/// A disallowed identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdentifierError {
+    /// The kind of the error.
+    pub kind: IdentifierErrorKind,
+    /// The index of the disallowed code point.
+    pub position: usize,
+}
+
+impl fmt::Display for IdentifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            IdentifierErrorKind::Empty => write!(f, "Empty identifier")?,
+            IdentifierErrorKind::InvalidStart(cp) => {
+                write!(f, "Invalid identifier start {:x}", cp)?
+            }
+            IdentifierErrorKind::InvalidContinue(cp) => {
+                write!(f, "Invalid identifier character {:x}", cp)?
+            }
+            IdentifierErrorKind::NotNfkc(cp) => {
+                write!(f, "Not NFKC identifier character {:x}", cp)?
+            }
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for IdentifierError {}
+
+/// Check if a unicode code point can start an identifier (`XID_Start` property).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is an `XID_Start` code point.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_xid_start(0x61)); // a
+/// assert!(is_xid_start(0x3B1)); // α
+/// assert!(is_xid_start(0x5B57)); // 字
+/// assert!(!is_xid_start(0x30)); // 0
+/// assert!(!is_xid_start(0x5F)); // _
+/// ```
+pub fn is_xid_start(code_point: u32) -> bool {
+    tables::in_ranges(tables::identifier::XID_START, code_point)
+}
+
+/// Check if a unicode code point can continue an identifier (`XID_Continue` property).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is an `XID_Continue` code point.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_xid_continue(0x61)); // a
+/// assert!(is_xid_continue(0x30)); // 0
+/// assert!(is_xid_continue(0x5F)); // _
+/// assert!(is_xid_continue(0x301)); // combining acute accent
+/// assert!(!is_xid_continue(0x2D)); // -
+/// ```
+pub fn is_xid_continue(code_point: u32) -> bool {
+    tables::in_ranges(tables::identifier::XID_CONTINUE, code_point)
+}
+
+/// Check an identifier with a profile.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+/// * `profile`: [`IdentifierProfile`] - The rules the identifier is checked with.
+///
+/// # Returns
+/// [`Ok`] if the identifier is allowed by the profile, or the [`IdentifierError`] of the first disallowed code point.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = decode_from_utf8("ﬁle_2".as_bytes().to_vec()); // with the ligature U+FB01
+/// assert_eq!(validate_identifier(&v, IdentifierProfile::Default), Ok(()));
+/// let err: IdentifierError = validate_identifier(&v, IdentifierProfile::Nfkc).unwrap_err();
+/// assert_eq!(err.kind, IdentifierErrorKind::NotNfkc(0xFB01));
+/// assert_eq!(err.position, 0);
+/// ```
+pub fn validate_identifier(
+    unicode_cp: &[u32],
+    profile: IdentifierProfile,
+) -> Result<(), IdentifierError> {
+    let (first, rest): (&u32, &[u32]) = unicode_cp.split_first().ok_or(IdentifierError {
+        kind: IdentifierErrorKind::Empty,
+        position: 0,
+    })?;
+    if !is_xid_start(*first) {
+        return Err(IdentifierError {
+            kind: IdentifierErrorKind::InvalidStart(*first),
+            position: 0,
+        });
+    }
+    if let Some(i) = rest.iter().position(|cp| !is_xid_continue(*cp)) {
+        return Err(IdentifierError {
+            kind: IdentifierErrorKind::InvalidContinue(rest[i]),
+            position: i + 1,
+        });
+    }
+    if profile == IdentifierProfile::Nfkc && !normalize::is_nfkc(unicode_cp.to_vec()) {
+        let position: usize = first_not_nfkc(unicode_cp);
+        return Err(IdentifierError {
+            kind: IdentifierErrorKind::NotNfkc(unicode_cp[position]),
+            position,
+        });
+    }
+    Ok(())
+}
+
+/// Check if a slice of unicode code points is an identifier, with the default identifier syntax.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// [`Ok`] if the code points are an `XID_Start` code point followed by `XID_Continue` code points, or the [`IdentifierError`] of the first disallowed code point (see [`validate_identifier`]).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = decode_from_utf8("größe_2".as_bytes().to_vec());
+/// assert!(is_valid_identifier(&v).is_ok());
+///
+/// let v: Vec<u32> = decode_from_utf8("naïve-name".as_bytes().to_vec());
+/// let err: IdentifierError = is_valid_identifier(&v).unwrap_err();
+/// assert_eq!(err.kind, IdentifierErrorKind::InvalidContinue(0x2D));
+/// assert_eq!(err.position, 5);
+/// ```
+pub fn is_valid_identifier(unicode_cp: &[u32]) -> Result<(), IdentifierError> {
+    validate_identifier(unicode_cp, IdentifierProfile::Default)
+}
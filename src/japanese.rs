@@ -0,0 +1,204 @@
+/*!
+Width folding and kana conversions of Japanese text.
+
+# Width folding
+Japanese text mixes characters of different widths, which look alike and are often typed by mistake (e.g. `ＡＢＣ` instead of `ABC`, or the half-width katakana of old systems):
+* [`fold_fullwidth`]: The full-width forms (U+FF01 to U+FF60, U+FFE0 to U+FFE6) and the ideographic space U+3000 are folded to the ASCII characters and the symbols they are the wide variant of (`ＡＢＣ１２３` to `ABC123`).
+* [`fold_halfwidth`]: The half-width katakana and punctuation (U+FF61 to U+FF9F) and the half-width symbols (U+FFE8 to U+FFEE) are folded to the usual (full-width) characters (`ｶﾀｶﾅ` to `カタカナ`). The half-width voiced sound marks `ﾞ` and `ﾟ` are composed with the kana before them (`ｶﾞ` to `ガ`, `ﾊﾟ` to `パ`).
+* [`fold_width`]: Both foldings.
+
+The foldings follow the `<wide>` and `<narrow>` compatibility decompositions of `UnicodeData.txt`, which are also applied by the NFKC (see [`crate::normalize`]), without changing the other characters of the text.
+
+# Kana conversion
+The Hiragana and the Katakana have the same syllables in the same order (`あ` U+3042 and `ア` U+30A2):
+* [`hiragana_to_katakana`]: `ひらがな` to `ヒラガナ`.
+* [`katakana_to_hiragana`]: `カタカナ` to `かたかな`.
+
+**Note**:
+
+* The transformations are independent, so they can be chained in any order (e.g. [`fold_halfwidth`] before [`katakana_to_hiragana`], to convert the half-width katakana too).
+* The prolonged sound mark `ー` U+30FC is used with both scripts, and is not converted.
+* The katakana `ヷ`, `ヸ`, `ヹ` and `ヺ` have no precomposed hiragana: they are converted to `わ`, `ゐ`, `ゑ` and `を` followed by U+3099 COMBINING KATAKANA-HIRAGANA VOICED SOUND MARK.
+* The half-width Hangul letters (U+FFA0 to U+FFDC) are not folded.
+*/
+
+use crate::normalize;
+
+/// U+3099 COMBINING KATAKANA-HIRAGANA VOICED SOUND MARK.
+const VOICED_SOUND_MARK: u32 = 0x3099;
+
+/// U+309A COMBINING KATAKANA-HIRAGANA SEMI-VOICED SOUND MARK.
+const SEMI_VOICED_SOUND_MARK: u32 = 0x309A;
+
+/// The offset from a hiragana to its katakana.
+const KANA_OFFSET: u32 = 0x60;
+
+/// Check if a code point is folded by [`fold_fullwidth`].
+fn is_fullwidth(code_point: u32) -> bool {
+    matches!(code_point, 0x3000 | 0xFF01..=0xFF60 | 0xFFE0..=0xFFE6)
+}
+
+/// Check if a code point is folded by [`fold_halfwidth`].
+fn is_halfwidth(code_point: u32) -> bool {
+    matches!(code_point, 0xFF61..=0xFF9F | 0xFFE8..=0xFFEE)
+}
+
+/// Fold the code points of a vector with their `<wide>` or `<narrow>` compatibility decomposition.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `fullwidth`: [`bool`] - A flag to fold the full-width forms.
+/// * `halfwidth`: [`bool`] - A flag to fold the half-width forms, and compose the voiced sound marks.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the folded code points.
+fn fold<T: AsRef<Vec<u32>>>(unicode_cp: T, fullwidth: bool, halfwidth: bool) -> Vec<u32> {
+    let mut folded: Vec<u32> = Vec::with_capacity(unicode_cp.as_ref().len());
+    for code_point in unicode_cp.as_ref() {
+        if !(fullwidth && is_fullwidth(*code_point) || halfwidth && is_halfwidth(*code_point)) {
+            folded.push(*code_point);
+            continue;
+        }
+        // The mappings of the width variants are single code points
+        let mapped: u32 =
+            normalize::decomposition_mapping(*code_point, true).map_or(*code_point, |m| m[0]);
+        if mapped == VOICED_SOUND_MARK || mapped == SEMI_VOICED_SOUND_MARK {
+            match folded
+                .last()
+                .and_then(|last| normalize::compose_pair(*last, mapped))
+            {
+                Some(composed) => *folded.last_mut().unwrap() = composed,
+                // The spacing sound marks U+309B and U+309C, when there is nothing to compose with
+                None => folded.push(mapped + 2),
+            }
+        } else {
+            folded.push(mapped);
+        }
+    }
+    folded
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// Fold the full-width forms of a vector of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the code points, with the full-width forms replaced by the characters they are the wide variant of (e.g. `Ａ` by `A`, `￥` by `¥`, and the ideographic space by a space).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "ＡＢＣ　１２３！".chars().map(|c| c as u32).collect();
+/// let folded: String = fold_fullwidth(&v).iter().map(|cp| char::from_u32(*cp).unwrap()).collect();
+/// assert_eq!(folded, "ABC 123!");
+/// ```
+pub fn fold_fullwidth<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    fold(unicode_cp, true, false)
+}
+
+/// Fold the half-width forms of a vector of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the code points, with the half-width katakana, punctuation and symbols replaced by the usual characters (e.g. `ｶ` by `カ`, `｡` by `。`).
+/// The half-width voiced sound marks are composed with the kana before them, or replaced by the spacing marks `゛` and `゜` if they cannot be composed.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "ｶﾀｶﾅ ｶﾞｲﾄﾞﾌﾞｯｸ｡".chars().map(|c| c as u32).collect();
+/// let folded: String = fold_halfwidth(&v).iter().map(|cp| char::from_u32(*cp).unwrap()).collect();
+/// assert_eq!(folded, "カタカナ ガイドブック。");
+/// ```
+pub fn fold_halfwidth<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    fold(unicode_cp, false, true)
+}
+
+/// Fold the full-width and the half-width forms of a vector of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the code points folded by [`fold_fullwidth`] and [`fold_halfwidth`].
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "ＡＢＣｶﾀｶﾅ".chars().map(|c| c as u32).collect();
+/// let folded: String = fold_width(&v).iter().map(|cp| char::from_u32(*cp).unwrap()).collect();
+/// assert_eq!(folded, "ABCカタカナ");
+/// ```
+pub fn fold_width<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    fold(unicode_cp, true, true)
+}
+
+/// Convert the hiragana of a vector of unicode code points to katakana.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the code points, with the hiragana (U+3041 to U+3096) and the hiragana iteration marks (`ゝ`, `ゞ`) replaced by the katakana.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "ひらがなとカタカナ".chars().map(|c| c as u32).collect();
+/// let converted: String = hiragana_to_katakana(&v).iter().map(|cp| char::from_u32(*cp).unwrap()).collect();
+/// assert_eq!(converted, "ヒラガナトカタカナ");
+/// ```
+pub fn hiragana_to_katakana<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    unicode_cp
+        .as_ref()
+        .iter()
+        .map(|cp| match cp {
+            0x3041..=0x3096 | 0x309D..=0x309E => cp + KANA_OFFSET,
+            _ => *cp,
+        })
+        .collect()
+}
+
+/// Convert the katakana of a vector of unicode code points to hiragana.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the code points, with the katakana (U+30A1 to U+30FA) and the katakana iteration marks (`ヽ`, `ヾ`) replaced by the hiragana.
+/// The katakana without a precomposed hiragana (`ヷ`, `ヸ`, `ヹ` and `ヺ`) are replaced by a hiragana followed by U+3099.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "カタカナとコーヒー".chars().map(|c| c as u32).collect();
+/// let converted: String = katakana_to_hiragana(&v).iter().map(|cp| char::from_u32(*cp).unwrap()).collect();
+/// assert_eq!(converted, "かたかなとこーひー");
+/// ```
+pub fn katakana_to_hiragana<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    let mut converted: Vec<u32> = Vec::with_capacity(unicode_cp.as_ref().len());
+    for code_point in unicode_cp.as_ref() {
+        match code_point {
+            0x30A1..=0x30F6 | 0x30FD..=0x30FE => converted.push(code_point - KANA_OFFSET),
+            // ヷ, ヸ, ヹ and ヺ are the voiced ワ, ヰ, ヱ and ヲ
+            0x30F7..=0x30FA => {
+                converted.push(code_point - 0x30F7 + 0x308F);
+                converted.push(VOICED_SOUND_MARK);
+            }
+            _ => converted.push(*code_point),
+        }
+    }
+    converted
+}
@@ -1,13 +1,47 @@
 #![deny(unconditional_recursion)]
 
+pub mod bidi;
+pub mod case;
+pub mod collate;
+pub mod emoji;
+pub mod escape;
+pub mod gsm7;
+pub mod identifier;
+pub mod japanese;
+pub mod linebreak;
+pub mod mime;
+pub mod normalize;
+pub mod percent;
+pub mod security;
+pub mod segment;
+pub mod transcode;
 pub mod ucs2;
 pub mod unicode;
 pub mod utf16;
 pub mod utf8;
+pub mod whitespace;
+
+mod tables;
 
 pub mod prelude {
+    pub use crate::bidi::*;
+    pub use crate::case::*;
+    pub use crate::collate::*;
+    pub use crate::emoji::*;
+    pub use crate::escape::*;
+    pub use crate::gsm7::*;
+    pub use crate::identifier::*;
+    pub use crate::japanese::*;
+    pub use crate::linebreak::*;
+    pub use crate::mime::*;
+    pub use crate::normalize::*;
+    pub use crate::percent::*;
+    pub use crate::security::*;
+    pub use crate::segment::*;
+    pub use crate::transcode::*;
     pub use crate::ucs2::*;
     pub use crate::unicode::*;
     pub use crate::utf16::*;
     pub use crate::utf8::*;
+    pub use crate::whitespace::*;
 }
@@ -0,0 +1,614 @@
+/*!
+Line breaking of unicode code points (UAX #14).
+
+# Line breaking opportunities
+A line can be broken only at some positions of a text, which depend on the `Line_Break` class of the code points around them:
+* A **mandatory** break follows a line terminator (`CR`, `LF`, `CR LF`, `U+000B`, `U+000C`, `U+0085`, `U+2028` and `U+2029`) and the end of the text.
+* An **allowed** break is a position where the line can be wrapped, like after the spaces between two words, after a hyphen or between two ideographs.
+
+The positions are computed with the default line breaking algorithm of the Unicode Standard (rules LB1 to LB31), including:
+* The East Asian rules: the ideographs can be broken between each other, but not before the small kana and the closing punctuation (`NS`, `CL`), and the ambiguous characters are treated as alphabetic.
+* The emoji rules: no break inside a flag (LB30a) and between an emoji base and its modifier (LB30b).
+* The numbers: the tailoring of Example 7 of UAX #14 for LB25, so that `$(12.35)` or `-1,234.5%` is never broken.
+
+The characters of the scripts that need a dictionary to find the breaks (`SA`, like Thai) are treated as alphabetic, and the combining marks of these scripts as combining marks.
+
+# Wrapping
+[`wrap`] breaks a text into lines at the line breaking opportunities, so that every line fits in a number of columns of a terminal:
+* The width of a line is its [`display_width`](crate::unicode::display_width) in the columns of a terminal (e.g. 2 columns for the ideographs and the emoji, 0 for the combining marks).
+* The lines are filled greedily, and the spaces at the end of a line do not count towards its width (they are removed, together with the line terminators).
+* A word wider than the line is broken between its grapheme clusters.
+
+# Code units
+Like in the [`crate::segment`] module, the line breaking works on vectors of unicode code points and on UTF-8 and UTF-16 buffers:
+every break position is the index of the code unit (code point, byte or UTF-16 code point) after the break.
+*/
+
+use crate::emoji;
+use crate::segment::{self, Decoder};
+use crate::tables;
+use crate::unicode;
+use crate::unicode::{EastAsianWidth, GeneralCategory};
+
+/// The line break classes of the unicode code points (values of the `Line_Break` property).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineBreak {
+    /// Cause a line break after the character (`BK`).
+    MandatoryBreak,
+    /// The carriage return (`CR`).
+    CarriageReturn,
+    /// The line feed (`LF`).
+    LineFeed,
+    /// The next line character U+0085 (`NL`).
+    NextLine,
+    /// The space U+0020 (`SP`).
+    Space,
+    /// The zero width space U+200B (`ZW`).
+    ZwSpace,
+    /// The zero width joiner U+200D (`ZWJ`).
+    Zwj,
+    /// A combining mark, which takes the class of the character before it (`CM`).
+    CombiningMark,
+    /// Prohibit a line break before and after the character (`WJ`).
+    WordJoiner,
+    /// A non-breaking character, like the no-break space (`GL`).
+    Glue,
+    /// Allow a line break after the character, like the spaces other than U+0020 (`BA`).
+    BreakAfter,
+    /// Allow a line break before the character (`BB`).
+    BreakBefore,
+    /// Allow a line break before and after the character, like the em dash (`B2`).
+    BreakBoth,
+    /// The hyphen-minus U+002D (`HY`).
+    Hyphen,
+    /// An object replacement character, whose breaks are up to the application (`CB`).
+    ContingentBreak,
+    /// A closing punctuation (`CL`).
+    ClosePunctuation,
+    /// A closing parenthesis or square bracket (`CP`).
+    CloseParenthesis,
+    /// An exclamation or interrogation mark (`EX`).
+    Exclamation,
+    /// An inseparable character, like the ellipsis (`IN`).
+    Inseparable,
+    /// A character that cannot start a line, like the small kana (`NS`).
+    Nonstarter,
+    /// An opening punctuation (`OP`).
+    OpenPunctuation,
+    /// A quotation mark (`QU`).
+    Quotation,
+    /// A separator inside numbers, like the comma and the full stop (`IS`).
+    InfixNumeric,
+    /// A digit (`NU`).
+    Numeric,
+    /// A character following numbers, like the percent sign (`PO`).
+    PostfixNumeric,
+    /// A character preceding numbers, like the currency signs (`PR`).
+    PrefixNumeric,
+    /// The solidus U+002F, which allows a line break after it except before digits (`SY`).
+    BreakSymbols,
+    /// A character which is alphabetic or ideographic depending on the context (`AI`).
+    Ambiguous,
+    /// An alphabetic character or a symbol (`AL`).
+    Alphabetic,
+    /// A small kana, which is a nonstarter in strict line breaking (`CJ`).
+    ConditionalJapaneseStarter,
+    /// An emoji which can be followed by an emoji modifier (`EB`).
+    EBase,
+    /// An emoji modifier, like the skin tones (`EM`).
+    EModifier,
+    /// A Hangul LV syllable (`H2`).
+    H2,
+    /// A Hangul LVT syllable (`H3`).
+    H3,
+    /// A Hebrew letter (`HL`).
+    HebrewLetter,
+    /// An ideographic character (`ID`).
+    Ideographic,
+    /// A Hangul leading jamo (`JL`).
+    Jl,
+    /// A Hangul vowel jamo (`JV`).
+    Jv,
+    /// A Hangul trailing jamo (`JT`).
+    Jt,
+    /// A regional indicator (`RI`).
+    RegionalIndicator,
+    /// A character of a script which needs a dictionary to find the breaks, like Thai (`SA`).
+    ComplexContext,
+    /// An unassigned code point or a character with an unknown line breaking behavior (`XX`).
+    Unknown,
+    /// A surrogate code point (`SG`).
+    Surrogate,
+}
+
+/// The kind of a line breaking opportunity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakOpportunity {
+    /// The line must be broken, after a line terminator or at the end of the text.
+    Mandatory,
+    /// The line can be broken.
+    Allowed,
+}
+
+/// Resolve the line break class of a code point with the rule LB1 (and the `CJ` class as `NS`).
+fn resolved_line_break(code_point: u32) -> LineBreak {
+    match line_break(code_point) {
+        LineBreak::Ambiguous | LineBreak::Surrogate | LineBreak::Unknown => LineBreak::Alphabetic,
+        LineBreak::ComplexContext => match unicode::general_category(code_point) {
+            GeneralCategory::NonspacingMark | GeneralCategory::SpacingMark => {
+                LineBreak::CombiningMark
+            }
+            _ => LineBreak::Alphabetic,
+        },
+        LineBreak::ConditionalJapaneseStarter => LineBreak::Nonstarter,
+        lb => lb,
+    }
+}
+
+/// Check if a code point is wide, full-width or half-width, which excludes it from the rule LB30.
+fn is_wide(code_point: u32) -> bool {
+    matches!(
+        unicode::east_asian_width(code_point),
+        EastAsianWidth::Fullwidth | EastAsianWidth::Wide | EastAsianWidth::Halfwidth
+    )
+}
+
+/// The line breaking state before a position.
+#[derive(Clone, Copy)]
+struct LineBreakState {
+    /// The class of the code point before the position, [`None`] at the beginning.
+    raw: Option<LineBreak>,
+    /// The class of the code point before the position, with the combining marks treated as the character before them (LB9, LB10).
+    prev: LineBreak,
+    /// The class before `prev`.
+    prev_prev: LineBreak,
+    /// The code point of `prev`.
+    prev_code_point: u32,
+    /// The last class before the position which is not a space (LB8, LB14 to LB17).
+    before_spaces: LineBreak,
+    /// The number of consecutive regional indicators ending at `prev` (LB30a).
+    regional_indicators: usize,
+    /// The position follows `NU (NU | SY | IS)*` (LB25).
+    number: bool,
+    /// The position follows `NU (NU | SY | IS)* (CL | CP)` (LB25).
+    closed_number: bool,
+}
+
+impl LineBreakState {
+    /// Move the position after a code point.
+    fn push(&mut self, code_point: u32, next: LineBreak) {
+        use LineBreak as L;
+        let raw: Option<LineBreak> = self.raw.replace(next);
+        match (raw, next) {
+            // A combining mark without a base is alphabetic (LB10)
+            (
+                None
+                | Some(
+                    L::MandatoryBreak
+                    | L::CarriageReturn
+                    | L::LineFeed
+                    | L::NextLine
+                    | L::Space
+                    | L::ZwSpace,
+                ),
+                L::CombiningMark | L::Zwj,
+            ) => self.push_resolved(code_point, L::Alphabetic),
+            // A combining mark takes the class of the character before it (LB9)
+            (_, L::CombiningMark | L::Zwj) => {}
+            _ => self.push_resolved(code_point, next),
+        }
+    }
+
+    /// Move the position after a code point which is not ignored by the rule LB9.
+    fn push_resolved(&mut self, code_point: u32, next: LineBreak) {
+        use LineBreak as L;
+        self.prev_prev = self.prev;
+        self.prev = next;
+        self.prev_code_point = code_point;
+        if next != L::Space {
+            self.before_spaces = next;
+        }
+        self.regional_indicators = match next {
+            L::RegionalIndicator => self.regional_indicators + 1,
+            _ => 0,
+        };
+        match next {
+            L::Numeric => {
+                self.number = true;
+                self.closed_number = false;
+            }
+            L::BreakSymbols | L::InfixNumeric if self.number => {}
+            L::ClosePunctuation | L::CloseParenthesis if self.number => {
+                self.number = false;
+                self.closed_number = true;
+            }
+            _ => {
+                self.number = false;
+                self.closed_number = false;
+            }
+        }
+    }
+}
+
+/// An iterator over the line breaking opportunities of a slice of code units.
+///
+/// It is created by [`line_breaks`], [`line_breaks_utf8`] and [`line_breaks_utf16`].
+#[derive(Clone)]
+pub struct LineBreaks<'a, T> {
+    units: &'a [T],
+    decode: Decoder<T>,
+    position: usize,
+    state: LineBreakState,
+    done: bool,
+}
+
+impl<'a, T> LineBreaks<'a, T> {
+    /// Create an iterator over the line breaking opportunities of a slice of code units.
+    fn new(units: &'a [T], decode: Decoder<T>) -> Self {
+        LineBreaks {
+            units,
+            decode,
+            position: 0,
+            state: LineBreakState {
+                raw: None,
+                prev: LineBreak::Alphabetic,
+                prev_prev: LineBreak::Alphabetic,
+                prev_code_point: 0,
+                before_spaces: LineBreak::Alphabetic,
+                regional_indicators: 0,
+                number: false,
+                closed_number: false,
+            },
+            done: false,
+        }
+    }
+
+    /// Get the class of the first code point from the index `i`, skipping the combining marks (LB9).
+    fn lookahead(&self, mut i: usize) -> Option<LineBreak> {
+        while let Some((code_point, len)) = (self.decode)(self.units, i) {
+            match resolved_line_break(code_point) {
+                LineBreak::CombiningMark | LineBreak::Zwj => i += len,
+                lb => return Some(lb),
+            }
+        }
+        None
+    }
+
+    /// Check if there is a line breaking opportunity before a code point (rules LB4 to LB31).
+    ///
+    /// # Parameters
+    /// * `code_point`: [`u32`] - The unicode code point after the position.
+    /// * `next`: [`LineBreak`] - The resolved class of the code point after the position.
+    /// * `after`: [`usize`] - The index of the code unit after the code point.
+    ///
+    /// # Returns
+    /// The [`BreakOpportunity`] before the code point, or [`None`] if the line cannot be broken.
+    fn break_before(
+        &self,
+        code_point: u32,
+        next: LineBreak,
+        after: usize,
+    ) -> Option<BreakOpportunity> {
+        use LineBreak as L;
+        let state: &LineBreakState = &self.state;
+        let allowed: Option<BreakOpportunity> = Some(BreakOpportunity::Allowed);
+        match (state.raw, next) {
+            (Some(L::MandatoryBreak), _) => return Some(BreakOpportunity::Mandatory),
+            (Some(L::CarriageReturn), L::LineFeed) => return None,
+            (Some(L::CarriageReturn | L::LineFeed | L::NextLine), _) => {
+                return Some(BreakOpportunity::Mandatory)
+            }
+            (_, L::MandatoryBreak | L::CarriageReturn | L::LineFeed | L::NextLine) => return None,
+            (_, L::Space | L::ZwSpace) => return None,
+            _ if state.before_spaces == L::ZwSpace => return allowed,
+            (Some(L::Zwj), _) => return None,
+            (Some(L::Space), L::CombiningMark | L::Zwj) => {}
+            (_, L::CombiningMark | L::Zwj) => return None,
+            _ => {}
+        }
+        // A combining mark after a space is alphabetic (LB10)
+        let next: LineBreak = match next {
+            L::CombiningMark | L::Zwj => L::Alphabetic,
+            lb => lb,
+        };
+        let (prev, before_spaces) = (state.prev, state.before_spaces);
+        let is_letter = |lb: LineBreak| matches!(lb, L::Alphabetic | L::HebrewLetter);
+        let is_jamo = |lb: LineBreak| matches!(lb, L::Jl | L::Jv | L::Jt | L::H2 | L::H3);
+        let joined: bool = match (prev, next) {
+            (L::WordJoiner, _) | (_, L::WordJoiner) => true,
+            (L::Glue, _) => true,
+            (p, L::Glue) if !matches!(p, L::Space | L::BreakAfter | L::Hyphen) => true,
+            (
+                _,
+                L::ClosePunctuation
+                | L::CloseParenthesis
+                | L::Exclamation
+                | L::InfixNumeric
+                | L::BreakSymbols,
+            ) => true,
+            _ if before_spaces == L::OpenPunctuation => true,
+            (_, L::OpenPunctuation) if before_spaces == L::Quotation => true,
+            (_, L::Nonstarter)
+                if matches!(before_spaces, L::ClosePunctuation | L::CloseParenthesis) =>
+            {
+                true
+            }
+            (_, L::BreakBoth) if before_spaces == L::BreakBoth => true,
+            (L::Space, _) => false,
+            (L::Quotation, _) | (_, L::Quotation) => true,
+            (L::ContingentBreak, _) | (_, L::ContingentBreak) => false,
+            (_, L::BreakAfter | L::Hyphen | L::Nonstarter) | (L::BreakBefore, _) => true,
+            (L::Hyphen | L::BreakAfter, _) if state.prev_prev == L::HebrewLetter => true,
+            (L::BreakSymbols, L::HebrewLetter) => true,
+            (_, L::Inseparable) => true,
+            (p, L::Numeric) if is_letter(p) => true,
+            (L::Numeric, n) if is_letter(n) => true,
+            (L::PrefixNumeric, L::Ideographic | L::EBase | L::EModifier) => true,
+            (L::Ideographic | L::EBase | L::EModifier, L::PostfixNumeric) => true,
+            (L::PrefixNumeric | L::PostfixNumeric, n) if is_letter(n) => true,
+            (p, L::PrefixNumeric | L::PostfixNumeric) if is_letter(p) => true,
+            // LB25, as tailored in Example 7 (the rules before `HY`, `SY`, `IS`, `CL` and `CP` are already covered by LB13 and LB21)
+            (L::PrefixNumeric | L::PostfixNumeric, L::Numeric) => true,
+            (L::PrefixNumeric | L::PostfixNumeric, L::OpenPunctuation)
+                if self.lookahead(after) == Some(L::Numeric) =>
+            {
+                true
+            }
+            (L::OpenPunctuation | L::Hyphen, L::Numeric) => true,
+            (_, L::Numeric) if state.number => true,
+            (_, L::PostfixNumeric | L::PrefixNumeric) if state.number || state.closed_number => {
+                true
+            }
+            (L::Jl, L::Jl | L::Jv | L::H2 | L::H3) => true,
+            (L::Jv | L::H2, L::Jv | L::Jt) => true,
+            (L::Jt | L::H3, L::Jt) => true,
+            (p, L::PostfixNumeric) if is_jamo(p) => true,
+            (L::PrefixNumeric, n) if is_jamo(n) => true,
+            (p, n) if is_letter(p) && is_letter(n) => true,
+            (L::InfixNumeric, n) if is_letter(n) => true,
+            (p, L::OpenPunctuation)
+                if (is_letter(p) || p == L::Numeric) && !is_wide(code_point) =>
+            {
+                true
+            }
+            (L::CloseParenthesis, n)
+                if (is_letter(n) || n == L::Numeric) && !is_wide(state.prev_code_point) =>
+            {
+                true
+            }
+            (L::RegionalIndicator, L::RegionalIndicator) if state.regional_indicators % 2 == 1 => {
+                true
+            }
+            (L::EBase, L::EModifier) => true,
+            (_, L::EModifier)
+                if emoji::is_extended_pictographic(state.prev_code_point)
+                    && unicode::general_category(state.prev_code_point)
+                        == GeneralCategory::Unassigned =>
+            {
+                true
+            }
+            _ => false,
+        };
+        if joined {
+            None
+        } else {
+            allowed
+        }
+    }
+}
+
+impl<T> Iterator for LineBreaks<'_, T> {
+    type Item = (usize, BreakOpportunity);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        while let Some((code_point, len)) = (self.decode)(self.units, self.position) {
+            let next: LineBreak = resolved_line_break(code_point);
+            let position: usize = self.position;
+            let opportunity: Option<BreakOpportunity> = match self.state.raw {
+                // Never break at the start of the text (LB2)
+                None => None,
+                Some(_) => self.break_before(code_point, next, position + len),
+            };
+            self.state.push(code_point, next);
+            self.position += len;
+            if let Some(opportunity) = opportunity {
+                return Some((position, opportunity));
+            }
+        }
+        // Always break at the end of the text (LB3)
+        self.done = true;
+        match self.position {
+            0 => None,
+            position => Some((position, BreakOpportunity::Mandatory)),
+        }
+    }
+}
+
+/// Check if a code point hangs at the end of a line: a space or a line terminator.
+fn is_hanging(code_point: u32) -> bool {
+    matches!(
+        line_break(code_point),
+        LineBreak::Space
+            | LineBreak::MandatoryBreak
+            | LineBreak::CarriageReturn
+            | LineBreak::LineFeed
+            | LineBreak::NextLine
+    )
+}
+
+/// Push a line without the spaces and the line terminators at its end.
+fn push_line(lines: &mut Vec<Vec<u32>>, line: &mut Vec<u32>) {
+    let len: usize = line.len() - line.iter().rev().take_while(|cp| is_hanging(**cp)).count();
+    line.truncate(len);
+    lines.push(std::mem::take(line));
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// Get the line break class of a unicode code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The [`LineBreak`] class of the code point, [`LineBreak::Unknown`] if it has none.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// assert_eq!(line_break(0x0041), LineBreak::Alphabetic);
+/// assert_eq!(line_break(0x0020), LineBreak::Space);
+/// assert_eq!(line_break(0x4E00), LineBreak::Ideographic);
+/// assert_eq!(line_break(0x3001), LineBreak::ClosePunctuation);
+/// assert_eq!(line_break(0xE000), LineBreak::Unknown);
+/// ```
+pub fn line_break(code_point: u32) -> LineBreak {
+    tables::lookup_range(tables::line_break::LINE_BREAK, code_point).unwrap_or(LineBreak::Unknown)
+}
+
+/// Iterate the line breaking opportunities of a slice of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// A [`LineBreaks`] iterator over the indexes of the code points after the breaks, with their [`BreakOpportunity`].
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = vec![0x61, 0x20, 0x62, 0x2D, 0x63, 0x0A, 0x64]; // "a b-c\nd"
+/// let breaks: Vec<(usize, BreakOpportunity)> = line_breaks(&v).collect();
+/// assert_eq!(
+///     breaks,
+///     vec![
+///         (2, BreakOpportunity::Allowed),
+///         (4, BreakOpportunity::Allowed),
+///         (6, BreakOpportunity::Mandatory),
+///         (7, BreakOpportunity::Mandatory)
+///     ]
+/// );
+/// ```
+pub fn line_breaks(unicode_cp: &[u32]) -> LineBreaks<'_, u32> {
+    LineBreaks::new(unicode_cp, segment::decode_code_point)
+}
+
+/// Iterate the line breaking opportunities of a slice of UTF-8 code points.
+///
+/// # Parameters
+/// * `utf8_cp`: [`&[u8]`] - A slice of UTF-8 code points.
+///
+/// # Returns
+/// A [`LineBreaks`] iterator over the indexes of the bytes after the breaks, with their [`BreakOpportunity`].
+///
+/// # Panics
+/// * If the input slice (`utf8_cp`) of UTF-8 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let s: &str = "漢字。(1.5%)";
+/// let breaks: Vec<usize> = line_breaks_utf8(s.as_bytes()).map(|(i, _)| i).collect();
+/// assert_eq!(breaks, vec![3, 9, 15]);
+/// ```
+pub fn line_breaks_utf8(utf8_cp: &[u8]) -> LineBreaks<'_, u8> {
+    LineBreaks::new(utf8_cp, crate::utf8::decode_symbol)
+}
+
+/// Iterate the line breaking opportunities of a slice of UTF-16 code points.
+///
+/// # Parameters
+/// * `utf16_cp`: [`&[u16]`] - A slice of UTF-16 code points.
+///
+/// # Returns
+/// A [`LineBreaks`] iterator over the indexes of the UTF-16 code points after the breaks, with their [`BreakOpportunity`].
+///
+/// # Panics
+/// * If the input slice (`utf16_cp`) of UTF-16 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u16> = "👍🏽 ok".encode_utf16().collect();
+/// let breaks: Vec<usize> = line_breaks_utf16(&v).map(|(i, _)| i).collect();
+/// assert_eq!(breaks, vec![5, 7]);
+/// ```
+pub fn line_breaks_utf16(utf16_cp: &[u16]) -> LineBreaks<'_, u16> {
+    LineBreaks::new(utf16_cp, crate::utf16::decode_symbol)
+}
+
+/// Wrap a vector of unicode code points into lines that fit in a number of columns.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `width`: [`usize`] - The maximum width of a line, in the columns of a terminal.
+///
+/// # Returns
+/// A [`Vec<Vec<u32>>`] containing the lines, without the spaces and the line terminators at their end.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "The quick brown fox 跳过了懒狗".chars().map(|c| c as u32).collect();
+/// let lines: Vec<String> = wrap(&v, 10)
+///     .iter()
+///     .map(|line| line.iter().map(|cp| char::from_u32(*cp).unwrap()).collect())
+///     .collect();
+/// assert_eq!(lines, vec!["The quick", "brown fox", "跳过了懒狗"]);
+/// ```
+pub fn wrap<T: AsRef<Vec<u32>>>(unicode_cp: T, width: usize) -> Vec<Vec<u32>> {
+    let v: &[u32] = unicode_cp.as_ref();
+    let mut lines: Vec<Vec<u32>> = Vec::new();
+    let mut line: Vec<u32> = Vec::new();
+    let mut line_width: usize = 0;
+    let mut start: usize = 0;
+    for (end, opportunity) in line_breaks(v) {
+        let chunk: &[u32] = &v[start..end];
+        start = end;
+        // The spaces and the line terminators at the end of the chunk do not need to fit in the line
+        let len: usize = chunk.len() - chunk.iter().rev().take_while(|cp| is_hanging(**cp)).count();
+        let chunk_width: usize = unicode::display_width(&chunk[..len]);
+        if !line.is_empty() && line_width + chunk_width > width {
+            push_line(&mut lines, &mut line);
+            line_width = 0;
+        }
+        if chunk_width > width {
+            // Break the chunk between its grapheme clusters
+            for cluster in segment::graphemes(&chunk[..len]) {
+                let cluster_width: usize = unicode::display_width(&chunk[cluster.clone()]);
+                if !line.is_empty() && line_width + cluster_width > width {
+                    push_line(&mut lines, &mut line);
+                    line_width = 0;
+                }
+                line.extend_from_slice(&chunk[cluster]);
+                line_width += cluster_width;
+            }
+            line.extend_from_slice(&chunk[len..]);
+            line_width += unicode::display_width(&chunk[len..]);
+        } else {
+            line.extend_from_slice(chunk);
+            line_width += unicode::display_width(chunk);
+        }
+        let terminated: bool = chunk
+            .last()
+            .is_some_and(|cp| is_hanging(*cp) && line_break(*cp) != LineBreak::Space);
+        if opportunity == BreakOpportunity::Mandatory && terminated {
+            push_line(&mut lines, &mut line);
+            line_width = 0;
+        }
+    }
+    if !line.is_empty() {
+        push_line(&mut lines, &mut line);
+    }
+    lines
+}
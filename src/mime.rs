@@ -0,0 +1,591 @@
+/*!
+MIME encoded-words (RFC 2047) and quoted-printable (RFC 2045) encoding and decoding.
+
+# Encoded-words
+An encoded-word carries non-ASCII text in an email header, and it has the form `=?charset?encoding?encoded-text?=`.
+* The `charset` is the name of the character set of the encoded bytes (see [charsets](#charsets)).
+* The `encoding` is `B` ([base64](#b-encoding)) or `Q` ([quoted-printable like](#q-encoding)), case insensitive.
+* An encoded-word is at most 75 characters long, so a long text is split into several encoded-words, folded on different lines.
+
+# Decoding
+A header is decoded using the following rules:
+* The header is unfolded, removing the line breaks followed by a space or a tab.
+* Every encoded-word is decoded into the bytes of its `encoded-text`, which are decoded into unicode code points by the decoder of its `charset`.
+* The white space between two adjacent encoded-words is ignored.
+* Everything else is decoded as it is.
+
+# Encoding
+A vector of unicode code points is encoded into UTF-8 encoded-words using the following rules:
+* Every encoded-word is at most 75 characters long, and the encoded-words are separated by a folding white space (`\r\n `).
+* A UTF-8 code point is never split across two encoded-words.
+
+## B encoding
+
+The bytes are encoded in base64 (RFC 4648), using the alphabet `A-Z`, `a-z`, `0-9`, `+`, `/` and the padding `=`.
+
+## Q encoding
+
+* The space is written as `_`.
+* The characters `A-Z`, `a-z`, `0-9`, `!`, `*`, `+`, `-` and `/` are written as they are.
+* Every other byte is written as `=` followed by the two uppercase hexadecimal digits of the byte.
+
+## Quoted-printable
+
+The quoted-printable transfer encoding of a body is similar to the Q encoding, but:
+* Every printable ASCII character except `=` is written as it is, including the space (unless it is at the end of a line).
+* The lines are at most 76 characters long: longer lines are split with a soft line break (`=\r\n`).
+* The line breaks of the text are written as `\r\n`.
+
+## Charsets
+
+| Charset                       | Decoder                                    |
+|-------------------------------|--------------------------------------------|
+| `UTF-8`                       | [`crate::utf8`]                            |
+| `UTF-16`, `UTF-16BE`, `UTF-16LE` | [`crate::utf16`] (`UTF-16` honours the byte order mark, big-endian by default) |
+| `UCS-2`, `ISO-10646-UCS-2`    | [`crate::ucs2`] (big-endian)               |
+| `ISO-8859-1`, `LATIN1`        | Every byte is a unicode code point         |
+| `US-ASCII`                    | Every byte less than `0x80` is a unicode code point |
+*/
+
+use std::fmt;
+
+use crate::ucs2;
+use crate::utf16;
+use crate::utf8;
+
+/// The base64 alphabet.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Maximum length of an encoded-word.
+const MAX_ENCODED_WORD_LEN: usize = 75;
+
+/// Maximum length of a quoted-printable line, excluding the soft line break `=`.
+const MAX_QUOTED_PRINTABLE_LINE_LEN: usize = 75;
+
+/// Encode bytes in base64.
+///
+/// # Parameters
+/// * `bytes`: [`&[u8]`] - A slice of bytes.
+///
+/// # Returns
+/// A [`String`] containing the base64 encoding of the bytes, with padding.
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut encoded: String = String::new();
+    for chunk in bytes.chunks(3) {
+        // bytes: 0b0100_1101, 0b0110_0001, 0b0110_1110 (Man)
+        // sextets: 0b01_0011, 0b01_0110, 0b00_0101, 0b10_1110 (TWFu)
+        let group: u32 = chunk
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, b)| acc | ((*b as u32) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64_ALPHABET[((group >> (18 - 6 * i)) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decode a base64 string into bytes.
+///
+/// # Parameters
+/// * `text`: [`&str`] - A base64 string, with or without padding.
+///
+/// # Returns
+/// A [`Vec<u8>`] containing the decoded bytes, or [`None`] if the string contains characters outside of the base64 alphabet or has an invalid length.
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let text: &str = text.trim_end_matches('=');
+    let sextets: Vec<u32> = text
+        .bytes()
+        .map(|c| {
+            BASE64_ALPHABET
+                .iter()
+                .position(|a| *a == c)
+                .map(|p| p as u32)
+        })
+        .collect::<Option<Vec<u32>>>()?;
+    if sextets.len() % 4 == 1 {
+        return None;
+    }
+    let mut bytes: Vec<u8> = Vec::new();
+    for chunk in sextets.chunks(4) {
+        let group: u32 = chunk
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, s)| acc | (s << (18 - 6 * i)));
+        for i in 0..chunk.len() - 1 {
+            bytes.push((group >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+/// Decode two hexadecimal digits.
+///
+/// # Parameters
+/// * `hex`: [`&[u8]`] - A slice starting with two hexadecimal digits.
+///
+/// # Returns
+/// The decoded byte, or [`None`] if the slice does not start with two hexadecimal digits.
+fn decode_hex(hex: &[u8]) -> Option<u8> {
+    let high: u32 = (*hex.first()? as char).to_digit(16)?;
+    let low: u32 = (*hex.get(1)? as char).to_digit(16)?;
+    Some((high * 16 + low) as u8)
+}
+
+/// Encode a byte in the Q encoding.
+///
+/// # Parameters
+/// * `byte`: [`u8`] - A byte.
+///
+/// # Returns
+/// A [`String`] containing the Q encoding of the byte.
+fn encode_q_byte(byte: u8) -> String {
+    if byte == b' ' {
+        "_".to_string()
+    } else if byte.is_ascii_alphanumeric() || b"!*+-/".contains(&byte) {
+        (byte as char).to_string()
+    } else {
+        format!("={:02X}", byte)
+    }
+}
+
+/// Decode a Q encoded string into bytes.
+///
+/// # Parameters
+/// * `text`: [`&str`] - A Q encoded string.
+///
+/// # Returns
+/// A [`Vec<u8>`] containing the decoded bytes, or [`None`] if a `=` is not followed by two hexadecimal digits.
+fn decode_q(text: &str) -> Option<Vec<u8>> {
+    let text: &[u8] = text.as_bytes();
+    let mut bytes: Vec<u8> = Vec::new();
+    let mut i: usize = 0;
+    while i < text.len() {
+        match text[i] {
+            b'_' => bytes.push(b' '),
+            b'=' => {
+                bytes.push(decode_hex(&text[i + 1..])?);
+                i += 2;
+            }
+            byte => bytes.push(byte),
+        }
+        i += 1;
+    }
+    Some(bytes)
+}
+
+/// Decode bytes into unicode code points, using the decoder of a charset.
+///
+/// # Parameters
+/// * `bytes`: [`&[u8]`] - A slice of bytes.
+/// * `charset`: [`&str`] - The name of the charset, case insensitive. A RFC 2231 language suffix (`*lang`) is ignored.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points, or the kind of the error and the index of the first invalid byte.
+fn decode_charset(bytes: &[u8], charset: &str) -> Result<Vec<u32>, (MimeErrorKind, usize)> {
+    let charset: String = charset
+        .split('*')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_uppercase();
+    let invalid = |i: usize| (MimeErrorKind::InvalidCharsetData, i);
+    match charset.as_str() {
+        "UTF-8" | "UTF8" => {
+            utf8::validate_utf8(bytes.to_vec()).map_err(invalid)?;
+            Ok(utf8::decode_from_utf8(bytes.to_vec()))
+        }
+        "US-ASCII" | "ASCII" => match bytes.iter().position(|b| *b > 0x7F) {
+            Some(i) => Err(invalid(i)),
+            None => Ok(bytes.iter().map(|b| *b as u32).collect()),
+        },
+        "ISO-8859-1" | "ISO_8859-1" | "LATIN1" | "L1" => {
+            Ok(bytes.iter().map(|b| *b as u32).collect())
+        }
+        "UTF-16" | "UTF-16BE" | "UTF-16LE" | "UCS-2" | "ISO-10646-UCS-2" => {
+            let (skip, little_endian): (usize, bool) = match (charset.as_str(), bytes) {
+                ("UTF-16", [0xFE, 0xFF, ..]) => (2, false),
+                ("UTF-16", [0xFF, 0xFE, ..]) => (2, true),
+                ("UTF-16LE", _) => (0, true),
+                _ => (0, false),
+            };
+            if (bytes.len() - skip) % 2 == 1 {
+                return Err(invalid(bytes.len() - 1));
+            }
+            let units: Vec<u16> = bytes[skip..]
+                .chunks(2)
+                .map(|pair| {
+                    if little_endian {
+                        u16::from_le_bytes([pair[0], pair[1]])
+                    } else {
+                        u16::from_be_bytes([pair[0], pair[1]])
+                    }
+                })
+                .collect();
+            if charset.starts_with("UTF") {
+                utf16::validate_utf16(&units).map_err(|i| invalid(skip + 2 * i))?;
+                Ok(utf16::decode_from_utf16(units))
+            } else {
+                match units.iter().position(|u| (0xD800..=0xDFFF).contains(u)) {
+                    Some(i) => Err(invalid(2 * i)),
+                    None => Ok(ucs2::decode_from_ucs2(units)),
+                }
+            }
+        }
+        _ => Err((MimeErrorKind::UnsupportedCharset, 0)),
+    }
+}
+
+/// Parse the syntax of an encoded-word at the start of a string.
+///
+/// # Parameters
+/// * `text`: [`&str`] - A string starting with `=?`.
+///
+/// # Returns
+/// A tuple containing the charset, the encoding, the encoded-text and the length of the encoded-word, or [`None`] if the string does not start with an encoded-word.
+fn parse_encoded_word(text: &str) -> Option<(&str, &str, &str, usize)> {
+    let rest: &str = text.strip_prefix("=?")?;
+    let mut parts = rest.splitn(3, '?');
+    let charset: &str = parts.next()?;
+    let encoding: &str = parts.next()?;
+    let tail: &str = parts.next()?;
+    let end: usize = tail.find("?=")?;
+    let encoded_text: &str = &tail[..end];
+    let is_token =
+        |s: &str| !s.is_empty() && !s.contains(|c: char| c.is_ascii_whitespace() || c == '?');
+    if !is_token(charset)
+        || !is_token(encoding)
+        || encoded_text.contains(|c: char| c.is_ascii_whitespace() || c == '?')
+    {
+        return None;
+    }
+    let len: usize = 2 + charset.len() + 1 + encoding.len() + 1 + end + 2;
+    Some((charset, encoding, encoded_text, len))
+}
+
+/// Decode the parts of an encoded-word.
+///
+/// # Parameters
+/// * `charset`: [`&str`] - The charset of the encoded-word.
+/// * `encoding`: [`&str`] - The encoding of the encoded-word.
+/// * `encoded_text`: [`&str`] - The encoded-text of the encoded-word.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points, or the kind of the error.
+fn decode_word_parts(
+    charset: &str,
+    encoding: &str,
+    encoded_text: &str,
+) -> Result<Vec<u32>, MimeErrorKind> {
+    let bytes: Vec<u8> = match encoding {
+        "B" | "b" => decode_base64(encoded_text).ok_or(MimeErrorKind::InvalidBase64)?,
+        "Q" | "q" => decode_q(encoded_text).ok_or(MimeErrorKind::InvalidQuotedPrintable)?,
+        _ => return Err(MimeErrorKind::UnsupportedEncoding),
+    };
+    decode_charset(&bytes, charset).map_err(|(kind, _)| kind)
+}
+
+/// Split a vector of unicode code points into chunks of UTF-8 code points, so that every chunk is encoded in at most `max_len` characters.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+/// * `encoded_len`: [`Fn(&[u8]) -> usize`] - The length of the encoding of a chunk.
+/// * `max_len`: [`usize`] - The maximum length of the encoding of a chunk.
+///
+/// # Returns
+/// A [`Vec<Vec<u8>>`] containing the chunks. A UTF-8 code point is never split across two chunks.
+fn split_utf8_chunks(
+    unicode_cp: &[u32],
+    encoded_len: impl Fn(&[u8]) -> usize,
+    max_len: usize,
+) -> Vec<Vec<u8>> {
+    let mut chunks: Vec<Vec<u8>> = Vec::new();
+    let mut chunk: Vec<u8> = Vec::new();
+    for cp in unicode_cp {
+        let bytes: Vec<u8> = utf8::encode_in_utf8(vec![*cp]);
+        let mut candidate: Vec<u8> = chunk.clone();
+        candidate.extend_from_slice(&bytes);
+        if !chunk.is_empty() && encoded_len(&candidate) > max_len {
+            chunks.push(chunk);
+            chunk = bytes;
+        } else {
+            chunk = candidate;
+        }
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+    chunks
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// The encoding of an encoded-word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MimeEncoding {
+    /// The base64 encoding.
+    B,
+    /// The quoted-printable like encoding.
+    Q,
+}
+
+/// The kind of a [`MimeError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MimeErrorKind {
+    /// The string is not an encoded-word.
+    MalformedEncodedWord,
+    /// The charset has no decoder in this crate.
+    UnsupportedCharset,
+    /// The encoding of the encoded-word is neither `B` nor `Q`.
+    UnsupportedEncoding,
+    /// The encoded-text is not valid base64.
+    InvalidBase64,
+    /// A `=` is not followed by two hexadecimal digits.
+    InvalidQuotedPrintable,
+    /// The decoded bytes are not valid in the charset.
+    InvalidCharsetData,
+}
+
+/// A malformed encoded-word or quoted-printable text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MimeError {
+    /// The kind of the error.
+    pub kind: MimeErrorKind,
+    /// The byte offset of the encoded-word, or of the quoted-printable escape (or character) holding the first invalid byte.
+    pub position: usize,
+}
+
+impl fmt::Display for MimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            MimeErrorKind::MalformedEncodedWord => write!(f, "Malformed encoded-word")?,
+            MimeErrorKind::UnsupportedCharset => write!(f, "Unsupported charset")?,
+            MimeErrorKind::UnsupportedEncoding => write!(f, "Unsupported encoding")?,
+            MimeErrorKind::InvalidBase64 => write!(f, "Invalid base64")?,
+            MimeErrorKind::InvalidQuotedPrintable => write!(f, "Invalid quoted-printable")?,
+            MimeErrorKind::InvalidCharsetData => write!(f, "Invalid charset data")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for MimeError {}
+
+/// Decode a single encoded-word into a vector of unicode code points.
+///
+/// # Parameters
+/// * `word`: [`&str`] - An encoded-word (`=?charset?encoding?encoded-text?=`).
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points, or a [`MimeError`] if the string is not a valid encoded-word.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(decode_encoded_word("=?UTF-8?B?w6k=?=").unwrap(), vec![0xe9]);
+/// assert_eq!(decode_encoded_word("=?ISO-8859-1?Q?a=E9_b?=").unwrap(), vec![0x61, 0xe9, 0x20, 0x62]);
+/// ```
+pub fn decode_encoded_word(word: &str) -> Result<Vec<u32>, MimeError> {
+    let error = |kind: MimeErrorKind| MimeError { kind, position: 0 };
+    match parse_encoded_word(word) {
+        Some((charset, encoding, encoded_text, len)) if len == word.len() => {
+            decode_word_parts(charset, encoding, encoded_text).map_err(error)
+        }
+        _ => Err(error(MimeErrorKind::MalformedEncodedWord)),
+    }
+}
+
+/// Decode a header containing encoded-words into a vector of unicode code points.
+///
+/// # Parameters
+/// * `text`: [`&str`] - The (possibly folded) value of a header.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points, or the [`MimeError`] of the first encoded-word that can not be decoded.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = decode_mime_header("Re: =?UTF-8?Q?caf=C3=A9?=\r\n =?UTF-8?B?IOKCrA==?=").unwrap();
+/// assert_eq!(v, vec![0x52, 0x65, 0x3a, 0x20, 0x63, 0x61, 0x66, 0xe9, 0x20, 0x20ac]); // "Re: café €"
+/// ```
+pub fn decode_mime_header(text: &str) -> Result<Vec<u32>, MimeError> {
+    let mut unicode_cp: Vec<u32> = Vec::new();
+    let mut white_space: Vec<u32> = Vec::new();
+    let mut after_word: bool = false;
+    let mut i: usize = 0;
+    while i < text.len() {
+        let rest: &str = &text[i..];
+        if let Some((charset, encoding, encoded_text, len)) = parse_encoded_word(rest) {
+            let mut decoded: Vec<u32> = decode_word_parts(charset, encoding, encoded_text)
+                .map_err(|kind| MimeError { kind, position: i })?;
+            // The white space between two encoded-words is ignored
+            if !after_word {
+                unicode_cp.append(&mut white_space);
+            }
+            white_space.clear();
+            unicode_cp.append(&mut decoded);
+            after_word = true;
+            i += len;
+            continue;
+        }
+        let c: char = rest.chars().next().unwrap();
+        i += c.len_utf8();
+        match c {
+            // Unfolding
+            '\r' | '\n' => {}
+            ' ' | '\t' => white_space.push(c as u32),
+            _ => {
+                unicode_cp.append(&mut white_space);
+                unicode_cp.push(c as u32);
+                after_word = false;
+            }
+        }
+    }
+    unicode_cp.append(&mut white_space);
+    Ok(unicode_cp)
+}
+
+/// Encode a vector of unicode code points into folded UTF-8 encoded-words.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `encoding`: [`MimeEncoding`] - The encoding of the encoded-words.
+///
+/// # Returns
+/// A [`String`] containing the encoded-words, each one at most 75 characters long, separated by `\r\n `.
+///
+/// # Panics
+/// * If the input vector (`unicode_cp`) of unicode code points contains invalid unicode code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x63, 0x61, 0x66, 0xe9]; // "café"
+/// assert_eq!(encode_mime_header(&v, MimeEncoding::B), "=?UTF-8?B?Y2Fmw6k=?=");
+/// assert_eq!(encode_mime_header(&v, MimeEncoding::Q), "=?UTF-8?Q?caf=C3=A9?=");
+/// ```
+pub fn encode_mime_header<T: AsRef<Vec<u32>>>(unicode_cp: T, encoding: MimeEncoding) -> String {
+    let prefix: &str = match encoding {
+        MimeEncoding::B => "=?UTF-8?B?",
+        MimeEncoding::Q => "=?UTF-8?Q?",
+    };
+    let max_len: usize = MAX_ENCODED_WORD_LEN - prefix.len() - "?=".len();
+    let encode = |bytes: &[u8]| -> String {
+        match encoding {
+            MimeEncoding::B => encode_base64(bytes),
+            MimeEncoding::Q => bytes.iter().map(|b| encode_q_byte(*b)).collect(),
+        }
+    };
+    split_utf8_chunks(unicode_cp.as_ref(), |chunk| encode(chunk).len(), max_len)
+        .iter()
+        .map(|chunk| format!("{}{}?=", prefix, encode(chunk)))
+        .collect::<Vec<String>>()
+        .join("\r\n ")
+}
+
+/// Encode a vector of unicode code points into a quoted-printable UTF-8 body.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points. Both `\n` and `\r\n` are line breaks.
+///
+/// # Returns
+/// A [`String`] containing the quoted-printable text, with lines at most 76 characters long and `\r\n` line breaks.
+///
+/// # Panics
+/// * If the input vector (`unicode_cp`) of unicode code points contains invalid unicode code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x63, 0x61, 0x66, 0xe9, 0x20, 0x3d, 0x20, 0x0a]; // "café = \n"
+/// assert_eq!(encode_quoted_printable(&v), "caf=C3=A9 =3D=20\r\n");
+/// ```
+pub fn encode_quoted_printable<T: AsRef<Vec<u32>>>(unicode_cp: T) -> String {
+    let bytes: Vec<u8> = utf8::encode_in_utf8(unicode_cp);
+    let mut lines: Vec<String> = Vec::new();
+    for line in bytes.split(|b| *b == b'\n') {
+        let line: &[u8] = line.strip_suffix(b"\r").unwrap_or(line);
+        let mut encoded: String = String::new();
+        let mut len: usize = 0;
+        for (j, byte) in line.iter().enumerate() {
+            let is_last: bool = j == line.len() - 1;
+            let token: String = match *byte {
+                b'=' => format!("={:02X}", byte),
+                b' ' | b'\t' if !is_last => (*byte as char).to_string(),
+                0x21..=0x7E => (*byte as char).to_string(),
+                _ => format!("={:02X}", byte),
+            };
+            if len + token.len() > MAX_QUOTED_PRINTABLE_LINE_LEN {
+                encoded.push_str("=\r\n");
+                len = 0;
+            }
+            len += token.len();
+            encoded.push_str(&token);
+        }
+        lines.push(encoded);
+    }
+    lines.join("\r\n")
+}
+
+/// Decode a quoted-printable body into a vector of unicode code points.
+///
+/// # Parameters
+/// * `text`: [`&str`] - A quoted-printable text.
+/// * `charset`: [`&str`] - The charset of the decoded bytes (see [charsets](self#charsets)).
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points, with `\r\n` line breaks, or the [`MimeError`] of the first malformed escape or invalid byte.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = decode_quoted_printable("caf=C3=A9 =3D=\r\n =20", "UTF-8").unwrap();
+/// assert_eq!(v, vec![0x63, 0x61, 0x66, 0xe9, 0x20, 0x3d, 0x20, 0x20]); // "café =  "
+/// ```
+pub fn decode_quoted_printable(text: &str, charset: &str) -> Result<Vec<u32>, MimeError> {
+    let mut bytes: Vec<u8> = Vec::new();
+    let mut offsets: Vec<usize> = Vec::new();
+    let mut line_start: usize = 0;
+    let lines: Vec<&str> = text.split('\n').collect();
+    for (n, line) in lines.iter().enumerate() {
+        // The trailing white space is added by the transport, so it is removed
+        let content: &str = line.trim_end_matches('\r').trim_end_matches([' ', '\t']);
+        let (content, soft_break): (&str, bool) = match content.strip_suffix('=') {
+            Some(content) => (content, true),
+            None => (content, false),
+        };
+        let raw: &[u8] = content.as_bytes();
+        let mut i: usize = 0;
+        while i < raw.len() {
+            offsets.push(line_start + i);
+            if raw[i] == b'=' {
+                let byte: u8 = decode_hex(&raw[i + 1..]).ok_or(MimeError {
+                    kind: MimeErrorKind::InvalidQuotedPrintable,
+                    position: line_start + i,
+                })?;
+                bytes.push(byte);
+                i += 3;
+            } else {
+                bytes.push(raw[i]);
+                i += 1;
+            }
+        }
+        if !soft_break && n != lines.len() - 1 {
+            offsets.push(line_start + line.len());
+            offsets.push(line_start + line.len());
+            bytes.extend_from_slice(b"\r\n");
+        }
+        line_start += line.len() + 1;
+    }
+    decode_charset(&bytes, charset).map_err(|(kind, i)| MimeError {
+        kind,
+        position: offsets.get(i).copied().unwrap_or(0),
+    })
+}
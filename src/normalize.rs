@@ -0,0 +1,641 @@
+/*!
+Unicode normalization forms (UAX #15) of unicode code points.
+
+# Normalization
+A vector of unicode code points is normalized in three steps:
+* **Decomposition**: Every code point is replaced by its canonical decomposition (NFD, NFC) or by its compatibility decomposition (NFKD, NFKC), recursively.
+* **Canonical ordering**: Every sequence of non-starters (code points with a canonical combining class different from `0`) is sorted by canonical combining class, keeping the order of the code points with the same class.
+* **Composition** (NFC, NFKC only): Every starter is composed with the following code points that are not blocked, when they have a primary composite.
+
+The Hangul syllables are decomposed into (and composed from) their jamos by algorithm.
+
+| Form   | Decomposition   | Composition |
+|--------|-----------------|-------------|
+| `Nfd`  | Canonical       | No          |
+| `Nfc`  | Canonical       | Yes         |
+| `Nfkd` | Compatibility   | No          |
+| `Nfkc` | Compatibility   | Yes         |
+
+# Streaming
+A [`Normalizer`] wraps an iterator of unicode code points (e.g. the code points decoded from a stream) and yields the normalized code points.
+It buffers the code points only up to the next starter that cannot be composed with the previous code points, so the normalization of every segment is independent from the others.
+
+To keep the buffer bounded on adversarial input, the normalizer follows the Stream-Safe Text Format (UAX #15): a `COMBINING GRAPHEME JOINER` (U+034F) is inserted before a code point that would make a sequence of more than 30 non-starters.
+The output is the same as [`normalize`] for every text in the Stream-Safe Text Format.
+
+# Quick check
+The quick check tells if a vector of unicode code points is normalized without normalizing it, using the `NF*_QC` properties of `DerivedNormalizationProps.txt`.
+The answer can be [`IsNormalized::Maybe`], in which case [`is_nfc`] (and the others) normalize the vector to compare it.
+
+**Note**:
+
+* The tables are generated from the Unicode Character Database (version 14.0.0).
+*/
+
+use std::collections::VecDeque;
+
+use crate::tables;
+use crate::unicode;
+
+/// The maximum number of consecutive non-starters in the Stream-Safe Text Format.
+const MAX_NON_STARTERS: usize = 30;
+
+/// The COMBINING GRAPHEME JOINER, inserted to break the sequences of non-starters.
+const CGJ: u32 = 0x034F;
+
+/// Find the decomposition mapping of a code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+/// * `compatibility`: [`bool`] - A flag to use the compatibility mappings besides the canonical ones.
+///
+/// # Returns
+/// The decomposition mapping (not decomposed recursively) of the code point, if any.
+pub(crate) fn decomposition_mapping(
+    code_point: u32,
+    compatibility: bool,
+) -> Option<&'static [u32]> {
+    let find = |table: &'static [(u32, &'static [u32])]| {
+        table
+            .binary_search_by_key(&code_point, |(cp, _)| *cp)
+            .ok()
+            .map(|i| table[i].1)
+    };
+    find(tables::normalization::CANONICAL_DECOMPOSITION).or_else(|| {
+        if compatibility {
+            find(tables::normalization::COMPATIBILITY_DECOMPOSITION)
+        } else {
+            None
+        }
+    })
+}
+
+/// Find the primary composite of two code points.
+///
+/// # Parameters
+/// * `first`: [`u32`] - A starter.
+/// * `second`: [`u32`] - A code point following the starter.
+///
+/// # Returns
+/// The primary composite of the two code points, if any.
+pub(crate) fn compose_pair(first: u32, second: u32) -> Option<u32> {
+    if let Some(syllable) = unicode::compose_jamos(first, second) {
+        return Some(syllable);
+    }
+    let table: &[(u32, u32, u32)] = tables::normalization::COMPOSITION;
+    table
+        .binary_search_by_key(&(first, second), |(f, s, _)| (*f, *s))
+        .ok()
+        .map(|i| table[i].2)
+}
+
+/// Get the quick check table of a normalization form.
+///
+/// # Parameters
+/// * `form`: [`NormalizationForm`] - A normalization form.
+///
+/// # Returns
+/// The table of the code points that are not (or maybe not) in the normalization form.
+pub(crate) fn quick_check_table(form: NormalizationForm) -> &'static [(u32, u32, IsNormalized)] {
+    match form {
+        NormalizationForm::Nfc => tables::normalization::NFC_QUICK_CHECK,
+        NormalizationForm::Nfd => tables::normalization::NFD_QUICK_CHECK,
+        NormalizationForm::Nfkc => tables::normalization::NFKC_QUICK_CHECK,
+        NormalizationForm::Nfkd => tables::normalization::NFKD_QUICK_CHECK,
+    }
+}
+
+/// Decompose a code point recursively, appending the decomposition to a vector.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+/// * `compatibility`: [`bool`] - A flag to use the compatibility decomposition instead of the canonical one.
+/// * `decomposed`: [`&mut Vec<u32>`] - The vector the decomposition is appended to.
+pub(crate) fn decompose_into(code_point: u32, compatibility: bool, decomposed: &mut Vec<u32>) {
+    if unicode::decompose_syllable_into(code_point, decomposed) {
+        return;
+    }
+    match decomposition_mapping(code_point, compatibility) {
+        Some(mapping) => {
+            for cp in mapping {
+                decompose_into(*cp, compatibility, decomposed);
+            }
+        }
+        None => decomposed.push(code_point),
+    }
+}
+
+/// Sort the sequences of non-starters by canonical combining class (canonical ordering algorithm).
+///
+/// # Parameters
+/// * `decomposed`: [`&mut [u32]`] - A slice of decomposed unicode code points.
+pub(crate) fn canonical_order(decomposed: &mut [u32]) {
+    let mut start: usize = 0;
+    while start < decomposed.len() {
+        if canonical_combining_class(decomposed[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let mut end: usize = start;
+        while end < decomposed.len() && canonical_combining_class(decomposed[end]) != 0 {
+            end += 1;
+        }
+        // The sort is stable, so the code points with the same class keep their order
+        decomposed[start..end].sort_by_key(|cp| canonical_combining_class(*cp));
+        start = end;
+    }
+}
+
+/// Compose a vector of decomposed and canonically ordered code points (canonical composition algorithm).
+///
+/// # Parameters
+/// * `decomposed`: [`&mut Vec<u32>`] - A vector of decomposed and canonically ordered unicode code points.
+pub(crate) fn compose(decomposed: &mut Vec<u32>) {
+    let mut starter: Option<usize> = None;
+    let mut last_ccc: u8 = 0;
+    let mut composed_len: usize = 0;
+    for i in 0..decomposed.len() {
+        let code_point: u32 = decomposed[i];
+        let ccc: u8 = canonical_combining_class(code_point);
+        if let Some(s) = starter {
+            // The code point is blocked if a code point between it and the starter has the same or a higher class
+            let blocked: bool = composed_len > s + 1 && (last_ccc == 0 || last_ccc >= ccc);
+            if !blocked {
+                if let Some(composite) = compose_pair(decomposed[s], code_point) {
+                    decomposed[s] = composite;
+                    continue;
+                }
+            }
+        }
+        if ccc == 0 {
+            starter = Some(composed_len);
+        }
+        last_ccc = ccc;
+        decomposed[composed_len] = code_point;
+        composed_len += 1;
+    }
+    decomposed.truncate(composed_len);
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// A Unicode normalization form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizationForm {
+    /// Canonical decomposition, followed by canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition, followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+impl NormalizationForm {
+    /// Check if the normalization form uses the compatibility decomposition.
+    ///
+    /// # Returns
+    /// `true` for [`NormalizationForm::Nfkc`] and [`NormalizationForm::Nfkd`].
+    pub fn is_compatibility(&self) -> bool {
+        matches!(self, NormalizationForm::Nfkc | NormalizationForm::Nfkd)
+    }
+
+    /// Check if the normalization form composes the code points.
+    ///
+    /// # Returns
+    /// `true` for [`NormalizationForm::Nfc`] and [`NormalizationForm::Nfkc`].
+    pub fn is_composed(&self) -> bool {
+        matches!(self, NormalizationForm::Nfc | NormalizationForm::Nfkc)
+    }
+}
+
+/// The result of a quick check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsNormalized {
+    /// The code points are in the normalization form.
+    Yes,
+    /// The code points are not in the normalization form.
+    No,
+    /// The code points may be in the normalization form, normalize them to know.
+    Maybe,
+}
+
+/// Get the canonical combining class of a unicode code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The canonical combining class of the code point (`0` for the starters).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(canonical_combining_class(0x41), 0);
+/// assert_eq!(canonical_combining_class(0x301), 230);
+/// ```
+pub fn canonical_combining_class(code_point: u32) -> u8 {
+    tables::lookup_range(tables::normalization::CANONICAL_COMBINING_CLASS, code_point).unwrap_or(0)
+}
+
+/// Normalize a vector of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `form`: [`NormalizationForm`] - The normalization form.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the normalized unicode code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x65, 0x301, 0xfb01]; // "e\u{301}ﬁ"
+/// assert_eq!(normalize(&v, NormalizationForm::Nfc), vec![0xe9, 0xfb01]);
+/// assert_eq!(normalize(&v, NormalizationForm::Nfkc), vec![0xe9, 0x66, 0x69]);
+/// ```
+pub fn normalize<T: AsRef<Vec<u32>>>(unicode_cp: T, form: NormalizationForm) -> Vec<u32> {
+    let v: &Vec<u32> = unicode_cp.as_ref();
+    let mut normalized: Vec<u32> = Vec::with_capacity(v.len());
+    for code_point in v {
+        decompose_into(*code_point, form.is_compatibility(), &mut normalized);
+    }
+    canonical_order(&mut normalized);
+    if form.is_composed() {
+        compose(&mut normalized);
+    }
+    normalized
+}
+
+/// Normalize a vector of unicode code points in the normalization form C (canonical composition).
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points in NFC.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x1100, 0x1161, 0x11a8]; // Hangul jamos
+/// assert_eq!(nfc(&v), vec![0xac01]);
+/// ```
+pub fn nfc<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    normalize(unicode_cp, NormalizationForm::Nfc)
+}
+
+/// Normalize a vector of unicode code points in the normalization form D (canonical decomposition).
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points in NFD.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x1e09]; // "ḉ"
+/// assert_eq!(nfd(&v), vec![0x63, 0x327, 0x301]);
+/// ```
+pub fn nfd<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    normalize(unicode_cp, NormalizationForm::Nfd)
+}
+
+/// Normalize a vector of unicode code points in the normalization form KC (compatibility decomposition, canonical composition).
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points in NFKC.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x1e9b, 0x323]; // "ẛ̣"
+/// assert_eq!(nfkc(&v), vec![0x1e69]);
+/// ```
+pub fn nfkc<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    normalize(unicode_cp, NormalizationForm::Nfkc)
+}
+
+/// Normalize a vector of unicode code points in the normalization form KD (compatibility decomposition).
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points in NFKD.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x1e9b, 0x323]; // "ẛ̣"
+/// assert_eq!(nfkd(&v), vec![0x73, 0x323, 0x307]);
+/// ```
+pub fn nfkd<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    normalize(unicode_cp, NormalizationForm::Nfkd)
+}
+
+/// Quick check if a vector of unicode code points is in a normalization form, without normalizing it.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `form`: [`NormalizationForm`] - The normalization form.
+///
+/// # Returns
+/// [`IsNormalized::Yes`] or [`IsNormalized::No`] if the answer is known, [`IsNormalized::Maybe`] otherwise.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(quick_check(&vec![0xe9], NormalizationForm::Nfc), IsNormalized::Yes);
+/// assert_eq!(quick_check(&vec![0xe9], NormalizationForm::Nfd), IsNormalized::No);
+/// assert_eq!(quick_check(&vec![0x65, 0x301], NormalizationForm::Nfc), IsNormalized::Maybe);
+/// ```
+pub fn quick_check<T: AsRef<Vec<u32>>>(unicode_cp: T, form: NormalizationForm) -> IsNormalized {
+    let table: &[(u32, u32, IsNormalized)] = quick_check_table(form);
+    let mut result: IsNormalized = IsNormalized::Yes;
+    let mut last_ccc: u8 = 0;
+    for code_point in unicode_cp.as_ref() {
+        let ccc: u8 = canonical_combining_class(*code_point);
+        if ccc != 0 && last_ccc > ccc {
+            return IsNormalized::No;
+        }
+        match tables::lookup_range(table, *code_point) {
+            Some(IsNormalized::No) => return IsNormalized::No,
+            Some(IsNormalized::Maybe) => result = IsNormalized::Maybe,
+            _ => {}
+        }
+        last_ccc = ccc;
+    }
+    result
+}
+
+/// Check if a vector of unicode code points is in a normalization form.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+/// * `form`: [`NormalizationForm`] - The normalization form.
+///
+/// # Returns
+/// `true` if the unicode code points are in the normalization form.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_normalized(&vec![0xe9], NormalizationForm::Nfc));
+/// assert!(!is_normalized(&vec![0x65, 0x301], NormalizationForm::Nfc));
+/// ```
+pub fn is_normalized<T: AsRef<Vec<u32>>>(unicode_cp: T, form: NormalizationForm) -> bool {
+    let v: &Vec<u32> = unicode_cp.as_ref();
+    match quick_check(v, form) {
+        IsNormalized::Yes => true,
+        IsNormalized::No => false,
+        IsNormalized::Maybe => normalize(v, form) == *v,
+    }
+}
+
+/// Check if a vector of unicode code points is in the normalization form C.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// `true` if the unicode code points are in NFC.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_nfc(&vec![0xe9]));
+/// assert!(!is_nfc(&vec![0x65, 0x301]));
+/// ```
+pub fn is_nfc<T: AsRef<Vec<u32>>>(unicode_cp: T) -> bool {
+    is_normalized(unicode_cp, NormalizationForm::Nfc)
+}
+
+/// Check if a vector of unicode code points is in the normalization form D.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// `true` if the unicode code points are in NFD.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_nfd(&vec![0x65, 0x301]));
+/// assert!(!is_nfd(&vec![0xe9]));
+/// ```
+pub fn is_nfd<T: AsRef<Vec<u32>>>(unicode_cp: T) -> bool {
+    is_normalized(unicode_cp, NormalizationForm::Nfd)
+}
+
+/// Check if a vector of unicode code points is in the normalization form KC.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// `true` if the unicode code points are in NFKC.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_nfkc(&vec![0xe9]));
+/// assert!(!is_nfkc(&vec![0xfb01]));
+/// ```
+pub fn is_nfkc<T: AsRef<Vec<u32>>>(unicode_cp: T) -> bool {
+    is_normalized(unicode_cp, NormalizationForm::Nfkc)
+}
+
+/// Check if a vector of unicode code points is in the normalization form KD.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// `true` if the unicode code points are in NFKD.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_nfkd(&vec![0x66, 0x69]));
+/// assert!(!is_nfkd(&vec![0xfb01]));
+/// ```
+pub fn is_nfkd<T: AsRef<Vec<u32>>>(unicode_cp: T) -> bool {
+    is_normalized(unicode_cp, NormalizationForm::Nfkd)
+}
+
+/// A streaming normalizer, yielding the normalized code points of an iterator of unicode code points.
+///
+/// See [`normalize_iter`].
+#[derive(Debug, Clone)]
+pub struct Normalizer<I: Iterator<Item = u32>> {
+    iter: I,
+    form: NormalizationForm,
+    /// The decomposed code points of the current segment.
+    segment: Vec<u32>,
+    /// The normalized code points, not yielded yet.
+    ready: VecDeque<u32>,
+    /// The number of consecutive non-starters at the end of the input read so far.
+    non_starters: usize,
+    /// The decomposition of the last code point read.
+    decomposed: Vec<u32>,
+}
+
+impl<I: Iterator<Item = u32>> Normalizer<I> {
+    /// Create a streaming normalizer.
+    ///
+    /// # Parameters
+    /// * `iter`: [`Iterator<Item = u32>`] - An iterator of unicode code points.
+    /// * `form`: [`NormalizationForm`] - The normalization form.
+    ///
+    /// # Returns
+    /// A [`Normalizer`] yielding the normalized code points.
+    pub fn new(iter: I, form: NormalizationForm) -> Self {
+        Normalizer {
+            iter,
+            form,
+            segment: Vec::new(),
+            ready: VecDeque::new(),
+            non_starters: 0,
+            decomposed: Vec::new(),
+        }
+    }
+
+    /// Check if a decomposed code point starts a new segment, that is a starter that cannot be composed with the previous code points.
+    fn is_boundary(&self, code_point: u32) -> bool {
+        canonical_combining_class(code_point) == 0
+            && (!self.form.is_composed()
+                || tables::lookup_range(quick_check_table(self.form), code_point)
+                    != Some(IsNormalized::Maybe))
+    }
+
+    /// Normalize the current segment, moving it to the code points ready to be yielded.
+    fn flush(&mut self) {
+        canonical_order(&mut self.segment);
+        if self.form.is_composed() {
+            compose(&mut self.segment);
+        }
+        self.ready.extend(self.segment.drain(..));
+    }
+
+    /// Update the number of consecutive non-starters with a code point, as defined by the Stream-Safe Text Format.
+    ///
+    /// # Returns
+    /// `true` if a `COMBINING GRAPHEME JOINER` must be inserted before the code point.
+    fn exceeds_non_starters(&mut self, code_point: u32) -> bool {
+        self.decomposed.clear();
+        decompose_into(code_point, true, &mut self.decomposed);
+        let is_non_starter = |cp: &u32| canonical_combining_class(*cp) != 0;
+        let leading: usize = self
+            .decomposed
+            .iter()
+            .take_while(|cp| is_non_starter(cp))
+            .count();
+        let exceeds: bool = self.non_starters + leading > MAX_NON_STARTERS;
+        if exceeds {
+            self.non_starters = 0;
+        }
+        if leading == self.decomposed.len() {
+            self.non_starters += leading;
+        } else {
+            self.non_starters = self
+                .decomposed
+                .iter()
+                .rev()
+                .take_while(|cp| is_non_starter(cp))
+                .count();
+        }
+        exceeds
+    }
+
+    /// Compose the current segment after a starter that may compose with the previous code points, moving everything before its last starter to the code points ready to be yielded.
+    ///
+    /// Only the last starter can compose with the code points that follow it, so the segment is left with one starter and its non-starters.
+    fn compose_prefix(&mut self) {
+        canonical_order(&mut self.segment);
+        compose(&mut self.segment);
+        let last_starter: usize = self
+            .segment
+            .iter()
+            .rposition(|cp| canonical_combining_class(*cp) == 0)
+            .unwrap_or(0);
+        self.ready.extend(self.segment.drain(..last_starter));
+    }
+
+    /// Decompose a code point, appending it to the current segment (and flushing the segment at every boundary).
+    fn push(&mut self, code_point: u32) {
+        self.decomposed.clear();
+        decompose_into(
+            code_point,
+            self.form.is_compatibility(),
+            &mut self.decomposed,
+        );
+        for i in 0..self.decomposed.len() {
+            let cp: u32 = self.decomposed[i];
+            if !self.segment.is_empty() && self.is_boundary(cp) {
+                self.flush();
+            }
+            self.segment.push(cp);
+            if self.segment.len() > 1 && canonical_combining_class(cp) == 0 {
+                self.compose_prefix();
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item = u32>> Iterator for Normalizer<I> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        loop {
+            if let Some(cp) = self.ready.pop_front() {
+                return Some(cp);
+            }
+            match self.iter.next() {
+                Some(cp) => {
+                    if self.exceeds_non_starters(cp) {
+                        self.push(CGJ);
+                    }
+                    self.push(cp);
+                }
+                None if self.segment.is_empty() => return None,
+                None => self.flush(),
+            }
+        }
+    }
+}
+
+/// Normalize an iterator of unicode code points, yielding the normalized code points while reading the input.
+///
+/// # Parameters
+/// * `iter`: [`IntoIterator<Item = u32>`] - An iterator of unicode code points.
+/// * `form`: [`NormalizationForm`] - The normalization form.
+///
+/// # Returns
+/// A [`Normalizer`] yielding the normalized code points.
+///
+/// # Note
+/// A `COMBINING GRAPHEME JOINER` (U+034F) is inserted in the sequences of more than 30 non-starters (Stream-Safe Text Format).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u8> = vec![0x65, 0xcc, 0x81, 0x41]; // "e\u{301}A" in UTF-8
+/// let norm: Vec<u32> = normalize_iter(decode_from_utf8(&v), NormalizationForm::Nfc).collect();
+/// assert_eq!(norm, vec![0xe9, 0x41]);
+/// ```
+pub fn normalize_iter<I: IntoIterator<Item = u32>>(
+    iter: I,
+    form: NormalizationForm,
+) -> Normalizer<I::IntoIter> {
+    Normalizer::new(iter.into_iter(), form)
+}
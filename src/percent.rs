@@ -0,0 +1,252 @@
+/*!
+Percent-encoding (RFC 3986) of UTF-8 code points.
+
+# Encoding
+A vector of unicode code points is first encoded in [UTF-8](crate::utf8), then every byte is percent-encoded using the following rules:
+* If the byte is an ASCII character allowed in the [component](#sets), it is written as it is.
+* Otherwise, it is written as `%` followed by the two uppercase hexadecimal digits of the byte.
+* In the [`PercentEncodeSet::Form`] set the space is written as `+`.
+
+# Decoding
+A percent-encoded string is decoded using the following rules:
+* Every `%` followed by two hexadecimal digits is decoded into the byte they represent.
+* Every other character is decoded into its UTF-8 code points.
+* The resulting bytes are validated and decoded as [UTF-8](crate::utf8).
+
+A `%` not followed by two hexadecimal digits, or bytes that are not valid UTF-8, are reported as a [`PercentDecodeError`] with the byte offset in the original string.
+
+## Sets
+
+The unreserved characters (`A-Z`, `a-z`, `0-9`, `-`, `.`, `_`, `~`) are never percent-encoded, except for `~` in the [`PercentEncodeSet::Form`] set.
+
+| Set        | Allowed characters (besides the unreserved ones)        |
+|------------|---------------------------------------------------------|
+| `Path`     | `!`, `$`, `&`, `'`, `(`, `)`, `*`, `+`, `,`, `;`, `=`, `:`, `@`, `/` |
+| `Query`    | Same as `Path`, plus `?`                                |
+| `Fragment` | Same as `Path`, plus `?`                                |
+| `Userinfo` | `!`, `$`, `&`, `'`, `(`, `)`, `*`, `+`, `,`, `;`, `=`, `:` |
+| `Form`     | `*` (and the space, written as `+`)                     |
+
+**Note**:
+
+* The `Form` set follows the `application/x-www-form-urlencoded` serializer of the WHATWG URL standard.
+*/
+
+use std::fmt;
+
+use crate::utf8;
+
+/// The sub-delimiters of RFC 3986.
+const SUB_DELIMS: &[u8] = b"!$&'()*+,;=";
+
+/// Check if a byte is allowed, without being percent-encoded, in a set.
+///
+/// # Parameters
+/// * `byte`: [`u8`] - A byte.
+/// * `set`: [`PercentEncodeSet`] - The set.
+///
+/// # Returns
+/// `true` if the byte is written as it is.
+fn is_allowed(byte: u8, set: PercentEncodeSet) -> bool {
+    if byte.is_ascii_alphanumeric() || b"-._".contains(&byte) {
+        return true;
+    }
+    match set {
+        PercentEncodeSet::Path => {
+            byte == b'~' || SUB_DELIMS.contains(&byte) || b":@/".contains(&byte)
+        }
+        PercentEncodeSet::Query | PercentEncodeSet::Fragment => {
+            byte == b'~' || SUB_DELIMS.contains(&byte) || b":@/?".contains(&byte)
+        }
+        PercentEncodeSet::Userinfo => byte == b'~' || SUB_DELIMS.contains(&byte) || byte == b':',
+        PercentEncodeSet::Form => byte == b'*',
+    }
+}
+
+/// Decode a percent-encoded string into bytes.
+///
+/// # Parameters
+/// * `text`: [`&str`] - A percent-encoded string.
+/// * `plus_as_space`: [`bool`] - A flag to decode `+` as a space.
+///
+/// # Returns
+/// A tuple containing the decoded bytes and, for each byte, the byte offset in `text` it comes from.
+fn decode_bytes(
+    text: &str,
+    plus_as_space: bool,
+) -> Result<(Vec<u8>, Vec<usize>), PercentDecodeError> {
+    let bytes: &[u8] = text.as_bytes();
+    let mut decoded: Vec<u8> = Vec::with_capacity(bytes.len());
+    let mut offsets: Vec<usize> = Vec::with_capacity(bytes.len());
+    let mut i: usize = 0;
+    while i < bytes.len() {
+        offsets.push(i);
+        match bytes[i] {
+            b'%' => {
+                let hex: Option<u8> = bytes
+                    .get(i + 1..i + 3)
+                    .and_then(|h| std::str::from_utf8(h).ok())
+                    .filter(|h| h.bytes().all(|b| b.is_ascii_hexdigit()))
+                    .and_then(|h| u8::from_str_radix(h, 16).ok());
+                match hex {
+                    Some(byte) => decoded.push(byte),
+                    None => {
+                        return Err(PercentDecodeError {
+                            kind: PercentDecodeErrorKind::InvalidEscape,
+                            position: i,
+                        })
+                    }
+                }
+                i += 3;
+            }
+            b'+' if plus_as_space => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            byte => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    Ok((decoded, offsets))
+}
+
+/// Decode a percent-encoded string into unicode code points.
+///
+/// # Parameters
+/// * `text`: [`&str`] - A percent-encoded string.
+/// * `plus_as_space`: [`bool`] - A flag to decode `+` as a space.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points, or the [`PercentDecodeError`] of the first malformed escape or invalid UTF-8 code point.
+fn decode(text: &str, plus_as_space: bool) -> Result<Vec<u32>, PercentDecodeError> {
+    let (bytes, offsets) = decode_bytes(text, plus_as_space)?;
+    match utf8::validate_utf8(&bytes) {
+        Ok(()) => Ok(utf8::decode_from_utf8(&bytes)),
+        Err(i) => Err(PercentDecodeError {
+            kind: PercentDecodeErrorKind::InvalidUtf8,
+            position: offsets[i],
+        }),
+    }
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// The set of characters that are allowed, without being percent-encoded, in a component of a URI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PercentEncodeSet {
+    /// The path of a URI (`pchar` and `/`).
+    Path,
+    /// The query of a URI (`pchar`, `/` and `?`).
+    Query,
+    /// The fragment of a URI (`pchar`, `/` and `?`).
+    Fragment,
+    /// The userinfo of a URI (unreserved characters, sub-delimiters and `:`).
+    Userinfo,
+    /// The `application/x-www-form-urlencoded` format, where the space is written as `+`.
+    Form,
+}
+
+/// The kind of a [`PercentDecodeError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PercentDecodeErrorKind {
+    /// A `%` is not followed by two hexadecimal digits.
+    InvalidEscape,
+    /// The decoded bytes are not valid UTF-8.
+    InvalidUtf8,
+}
+
+/// A malformed percent-encoded string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PercentDecodeError {
+    /// The kind of the error.
+    pub kind: PercentDecodeErrorKind,
+    /// The byte offset in the percent-encoded string of the malformed escape, or of the escape (or character) holding the first byte of the invalid UTF-8 code point.
+    pub position: usize,
+}
+
+impl fmt::Display for PercentDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            PercentDecodeErrorKind::InvalidEscape => write!(f, "Invalid percent escape")?,
+            PercentDecodeErrorKind::InvalidUtf8 => write!(f, "Invalid UTF-8 sequence")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for PercentDecodeError {}
+
+/// Percent-encode a slice of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+/// * `set`: [`PercentEncodeSet`] - The set of characters that are written as they are.
+///
+/// # Returns
+/// A [`String`] containing the percent-encoded UTF-8 code points.
+///
+/// # Panics
+/// * If the input slice (`unicode_cp`) of unicode code points contains invalid unicode code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x61, 0x20, 0x2f, 0xe9]; // "a /é"
+/// assert_eq!(percent_encode(&v, PercentEncodeSet::Path), "a%20/%C3%A9");
+/// assert_eq!(percent_encode(&v, PercentEncodeSet::Form), "a+%2F%C3%A9");
+/// ```
+pub fn percent_encode(unicode_cp: &[u32], set: PercentEncodeSet) -> String {
+    let mut encoded: String = String::new();
+    for byte in utf8::encode_in_utf8(unicode_cp.to_vec()) {
+        if set == PercentEncodeSet::Form && byte == b' ' {
+            encoded.push('+');
+        } else if is_allowed(byte, set) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// Decode a percent-encoded string into a vector of unicode code points.
+///
+/// # Parameters
+/// * `text`: [`&str`] - A percent-encoded string. The `+` is decoded as it is (see [`percent_decode_form`]).
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points, or the [`PercentDecodeError`] of the first malformed escape or invalid UTF-8 code point.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(percent_decode("a%20/%C3%A9").unwrap(), vec![0x61, 0x20, 0x2f, 0xe9]);
+///
+/// let err: PercentDecodeError = percent_decode("ab%C3%28").unwrap_err();
+/// assert_eq!(err.kind, PercentDecodeErrorKind::InvalidUtf8);
+/// assert_eq!(err.position, 2);
+/// ```
+pub fn percent_decode(text: &str) -> Result<Vec<u32>, PercentDecodeError> {
+    decode(text, false)
+}
+
+/// Decode an `application/x-www-form-urlencoded` string into a vector of unicode code points.
+///
+/// # Parameters
+/// * `text`: [`&str`] - A percent-encoded string, where the space is written as `+`.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the unicode code points, or the [`PercentDecodeError`] of the first malformed escape or invalid UTF-8 code point.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(percent_decode_form("a+b%2B").unwrap(), vec![0x61, 0x20, 0x62, 0x2b]);
+/// ```
+pub fn percent_decode_form(text: &str) -> Result<Vec<u32>, PercentDecodeError> {
+    decode(text, true)
+}
@@ -0,0 +1,220 @@
+/*!
+Detection of confusable and mixed-script identifiers (UTS #39, Unicode Security Mechanisms).
+
+# Confusables
+Some characters look the same as others, like the Latin `a` and the Cyrillic `а` (U+0430), or `rn` and `m`.
+The **skeleton** of a text maps every code point to a prototype of the characters it can be confused with (`confusables.txt`), so that two texts are visually confusable when they have the same skeleton:
+* The text is normalized to NFD, every code point is replaced by its prototype, and the result is normalized to NFD again.
+* The skeleton is only meant to be compared with other skeletons: it is not a readable text, and it changes with the versions of the data.
+
+# Restriction levels
+The restriction level classifies an identifier by the scripts it mixes, from the most to the least restrictive (see [`crate::unicode::resolved_script_set`]):
+
+| Level                   | Identifiers                                                                                                  |
+|-------------------------|--------------------------------------------------------------------------------------------------------------|
+| `AsciiOnly`             | Only ASCII characters.                                                                                       |
+| `SingleScript`          | The characters share a script (e.g. `Ελληνικά`).                                                              |
+| `HighlyRestrictive`     | Latin with Han, Hiragana and Katakana, or with Han and Bopomofo, or with Han and Hangul (e.g. `ABCひらがな`). |
+| `ModeratelyRestrictive` | Latin with another recommended script, except Cyrillic and Greek (e.g. `ABCअनुच्छेद`).                         |
+| `MinimallyRestrictive`  | Any mix of scripts (e.g. `pаypal` with a Cyrillic `а`).                                                      |
+| `Unrestricted`          | Characters which are not allowed in identifiers (`IdentifierStatus.txt`), like the spaces and the symbols.   |
+
+**Note**:
+
+* The characters of the `Common` and `Inherited` scripts, like the digits and the combining marks, are used with all the scripts.
+* The levels are ordered, so that an identifier can be checked with `restriction_level(&v) <= RestrictionLevel::HighlyRestrictive`.
+*/
+
+use crate::normalize;
+use crate::tables;
+use crate::unicode::{self, Script, ScriptSet};
+
+/// The recommended scripts for the identifiers (UAX #31), besides `Common` and `Inherited`.
+const RECOMMENDED_SCRIPTS: [Script; 29] = [
+    Script::Arabic,
+    Script::Armenian,
+    Script::Bengali,
+    Script::Bopomofo,
+    Script::Cyrillic,
+    Script::Devanagari,
+    Script::Ethiopic,
+    Script::Georgian,
+    Script::Greek,
+    Script::Gujarati,
+    Script::Gurmukhi,
+    Script::Hangul,
+    Script::Han,
+    Script::Hebrew,
+    Script::Hiragana,
+    Script::Katakana,
+    Script::Kannada,
+    Script::Khmer,
+    Script::Lao,
+    Script::Latin,
+    Script::Malayalam,
+    Script::Myanmar,
+    Script::Oriya,
+    Script::Sinhala,
+    Script::Tamil,
+    Script::Telugu,
+    Script::Thaana,
+    Script::Thai,
+    Script::Tibetan,
+];
+
+/// Get the prototype of a confusable code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`&[u32]`] containing the prototype, or [`None`] if the code point is its own prototype.
+fn prototype(code_point: u32) -> Option<&'static [u32]> {
+    let table: &[(u32, &[u32])] = tables::security::CONFUSABLES;
+    table
+        .binary_search_by_key(&code_point, |(cp, _)| *cp)
+        .ok()
+        .map(|i| table[i].1)
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// The restriction level of an identifier, from the most to the least restrictive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RestrictionLevel {
+    /// Only ASCII characters.
+    AsciiOnly,
+    /// The characters share a script.
+    SingleScript,
+    /// Latin with the scripts of Japanese (Han, Hiragana and Katakana), of Chinese (Han and Bopomofo) or of Korean (Han and Hangul).
+    HighlyRestrictive,
+    /// Latin with another recommended script, except Cyrillic and Greek.
+    ModeratelyRestrictive,
+    /// Any mix of scripts.
+    MinimallyRestrictive,
+    /// Some characters are not allowed in identifiers.
+    Unrestricted,
+}
+
+/// Check if a unicode code point is allowed in identifiers (`Identifier_Status=Allowed`).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is allowed by the general security profile for identifiers.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_identifier_allowed(0x61)); // a
+/// assert!(is_identifier_allowed(0x430)); // а
+/// assert!(!is_identifier_allowed(0x20)); // space
+/// assert!(!is_identifier_allowed(0x1F600)); // 😀
+/// ```
+pub fn is_identifier_allowed(code_point: u32) -> bool {
+    tables::in_ranges(tables::security::IDENTIFIER_ALLOWED, code_point)
+}
+
+/// Get the skeleton of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the NFD of the prototypes of the code points of the NFD of the slice.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let a: Vec<u32> = "paypal".chars().map(|c| c as u32).collect();
+/// let b: Vec<u32> = "p\u{430}ypa1".chars().map(|c| c as u32).collect(); // with a Cyrillic "а" and a "1"
+/// assert_eq!(skeleton(&a), skeleton(&b));
+/// ```
+pub fn skeleton(unicode_cp: &[u32]) -> Vec<u32> {
+    let mut mapped: Vec<u32> = Vec::new();
+    for code_point in normalize::nfd(unicode_cp.to_vec()) {
+        match prototype(code_point) {
+            Some(prototype) => mapped.extend_from_slice(prototype),
+            None => mapped.push(code_point),
+        }
+    }
+    normalize::nfd(mapped)
+}
+
+/// Check if two slices of unicode code points are visually confusable.
+///
+/// # Parameters
+/// * `a`: [`&[u32]`] - A slice of unicode code points.
+/// * `b`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// A [`bool`] indicating if the slices have the same [`skeleton`].
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let a: Vec<u32> = "modern".chars().map(|c| c as u32).collect();
+/// let b: Vec<u32> = "rnodern".chars().map(|c| c as u32).collect();
+/// assert!(is_confusable(&a, &b));
+///
+/// let c: Vec<u32> = "madern".chars().map(|c| c as u32).collect();
+/// assert!(!is_confusable(&a, &c));
+/// ```
+pub fn is_confusable(a: &[u32], b: &[u32]) -> bool {
+    skeleton(a) == skeleton(b)
+}
+
+/// Get the restriction level of an identifier.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// The most restrictive [`RestrictionLevel`] the identifier satisfies.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let level = |s: &str| restriction_level(&s.chars().map(|c| c as u32).collect::<Vec<u32>>());
+/// assert_eq!(level("paypal"), RestrictionLevel::AsciiOnly);
+/// assert_eq!(level("Ελληνικά"), RestrictionLevel::SingleScript);
+/// assert_eq!(level("ABCひらがな"), RestrictionLevel::HighlyRestrictive);
+/// assert_eq!(level("p\u{430}ypal"), RestrictionLevel::MinimallyRestrictive);
+/// assert_eq!(level("hello world"), RestrictionLevel::Unrestricted);
+/// ```
+pub fn restriction_level(unicode_cp: &[u32]) -> RestrictionLevel {
+    if !unicode_cp.iter().all(|cp| is_identifier_allowed(*cp)) {
+        return RestrictionLevel::Unrestricted;
+    }
+    if unicode_cp.iter().all(|cp| *cp < 0x80) {
+        return RestrictionLevel::AsciiOnly;
+    }
+    if !unicode::resolved_script_set(unicode_cp).is_empty() {
+        return RestrictionLevel::SingleScript;
+    }
+    // The scripts shared by the characters which are not Latin
+    let others: Vec<u32> = unicode_cp
+        .iter()
+        .copied()
+        .filter(|cp| !unicode::resolved_script_set(&[*cp]).contains(Script::Latin))
+        .collect();
+    let others: ScriptSet = unicode::resolved_script_set(&others);
+    if [Script::Japanese, Script::HanWithBopomofo, Script::Korean]
+        .iter()
+        .any(|script| others.contains(*script))
+    {
+        return RestrictionLevel::HighlyRestrictive;
+    }
+    if RECOMMENDED_SCRIPTS.iter().any(|script| {
+        !matches!(script, Script::Cyrillic | Script::Greek) && others.contains(*script)
+    }) {
+        return RestrictionLevel::ModeratelyRestrictive;
+    }
+    RestrictionLevel::MinimallyRestrictive
+}
@@ -0,0 +1,859 @@
+/*!
+Text segmentation of unicode code points (UAX #29).
+
+# Grapheme clusters
+A grapheme cluster is what a user perceives as a single character: a base letter with its combining marks, a Hangul syllable made of jamos, a flag made of two regional indicators or an emoji ZWJ sequence.
+The boundaries are the extended grapheme cluster boundaries of the Unicode Standard, computed from the `Grapheme_Cluster_Break` and `Extended_Pictographic` properties:
+
+| Rule     | Boundary                                                                        |
+|----------|---------------------------------------------------------------------------------|
+| GB3      | No break between `CR` and `LF`.                                                 |
+| GB4, GB5 | Break before and after controls, `CR` and `LF`.                                 |
+| GB6-GB8  | No break inside a Hangul syllable sequence (`L`, `V`, `T`, `LV`, `LVT`).        |
+| GB9      | No break before `Extend` and `ZWJ`.                                             |
+| GB9a     | No break before `SpacingMark`.                                                  |
+| GB9b     | No break after `Prepend`.                                                       |
+| GB11     | No break inside an emoji ZWJ sequence (`ExtPict Extend* ZWJ × ExtPict`).         |
+| GB12     | No break between the two regional indicators of a flag.                         |
+| GB999    | Break everywhere else.                                                          |
+
+# Words
+The word boundaries are the default word boundaries of the Unicode Standard, computed from the `Word_Break` property:
+* Letters, digits and the connector punctuation are kept together (`can't`, `3.14`, `e.g`, `foo_bar`), the other punctuation is a segment on its own.
+* A sequence of horizontal spaces is a single segment, and so is a run of Katakana.
+* The `Extend`, `Format` and `ZWJ` code points are kept with the code point before them (WB4), so the words never break a grapheme cluster.
+
+The segments between the boundaries are not only the words, but also the spaces and the punctuation between them: the words are the segments containing letters or digits.
+
+# Sentences
+The sentence boundaries are the default sentence boundaries of the Unicode Standard, computed from the `Sentence_Break` property:
+* A sentence ends after a terminator (`.`, `?`, `!`, ...) followed by closing punctuation, spaces and a paragraph separator.
+* A full stop does not end a sentence when it is followed by a lowercase letter (`e.g. this`), a digit (`3.14`) or an uppercase letter after an uppercase or lowercase letter (`U.S.A.`).
+* A paragraph separator (`CR`, `LF`, `U+0085`, `U+2028` and `U+2029`) always ends a sentence.
+
+# Code units
+The segmentation works on vectors of unicode code points and on UTF-8 and UTF-16 buffers, without decoding them first:
+every segment is a [`Range`] of indexes of code units in the source encoding (code points, bytes or UTF-16 code points), so that it can be used to slice the input.
+
+**Note**:
+
+* The UTF-8 and UTF-16 buffers are decoded with the same rules used by [`crate::utf8::decode_from_utf8`] and [`crate::utf16::decode_from_utf16`], and the iterators panic on invalid input.
+*/
+
+use std::ops::Range;
+
+use crate::emoji::is_extended_pictographic;
+use crate::tables;
+
+/// The values of the `Grapheme_Cluster_Break` property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GraphemeClusterBreak {
+    /// The carriage return U+000D (`CR`).
+    Cr,
+    /// The line feed U+000A (`LF`).
+    Lf,
+    /// A control or format character other than the carriage return and the line feed, like U+2028 LINE SEPARATOR (`Control`).
+    Control,
+    /// A combining mark or another character extending the grapheme cluster before it (`Extend`).
+    Extend,
+    /// The zero width joiner U+200D (`ZWJ`).
+    Zwj,
+    /// A regional indicator symbol, two of which make a flag (`RI`).
+    RegionalIndicator,
+    /// A character joining the grapheme cluster after it, like the Arabic number signs (`Prepend`).
+    Prepend,
+    /// A spacing combining mark, like the Devanagari vowel signs (`SpacingMark`).
+    SpacingMark,
+    /// A Hangul leading consonant jamo (`L`).
+    L,
+    /// A Hangul vowel jamo (`V`).
+    V,
+    /// A Hangul trailing consonant jamo (`T`).
+    T,
+    /// A Hangul syllable without a trailing consonant (`LV`).
+    Lv,
+    /// A Hangul syllable with a trailing consonant (`LVT`).
+    Lvt,
+    /// Any other character (`XX`).
+    Other,
+}
+
+/// The values of the `Word_Break` property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WordBreak {
+    /// The carriage return U+000D (`CR`).
+    Cr,
+    /// The line feed U+000A (`LF`).
+    Lf,
+    /// A line break other than the carriage return and the line feed, like U+0085 NEXT LINE (`Newline`).
+    Newline,
+    /// A combining mark or another character extending the character before it (`Extend`).
+    Extend,
+    /// The zero width joiner U+200D (`ZWJ`).
+    Zwj,
+    /// A regional indicator symbol, two of which make a flag (`RI`).
+    RegionalIndicator,
+    /// A format character, like U+00AD SOFT HYPHEN (`Format`).
+    Format,
+    /// A Katakana character (`Katakana`).
+    Katakana,
+    /// A Hebrew letter (`Hebrew_Letter`).
+    HebrewLetter,
+    /// An alphabetic letter, like the Latin letters (`ALetter`).
+    ALetter,
+    /// The apostrophe U+0027 (`Single_Quote`).
+    SingleQuote,
+    /// The quotation mark U+0022 (`Double_Quote`).
+    DoubleQuote,
+    /// A punctuation allowed inside both words and numbers, like the full stop (`MidNumLet`).
+    MidNumLet,
+    /// A punctuation allowed inside words, like the colon (`MidLetter`).
+    MidLetter,
+    /// A punctuation allowed inside numbers, like the comma (`MidNum`).
+    MidNum,
+    /// A digit (`Numeric`).
+    Numeric,
+    /// A connector punctuation joining words and numbers, like the low line U+005F (`ExtendNumLet`).
+    ExtendNumLet,
+    /// A horizontal space, like U+0020 SPACE (`WSegSpace`).
+    WSegSpace,
+    /// Any other character (`XX`).
+    Other,
+}
+
+/// The values of the `Sentence_Break` property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SentenceBreak {
+    /// The carriage return U+000D (`CR`).
+    Cr,
+    /// The line feed U+000A (`LF`).
+    Lf,
+    /// A combining mark or another character extending the character before it (`Extend`).
+    Extend,
+    /// A paragraph separator, like U+2029 PARAGRAPH SEPARATOR (`Sep`).
+    Sep,
+    /// A format character, like U+00AD SOFT HYPHEN (`Format`).
+    Format,
+    /// A space (`Sp`).
+    Sp,
+    /// A lowercase letter (`Lower`).
+    Lower,
+    /// An uppercase or titlecase letter (`Upper`).
+    Upper,
+    /// A letter without case, like the Hebrew letters (`OLetter`).
+    OLetter,
+    /// A digit (`Numeric`).
+    Numeric,
+    /// The full stop U+002E and the other terminators that may end an abbreviation (`ATerm`).
+    ATerm,
+    /// A punctuation continuing a sentence, like the comma (`SContinue`).
+    SContinue,
+    /// A sentence terminator, like the question mark and the exclamation mark (`STerm`).
+    STerm,
+    /// A closing punctuation or quotation mark, like the right parenthesis (`Close`).
+    Close,
+    /// Any other character (`XX`).
+    Other,
+}
+
+/// A function decoding the code point starting at an index of a slice of code units, and returning it with the number of code units read.
+pub(crate) type Decoder<T> = fn(&[T], usize) -> Option<(u32, usize)>;
+
+/// A slice of code units, with the function decoding a code point from them.
+#[derive(Clone, Copy)]
+struct Units<'a, T> {
+    units: &'a [T],
+    decode: Decoder<T>,
+}
+
+impl<T> Units<'_, T> {
+    /// Decode the code point starting at the index `i`.
+    ///
+    /// # Returns
+    /// The code point and the number of code units it is made of, or [`None`] at the end of the slice.
+    fn decode(&self, i: usize) -> Option<(u32, usize)> {
+        (self.decode)(self.units, i)
+    }
+}
+
+/// Decode a code point of a slice of unicode code points.
+pub(crate) fn decode_code_point(unicode_cp: &[u32], i: usize) -> Option<(u32, usize)> {
+    unicode_cp.get(i).map(|code_point| (*code_point, 1))
+}
+
+/// Check if there is a grapheme cluster boundary between two code points (rules GB3 to GB999, except GB11 and GB12).
+///
+/// # Parameters
+/// * `prev`: [`GraphemeClusterBreak`] - The property of the code point before the position.
+/// * `next`: [`GraphemeClusterBreak`] - The property of the code point after the position.
+///
+/// # Returns
+/// `true` if there is a boundary between the two code points.
+fn is_grapheme_break(prev: GraphemeClusterBreak, next: GraphemeClusterBreak) -> bool {
+    use GraphemeClusterBreak as G;
+    match (prev, next) {
+        (G::Cr, G::Lf) => false,
+        (G::Cr | G::Lf | G::Control, _) | (_, G::Cr | G::Lf | G::Control) => true,
+        (G::L, G::L | G::V | G::Lv | G::Lvt) => false,
+        (G::Lv | G::V, G::V | G::T) => false,
+        (G::Lvt | G::T, G::T) => false,
+        (_, G::Extend | G::Zwj | G::SpacingMark) => false,
+        (G::Prepend, _) => false,
+        _ => true,
+    }
+}
+
+/// An iterator over the grapheme clusters of a slice of code units.
+///
+/// It is created by [`graphemes`], [`graphemes_utf8`] and [`graphemes_utf16`].
+#[derive(Clone)]
+pub struct Graphemes<'a, T> {
+    units: Units<'a, T>,
+    position: usize,
+}
+
+impl<'a, T> Graphemes<'a, T> {
+    /// Create an iterator over the grapheme clusters of a slice of code units.
+    fn new(units: &'a [T], decode: Decoder<T>) -> Self {
+        Graphemes {
+            units: Units { units, decode },
+            position: 0,
+        }
+    }
+}
+
+impl<T> Iterator for Graphemes<'_, T> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use GraphemeClusterBreak as G;
+        let start: usize = self.position;
+        let (code_point, len) = self.units.decode(start)?;
+        self.position += len;
+        let mut prev: G = grapheme_cluster_break(code_point);
+        // The number of consecutive regional indicators before the position (GB12)
+        let mut regional_indicators: usize = (prev == G::RegionalIndicator) as usize;
+        // An extended pictographic followed by extends before the position (GB11)
+        let mut pictographic: bool = is_extended_pictographic(code_point);
+        // An extended pictographic followed by extends and a ZWJ before the position (GB11)
+        let mut pictographic_zwj: bool = false;
+        while let Some((code_point, len)) = self.units.decode(self.position) {
+            let next: G = grapheme_cluster_break(code_point);
+            let joined: bool = match (prev, next) {
+                (G::Zwj, _) => pictographic_zwj && is_extended_pictographic(code_point),
+                (G::RegionalIndicator, G::RegionalIndicator) => regional_indicators % 2 == 1,
+                _ => false,
+            };
+            if !joined && is_grapheme_break(prev, next) {
+                break;
+            }
+            regional_indicators = match next {
+                G::RegionalIndicator => regional_indicators + 1,
+                _ => 0,
+            };
+            pictographic_zwj = pictographic && next == G::Zwj;
+            pictographic =
+                is_extended_pictographic(code_point) || (pictographic && next == G::Extend);
+            prev = next;
+            self.position += len;
+        }
+        Some(start..self.position)
+    }
+}
+
+/// The word boundaries state before a position.
+#[derive(Clone, Copy)]
+struct WordState {
+    /// The property of the code point before the position, [`None`] at the beginning.
+    raw: Option<WordBreak>,
+    /// The property of the code point before the position, ignoring `Extend`, `Format` and `ZWJ` (WB4).
+    prev: WordBreak,
+    /// The property of the code point before `prev`, ignoring `Extend`, `Format` and `ZWJ` (WB4).
+    prev_prev: WordBreak,
+    /// The number of consecutive regional indicators ending at `prev` (WB15, WB16).
+    regional_indicators: usize,
+}
+
+impl WordState {
+    /// Move the position after a code point.
+    fn push(&mut self, next: WordBreak) {
+        use WordBreak as W;
+        let ignored: bool = matches!(next, W::Extend | W::Format | W::Zwj)
+            && !matches!(self.raw, None | Some(W::Cr | W::Lf | W::Newline));
+        self.raw = Some(next);
+        if ignored {
+            return;
+        }
+        self.prev_prev = self.prev;
+        self.prev = next;
+        self.regional_indicators = match next {
+            W::RegionalIndicator => self.regional_indicators + 1,
+            _ => 0,
+        };
+    }
+}
+
+/// Check if a `Word_Break` property is a letter (`AHLetter`).
+fn is_ah_letter(wb: WordBreak) -> bool {
+    matches!(wb, WordBreak::ALetter | WordBreak::HebrewLetter)
+}
+
+/// Check if a `Word_Break` property is a separator between letters (`MidLetter` or `MidNumLetQ`).
+fn is_mid_letter(wb: WordBreak) -> bool {
+    matches!(
+        wb,
+        WordBreak::MidLetter | WordBreak::MidNumLet | WordBreak::SingleQuote
+    )
+}
+
+/// Check if a `Word_Break` property is a separator between digits (`MidNum` or `MidNumLetQ`).
+fn is_mid_num(wb: WordBreak) -> bool {
+    matches!(
+        wb,
+        WordBreak::MidNum | WordBreak::MidNumLet | WordBreak::SingleQuote
+    )
+}
+
+/// An iterator over the words of a slice of code units.
+///
+/// It is created by [`words`], [`words_utf8`] and [`words_utf16`].
+#[derive(Clone)]
+pub struct Words<'a, T> {
+    units: Units<'a, T>,
+    position: usize,
+    state: WordState,
+}
+
+impl<'a, T> Words<'a, T> {
+    /// Create an iterator over the words of a slice of code units.
+    fn new(units: &'a [T], decode: Decoder<T>) -> Self {
+        Words {
+            units: Units { units, decode },
+            position: 0,
+            state: WordState {
+                raw: None,
+                prev: WordBreak::Other,
+                prev_prev: WordBreak::Other,
+                regional_indicators: 0,
+            },
+        }
+    }
+
+    /// Get the `Word_Break` property of the first code point from the index `i`, ignoring `Extend`, `Format` and `ZWJ` (WB4).
+    fn lookahead(&self, mut i: usize) -> WordBreak {
+        while let Some((code_point, len)) = self.units.decode(i) {
+            match word_break(code_point) {
+                WordBreak::Extend | WordBreak::Format | WordBreak::Zwj => i += len,
+                wb => return wb,
+            }
+        }
+        WordBreak::Other
+    }
+
+    /// Check if there is a word boundary before a code point (rules WB3 to WB999).
+    ///
+    /// # Parameters
+    /// * `code_point`: [`u32`] - The unicode code point after the position.
+    /// * `next`: [`WordBreak`] - The property of the code point after the position.
+    /// * `after`: [`usize`] - The index of the code unit after the code point.
+    ///
+    /// # Returns
+    /// `true` if there is a boundary before the code point.
+    fn is_break(&self, code_point: u32, next: WordBreak, after: usize) -> bool {
+        use WordBreak as W;
+        let state: &WordState = &self.state;
+        let (prev, prev_prev) = (state.prev, state.prev_prev);
+        match (state.raw, next) {
+            (Some(W::Cr), W::Lf) => return false,
+            (Some(W::Cr | W::Lf | W::Newline), _) | (_, W::Cr | W::Lf | W::Newline) => return true,
+            (Some(W::Zwj), _) if is_extended_pictographic(code_point) => return false,
+            (Some(W::WSegSpace), W::WSegSpace) => return false,
+            (_, W::Extend | W::Format | W::Zwj) => return false,
+            _ => {}
+        }
+        let joined: bool = match (prev, next) {
+            (p, n) if is_ah_letter(p) && is_ah_letter(n) => true,
+            (p, n)
+                if is_ah_letter(p) && is_mid_letter(n) && is_ah_letter(self.lookahead(after)) =>
+            {
+                true
+            }
+            (p, n) if is_ah_letter(prev_prev) && is_mid_letter(p) && is_ah_letter(n) => true,
+            (W::HebrewLetter, W::SingleQuote) => true,
+            (W::HebrewLetter, W::DoubleQuote) if self.lookahead(after) == W::HebrewLetter => true,
+            (W::DoubleQuote, W::HebrewLetter) if prev_prev == W::HebrewLetter => true,
+            (W::Numeric, W::Numeric) => true,
+            (p, W::Numeric) if is_ah_letter(p) => true,
+            (W::Numeric, n) if is_ah_letter(n) => true,
+            (p, W::Numeric) if prev_prev == W::Numeric && is_mid_num(p) => true,
+            (W::Numeric, n) if is_mid_num(n) && self.lookahead(after) == W::Numeric => true,
+            (W::Katakana, W::Katakana) => true,
+            (W::Numeric | W::Katakana | W::ExtendNumLet, W::ExtendNumLet) => true,
+            (p, W::ExtendNumLet) if is_ah_letter(p) => true,
+            (W::ExtendNumLet, W::Numeric | W::Katakana) => true,
+            (W::ExtendNumLet, n) if is_ah_letter(n) => true,
+            (W::RegionalIndicator, W::RegionalIndicator) => state.regional_indicators % 2 == 1,
+            _ => false,
+        };
+        !joined
+    }
+}
+
+impl<T> Iterator for Words<'_, T> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start: usize = self.position;
+        let (code_point, len) = self.units.decode(start)?;
+        self.state.push(word_break(code_point));
+        self.position += len;
+        while let Some((code_point, len)) = self.units.decode(self.position) {
+            let next: WordBreak = word_break(code_point);
+            if self.is_break(code_point, next, self.position + len) {
+                break;
+            }
+            self.state.push(next);
+            self.position += len;
+        }
+        Some(start..self.position)
+    }
+}
+
+/// The sentence boundaries state before a position.
+#[derive(Clone, Copy)]
+struct SentenceState {
+    /// The property of the code point before the position, [`None`] at the beginning.
+    raw: Option<SentenceBreak>,
+    /// The property of the code point before the position, ignoring `Extend` and `Format` (SB5).
+    prev: SentenceBreak,
+    /// The property of the code point before `prev`, ignoring `Extend` and `Format` (SB5).
+    prev_prev: SentenceBreak,
+    /// The terminator (`ATerm` or `STerm`) of a `SATerm Close* Sp* ParaSep?` sequence ending at `prev`, if any.
+    terminator: Option<SentenceBreak>,
+    /// The sequence of the terminator contains spaces.
+    spaces: bool,
+    /// The sequence of the terminator ends with a paragraph separator.
+    separator: bool,
+    /// The index where the last lookahead of SB8 stopped, and whether it found a lowercase letter there.
+    lower_next: Option<(usize, bool)>,
+}
+
+impl SentenceState {
+    /// Move the position after a code point.
+    fn push(&mut self, next: SentenceBreak) {
+        use SentenceBreak as S;
+        let ignored: bool = matches!(next, S::Extend | S::Format)
+            && !matches!(self.raw, None | Some(S::Sep | S::Cr | S::Lf));
+        self.raw = Some(next);
+        if ignored {
+            return;
+        }
+        self.prev_prev = self.prev;
+        self.prev = next;
+        let in_sequence: bool = self.terminator.is_some() && !self.separator;
+        match next {
+            S::ATerm | S::STerm => {
+                self.terminator = Some(next);
+                self.spaces = false;
+                self.separator = false;
+                self.lower_next = None;
+            }
+            S::Close if in_sequence && !self.spaces => {}
+            S::Sp if in_sequence => self.spaces = true,
+            S::Sep | S::Cr | S::Lf if in_sequence => self.separator = true,
+            _ => self.terminator = None,
+        }
+    }
+}
+
+/// An iterator over the sentences of a slice of code units.
+///
+/// It is created by [`sentences`], [`sentences_utf8`] and [`sentences_utf16`].
+#[derive(Clone)]
+pub struct Sentences<'a, T> {
+    units: Units<'a, T>,
+    position: usize,
+    state: SentenceState,
+}
+
+impl<'a, T> Sentences<'a, T> {
+    /// Create an iterator over the sentences of a slice of code units.
+    fn new(units: &'a [T], decode: Decoder<T>) -> Self {
+        Sentences {
+            units: Units { units, decode },
+            position: 0,
+            state: SentenceState {
+                raw: None,
+                prev: SentenceBreak::Other,
+                prev_prev: SentenceBreak::Other,
+                terminator: None,
+                spaces: false,
+                separator: false,
+                lower_next: None,
+            },
+        }
+    }
+
+    /// Check if the first letter from the index `i` is lowercase, skipping everything but letters, paragraph separators and terminators (SB8).
+    ///
+    /// The result is cached with the index where the lookahead stopped, so that a long sequence of closing punctuation and spaces after a terminator is scanned once.
+    fn is_lower_next(&mut self, i: usize) -> bool {
+        use SentenceBreak as S;
+        if let Some((stop, lower)) = self.state.lower_next {
+            if i <= stop {
+                return lower;
+            }
+        }
+        let mut stop: usize = i;
+        let mut lower: bool = false;
+        while let Some((code_point, len)) = self.units.decode(stop) {
+            match sentence_break(code_point) {
+                S::Lower => {
+                    lower = true;
+                    break;
+                }
+                S::OLetter | S::Upper | S::Sep | S::Cr | S::Lf | S::ATerm | S::STerm => break,
+                _ => stop += len,
+            }
+        }
+        self.state.lower_next = Some((stop, lower));
+        lower
+    }
+
+    /// Check if there is a sentence boundary before a code point (rules SB3 to SB998).
+    ///
+    /// # Parameters
+    /// * `next`: [`SentenceBreak`] - The property of the code point after the position.
+    /// * `i`: [`usize`] - The index of the code point after the position.
+    ///
+    /// # Returns
+    /// `true` if there is a boundary before the code point.
+    fn is_break(&mut self, next: SentenceBreak, i: usize) -> bool {
+        use SentenceBreak as S;
+        let state: SentenceState = self.state;
+        match (state.raw, next) {
+            (Some(S::Cr), S::Lf) => return false,
+            (Some(S::Sep | S::Cr | S::Lf), _) => return true,
+            (_, S::Extend | S::Format) => return false,
+            _ => {}
+        }
+        match (state.prev_prev, state.prev, next) {
+            (_, S::ATerm, S::Numeric) => return false,
+            (S::Upper | S::Lower, S::ATerm, S::Upper) => return false,
+            _ => {}
+        }
+        match state.terminator {
+            Some(_) if state.separator => true,
+            Some(terminator) => match next {
+                _ if terminator == S::ATerm && self.is_lower_next(i) => false,
+                S::SContinue | S::STerm | S::ATerm => false,
+                S::Close => state.spaces,
+                S::Sp | S::Sep | S::Cr | S::Lf => false,
+                _ => true,
+            },
+            None => false,
+        }
+    }
+}
+
+impl<T> Iterator for Sentences<'_, T> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start: usize = self.position;
+        let (code_point, len) = self.units.decode(start)?;
+        self.state.push(sentence_break(code_point));
+        self.position += len;
+        while let Some((code_point, len)) = self.units.decode(self.position) {
+            let next: SentenceBreak = sentence_break(code_point);
+            if self.is_break(next, self.position) {
+                break;
+            }
+            self.state.push(next);
+            self.position += len;
+        }
+        Some(start..self.position)
+    }
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// Get the `Grapheme_Cluster_Break` property of a unicode code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The [`GraphemeClusterBreak`] of the code point, [`GraphemeClusterBreak::Other`] if it has none.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// assert_eq!(grapheme_cluster_break(0x0301), GraphemeClusterBreak::Extend);
+/// assert_eq!(grapheme_cluster_break(0x1F1EE), GraphemeClusterBreak::RegionalIndicator);
+/// assert_eq!(grapheme_cluster_break(0xAC00), GraphemeClusterBreak::Lv);
+/// assert_eq!(grapheme_cluster_break(0x0041), GraphemeClusterBreak::Other);
+/// ```
+pub fn grapheme_cluster_break(code_point: u32) -> GraphemeClusterBreak {
+    tables::lookup_range(tables::segment::GRAPHEME_CLUSTER_BREAK, code_point)
+        .unwrap_or(GraphemeClusterBreak::Other)
+}
+
+/// Iterate the grapheme clusters of a slice of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// A [`Graphemes`] iterator over the ranges of indexes of the grapheme clusters in the slice.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = vec![0x65, 0x301, 0x1F1EE, 0x1F1F9, 0x0D, 0x0A]; // "é🇮🇹\r\n"
+/// let clusters: Vec<std::ops::Range<usize>> = graphemes(&v).collect();
+/// assert_eq!(clusters, vec![0..2, 2..4, 4..6]);
+/// ```
+pub fn graphemes(unicode_cp: &[u32]) -> Graphemes<'_, u32> {
+    Graphemes::new(unicode_cp, decode_code_point)
+}
+
+/// Iterate the grapheme clusters of a slice of UTF-8 code points.
+///
+/// # Parameters
+/// * `utf8_cp`: [`&[u8]`] - A slice of UTF-8 code points.
+///
+/// # Returns
+/// A [`Graphemes`] iterator over the ranges of indexes of the grapheme clusters in the slice of bytes.
+///
+/// # Panics
+/// * If the input slice (`utf8_cp`) of UTF-8 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let s: &str = "👩\u{200D}👩\u{200D}👧 ok";
+/// let clusters: Vec<&str> = graphemes_utf8(s.as_bytes()).map(|r| &s[r]).collect();
+/// assert_eq!(clusters, vec!["👩\u{200D}👩\u{200D}👧", " ", "o", "k"]);
+/// ```
+pub fn graphemes_utf8(utf8_cp: &[u8]) -> Graphemes<'_, u8> {
+    Graphemes::new(utf8_cp, crate::utf8::decode_symbol)
+}
+
+/// Iterate the grapheme clusters of a slice of UTF-16 code points.
+///
+/// # Parameters
+/// * `utf16_cp`: [`&[u16]`] - A slice of UTF-16 code points.
+///
+/// # Returns
+/// A [`Graphemes`] iterator over the ranges of indexes of the grapheme clusters in the slice of UTF-16 code points.
+///
+/// # Panics
+/// * If the input slice (`utf16_cp`) of UTF-16 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u16> = vec![0x61, 0xD83D, 0xDC4D, 0xD83C, 0xDFFD]; // "a👍🏽"
+/// let clusters: Vec<std::ops::Range<usize>> = graphemes_utf16(&v).collect();
+/// assert_eq!(clusters, vec![0..1, 1..5]);
+/// ```
+pub fn graphemes_utf16(utf16_cp: &[u16]) -> Graphemes<'_, u16> {
+    Graphemes::new(utf16_cp, crate::utf16::decode_symbol)
+}
+
+/// Get the `Word_Break` property of a unicode code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The [`WordBreak`] of the code point, [`WordBreak::Other`] if it has none.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// assert_eq!(word_break(0x0041), WordBreak::ALetter);
+/// assert_eq!(word_break(0x0027), WordBreak::SingleQuote);
+/// assert_eq!(word_break(0x30A2), WordBreak::Katakana);
+/// assert_eq!(word_break(0x4E00), WordBreak::Other);
+/// ```
+pub fn word_break(code_point: u32) -> WordBreak {
+    tables::lookup_range(tables::segment::WORD_BREAK, code_point).unwrap_or(WordBreak::Other)
+}
+
+/// Get the `Sentence_Break` property of a unicode code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The [`SentenceBreak`] of the code point, [`SentenceBreak::Other`] if it has none.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// assert_eq!(sentence_break(0x002E), SentenceBreak::ATerm);
+/// assert_eq!(sentence_break(0x003F), SentenceBreak::STerm);
+/// assert_eq!(sentence_break(0x0061), SentenceBreak::Lower);
+/// assert_eq!(sentence_break(0x2029), SentenceBreak::Sep);
+/// ```
+pub fn sentence_break(code_point: u32) -> SentenceBreak {
+    tables::lookup_range(tables::segment::SENTENCE_BREAK, code_point)
+        .unwrap_or(SentenceBreak::Other)
+}
+
+/// Iterate the words of a slice of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// A [`Words`] iterator over the ranges of indexes of the segments between the word boundaries in the slice.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = vec![0x49, 0x27, 0x6D, 0x20, 0x33, 0x2E, 0x35]; // "I'm 3.5"
+/// let segments: Vec<std::ops::Range<usize>> = words(&v).collect();
+/// assert_eq!(segments, vec![0..3, 3..4, 4..7]);
+/// ```
+pub fn words(unicode_cp: &[u32]) -> Words<'_, u32> {
+    Words::new(unicode_cp, decode_code_point)
+}
+
+/// Iterate the words of a slice of UTF-8 code points.
+///
+/// # Parameters
+/// * `utf8_cp`: [`&[u8]`] - A slice of UTF-8 code points.
+///
+/// # Returns
+/// A [`Words`] iterator over the ranges of indexes of the segments between the word boundaries in the slice of bytes.
+///
+/// # Panics
+/// * If the input slice (`utf8_cp`) of UTF-8 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let s: &str = "The quick (\"brown\") fox can't jump 32.3 feet, right?";
+/// let words: Vec<&str> = words_utf8(s.as_bytes())
+///     .map(|r| &s[r])
+///     .filter(|w| w.chars().any(char::is_alphanumeric))
+///     .collect();
+/// assert_eq!(
+///     words,
+///     vec!["The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"]
+/// );
+/// ```
+pub fn words_utf8(utf8_cp: &[u8]) -> Words<'_, u8> {
+    Words::new(utf8_cp, crate::utf8::decode_symbol)
+}
+
+/// Iterate the words of a slice of UTF-16 code points.
+///
+/// # Parameters
+/// * `utf16_cp`: [`&[u16]`] - A slice of UTF-16 code points.
+///
+/// # Returns
+/// A [`Words`] iterator over the ranges of indexes of the segments between the word boundaries in the slice of UTF-16 code points.
+///
+/// # Panics
+/// * If the input slice (`utf16_cp`) of UTF-16 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u16> = vec![0x61, 0x62, 0x20, 0xD83D, 0xDC4D]; // "ab 👍"
+/// let segments: Vec<std::ops::Range<usize>> = words_utf16(&v).collect();
+/// assert_eq!(segments, vec![0..2, 2..3, 3..5]);
+/// ```
+pub fn words_utf16(utf16_cp: &[u16]) -> Words<'_, u16> {
+    Words::new(utf16_cp, crate::utf16::decode_symbol)
+}
+
+/// Iterate the sentences of a slice of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// A [`Sentences`] iterator over the ranges of indexes of the sentences in the slice.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = vec![0x48, 0x69, 0x2E, 0x20, 0x4F, 0x6B, 0x3F]; // "Hi. Ok?"
+/// let segments: Vec<std::ops::Range<usize>> = sentences(&v).collect();
+/// assert_eq!(segments, vec![0..4, 4..7]);
+/// ```
+pub fn sentences(unicode_cp: &[u32]) -> Sentences<'_, u32> {
+    Sentences::new(unicode_cp, decode_code_point)
+}
+
+/// Iterate the sentences of a slice of UTF-8 code points.
+///
+/// # Parameters
+/// * `utf8_cp`: [`&[u8]`] - A slice of UTF-8 code points.
+///
+/// # Returns
+/// A [`Sentences`] iterator over the ranges of indexes of the sentences in the slice of bytes.
+///
+/// # Panics
+/// * If the input slice (`utf8_cp`) of UTF-8 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let s: &str = "Mr. Smith paid $3.50 (e.g. a coffee). \"Fine!\" He left.\nNew line";
+/// let sentences: Vec<&str> = sentences_utf8(s.as_bytes()).map(|r| &s[r]).collect();
+/// assert_eq!(
+///     sentences,
+///     vec![
+///         "Mr. ",
+///         "Smith paid $3.50 (e.g. a coffee). ",
+///         "\"Fine!\" ",
+///         "He left.\n",
+///         "New line"
+///     ]
+/// );
+/// ```
+pub fn sentences_utf8(utf8_cp: &[u8]) -> Sentences<'_, u8> {
+    Sentences::new(utf8_cp, crate::utf8::decode_symbol)
+}
+
+/// Iterate the sentences of a slice of UTF-16 code points.
+///
+/// # Parameters
+/// * `utf16_cp`: [`&[u16]`] - A slice of UTF-16 code points.
+///
+/// # Returns
+/// A [`Sentences`] iterator over the ranges of indexes of the sentences in the slice of UTF-16 code points.
+///
+/// # Panics
+/// * If the input slice (`utf16_cp`) of UTF-16 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u16> = "Go! 👍 Yes.".encode_utf16().collect();
+/// let segments: Vec<std::ops::Range<usize>> = sentences_utf16(&v).collect();
+/// assert_eq!(segments, vec![0..4, 4..11]);
+/// ```
+pub fn sentences_utf16(utf16_cp: &[u16]) -> Sentences<'_, u16> {
+    Sentences::new(utf16_cp, crate::utf16::decode_symbol)
+}
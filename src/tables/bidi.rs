@@ -0,0 +1,1283 @@
+// Generated from the Unicode Character Database 14.0.0 (DerivedBidiClass.txt, BidiBrackets.txt, BidiMirroring.txt). Do not edit.
+
+use crate::bidi::BidiClass as B;
+
+#[rustfmt::skip]
+pub(crate) const BIDI_CLASS: &[(u32, u32, B)] = &[
+    (0x0000, 0x0008, B::BoundaryNeutral),
+    (0x0009, 0x0009, B::SegmentSeparator),
+    (0x000A, 0x000A, B::ParagraphSeparator),
+    (0x000B, 0x000B, B::SegmentSeparator),
+    (0x000C, 0x000C, B::WhiteSpace),
+    (0x000D, 0x000D, B::ParagraphSeparator),
+    (0x000E, 0x001B, B::BoundaryNeutral),
+    (0x001C, 0x001E, B::ParagraphSeparator),
+    (0x001F, 0x001F, B::SegmentSeparator),
+    (0x0020, 0x0020, B::WhiteSpace),
+    (0x0021, 0x0022, B::OtherNeutral),
+    (0x0023, 0x0025, B::EuropeanTerminator),
+    (0x0026, 0x002A, B::OtherNeutral),
+    (0x002B, 0x002B, B::EuropeanSeparator),
+    (0x002C, 0x002C, B::CommonSeparator),
+    (0x002D, 0x002D, B::EuropeanSeparator),
+    (0x002E, 0x002F, B::CommonSeparator),
+    (0x0030, 0x0039, B::EuropeanNumber),
+    (0x003A, 0x003A, B::CommonSeparator),
+    (0x003B, 0x0040, B::OtherNeutral),
+    (0x005B, 0x0060, B::OtherNeutral),
+    (0x007B, 0x007E, B::OtherNeutral),
+    (0x007F, 0x0084, B::BoundaryNeutral),
+    (0x0085, 0x0085, B::ParagraphSeparator),
+    (0x0086, 0x009F, B::BoundaryNeutral),
+    (0x00A0, 0x00A0, B::CommonSeparator),
+    (0x00A1, 0x00A1, B::OtherNeutral),
+    (0x00A2, 0x00A5, B::EuropeanTerminator),
+    (0x00A6, 0x00A9, B::OtherNeutral),
+    (0x00AB, 0x00AC, B::OtherNeutral),
+    (0x00AD, 0x00AD, B::BoundaryNeutral),
+    (0x00AE, 0x00AF, B::OtherNeutral),
+    (0x00B0, 0x00B1, B::EuropeanTerminator),
+    (0x00B2, 0x00B3, B::EuropeanNumber),
+    (0x00B4, 0x00B4, B::OtherNeutral),
+    (0x00B6, 0x00B8, B::OtherNeutral),
+    (0x00B9, 0x00B9, B::EuropeanNumber),
+    (0x00BB, 0x00BF, B::OtherNeutral),
+    (0x00D7, 0x00D7, B::OtherNeutral),
+    (0x00F7, 0x00F7, B::OtherNeutral),
+    (0x02B9, 0x02BA, B::OtherNeutral),
+    (0x02C2, 0x02CF, B::OtherNeutral),
+    (0x02D2, 0x02DF, B::OtherNeutral),
+    (0x02E5, 0x02ED, B::OtherNeutral),
+    (0x02EF, 0x02FF, B::OtherNeutral),
+    (0x0300, 0x036F, B::NonspacingMark),
+    (0x0374, 0x0375, B::OtherNeutral),
+    (0x037E, 0x037E, B::OtherNeutral),
+    (0x0384, 0x0385, B::OtherNeutral),
+    (0x0387, 0x0387, B::OtherNeutral),
+    (0x03F6, 0x03F6, B::OtherNeutral),
+    (0x0483, 0x0489, B::NonspacingMark),
+    (0x058A, 0x058A, B::OtherNeutral),
+    (0x058D, 0x058E, B::OtherNeutral),
+    (0x058F, 0x058F, B::EuropeanTerminator),
+    (0x0590, 0x0590, B::RightToLeft),
+    (0x0591, 0x05BD, B::NonspacingMark),
+    (0x05BE, 0x05BE, B::RightToLeft),
+    (0x05BF, 0x05BF, B::NonspacingMark),
+    (0x05C0, 0x05C0, B::RightToLeft),
+    (0x05C1, 0x05C2, B::NonspacingMark),
+    (0x05C3, 0x05C3, B::RightToLeft),
+    (0x05C4, 0x05C5, B::NonspacingMark),
+    (0x05C6, 0x05C6, B::RightToLeft),
+    (0x05C7, 0x05C7, B::NonspacingMark),
+    (0x05C8, 0x05FF, B::RightToLeft),
+    (0x0600, 0x0605, B::ArabicNumber),
+    (0x0606, 0x0607, B::OtherNeutral),
+    (0x0608, 0x0608, B::ArabicLetter),
+    (0x0609, 0x060A, B::EuropeanTerminator),
+    (0x060B, 0x060B, B::ArabicLetter),
+    (0x060C, 0x060C, B::CommonSeparator),
+    (0x060D, 0x060D, B::ArabicLetter),
+    (0x060E, 0x060F, B::OtherNeutral),
+    (0x0610, 0x061A, B::NonspacingMark),
+    (0x061B, 0x064A, B::ArabicLetter),
+    (0x064B, 0x065F, B::NonspacingMark),
+    (0x0660, 0x0669, B::ArabicNumber),
+    (0x066A, 0x066A, B::EuropeanTerminator),
+    (0x066B, 0x066C, B::ArabicNumber),
+    (0x066D, 0x066F, B::ArabicLetter),
+    (0x0670, 0x0670, B::NonspacingMark),
+    (0x0671, 0x06D5, B::ArabicLetter),
+    (0x06D6, 0x06DC, B::NonspacingMark),
+    (0x06DD, 0x06DD, B::ArabicNumber),
+    (0x06DE, 0x06DE, B::OtherNeutral),
+    (0x06DF, 0x06E4, B::NonspacingMark),
+    (0x06E5, 0x06E6, B::ArabicLetter),
+    (0x06E7, 0x06E8, B::NonspacingMark),
+    (0x06E9, 0x06E9, B::OtherNeutral),
+    (0x06EA, 0x06ED, B::NonspacingMark),
+    (0x06EE, 0x06EF, B::ArabicLetter),
+    (0x06F0, 0x06F9, B::EuropeanNumber),
+    (0x06FA, 0x0710, B::ArabicLetter),
+    (0x0711, 0x0711, B::NonspacingMark),
+    (0x0712, 0x072F, B::ArabicLetter),
+    (0x0730, 0x074A, B::NonspacingMark),
+    (0x074B, 0x07A5, B::ArabicLetter),
+    (0x07A6, 0x07B0, B::NonspacingMark),
+    (0x07B1, 0x07BF, B::ArabicLetter),
+    (0x07C0, 0x07EA, B::RightToLeft),
+    (0x07EB, 0x07F3, B::NonspacingMark),
+    (0x07F4, 0x07F5, B::RightToLeft),
+    (0x07F6, 0x07F9, B::OtherNeutral),
+    (0x07FA, 0x07FC, B::RightToLeft),
+    (0x07FD, 0x07FD, B::NonspacingMark),
+    (0x07FE, 0x0815, B::RightToLeft),
+    (0x0816, 0x0819, B::NonspacingMark),
+    (0x081A, 0x081A, B::RightToLeft),
+    (0x081B, 0x0823, B::NonspacingMark),
+    (0x0824, 0x0824, B::RightToLeft),
+    (0x0825, 0x0827, B::NonspacingMark),
+    (0x0828, 0x0828, B::RightToLeft),
+    (0x0829, 0x082D, B::NonspacingMark),
+    (0x082E, 0x0858, B::RightToLeft),
+    (0x0859, 0x085B, B::NonspacingMark),
+    (0x085C, 0x085F, B::RightToLeft),
+    (0x0860, 0x088F, B::ArabicLetter),
+    (0x0890, 0x0891, B::ArabicNumber),
+    (0x0892, 0x0897, B::ArabicLetter),
+    (0x0898, 0x089F, B::NonspacingMark),
+    (0x08A0, 0x08C9, B::ArabicLetter),
+    (0x08CA, 0x08E1, B::NonspacingMark),
+    (0x08E2, 0x08E2, B::ArabicNumber),
+    (0x08E3, 0x0902, B::NonspacingMark),
+    (0x093A, 0x093A, B::NonspacingMark),
+    (0x093C, 0x093C, B::NonspacingMark),
+    (0x0941, 0x0948, B::NonspacingMark),
+    (0x094D, 0x094D, B::NonspacingMark),
+    (0x0951, 0x0957, B::NonspacingMark),
+    (0x0962, 0x0963, B::NonspacingMark),
+    (0x0981, 0x0981, B::NonspacingMark),
+    (0x09BC, 0x09BC, B::NonspacingMark),
+    (0x09C1, 0x09C4, B::NonspacingMark),
+    (0x09CD, 0x09CD, B::NonspacingMark),
+    (0x09E2, 0x09E3, B::NonspacingMark),
+    (0x09F2, 0x09F3, B::EuropeanTerminator),
+    (0x09FB, 0x09FB, B::EuropeanTerminator),
+    (0x09FE, 0x09FE, B::NonspacingMark),
+    (0x0A01, 0x0A02, B::NonspacingMark),
+    (0x0A3C, 0x0A3C, B::NonspacingMark),
+    (0x0A41, 0x0A42, B::NonspacingMark),
+    (0x0A47, 0x0A48, B::NonspacingMark),
+    (0x0A4B, 0x0A4D, B::NonspacingMark),
+    (0x0A51, 0x0A51, B::NonspacingMark),
+    (0x0A70, 0x0A71, B::NonspacingMark),
+    (0x0A75, 0x0A75, B::NonspacingMark),
+    (0x0A81, 0x0A82, B::NonspacingMark),
+    (0x0ABC, 0x0ABC, B::NonspacingMark),
+    (0x0AC1, 0x0AC5, B::NonspacingMark),
+    (0x0AC7, 0x0AC8, B::NonspacingMark),
+    (0x0ACD, 0x0ACD, B::NonspacingMark),
+    (0x0AE2, 0x0AE3, B::NonspacingMark),
+    (0x0AF1, 0x0AF1, B::EuropeanTerminator),
+    (0x0AFA, 0x0AFF, B::NonspacingMark),
+    (0x0B01, 0x0B01, B::NonspacingMark),
+    (0x0B3C, 0x0B3C, B::NonspacingMark),
+    (0x0B3F, 0x0B3F, B::NonspacingMark),
+    (0x0B41, 0x0B44, B::NonspacingMark),
+    (0x0B4D, 0x0B4D, B::NonspacingMark),
+    (0x0B55, 0x0B56, B::NonspacingMark),
+    (0x0B62, 0x0B63, B::NonspacingMark),
+    (0x0B82, 0x0B82, B::NonspacingMark),
+    (0x0BC0, 0x0BC0, B::NonspacingMark),
+    (0x0BCD, 0x0BCD, B::NonspacingMark),
+    (0x0BF3, 0x0BF8, B::OtherNeutral),
+    (0x0BF9, 0x0BF9, B::EuropeanTerminator),
+    (0x0BFA, 0x0BFA, B::OtherNeutral),
+    (0x0C00, 0x0C00, B::NonspacingMark),
+    (0x0C04, 0x0C04, B::NonspacingMark),
+    (0x0C3C, 0x0C3C, B::NonspacingMark),
+    (0x0C3E, 0x0C40, B::NonspacingMark),
+    (0x0C46, 0x0C48, B::NonspacingMark),
+    (0x0C4A, 0x0C4D, B::NonspacingMark),
+    (0x0C55, 0x0C56, B::NonspacingMark),
+    (0x0C62, 0x0C63, B::NonspacingMark),
+    (0x0C78, 0x0C7E, B::OtherNeutral),
+    (0x0C81, 0x0C81, B::NonspacingMark),
+    (0x0CBC, 0x0CBC, B::NonspacingMark),
+    (0x0CCC, 0x0CCD, B::NonspacingMark),
+    (0x0CE2, 0x0CE3, B::NonspacingMark),
+    (0x0D00, 0x0D01, B::NonspacingMark),
+    (0x0D3B, 0x0D3C, B::NonspacingMark),
+    (0x0D41, 0x0D44, B::NonspacingMark),
+    (0x0D4D, 0x0D4D, B::NonspacingMark),
+    (0x0D62, 0x0D63, B::NonspacingMark),
+    (0x0D81, 0x0D81, B::NonspacingMark),
+    (0x0DCA, 0x0DCA, B::NonspacingMark),
+    (0x0DD2, 0x0DD4, B::NonspacingMark),
+    (0x0DD6, 0x0DD6, B::NonspacingMark),
+    (0x0E31, 0x0E31, B::NonspacingMark),
+    (0x0E34, 0x0E3A, B::NonspacingMark),
+    (0x0E3F, 0x0E3F, B::EuropeanTerminator),
+    (0x0E47, 0x0E4E, B::NonspacingMark),
+    (0x0EB1, 0x0EB1, B::NonspacingMark),
+    (0x0EB4, 0x0EBC, B::NonspacingMark),
+    (0x0EC8, 0x0ECD, B::NonspacingMark),
+    (0x0F18, 0x0F19, B::NonspacingMark),
+    (0x0F35, 0x0F35, B::NonspacingMark),
+    (0x0F37, 0x0F37, B::NonspacingMark),
+    (0x0F39, 0x0F39, B::NonspacingMark),
+    (0x0F3A, 0x0F3D, B::OtherNeutral),
+    (0x0F71, 0x0F7E, B::NonspacingMark),
+    (0x0F80, 0x0F84, B::NonspacingMark),
+    (0x0F86, 0x0F87, B::NonspacingMark),
+    (0x0F8D, 0x0F97, B::NonspacingMark),
+    (0x0F99, 0x0FBC, B::NonspacingMark),
+    (0x0FC6, 0x0FC6, B::NonspacingMark),
+    (0x102D, 0x1030, B::NonspacingMark),
+    (0x1032, 0x1037, B::NonspacingMark),
+    (0x1039, 0x103A, B::NonspacingMark),
+    (0x103D, 0x103E, B::NonspacingMark),
+    (0x1058, 0x1059, B::NonspacingMark),
+    (0x105E, 0x1060, B::NonspacingMark),
+    (0x1071, 0x1074, B::NonspacingMark),
+    (0x1082, 0x1082, B::NonspacingMark),
+    (0x1085, 0x1086, B::NonspacingMark),
+    (0x108D, 0x108D, B::NonspacingMark),
+    (0x109D, 0x109D, B::NonspacingMark),
+    (0x135D, 0x135F, B::NonspacingMark),
+    (0x1390, 0x1399, B::OtherNeutral),
+    (0x1400, 0x1400, B::OtherNeutral),
+    (0x1680, 0x1680, B::WhiteSpace),
+    (0x169B, 0x169C, B::OtherNeutral),
+    (0x1712, 0x1714, B::NonspacingMark),
+    (0x1732, 0x1733, B::NonspacingMark),
+    (0x1752, 0x1753, B::NonspacingMark),
+    (0x1772, 0x1773, B::NonspacingMark),
+    (0x17B4, 0x17B5, B::NonspacingMark),
+    (0x17B7, 0x17BD, B::NonspacingMark),
+    (0x17C6, 0x17C6, B::NonspacingMark),
+    (0x17C9, 0x17D3, B::NonspacingMark),
+    (0x17DB, 0x17DB, B::EuropeanTerminator),
+    (0x17DD, 0x17DD, B::NonspacingMark),
+    (0x17F0, 0x17F9, B::OtherNeutral),
+    (0x1800, 0x180A, B::OtherNeutral),
+    (0x180B, 0x180D, B::NonspacingMark),
+    (0x180E, 0x180E, B::BoundaryNeutral),
+    (0x180F, 0x180F, B::NonspacingMark),
+    (0x1885, 0x1886, B::NonspacingMark),
+    (0x18A9, 0x18A9, B::NonspacingMark),
+    (0x1920, 0x1922, B::NonspacingMark),
+    (0x1927, 0x1928, B::NonspacingMark),
+    (0x1932, 0x1932, B::NonspacingMark),
+    (0x1939, 0x193B, B::NonspacingMark),
+    (0x1940, 0x1940, B::OtherNeutral),
+    (0x1944, 0x1945, B::OtherNeutral),
+    (0x19DE, 0x19FF, B::OtherNeutral),
+    (0x1A17, 0x1A18, B::NonspacingMark),
+    (0x1A1B, 0x1A1B, B::NonspacingMark),
+    (0x1A56, 0x1A56, B::NonspacingMark),
+    (0x1A58, 0x1A5E, B::NonspacingMark),
+    (0x1A60, 0x1A60, B::NonspacingMark),
+    (0x1A62, 0x1A62, B::NonspacingMark),
+    (0x1A65, 0x1A6C, B::NonspacingMark),
+    (0x1A73, 0x1A7C, B::NonspacingMark),
+    (0x1A7F, 0x1A7F, B::NonspacingMark),
+    (0x1AB0, 0x1ACE, B::NonspacingMark),
+    (0x1B00, 0x1B03, B::NonspacingMark),
+    (0x1B34, 0x1B34, B::NonspacingMark),
+    (0x1B36, 0x1B3A, B::NonspacingMark),
+    (0x1B3C, 0x1B3C, B::NonspacingMark),
+    (0x1B42, 0x1B42, B::NonspacingMark),
+    (0x1B6B, 0x1B73, B::NonspacingMark),
+    (0x1B80, 0x1B81, B::NonspacingMark),
+    (0x1BA2, 0x1BA5, B::NonspacingMark),
+    (0x1BA8, 0x1BA9, B::NonspacingMark),
+    (0x1BAB, 0x1BAD, B::NonspacingMark),
+    (0x1BE6, 0x1BE6, B::NonspacingMark),
+    (0x1BE8, 0x1BE9, B::NonspacingMark),
+    (0x1BED, 0x1BED, B::NonspacingMark),
+    (0x1BEF, 0x1BF1, B::NonspacingMark),
+    (0x1C2C, 0x1C33, B::NonspacingMark),
+    (0x1C36, 0x1C37, B::NonspacingMark),
+    (0x1CD0, 0x1CD2, B::NonspacingMark),
+    (0x1CD4, 0x1CE0, B::NonspacingMark),
+    (0x1CE2, 0x1CE8, B::NonspacingMark),
+    (0x1CED, 0x1CED, B::NonspacingMark),
+    (0x1CF4, 0x1CF4, B::NonspacingMark),
+    (0x1CF8, 0x1CF9, B::NonspacingMark),
+    (0x1DC0, 0x1DFF, B::NonspacingMark),
+    (0x1FBD, 0x1FBD, B::OtherNeutral),
+    (0x1FBF, 0x1FC1, B::OtherNeutral),
+    (0x1FCD, 0x1FCF, B::OtherNeutral),
+    (0x1FDD, 0x1FDF, B::OtherNeutral),
+    (0x1FED, 0x1FEF, B::OtherNeutral),
+    (0x1FFD, 0x1FFE, B::OtherNeutral),
+    (0x2000, 0x200A, B::WhiteSpace),
+    (0x200B, 0x200D, B::BoundaryNeutral),
+    (0x200F, 0x200F, B::RightToLeft),
+    (0x2010, 0x2027, B::OtherNeutral),
+    (0x2028, 0x2028, B::WhiteSpace),
+    (0x2029, 0x2029, B::ParagraphSeparator),
+    (0x202A, 0x202A, B::LeftToRightEmbedding),
+    (0x202B, 0x202B, B::RightToLeftEmbedding),
+    (0x202C, 0x202C, B::PopDirectionalFormat),
+    (0x202D, 0x202D, B::LeftToRightOverride),
+    (0x202E, 0x202E, B::RightToLeftOverride),
+    (0x202F, 0x202F, B::CommonSeparator),
+    (0x2030, 0x2034, B::EuropeanTerminator),
+    (0x2035, 0x2043, B::OtherNeutral),
+    (0x2044, 0x2044, B::CommonSeparator),
+    (0x2045, 0x205E, B::OtherNeutral),
+    (0x205F, 0x205F, B::WhiteSpace),
+    (0x2060, 0x2065, B::BoundaryNeutral),
+    (0x2066, 0x2066, B::LeftToRightIsolate),
+    (0x2067, 0x2067, B::RightToLeftIsolate),
+    (0x2068, 0x2068, B::FirstStrongIsolate),
+    (0x2069, 0x2069, B::PopDirectionalIsolate),
+    (0x206A, 0x206F, B::BoundaryNeutral),
+    (0x2070, 0x2070, B::EuropeanNumber),
+    (0x2074, 0x2079, B::EuropeanNumber),
+    (0x207A, 0x207B, B::EuropeanSeparator),
+    (0x207C, 0x207E, B::OtherNeutral),
+    (0x2080, 0x2089, B::EuropeanNumber),
+    (0x208A, 0x208B, B::EuropeanSeparator),
+    (0x208C, 0x208E, B::OtherNeutral),
+    (0x20A0, 0x20CF, B::EuropeanTerminator),
+    (0x20D0, 0x20F0, B::NonspacingMark),
+    (0x2100, 0x2101, B::OtherNeutral),
+    (0x2103, 0x2106, B::OtherNeutral),
+    (0x2108, 0x2109, B::OtherNeutral),
+    (0x2114, 0x2114, B::OtherNeutral),
+    (0x2116, 0x2118, B::OtherNeutral),
+    (0x211E, 0x2123, B::OtherNeutral),
+    (0x2125, 0x2125, B::OtherNeutral),
+    (0x2127, 0x2127, B::OtherNeutral),
+    (0x2129, 0x2129, B::OtherNeutral),
+    (0x212E, 0x212E, B::EuropeanTerminator),
+    (0x213A, 0x213B, B::OtherNeutral),
+    (0x2140, 0x2144, B::OtherNeutral),
+    (0x214A, 0x214D, B::OtherNeutral),
+    (0x2150, 0x215F, B::OtherNeutral),
+    (0x2189, 0x218B, B::OtherNeutral),
+    (0x2190, 0x2211, B::OtherNeutral),
+    (0x2212, 0x2212, B::EuropeanSeparator),
+    (0x2213, 0x2213, B::EuropeanTerminator),
+    (0x2214, 0x2335, B::OtherNeutral),
+    (0x237B, 0x2394, B::OtherNeutral),
+    (0x2396, 0x2426, B::OtherNeutral),
+    (0x2440, 0x244A, B::OtherNeutral),
+    (0x2460, 0x2487, B::OtherNeutral),
+    (0x2488, 0x249B, B::EuropeanNumber),
+    (0x24EA, 0x26AB, B::OtherNeutral),
+    (0x26AD, 0x27FF, B::OtherNeutral),
+    (0x2900, 0x2B73, B::OtherNeutral),
+    (0x2B76, 0x2B95, B::OtherNeutral),
+    (0x2B97, 0x2BFF, B::OtherNeutral),
+    (0x2CE5, 0x2CEA, B::OtherNeutral),
+    (0x2CEF, 0x2CF1, B::NonspacingMark),
+    (0x2CF9, 0x2CFF, B::OtherNeutral),
+    (0x2D7F, 0x2D7F, B::NonspacingMark),
+    (0x2DE0, 0x2DFF, B::NonspacingMark),
+    (0x2E00, 0x2E5D, B::OtherNeutral),
+    (0x2E80, 0x2E99, B::OtherNeutral),
+    (0x2E9B, 0x2EF3, B::OtherNeutral),
+    (0x2F00, 0x2FD5, B::OtherNeutral),
+    (0x2FF0, 0x2FFB, B::OtherNeutral),
+    (0x3000, 0x3000, B::WhiteSpace),
+    (0x3001, 0x3004, B::OtherNeutral),
+    (0x3008, 0x3020, B::OtherNeutral),
+    (0x302A, 0x302D, B::NonspacingMark),
+    (0x3030, 0x3030, B::OtherNeutral),
+    (0x3036, 0x3037, B::OtherNeutral),
+    (0x303D, 0x303F, B::OtherNeutral),
+    (0x3099, 0x309A, B::NonspacingMark),
+    (0x309B, 0x309C, B::OtherNeutral),
+    (0x30A0, 0x30A0, B::OtherNeutral),
+    (0x30FB, 0x30FB, B::OtherNeutral),
+    (0x31C0, 0x31E3, B::OtherNeutral),
+    (0x321D, 0x321E, B::OtherNeutral),
+    (0x3250, 0x325F, B::OtherNeutral),
+    (0x327C, 0x327E, B::OtherNeutral),
+    (0x32B1, 0x32BF, B::OtherNeutral),
+    (0x32CC, 0x32CF, B::OtherNeutral),
+    (0x3377, 0x337A, B::OtherNeutral),
+    (0x33DE, 0x33DF, B::OtherNeutral),
+    (0x33FF, 0x33FF, B::OtherNeutral),
+    (0x4DC0, 0x4DFF, B::OtherNeutral),
+    (0xA490, 0xA4C6, B::OtherNeutral),
+    (0xA60D, 0xA60F, B::OtherNeutral),
+    (0xA66F, 0xA672, B::NonspacingMark),
+    (0xA673, 0xA673, B::OtherNeutral),
+    (0xA674, 0xA67D, B::NonspacingMark),
+    (0xA67E, 0xA67F, B::OtherNeutral),
+    (0xA69E, 0xA69F, B::NonspacingMark),
+    (0xA6F0, 0xA6F1, B::NonspacingMark),
+    (0xA700, 0xA721, B::OtherNeutral),
+    (0xA788, 0xA788, B::OtherNeutral),
+    (0xA802, 0xA802, B::NonspacingMark),
+    (0xA806, 0xA806, B::NonspacingMark),
+    (0xA80B, 0xA80B, B::NonspacingMark),
+    (0xA825, 0xA826, B::NonspacingMark),
+    (0xA828, 0xA82B, B::OtherNeutral),
+    (0xA82C, 0xA82C, B::NonspacingMark),
+    (0xA838, 0xA839, B::EuropeanTerminator),
+    (0xA874, 0xA877, B::OtherNeutral),
+    (0xA8C4, 0xA8C5, B::NonspacingMark),
+    (0xA8E0, 0xA8F1, B::NonspacingMark),
+    (0xA8FF, 0xA8FF, B::NonspacingMark),
+    (0xA926, 0xA92D, B::NonspacingMark),
+    (0xA947, 0xA951, B::NonspacingMark),
+    (0xA980, 0xA982, B::NonspacingMark),
+    (0xA9B3, 0xA9B3, B::NonspacingMark),
+    (0xA9B6, 0xA9B9, B::NonspacingMark),
+    (0xA9BC, 0xA9BD, B::NonspacingMark),
+    (0xA9E5, 0xA9E5, B::NonspacingMark),
+    (0xAA29, 0xAA2E, B::NonspacingMark),
+    (0xAA31, 0xAA32, B::NonspacingMark),
+    (0xAA35, 0xAA36, B::NonspacingMark),
+    (0xAA43, 0xAA43, B::NonspacingMark),
+    (0xAA4C, 0xAA4C, B::NonspacingMark),
+    (0xAA7C, 0xAA7C, B::NonspacingMark),
+    (0xAAB0, 0xAAB0, B::NonspacingMark),
+    (0xAAB2, 0xAAB4, B::NonspacingMark),
+    (0xAAB7, 0xAAB8, B::NonspacingMark),
+    (0xAABE, 0xAABF, B::NonspacingMark),
+    (0xAAC1, 0xAAC1, B::NonspacingMark),
+    (0xAAEC, 0xAAED, B::NonspacingMark),
+    (0xAAF6, 0xAAF6, B::NonspacingMark),
+    (0xAB6A, 0xAB6B, B::OtherNeutral),
+    (0xABE5, 0xABE5, B::NonspacingMark),
+    (0xABE8, 0xABE8, B::NonspacingMark),
+    (0xABED, 0xABED, B::NonspacingMark),
+    (0xFB1D, 0xFB1D, B::RightToLeft),
+    (0xFB1E, 0xFB1E, B::NonspacingMark),
+    (0xFB1F, 0xFB28, B::RightToLeft),
+    (0xFB29, 0xFB29, B::EuropeanSeparator),
+    (0xFB2A, 0xFB4F, B::RightToLeft),
+    (0xFB50, 0xFD3D, B::ArabicLetter),
+    (0xFD3E, 0xFD4F, B::OtherNeutral),
+    (0xFD50, 0xFDCE, B::ArabicLetter),
+    (0xFDCF, 0xFDCF, B::OtherNeutral),
+    (0xFDD0, 0xFDEF, B::BoundaryNeutral),
+    (0xFDF0, 0xFDFC, B::ArabicLetter),
+    (0xFDFD, 0xFDFF, B::OtherNeutral),
+    (0xFE00, 0xFE0F, B::NonspacingMark),
+    (0xFE10, 0xFE19, B::OtherNeutral),
+    (0xFE20, 0xFE2F, B::NonspacingMark),
+    (0xFE30, 0xFE4F, B::OtherNeutral),
+    (0xFE50, 0xFE50, B::CommonSeparator),
+    (0xFE51, 0xFE51, B::OtherNeutral),
+    (0xFE52, 0xFE52, B::CommonSeparator),
+    (0xFE54, 0xFE54, B::OtherNeutral),
+    (0xFE55, 0xFE55, B::CommonSeparator),
+    (0xFE56, 0xFE5E, B::OtherNeutral),
+    (0xFE5F, 0xFE5F, B::EuropeanTerminator),
+    (0xFE60, 0xFE61, B::OtherNeutral),
+    (0xFE62, 0xFE63, B::EuropeanSeparator),
+    (0xFE64, 0xFE66, B::OtherNeutral),
+    (0xFE68, 0xFE68, B::OtherNeutral),
+    (0xFE69, 0xFE6A, B::EuropeanTerminator),
+    (0xFE6B, 0xFE6B, B::OtherNeutral),
+    (0xFE70, 0xFEFE, B::ArabicLetter),
+    (0xFEFF, 0xFEFF, B::BoundaryNeutral),
+    (0xFF01, 0xFF02, B::OtherNeutral),
+    (0xFF03, 0xFF05, B::EuropeanTerminator),
+    (0xFF06, 0xFF0A, B::OtherNeutral),
+    (0xFF0B, 0xFF0B, B::EuropeanSeparator),
+    (0xFF0C, 0xFF0C, B::CommonSeparator),
+    (0xFF0D, 0xFF0D, B::EuropeanSeparator),
+    (0xFF0E, 0xFF0F, B::CommonSeparator),
+    (0xFF10, 0xFF19, B::EuropeanNumber),
+    (0xFF1A, 0xFF1A, B::CommonSeparator),
+    (0xFF1B, 0xFF20, B::OtherNeutral),
+    (0xFF3B, 0xFF40, B::OtherNeutral),
+    (0xFF5B, 0xFF65, B::OtherNeutral),
+    (0xFFE0, 0xFFE1, B::EuropeanTerminator),
+    (0xFFE2, 0xFFE4, B::OtherNeutral),
+    (0xFFE5, 0xFFE6, B::EuropeanTerminator),
+    (0xFFE8, 0xFFEE, B::OtherNeutral),
+    (0xFFF0, 0xFFF8, B::BoundaryNeutral),
+    (0xFFF9, 0xFFFD, B::OtherNeutral),
+    (0xFFFE, 0xFFFF, B::BoundaryNeutral),
+    (0x10101, 0x10101, B::OtherNeutral),
+    (0x10140, 0x1018C, B::OtherNeutral),
+    (0x10190, 0x1019C, B::OtherNeutral),
+    (0x101A0, 0x101A0, B::OtherNeutral),
+    (0x101FD, 0x101FD, B::NonspacingMark),
+    (0x102E0, 0x102E0, B::NonspacingMark),
+    (0x102E1, 0x102FB, B::EuropeanNumber),
+    (0x10376, 0x1037A, B::NonspacingMark),
+    (0x10800, 0x1091E, B::RightToLeft),
+    (0x1091F, 0x1091F, B::OtherNeutral),
+    (0x10920, 0x10A00, B::RightToLeft),
+    (0x10A01, 0x10A03, B::NonspacingMark),
+    (0x10A04, 0x10A04, B::RightToLeft),
+    (0x10A05, 0x10A06, B::NonspacingMark),
+    (0x10A07, 0x10A0B, B::RightToLeft),
+    (0x10A0C, 0x10A0F, B::NonspacingMark),
+    (0x10A10, 0x10A37, B::RightToLeft),
+    (0x10A38, 0x10A3A, B::NonspacingMark),
+    (0x10A3B, 0x10A3E, B::RightToLeft),
+    (0x10A3F, 0x10A3F, B::NonspacingMark),
+    (0x10A40, 0x10AE4, B::RightToLeft),
+    (0x10AE5, 0x10AE6, B::NonspacingMark),
+    (0x10AE7, 0x10B38, B::RightToLeft),
+    (0x10B39, 0x10B3F, B::OtherNeutral),
+    (0x10B40, 0x10CFF, B::RightToLeft),
+    (0x10D00, 0x10D23, B::ArabicLetter),
+    (0x10D24, 0x10D27, B::NonspacingMark),
+    (0x10D28, 0x10D2F, B::ArabicLetter),
+    (0x10D30, 0x10D39, B::ArabicNumber),
+    (0x10D3A, 0x10D3F, B::ArabicLetter),
+    (0x10D40, 0x10E5F, B::RightToLeft),
+    (0x10E60, 0x10E7E, B::ArabicNumber),
+    (0x10E7F, 0x10EAA, B::RightToLeft),
+    (0x10EAB, 0x10EAC, B::NonspacingMark),
+    (0x10EAD, 0x10F2F, B::RightToLeft),
+    (0x10F30, 0x10F45, B::ArabicLetter),
+    (0x10F46, 0x10F50, B::NonspacingMark),
+    (0x10F51, 0x10F6F, B::ArabicLetter),
+    (0x10F70, 0x10F81, B::RightToLeft),
+    (0x10F82, 0x10F85, B::NonspacingMark),
+    (0x10F86, 0x10FFF, B::RightToLeft),
+    (0x11001, 0x11001, B::NonspacingMark),
+    (0x11038, 0x11046, B::NonspacingMark),
+    (0x11052, 0x11065, B::OtherNeutral),
+    (0x11070, 0x11070, B::NonspacingMark),
+    (0x11073, 0x11074, B::NonspacingMark),
+    (0x1107F, 0x11081, B::NonspacingMark),
+    (0x110B3, 0x110B6, B::NonspacingMark),
+    (0x110B9, 0x110BA, B::NonspacingMark),
+    (0x110C2, 0x110C2, B::NonspacingMark),
+    (0x11100, 0x11102, B::NonspacingMark),
+    (0x11127, 0x1112B, B::NonspacingMark),
+    (0x1112D, 0x11134, B::NonspacingMark),
+    (0x11173, 0x11173, B::NonspacingMark),
+    (0x11180, 0x11181, B::NonspacingMark),
+    (0x111B6, 0x111BE, B::NonspacingMark),
+    (0x111C9, 0x111CC, B::NonspacingMark),
+    (0x111CF, 0x111CF, B::NonspacingMark),
+    (0x1122F, 0x11231, B::NonspacingMark),
+    (0x11234, 0x11234, B::NonspacingMark),
+    (0x11236, 0x11237, B::NonspacingMark),
+    (0x1123E, 0x1123E, B::NonspacingMark),
+    (0x112DF, 0x112DF, B::NonspacingMark),
+    (0x112E3, 0x112EA, B::NonspacingMark),
+    (0x11300, 0x11301, B::NonspacingMark),
+    (0x1133B, 0x1133C, B::NonspacingMark),
+    (0x11340, 0x11340, B::NonspacingMark),
+    (0x11366, 0x1136C, B::NonspacingMark),
+    (0x11370, 0x11374, B::NonspacingMark),
+    (0x11438, 0x1143F, B::NonspacingMark),
+    (0x11442, 0x11444, B::NonspacingMark),
+    (0x11446, 0x11446, B::NonspacingMark),
+    (0x1145E, 0x1145E, B::NonspacingMark),
+    (0x114B3, 0x114B8, B::NonspacingMark),
+    (0x114BA, 0x114BA, B::NonspacingMark),
+    (0x114BF, 0x114C0, B::NonspacingMark),
+    (0x114C2, 0x114C3, B::NonspacingMark),
+    (0x115B2, 0x115B5, B::NonspacingMark),
+    (0x115BC, 0x115BD, B::NonspacingMark),
+    (0x115BF, 0x115C0, B::NonspacingMark),
+    (0x115DC, 0x115DD, B::NonspacingMark),
+    (0x11633, 0x1163A, B::NonspacingMark),
+    (0x1163D, 0x1163D, B::NonspacingMark),
+    (0x1163F, 0x11640, B::NonspacingMark),
+    (0x11660, 0x1166C, B::OtherNeutral),
+    (0x116AB, 0x116AB, B::NonspacingMark),
+    (0x116AD, 0x116AD, B::NonspacingMark),
+    (0x116B0, 0x116B5, B::NonspacingMark),
+    (0x116B7, 0x116B7, B::NonspacingMark),
+    (0x1171D, 0x1171F, B::NonspacingMark),
+    (0x11722, 0x11725, B::NonspacingMark),
+    (0x11727, 0x1172B, B::NonspacingMark),
+    (0x1182F, 0x11837, B::NonspacingMark),
+    (0x11839, 0x1183A, B::NonspacingMark),
+    (0x1193B, 0x1193C, B::NonspacingMark),
+    (0x1193E, 0x1193E, B::NonspacingMark),
+    (0x11943, 0x11943, B::NonspacingMark),
+    (0x119D4, 0x119D7, B::NonspacingMark),
+    (0x119DA, 0x119DB, B::NonspacingMark),
+    (0x119E0, 0x119E0, B::NonspacingMark),
+    (0x11A01, 0x11A06, B::NonspacingMark),
+    (0x11A09, 0x11A0A, B::NonspacingMark),
+    (0x11A33, 0x11A38, B::NonspacingMark),
+    (0x11A3B, 0x11A3E, B::NonspacingMark),
+    (0x11A47, 0x11A47, B::NonspacingMark),
+    (0x11A51, 0x11A56, B::NonspacingMark),
+    (0x11A59, 0x11A5B, B::NonspacingMark),
+    (0x11A8A, 0x11A96, B::NonspacingMark),
+    (0x11A98, 0x11A99, B::NonspacingMark),
+    (0x11C30, 0x11C36, B::NonspacingMark),
+    (0x11C38, 0x11C3D, B::NonspacingMark),
+    (0x11C92, 0x11CA7, B::NonspacingMark),
+    (0x11CAA, 0x11CB0, B::NonspacingMark),
+    (0x11CB2, 0x11CB3, B::NonspacingMark),
+    (0x11CB5, 0x11CB6, B::NonspacingMark),
+    (0x11D31, 0x11D36, B::NonspacingMark),
+    (0x11D3A, 0x11D3A, B::NonspacingMark),
+    (0x11D3C, 0x11D3D, B::NonspacingMark),
+    (0x11D3F, 0x11D45, B::NonspacingMark),
+    (0x11D47, 0x11D47, B::NonspacingMark),
+    (0x11D90, 0x11D91, B::NonspacingMark),
+    (0x11D95, 0x11D95, B::NonspacingMark),
+    (0x11D97, 0x11D97, B::NonspacingMark),
+    (0x11EF3, 0x11EF4, B::NonspacingMark),
+    (0x11FD5, 0x11FDC, B::OtherNeutral),
+    (0x11FDD, 0x11FE0, B::EuropeanTerminator),
+    (0x11FE1, 0x11FF1, B::OtherNeutral),
+    (0x16AF0, 0x16AF4, B::NonspacingMark),
+    (0x16B30, 0x16B36, B::NonspacingMark),
+    (0x16F4F, 0x16F4F, B::NonspacingMark),
+    (0x16F8F, 0x16F92, B::NonspacingMark),
+    (0x16FE2, 0x16FE2, B::OtherNeutral),
+    (0x16FE4, 0x16FE4, B::NonspacingMark),
+    (0x1BC9D, 0x1BC9E, B::NonspacingMark),
+    (0x1BCA0, 0x1BCA3, B::BoundaryNeutral),
+    (0x1CF00, 0x1CF2D, B::NonspacingMark),
+    (0x1CF30, 0x1CF46, B::NonspacingMark),
+    (0x1D167, 0x1D169, B::NonspacingMark),
+    (0x1D173, 0x1D17A, B::BoundaryNeutral),
+    (0x1D17B, 0x1D182, B::NonspacingMark),
+    (0x1D185, 0x1D18B, B::NonspacingMark),
+    (0x1D1AA, 0x1D1AD, B::NonspacingMark),
+    (0x1D1E9, 0x1D1EA, B::OtherNeutral),
+    (0x1D200, 0x1D241, B::OtherNeutral),
+    (0x1D242, 0x1D244, B::NonspacingMark),
+    (0x1D245, 0x1D245, B::OtherNeutral),
+    (0x1D300, 0x1D356, B::OtherNeutral),
+    (0x1D6DB, 0x1D6DB, B::OtherNeutral),
+    (0x1D715, 0x1D715, B::OtherNeutral),
+    (0x1D74F, 0x1D74F, B::OtherNeutral),
+    (0x1D789, 0x1D789, B::OtherNeutral),
+    (0x1D7C3, 0x1D7C3, B::OtherNeutral),
+    (0x1D7CE, 0x1D7FF, B::EuropeanNumber),
+    (0x1DA00, 0x1DA36, B::NonspacingMark),
+    (0x1DA3B, 0x1DA6C, B::NonspacingMark),
+    (0x1DA75, 0x1DA75, B::NonspacingMark),
+    (0x1DA84, 0x1DA84, B::NonspacingMark),
+    (0x1DA9B, 0x1DA9F, B::NonspacingMark),
+    (0x1DAA1, 0x1DAAF, B::NonspacingMark),
+    (0x1E000, 0x1E006, B::NonspacingMark),
+    (0x1E008, 0x1E018, B::NonspacingMark),
+    (0x1E01B, 0x1E021, B::NonspacingMark),
+    (0x1E023, 0x1E024, B::NonspacingMark),
+    (0x1E026, 0x1E02A, B::NonspacingMark),
+    (0x1E130, 0x1E136, B::NonspacingMark),
+    (0x1E2AE, 0x1E2AE, B::NonspacingMark),
+    (0x1E2EC, 0x1E2EF, B::NonspacingMark),
+    (0x1E2FF, 0x1E2FF, B::EuropeanTerminator),
+    (0x1E800, 0x1E8CF, B::RightToLeft),
+    (0x1E8D0, 0x1E8D6, B::NonspacingMark),
+    (0x1E8D7, 0x1E943, B::RightToLeft),
+    (0x1E944, 0x1E94A, B::NonspacingMark),
+    (0x1E94B, 0x1EC6F, B::RightToLeft),
+    (0x1EC70, 0x1ECBF, B::ArabicLetter),
+    (0x1ECC0, 0x1ECFF, B::RightToLeft),
+    (0x1ED00, 0x1ED4F, B::ArabicLetter),
+    (0x1ED50, 0x1EDFF, B::RightToLeft),
+    (0x1EE00, 0x1EEEF, B::ArabicLetter),
+    (0x1EEF0, 0x1EEF1, B::OtherNeutral),
+    (0x1EEF2, 0x1EEFF, B::ArabicLetter),
+    (0x1EF00, 0x1EFFF, B::RightToLeft),
+    (0x1F000, 0x1F02B, B::OtherNeutral),
+    (0x1F030, 0x1F093, B::OtherNeutral),
+    (0x1F0A0, 0x1F0AE, B::OtherNeutral),
+    (0x1F0B1, 0x1F0BF, B::OtherNeutral),
+    (0x1F0C1, 0x1F0CF, B::OtherNeutral),
+    (0x1F0D1, 0x1F0F5, B::OtherNeutral),
+    (0x1F100, 0x1F10A, B::EuropeanNumber),
+    (0x1F10B, 0x1F10F, B::OtherNeutral),
+    (0x1F12F, 0x1F12F, B::OtherNeutral),
+    (0x1F16A, 0x1F16F, B::OtherNeutral),
+    (0x1F1AD, 0x1F1AD, B::OtherNeutral),
+    (0x1F260, 0x1F265, B::OtherNeutral),
+    (0x1F300, 0x1F6D7, B::OtherNeutral),
+    (0x1F6DD, 0x1F6EC, B::OtherNeutral),
+    (0x1F6F0, 0x1F6FC, B::OtherNeutral),
+    (0x1F700, 0x1F773, B::OtherNeutral),
+    (0x1F780, 0x1F7D8, B::OtherNeutral),
+    (0x1F7E0, 0x1F7EB, B::OtherNeutral),
+    (0x1F7F0, 0x1F7F0, B::OtherNeutral),
+    (0x1F800, 0x1F80B, B::OtherNeutral),
+    (0x1F810, 0x1F847, B::OtherNeutral),
+    (0x1F850, 0x1F859, B::OtherNeutral),
+    (0x1F860, 0x1F887, B::OtherNeutral),
+    (0x1F890, 0x1F8AD, B::OtherNeutral),
+    (0x1F8B0, 0x1F8B1, B::OtherNeutral),
+    (0x1F900, 0x1FA53, B::OtherNeutral),
+    (0x1FA60, 0x1FA6D, B::OtherNeutral),
+    (0x1FA70, 0x1FA74, B::OtherNeutral),
+    (0x1FA78, 0x1FA7C, B::OtherNeutral),
+    (0x1FA80, 0x1FA86, B::OtherNeutral),
+    (0x1FA90, 0x1FAAC, B::OtherNeutral),
+    (0x1FAB0, 0x1FABA, B::OtherNeutral),
+    (0x1FAC0, 0x1FAC5, B::OtherNeutral),
+    (0x1FAD0, 0x1FAD9, B::OtherNeutral),
+    (0x1FAE0, 0x1FAE7, B::OtherNeutral),
+    (0x1FAF0, 0x1FAF6, B::OtherNeutral),
+    (0x1FB00, 0x1FB92, B::OtherNeutral),
+    (0x1FB94, 0x1FBCA, B::OtherNeutral),
+    (0x1FBF0, 0x1FBF9, B::EuropeanNumber),
+    (0x1FFFE, 0x1FFFF, B::BoundaryNeutral),
+    (0x2FFFE, 0x2FFFF, B::BoundaryNeutral),
+    (0x3FFFE, 0x3FFFF, B::BoundaryNeutral),
+    (0x4FFFE, 0x4FFFF, B::BoundaryNeutral),
+    (0x5FFFE, 0x5FFFF, B::BoundaryNeutral),
+    (0x6FFFE, 0x6FFFF, B::BoundaryNeutral),
+    (0x7FFFE, 0x7FFFF, B::BoundaryNeutral),
+    (0x8FFFE, 0x8FFFF, B::BoundaryNeutral),
+    (0x9FFFE, 0x9FFFF, B::BoundaryNeutral),
+    (0xAFFFE, 0xAFFFF, B::BoundaryNeutral),
+    (0xBFFFE, 0xBFFFF, B::BoundaryNeutral),
+    (0xCFFFE, 0xCFFFF, B::BoundaryNeutral),
+    (0xDFFFE, 0xE00FF, B::BoundaryNeutral),
+    (0xE0100, 0xE01EF, B::NonspacingMark),
+    (0xE01F0, 0xE0FFF, B::BoundaryNeutral),
+    (0xEFFFE, 0xEFFFF, B::BoundaryNeutral),
+    (0xFFFFE, 0xFFFFF, B::BoundaryNeutral),
+    (0x10FFFE, 0x10FFFF, B::BoundaryNeutral),
+];
+
+#[rustfmt::skip]
+pub(crate) const BIDI_PAIRED_BRACKETS: &[(u32, u32, bool)] = &[
+    (0x0028, 0x0029, true),
+    (0x0029, 0x0028, false),
+    (0x005B, 0x005D, true),
+    (0x005D, 0x005B, false),
+    (0x007B, 0x007D, true),
+    (0x007D, 0x007B, false),
+    (0x0F3A, 0x0F3B, true),
+    (0x0F3B, 0x0F3A, false),
+    (0x0F3C, 0x0F3D, true),
+    (0x0F3D, 0x0F3C, false),
+    (0x169B, 0x169C, true),
+    (0x169C, 0x169B, false),
+    (0x2045, 0x2046, true),
+    (0x2046, 0x2045, false),
+    (0x207D, 0x207E, true),
+    (0x207E, 0x207D, false),
+    (0x208D, 0x208E, true),
+    (0x208E, 0x208D, false),
+    (0x2308, 0x2309, true),
+    (0x2309, 0x2308, false),
+    (0x230A, 0x230B, true),
+    (0x230B, 0x230A, false),
+    (0x2329, 0x232A, true),
+    (0x232A, 0x2329, false),
+    (0x2768, 0x2769, true),
+    (0x2769, 0x2768, false),
+    (0x276A, 0x276B, true),
+    (0x276B, 0x276A, false),
+    (0x276C, 0x276D, true),
+    (0x276D, 0x276C, false),
+    (0x276E, 0x276F, true),
+    (0x276F, 0x276E, false),
+    (0x2770, 0x2771, true),
+    (0x2771, 0x2770, false),
+    (0x2772, 0x2773, true),
+    (0x2773, 0x2772, false),
+    (0x2774, 0x2775, true),
+    (0x2775, 0x2774, false),
+    (0x27C5, 0x27C6, true),
+    (0x27C6, 0x27C5, false),
+    (0x27E6, 0x27E7, true),
+    (0x27E7, 0x27E6, false),
+    (0x27E8, 0x27E9, true),
+    (0x27E9, 0x27E8, false),
+    (0x27EA, 0x27EB, true),
+    (0x27EB, 0x27EA, false),
+    (0x27EC, 0x27ED, true),
+    (0x27ED, 0x27EC, false),
+    (0x27EE, 0x27EF, true),
+    (0x27EF, 0x27EE, false),
+    (0x2983, 0x2984, true),
+    (0x2984, 0x2983, false),
+    (0x2985, 0x2986, true),
+    (0x2986, 0x2985, false),
+    (0x2987, 0x2988, true),
+    (0x2988, 0x2987, false),
+    (0x2989, 0x298A, true),
+    (0x298A, 0x2989, false),
+    (0x298B, 0x298C, true),
+    (0x298C, 0x298B, false),
+    (0x298D, 0x2990, true),
+    (0x298E, 0x298F, false),
+    (0x298F, 0x298E, true),
+    (0x2990, 0x298D, false),
+    (0x2991, 0x2992, true),
+    (0x2992, 0x2991, false),
+    (0x2993, 0x2994, true),
+    (0x2994, 0x2993, false),
+    (0x2995, 0x2996, true),
+    (0x2996, 0x2995, false),
+    (0x2997, 0x2998, true),
+    (0x2998, 0x2997, false),
+    (0x29D8, 0x29D9, true),
+    (0x29D9, 0x29D8, false),
+    (0x29DA, 0x29DB, true),
+    (0x29DB, 0x29DA, false),
+    (0x29FC, 0x29FD, true),
+    (0x29FD, 0x29FC, false),
+    (0x2E22, 0x2E23, true),
+    (0x2E23, 0x2E22, false),
+    (0x2E24, 0x2E25, true),
+    (0x2E25, 0x2E24, false),
+    (0x2E26, 0x2E27, true),
+    (0x2E27, 0x2E26, false),
+    (0x2E28, 0x2E29, true),
+    (0x2E29, 0x2E28, false),
+    (0x2E55, 0x2E56, true),
+    (0x2E56, 0x2E55, false),
+    (0x2E57, 0x2E58, true),
+    (0x2E58, 0x2E57, false),
+    (0x2E59, 0x2E5A, true),
+    (0x2E5A, 0x2E59, false),
+    (0x2E5B, 0x2E5C, true),
+    (0x2E5C, 0x2E5B, false),
+    (0x3008, 0x3009, true),
+    (0x3009, 0x3008, false),
+    (0x300A, 0x300B, true),
+    (0x300B, 0x300A, false),
+    (0x300C, 0x300D, true),
+    (0x300D, 0x300C, false),
+    (0x300E, 0x300F, true),
+    (0x300F, 0x300E, false),
+    (0x3010, 0x3011, true),
+    (0x3011, 0x3010, false),
+    (0x3014, 0x3015, true),
+    (0x3015, 0x3014, false),
+    (0x3016, 0x3017, true),
+    (0x3017, 0x3016, false),
+    (0x3018, 0x3019, true),
+    (0x3019, 0x3018, false),
+    (0x301A, 0x301B, true),
+    (0x301B, 0x301A, false),
+    (0xFE59, 0xFE5A, true),
+    (0xFE5A, 0xFE59, false),
+    (0xFE5B, 0xFE5C, true),
+    (0xFE5C, 0xFE5B, false),
+    (0xFE5D, 0xFE5E, true),
+    (0xFE5E, 0xFE5D, false),
+    (0xFF08, 0xFF09, true),
+    (0xFF09, 0xFF08, false),
+    (0xFF3B, 0xFF3D, true),
+    (0xFF3D, 0xFF3B, false),
+    (0xFF5B, 0xFF5D, true),
+    (0xFF5D, 0xFF5B, false),
+    (0xFF5F, 0xFF60, true),
+    (0xFF60, 0xFF5F, false),
+    (0xFF62, 0xFF63, true),
+    (0xFF63, 0xFF62, false),
+];
+
+#[rustfmt::skip]
+pub(crate) const BIDI_MIRRORING_GLYPH: &[(u32, u32)] = &[
+    (0x0028, 0x0029),
+    (0x0029, 0x0028),
+    (0x003C, 0x003E),
+    (0x003E, 0x003C),
+    (0x005B, 0x005D),
+    (0x005D, 0x005B),
+    (0x007B, 0x007D),
+    (0x007D, 0x007B),
+    (0x00AB, 0x00BB),
+    (0x00BB, 0x00AB),
+    (0x0F3A, 0x0F3B),
+    (0x0F3B, 0x0F3A),
+    (0x0F3C, 0x0F3D),
+    (0x0F3D, 0x0F3C),
+    (0x169B, 0x169C),
+    (0x169C, 0x169B),
+    (0x2039, 0x203A),
+    (0x203A, 0x2039),
+    (0x2045, 0x2046),
+    (0x2046, 0x2045),
+    (0x207D, 0x207E),
+    (0x207E, 0x207D),
+    (0x208D, 0x208E),
+    (0x208E, 0x208D),
+    (0x2208, 0x220B),
+    (0x2209, 0x220C),
+    (0x220A, 0x220D),
+    (0x220B, 0x2208),
+    (0x220C, 0x2209),
+    (0x220D, 0x220A),
+    (0x2215, 0x29F5),
+    (0x221F, 0x2BFE),
+    (0x2220, 0x29A3),
+    (0x2221, 0x299B),
+    (0x2222, 0x29A0),
+    (0x2224, 0x2AEE),
+    (0x223C, 0x223D),
+    (0x223D, 0x223C),
+    (0x2243, 0x22CD),
+    (0x2245, 0x224C),
+    (0x224C, 0x2245),
+    (0x2252, 0x2253),
+    (0x2253, 0x2252),
+    (0x2254, 0x2255),
+    (0x2255, 0x2254),
+    (0x2264, 0x2265),
+    (0x2265, 0x2264),
+    (0x2266, 0x2267),
+    (0x2267, 0x2266),
+    (0x2268, 0x2269),
+    (0x2269, 0x2268),
+    (0x226A, 0x226B),
+    (0x226B, 0x226A),
+    (0x226E, 0x226F),
+    (0x226F, 0x226E),
+    (0x2270, 0x2271),
+    (0x2271, 0x2270),
+    (0x2272, 0x2273),
+    (0x2273, 0x2272),
+    (0x2274, 0x2275),
+    (0x2275, 0x2274),
+    (0x2276, 0x2277),
+    (0x2277, 0x2276),
+    (0x2278, 0x2279),
+    (0x2279, 0x2278),
+    (0x227A, 0x227B),
+    (0x227B, 0x227A),
+    (0x227C, 0x227D),
+    (0x227D, 0x227C),
+    (0x227E, 0x227F),
+    (0x227F, 0x227E),
+    (0x2280, 0x2281),
+    (0x2281, 0x2280),
+    (0x2282, 0x2283),
+    (0x2283, 0x2282),
+    (0x2284, 0x2285),
+    (0x2285, 0x2284),
+    (0x2286, 0x2287),
+    (0x2287, 0x2286),
+    (0x2288, 0x2289),
+    (0x2289, 0x2288),
+    (0x228A, 0x228B),
+    (0x228B, 0x228A),
+    (0x228F, 0x2290),
+    (0x2290, 0x228F),
+    (0x2291, 0x2292),
+    (0x2292, 0x2291),
+    (0x2298, 0x29B8),
+    (0x22A2, 0x22A3),
+    (0x22A3, 0x22A2),
+    (0x22A6, 0x2ADE),
+    (0x22A8, 0x2AE4),
+    (0x22A9, 0x2AE3),
+    (0x22AB, 0x2AE5),
+    (0x22B0, 0x22B1),
+    (0x22B1, 0x22B0),
+    (0x22B2, 0x22B3),
+    (0x22B3, 0x22B2),
+    (0x22B4, 0x22B5),
+    (0x22B5, 0x22B4),
+    (0x22B6, 0x22B7),
+    (0x22B7, 0x22B6),
+    (0x22B8, 0x27DC),
+    (0x22C9, 0x22CA),
+    (0x22CA, 0x22C9),
+    (0x22CB, 0x22CC),
+    (0x22CC, 0x22CB),
+    (0x22CD, 0x2243),
+    (0x22D0, 0x22D1),
+    (0x22D1, 0x22D0),
+    (0x22D6, 0x22D7),
+    (0x22D7, 0x22D6),
+    (0x22D8, 0x22D9),
+    (0x22D9, 0x22D8),
+    (0x22DA, 0x22DB),
+    (0x22DB, 0x22DA),
+    (0x22DC, 0x22DD),
+    (0x22DD, 0x22DC),
+    (0x22DE, 0x22DF),
+    (0x22DF, 0x22DE),
+    (0x22E0, 0x22E1),
+    (0x22E1, 0x22E0),
+    (0x22E2, 0x22E3),
+    (0x22E3, 0x22E2),
+    (0x22E4, 0x22E5),
+    (0x22E5, 0x22E4),
+    (0x22E6, 0x22E7),
+    (0x22E7, 0x22E6),
+    (0x22E8, 0x22E9),
+    (0x22E9, 0x22E8),
+    (0x22EA, 0x22EB),
+    (0x22EB, 0x22EA),
+    (0x22EC, 0x22ED),
+    (0x22ED, 0x22EC),
+    (0x22F0, 0x22F1),
+    (0x22F1, 0x22F0),
+    (0x22F2, 0x22FA),
+    (0x22F3, 0x22FB),
+    (0x22F4, 0x22FC),
+    (0x22F6, 0x22FD),
+    (0x22F7, 0x22FE),
+    (0x22FA, 0x22F2),
+    (0x22FB, 0x22F3),
+    (0x22FC, 0x22F4),
+    (0x22FD, 0x22F6),
+    (0x22FE, 0x22F7),
+    (0x2308, 0x2309),
+    (0x2309, 0x2308),
+    (0x230A, 0x230B),
+    (0x230B, 0x230A),
+    (0x2329, 0x232A),
+    (0x232A, 0x2329),
+    (0x2768, 0x2769),
+    (0x2769, 0x2768),
+    (0x276A, 0x276B),
+    (0x276B, 0x276A),
+    (0x276C, 0x276D),
+    (0x276D, 0x276C),
+    (0x276E, 0x276F),
+    (0x276F, 0x276E),
+    (0x2770, 0x2771),
+    (0x2771, 0x2770),
+    (0x2772, 0x2773),
+    (0x2773, 0x2772),
+    (0x2774, 0x2775),
+    (0x2775, 0x2774),
+    (0x27C3, 0x27C4),
+    (0x27C4, 0x27C3),
+    (0x27C5, 0x27C6),
+    (0x27C6, 0x27C5),
+    (0x27C8, 0x27C9),
+    (0x27C9, 0x27C8),
+    (0x27CB, 0x27CD),
+    (0x27CD, 0x27CB),
+    (0x27D5, 0x27D6),
+    (0x27D6, 0x27D5),
+    (0x27DC, 0x22B8),
+    (0x27DD, 0x27DE),
+    (0x27DE, 0x27DD),
+    (0x27E2, 0x27E3),
+    (0x27E3, 0x27E2),
+    (0x27E4, 0x27E5),
+    (0x27E5, 0x27E4),
+    (0x27E6, 0x27E7),
+    (0x27E7, 0x27E6),
+    (0x27E8, 0x27E9),
+    (0x27E9, 0x27E8),
+    (0x27EA, 0x27EB),
+    (0x27EB, 0x27EA),
+    (0x27EC, 0x27ED),
+    (0x27ED, 0x27EC),
+    (0x27EE, 0x27EF),
+    (0x27EF, 0x27EE),
+    (0x2983, 0x2984),
+    (0x2984, 0x2983),
+    (0x2985, 0x2986),
+    (0x2986, 0x2985),
+    (0x2987, 0x2988),
+    (0x2988, 0x2987),
+    (0x2989, 0x298A),
+    (0x298A, 0x2989),
+    (0x298B, 0x298C),
+    (0x298C, 0x298B),
+    (0x298D, 0x2990),
+    (0x298E, 0x298F),
+    (0x298F, 0x298E),
+    (0x2990, 0x298D),
+    (0x2991, 0x2992),
+    (0x2992, 0x2991),
+    (0x2993, 0x2994),
+    (0x2994, 0x2993),
+    (0x2995, 0x2996),
+    (0x2996, 0x2995),
+    (0x2997, 0x2998),
+    (0x2998, 0x2997),
+    (0x299B, 0x2221),
+    (0x29A0, 0x2222),
+    (0x29A3, 0x2220),
+    (0x29A4, 0x29A5),
+    (0x29A5, 0x29A4),
+    (0x29A8, 0x29A9),
+    (0x29A9, 0x29A8),
+    (0x29AA, 0x29AB),
+    (0x29AB, 0x29AA),
+    (0x29AC, 0x29AD),
+    (0x29AD, 0x29AC),
+    (0x29AE, 0x29AF),
+    (0x29AF, 0x29AE),
+    (0x29B8, 0x2298),
+    (0x29C0, 0x29C1),
+    (0x29C1, 0x29C0),
+    (0x29C4, 0x29C5),
+    (0x29C5, 0x29C4),
+    (0x29CF, 0x29D0),
+    (0x29D0, 0x29CF),
+    (0x29D1, 0x29D2),
+    (0x29D2, 0x29D1),
+    (0x29D4, 0x29D5),
+    (0x29D5, 0x29D4),
+    (0x29D8, 0x29D9),
+    (0x29D9, 0x29D8),
+    (0x29DA, 0x29DB),
+    (0x29DB, 0x29DA),
+    (0x29E8, 0x29E9),
+    (0x29E9, 0x29E8),
+    (0x29F5, 0x2215),
+    (0x29F8, 0x29F9),
+    (0x29F9, 0x29F8),
+    (0x29FC, 0x29FD),
+    (0x29FD, 0x29FC),
+    (0x2A2B, 0x2A2C),
+    (0x2A2C, 0x2A2B),
+    (0x2A2D, 0x2A2E),
+    (0x2A2E, 0x2A2D),
+    (0x2A34, 0x2A35),
+    (0x2A35, 0x2A34),
+    (0x2A3C, 0x2A3D),
+    (0x2A3D, 0x2A3C),
+    (0x2A64, 0x2A65),
+    (0x2A65, 0x2A64),
+    (0x2A79, 0x2A7A),
+    (0x2A7A, 0x2A79),
+    (0x2A7B, 0x2A7C),
+    (0x2A7C, 0x2A7B),
+    (0x2A7D, 0x2A7E),
+    (0x2A7E, 0x2A7D),
+    (0x2A7F, 0x2A80),
+    (0x2A80, 0x2A7F),
+    (0x2A81, 0x2A82),
+    (0x2A82, 0x2A81),
+    (0x2A83, 0x2A84),
+    (0x2A84, 0x2A83),
+    (0x2A85, 0x2A86),
+    (0x2A86, 0x2A85),
+    (0x2A87, 0x2A88),
+    (0x2A88, 0x2A87),
+    (0x2A89, 0x2A8A),
+    (0x2A8A, 0x2A89),
+    (0x2A8B, 0x2A8C),
+    (0x2A8C, 0x2A8B),
+    (0x2A8D, 0x2A8E),
+    (0x2A8E, 0x2A8D),
+    (0x2A8F, 0x2A90),
+    (0x2A90, 0x2A8F),
+    (0x2A91, 0x2A92),
+    (0x2A92, 0x2A91),
+    (0x2A93, 0x2A94),
+    (0x2A94, 0x2A93),
+    (0x2A95, 0x2A96),
+    (0x2A96, 0x2A95),
+    (0x2A97, 0x2A98),
+    (0x2A98, 0x2A97),
+    (0x2A99, 0x2A9A),
+    (0x2A9A, 0x2A99),
+    (0x2A9B, 0x2A9C),
+    (0x2A9C, 0x2A9B),
+    (0x2A9D, 0x2A9E),
+    (0x2A9E, 0x2A9D),
+    (0x2A9F, 0x2AA0),
+    (0x2AA0, 0x2A9F),
+    (0x2AA1, 0x2AA2),
+    (0x2AA2, 0x2AA1),
+    (0x2AA6, 0x2AA7),
+    (0x2AA7, 0x2AA6),
+    (0x2AA8, 0x2AA9),
+    (0x2AA9, 0x2AA8),
+    (0x2AAA, 0x2AAB),
+    (0x2AAB, 0x2AAA),
+    (0x2AAC, 0x2AAD),
+    (0x2AAD, 0x2AAC),
+    (0x2AAF, 0x2AB0),
+    (0x2AB0, 0x2AAF),
+    (0x2AB1, 0x2AB2),
+    (0x2AB2, 0x2AB1),
+    (0x2AB3, 0x2AB4),
+    (0x2AB4, 0x2AB3),
+    (0x2AB5, 0x2AB6),
+    (0x2AB6, 0x2AB5),
+    (0x2AB7, 0x2AB8),
+    (0x2AB8, 0x2AB7),
+    (0x2AB9, 0x2ABA),
+    (0x2ABA, 0x2AB9),
+    (0x2ABB, 0x2ABC),
+    (0x2ABC, 0x2ABB),
+    (0x2ABD, 0x2ABE),
+    (0x2ABE, 0x2ABD),
+    (0x2ABF, 0x2AC0),
+    (0x2AC0, 0x2ABF),
+    (0x2AC1, 0x2AC2),
+    (0x2AC2, 0x2AC1),
+    (0x2AC3, 0x2AC4),
+    (0x2AC4, 0x2AC3),
+    (0x2AC5, 0x2AC6),
+    (0x2AC6, 0x2AC5),
+    (0x2AC7, 0x2AC8),
+    (0x2AC8, 0x2AC7),
+    (0x2AC9, 0x2ACA),
+    (0x2ACA, 0x2AC9),
+    (0x2ACB, 0x2ACC),
+    (0x2ACC, 0x2ACB),
+    (0x2ACD, 0x2ACE),
+    (0x2ACE, 0x2ACD),
+    (0x2ACF, 0x2AD0),
+    (0x2AD0, 0x2ACF),
+    (0x2AD1, 0x2AD2),
+    (0x2AD2, 0x2AD1),
+    (0x2AD3, 0x2AD4),
+    (0x2AD4, 0x2AD3),
+    (0x2AD5, 0x2AD6),
+    (0x2AD6, 0x2AD5),
+    (0x2ADE, 0x22A6),
+    (0x2AE3, 0x22A9),
+    (0x2AE4, 0x22A8),
+    (0x2AE5, 0x22AB),
+    (0x2AEC, 0x2AED),
+    (0x2AED, 0x2AEC),
+    (0x2AEE, 0x2224),
+    (0x2AF7, 0x2AF8),
+    (0x2AF8, 0x2AF7),
+    (0x2AF9, 0x2AFA),
+    (0x2AFA, 0x2AF9),
+    (0x2BFE, 0x221F),
+    (0x2E02, 0x2E03),
+    (0x2E03, 0x2E02),
+    (0x2E04, 0x2E05),
+    (0x2E05, 0x2E04),
+    (0x2E09, 0x2E0A),
+    (0x2E0A, 0x2E09),
+    (0x2E0C, 0x2E0D),
+    (0x2E0D, 0x2E0C),
+    (0x2E1C, 0x2E1D),
+    (0x2E1D, 0x2E1C),
+    (0x2E20, 0x2E21),
+    (0x2E21, 0x2E20),
+    (0x2E22, 0x2E23),
+    (0x2E23, 0x2E22),
+    (0x2E24, 0x2E25),
+    (0x2E25, 0x2E24),
+    (0x2E26, 0x2E27),
+    (0x2E27, 0x2E26),
+    (0x2E28, 0x2E29),
+    (0x2E29, 0x2E28),
+    (0x2E55, 0x2E56),
+    (0x2E56, 0x2E55),
+    (0x2E57, 0x2E58),
+    (0x2E58, 0x2E57),
+    (0x2E59, 0x2E5A),
+    (0x2E5A, 0x2E59),
+    (0x2E5B, 0x2E5C),
+    (0x2E5C, 0x2E5B),
+    (0x3008, 0x3009),
+    (0x3009, 0x3008),
+    (0x300A, 0x300B),
+    (0x300B, 0x300A),
+    (0x300C, 0x300D),
+    (0x300D, 0x300C),
+    (0x300E, 0x300F),
+    (0x300F, 0x300E),
+    (0x3010, 0x3011),
+    (0x3011, 0x3010),
+    (0x3014, 0x3015),
+    (0x3015, 0x3014),
+    (0x3016, 0x3017),
+    (0x3017, 0x3016),
+    (0x3018, 0x3019),
+    (0x3019, 0x3018),
+    (0x301A, 0x301B),
+    (0x301B, 0x301A),
+    (0xFE59, 0xFE5A),
+    (0xFE5A, 0xFE59),
+    (0xFE5B, 0xFE5C),
+    (0xFE5C, 0xFE5B),
+    (0xFE5D, 0xFE5E),
+    (0xFE5E, 0xFE5D),
+    (0xFE64, 0xFE65),
+    (0xFE65, 0xFE64),
+    (0xFF08, 0xFF09),
+    (0xFF09, 0xFF08),
+    (0xFF1C, 0xFF1E),
+    (0xFF1E, 0xFF1C),
+    (0xFF3B, 0xFF3D),
+    (0xFF3D, 0xFF3B),
+    (0xFF5B, 0xFF5D),
+    (0xFF5D, 0xFF5B),
+    (0xFF5F, 0xFF60),
+    (0xFF60, 0xFF5F),
+    (0xFF62, 0xFF63),
+    (0xFF63, 0xFF62),
+];
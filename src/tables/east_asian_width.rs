@@ -0,0 +1,322 @@
+// Generated from the Unicode Character Database 14.0.0 (EastAsianWidth.txt). Do not edit.
+
+use crate::unicode::EastAsianWidth as E;
+
+#[rustfmt::skip]
+pub(crate) const EAST_ASIAN_WIDTH: &[(u32, u32, E)] = &[
+    (0x0020, 0x007E, E::Narrow),
+    (0x00A1, 0x00A1, E::Ambiguous),
+    (0x00A2, 0x00A3, E::Narrow),
+    (0x00A4, 0x00A4, E::Ambiguous),
+    (0x00A5, 0x00A6, E::Narrow),
+    (0x00A7, 0x00A8, E::Ambiguous),
+    (0x00AA, 0x00AA, E::Ambiguous),
+    (0x00AC, 0x00AC, E::Narrow),
+    (0x00AD, 0x00AE, E::Ambiguous),
+    (0x00AF, 0x00AF, E::Narrow),
+    (0x00B0, 0x00B4, E::Ambiguous),
+    (0x00B6, 0x00BA, E::Ambiguous),
+    (0x00BC, 0x00BF, E::Ambiguous),
+    (0x00C6, 0x00C6, E::Ambiguous),
+    (0x00D0, 0x00D0, E::Ambiguous),
+    (0x00D7, 0x00D8, E::Ambiguous),
+    (0x00DE, 0x00E1, E::Ambiguous),
+    (0x00E6, 0x00E6, E::Ambiguous),
+    (0x00E8, 0x00EA, E::Ambiguous),
+    (0x00EC, 0x00ED, E::Ambiguous),
+    (0x00F0, 0x00F0, E::Ambiguous),
+    (0x00F2, 0x00F3, E::Ambiguous),
+    (0x00F7, 0x00FA, E::Ambiguous),
+    (0x00FC, 0x00FC, E::Ambiguous),
+    (0x00FE, 0x00FE, E::Ambiguous),
+    (0x0101, 0x0101, E::Ambiguous),
+    (0x0111, 0x0111, E::Ambiguous),
+    (0x0113, 0x0113, E::Ambiguous),
+    (0x011B, 0x011B, E::Ambiguous),
+    (0x0126, 0x0127, E::Ambiguous),
+    (0x012B, 0x012B, E::Ambiguous),
+    (0x0131, 0x0133, E::Ambiguous),
+    (0x0138, 0x0138, E::Ambiguous),
+    (0x013F, 0x0142, E::Ambiguous),
+    (0x0144, 0x0144, E::Ambiguous),
+    (0x0148, 0x014B, E::Ambiguous),
+    (0x014D, 0x014D, E::Ambiguous),
+    (0x0152, 0x0153, E::Ambiguous),
+    (0x0166, 0x0167, E::Ambiguous),
+    (0x016B, 0x016B, E::Ambiguous),
+    (0x01CE, 0x01CE, E::Ambiguous),
+    (0x01D0, 0x01D0, E::Ambiguous),
+    (0x01D2, 0x01D2, E::Ambiguous),
+    (0x01D4, 0x01D4, E::Ambiguous),
+    (0x01D6, 0x01D6, E::Ambiguous),
+    (0x01D8, 0x01D8, E::Ambiguous),
+    (0x01DA, 0x01DA, E::Ambiguous),
+    (0x01DC, 0x01DC, E::Ambiguous),
+    (0x0251, 0x0251, E::Ambiguous),
+    (0x0261, 0x0261, E::Ambiguous),
+    (0x02C4, 0x02C4, E::Ambiguous),
+    (0x02C7, 0x02C7, E::Ambiguous),
+    (0x02C9, 0x02CB, E::Ambiguous),
+    (0x02CD, 0x02CD, E::Ambiguous),
+    (0x02D0, 0x02D0, E::Ambiguous),
+    (0x02D8, 0x02DB, E::Ambiguous),
+    (0x02DD, 0x02DD, E::Ambiguous),
+    (0x02DF, 0x02DF, E::Ambiguous),
+    (0x0300, 0x036F, E::Ambiguous),
+    (0x0391, 0x03A1, E::Ambiguous),
+    (0x03A3, 0x03A9, E::Ambiguous),
+    (0x03B1, 0x03C1, E::Ambiguous),
+    (0x03C3, 0x03C9, E::Ambiguous),
+    (0x0401, 0x0401, E::Ambiguous),
+    (0x0410, 0x044F, E::Ambiguous),
+    (0x0451, 0x0451, E::Ambiguous),
+    (0x1100, 0x115F, E::Wide),
+    (0x2010, 0x2010, E::Ambiguous),
+    (0x2013, 0x2016, E::Ambiguous),
+    (0x2018, 0x2019, E::Ambiguous),
+    (0x201C, 0x201D, E::Ambiguous),
+    (0x2020, 0x2022, E::Ambiguous),
+    (0x2024, 0x2027, E::Ambiguous),
+    (0x2030, 0x2030, E::Ambiguous),
+    (0x2032, 0x2033, E::Ambiguous),
+    (0x2035, 0x2035, E::Ambiguous),
+    (0x203B, 0x203B, E::Ambiguous),
+    (0x203E, 0x203E, E::Ambiguous),
+    (0x2074, 0x2074, E::Ambiguous),
+    (0x207F, 0x207F, E::Ambiguous),
+    (0x2081, 0x2084, E::Ambiguous),
+    (0x20A9, 0x20A9, E::Halfwidth),
+    (0x20AC, 0x20AC, E::Ambiguous),
+    (0x2103, 0x2103, E::Ambiguous),
+    (0x2105, 0x2105, E::Ambiguous),
+    (0x2109, 0x2109, E::Ambiguous),
+    (0x2113, 0x2113, E::Ambiguous),
+    (0x2116, 0x2116, E::Ambiguous),
+    (0x2121, 0x2122, E::Ambiguous),
+    (0x2126, 0x2126, E::Ambiguous),
+    (0x212B, 0x212B, E::Ambiguous),
+    (0x2153, 0x2154, E::Ambiguous),
+    (0x215B, 0x215E, E::Ambiguous),
+    (0x2160, 0x216B, E::Ambiguous),
+    (0x2170, 0x2179, E::Ambiguous),
+    (0x2189, 0x2189, E::Ambiguous),
+    (0x2190, 0x2199, E::Ambiguous),
+    (0x21B8, 0x21B9, E::Ambiguous),
+    (0x21D2, 0x21D2, E::Ambiguous),
+    (0x21D4, 0x21D4, E::Ambiguous),
+    (0x21E7, 0x21E7, E::Ambiguous),
+    (0x2200, 0x2200, E::Ambiguous),
+    (0x2202, 0x2203, E::Ambiguous),
+    (0x2207, 0x2208, E::Ambiguous),
+    (0x220B, 0x220B, E::Ambiguous),
+    (0x220F, 0x220F, E::Ambiguous),
+    (0x2211, 0x2211, E::Ambiguous),
+    (0x2215, 0x2215, E::Ambiguous),
+    (0x221A, 0x221A, E::Ambiguous),
+    (0x221D, 0x2220, E::Ambiguous),
+    (0x2223, 0x2223, E::Ambiguous),
+    (0x2225, 0x2225, E::Ambiguous),
+    (0x2227, 0x222C, E::Ambiguous),
+    (0x222E, 0x222E, E::Ambiguous),
+    (0x2234, 0x2237, E::Ambiguous),
+    (0x223C, 0x223D, E::Ambiguous),
+    (0x2248, 0x2248, E::Ambiguous),
+    (0x224C, 0x224C, E::Ambiguous),
+    (0x2252, 0x2252, E::Ambiguous),
+    (0x2260, 0x2261, E::Ambiguous),
+    (0x2264, 0x2267, E::Ambiguous),
+    (0x226A, 0x226B, E::Ambiguous),
+    (0x226E, 0x226F, E::Ambiguous),
+    (0x2282, 0x2283, E::Ambiguous),
+    (0x2286, 0x2287, E::Ambiguous),
+    (0x2295, 0x2295, E::Ambiguous),
+    (0x2299, 0x2299, E::Ambiguous),
+    (0x22A5, 0x22A5, E::Ambiguous),
+    (0x22BF, 0x22BF, E::Ambiguous),
+    (0x2312, 0x2312, E::Ambiguous),
+    (0x231A, 0x231B, E::Wide),
+    (0x2329, 0x232A, E::Wide),
+    (0x23E9, 0x23EC, E::Wide),
+    (0x23F0, 0x23F0, E::Wide),
+    (0x23F3, 0x23F3, E::Wide),
+    (0x2460, 0x24E9, E::Ambiguous),
+    (0x24EB, 0x254B, E::Ambiguous),
+    (0x2550, 0x2573, E::Ambiguous),
+    (0x2580, 0x258F, E::Ambiguous),
+    (0x2592, 0x2595, E::Ambiguous),
+    (0x25A0, 0x25A1, E::Ambiguous),
+    (0x25A3, 0x25A9, E::Ambiguous),
+    (0x25B2, 0x25B3, E::Ambiguous),
+    (0x25B6, 0x25B7, E::Ambiguous),
+    (0x25BC, 0x25BD, E::Ambiguous),
+    (0x25C0, 0x25C1, E::Ambiguous),
+    (0x25C6, 0x25C8, E::Ambiguous),
+    (0x25CB, 0x25CB, E::Ambiguous),
+    (0x25CE, 0x25D1, E::Ambiguous),
+    (0x25E2, 0x25E5, E::Ambiguous),
+    (0x25EF, 0x25EF, E::Ambiguous),
+    (0x25FD, 0x25FE, E::Wide),
+    (0x2605, 0x2606, E::Ambiguous),
+    (0x2609, 0x2609, E::Ambiguous),
+    (0x260E, 0x260F, E::Ambiguous),
+    (0x2614, 0x2615, E::Wide),
+    (0x261C, 0x261C, E::Ambiguous),
+    (0x261E, 0x261E, E::Ambiguous),
+    (0x2640, 0x2640, E::Ambiguous),
+    (0x2642, 0x2642, E::Ambiguous),
+    (0x2648, 0x2653, E::Wide),
+    (0x2660, 0x2661, E::Ambiguous),
+    (0x2663, 0x2665, E::Ambiguous),
+    (0x2667, 0x266A, E::Ambiguous),
+    (0x266C, 0x266D, E::Ambiguous),
+    (0x266F, 0x266F, E::Ambiguous),
+    (0x267F, 0x267F, E::Wide),
+    (0x2693, 0x2693, E::Wide),
+    (0x269E, 0x269F, E::Ambiguous),
+    (0x26A1, 0x26A1, E::Wide),
+    (0x26AA, 0x26AB, E::Wide),
+    (0x26BD, 0x26BE, E::Wide),
+    (0x26BF, 0x26BF, E::Ambiguous),
+    (0x26C4, 0x26C5, E::Wide),
+    (0x26C6, 0x26CD, E::Ambiguous),
+    (0x26CE, 0x26CE, E::Wide),
+    (0x26CF, 0x26D3, E::Ambiguous),
+    (0x26D4, 0x26D4, E::Wide),
+    (0x26D5, 0x26E1, E::Ambiguous),
+    (0x26E3, 0x26E3, E::Ambiguous),
+    (0x26E8, 0x26E9, E::Ambiguous),
+    (0x26EA, 0x26EA, E::Wide),
+    (0x26EB, 0x26F1, E::Ambiguous),
+    (0x26F2, 0x26F3, E::Wide),
+    (0x26F4, 0x26F4, E::Ambiguous),
+    (0x26F5, 0x26F5, E::Wide),
+    (0x26F6, 0x26F9, E::Ambiguous),
+    (0x26FA, 0x26FA, E::Wide),
+    (0x26FB, 0x26FC, E::Ambiguous),
+    (0x26FD, 0x26FD, E::Wide),
+    (0x26FE, 0x26FF, E::Ambiguous),
+    (0x2705, 0x2705, E::Wide),
+    (0x270A, 0x270B, E::Wide),
+    (0x2728, 0x2728, E::Wide),
+    (0x273D, 0x273D, E::Ambiguous),
+    (0x274C, 0x274C, E::Wide),
+    (0x274E, 0x274E, E::Wide),
+    (0x2753, 0x2755, E::Wide),
+    (0x2757, 0x2757, E::Wide),
+    (0x2776, 0x277F, E::Ambiguous),
+    (0x2795, 0x2797, E::Wide),
+    (0x27B0, 0x27B0, E::Wide),
+    (0x27BF, 0x27BF, E::Wide),
+    (0x27E6, 0x27ED, E::Narrow),
+    (0x2985, 0x2986, E::Narrow),
+    (0x2B1B, 0x2B1C, E::Wide),
+    (0x2B50, 0x2B50, E::Wide),
+    (0x2B55, 0x2B55, E::Wide),
+    (0x2B56, 0x2B59, E::Ambiguous),
+    (0x2E80, 0x2E99, E::Wide),
+    (0x2E9B, 0x2EF3, E::Wide),
+    (0x2F00, 0x2FD5, E::Wide),
+    (0x2FF0, 0x2FFB, E::Wide),
+    (0x3000, 0x3000, E::Fullwidth),
+    (0x3001, 0x303E, E::Wide),
+    (0x3041, 0x3096, E::Wide),
+    (0x3099, 0x30FF, E::Wide),
+    (0x3105, 0x312F, E::Wide),
+    (0x3131, 0x318E, E::Wide),
+    (0x3190, 0x31E3, E::Wide),
+    (0x31F0, 0x321E, E::Wide),
+    (0x3220, 0x3247, E::Wide),
+    (0x3248, 0x324F, E::Ambiguous),
+    (0x3250, 0x4DBF, E::Wide),
+    (0x4E00, 0xA48C, E::Wide),
+    (0xA490, 0xA4C6, E::Wide),
+    (0xA960, 0xA97C, E::Wide),
+    (0xAC00, 0xD7A3, E::Wide),
+    (0xE000, 0xF8FF, E::Ambiguous),
+    (0xF900, 0xFAFF, E::Wide),
+    (0xFE00, 0xFE0F, E::Ambiguous),
+    (0xFE10, 0xFE19, E::Wide),
+    (0xFE30, 0xFE52, E::Wide),
+    (0xFE54, 0xFE66, E::Wide),
+    (0xFE68, 0xFE6B, E::Wide),
+    (0xFF01, 0xFF60, E::Fullwidth),
+    (0xFF61, 0xFFBE, E::Halfwidth),
+    (0xFFC2, 0xFFC7, E::Halfwidth),
+    (0xFFCA, 0xFFCF, E::Halfwidth),
+    (0xFFD2, 0xFFD7, E::Halfwidth),
+    (0xFFDA, 0xFFDC, E::Halfwidth),
+    (0xFFE0, 0xFFE6, E::Fullwidth),
+    (0xFFE8, 0xFFEE, E::Halfwidth),
+    (0xFFFD, 0xFFFD, E::Ambiguous),
+    (0x16FE0, 0x16FE4, E::Wide),
+    (0x16FF0, 0x16FF1, E::Wide),
+    (0x17000, 0x187F7, E::Wide),
+    (0x18800, 0x18CD5, E::Wide),
+    (0x18D00, 0x18D08, E::Wide),
+    (0x1AFF0, 0x1AFF3, E::Wide),
+    (0x1AFF5, 0x1AFFB, E::Wide),
+    (0x1AFFD, 0x1AFFE, E::Wide),
+    (0x1B000, 0x1B122, E::Wide),
+    (0x1B150, 0x1B152, E::Wide),
+    (0x1B164, 0x1B167, E::Wide),
+    (0x1B170, 0x1B2FB, E::Wide),
+    (0x1F004, 0x1F004, E::Wide),
+    (0x1F0CF, 0x1F0CF, E::Wide),
+    (0x1F100, 0x1F10A, E::Ambiguous),
+    (0x1F110, 0x1F12D, E::Ambiguous),
+    (0x1F130, 0x1F169, E::Ambiguous),
+    (0x1F170, 0x1F18D, E::Ambiguous),
+    (0x1F18E, 0x1F18E, E::Wide),
+    (0x1F18F, 0x1F190, E::Ambiguous),
+    (0x1F191, 0x1F19A, E::Wide),
+    (0x1F19B, 0x1F1AC, E::Ambiguous),
+    (0x1F200, 0x1F202, E::Wide),
+    (0x1F210, 0x1F23B, E::Wide),
+    (0x1F240, 0x1F248, E::Wide),
+    (0x1F250, 0x1F251, E::Wide),
+    (0x1F260, 0x1F265, E::Wide),
+    (0x1F300, 0x1F320, E::Wide),
+    (0x1F32D, 0x1F335, E::Wide),
+    (0x1F337, 0x1F37C, E::Wide),
+    (0x1F37E, 0x1F393, E::Wide),
+    (0x1F3A0, 0x1F3CA, E::Wide),
+    (0x1F3CF, 0x1F3D3, E::Wide),
+    (0x1F3E0, 0x1F3F0, E::Wide),
+    (0x1F3F4, 0x1F3F4, E::Wide),
+    (0x1F3F8, 0x1F43E, E::Wide),
+    (0x1F440, 0x1F440, E::Wide),
+    (0x1F442, 0x1F4FC, E::Wide),
+    (0x1F4FF, 0x1F53D, E::Wide),
+    (0x1F54B, 0x1F54E, E::Wide),
+    (0x1F550, 0x1F567, E::Wide),
+    (0x1F57A, 0x1F57A, E::Wide),
+    (0x1F595, 0x1F596, E::Wide),
+    (0x1F5A4, 0x1F5A4, E::Wide),
+    (0x1F5FB, 0x1F64F, E::Wide),
+    (0x1F680, 0x1F6C5, E::Wide),
+    (0x1F6CC, 0x1F6CC, E::Wide),
+    (0x1F6D0, 0x1F6D2, E::Wide),
+    (0x1F6D5, 0x1F6D7, E::Wide),
+    (0x1F6DD, 0x1F6DF, E::Wide),
+    (0x1F6EB, 0x1F6EC, E::Wide),
+    (0x1F6F4, 0x1F6FC, E::Wide),
+    (0x1F7E0, 0x1F7EB, E::Wide),
+    (0x1F7F0, 0x1F7F0, E::Wide),
+    (0x1F90C, 0x1F93A, E::Wide),
+    (0x1F93C, 0x1F945, E::Wide),
+    (0x1F947, 0x1F9FF, E::Wide),
+    (0x1FA70, 0x1FA74, E::Wide),
+    (0x1FA78, 0x1FA7C, E::Wide),
+    (0x1FA80, 0x1FA86, E::Wide),
+    (0x1FA90, 0x1FAAC, E::Wide),
+    (0x1FAB0, 0x1FABA, E::Wide),
+    (0x1FAC0, 0x1FAC5, E::Wide),
+    (0x1FAD0, 0x1FAD9, E::Wide),
+    (0x1FAE0, 0x1FAE7, E::Wide),
+    (0x1FAF0, 0x1FAF6, E::Wide),
+    (0x20000, 0x2FFFD, E::Wide),
+    (0x30000, 0x3FFFD, E::Wide),
+    (0xE0100, 0xE01EF, E::Ambiguous),
+    (0xF0000, 0xFFFFD, E::Ambiguous),
+    (0x100000, 0x10FFFD, E::Ambiguous),
+];
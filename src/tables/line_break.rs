@@ -0,0 +1,2797 @@
+// Generated from the Unicode Character Database 14.0.0 (LineBreak.txt). Do not edit.
+
+use crate::linebreak::LineBreak as L;
+
+#[rustfmt::skip]
+pub(crate) const LINE_BREAK: &[(u32, u32, L)] = &[
+    (0x0000, 0x0008, L::CombiningMark),
+    (0x0009, 0x0009, L::BreakAfter),
+    (0x000A, 0x000A, L::LineFeed),
+    (0x000B, 0x000C, L::MandatoryBreak),
+    (0x000D, 0x000D, L::CarriageReturn),
+    (0x000E, 0x001F, L::CombiningMark),
+    (0x0020, 0x0020, L::Space),
+    (0x0021, 0x0021, L::Exclamation),
+    (0x0022, 0x0022, L::Quotation),
+    (0x0023, 0x0023, L::Alphabetic),
+    (0x0024, 0x0024, L::PrefixNumeric),
+    (0x0025, 0x0025, L::PostfixNumeric),
+    (0x0026, 0x0026, L::Alphabetic),
+    (0x0027, 0x0027, L::Quotation),
+    (0x0028, 0x0028, L::OpenPunctuation),
+    (0x0029, 0x0029, L::CloseParenthesis),
+    (0x002A, 0x002A, L::Alphabetic),
+    (0x002B, 0x002B, L::PrefixNumeric),
+    (0x002C, 0x002C, L::InfixNumeric),
+    (0x002D, 0x002D, L::Hyphen),
+    (0x002E, 0x002E, L::InfixNumeric),
+    (0x002F, 0x002F, L::BreakSymbols),
+    (0x0030, 0x0039, L::Numeric),
+    (0x003A, 0x003B, L::InfixNumeric),
+    (0x003C, 0x003E, L::Alphabetic),
+    (0x003F, 0x003F, L::Exclamation),
+    (0x0040, 0x005A, L::Alphabetic),
+    (0x005B, 0x005B, L::OpenPunctuation),
+    (0x005C, 0x005C, L::PrefixNumeric),
+    (0x005D, 0x005D, L::CloseParenthesis),
+    (0x005E, 0x007A, L::Alphabetic),
+    (0x007B, 0x007B, L::OpenPunctuation),
+    (0x007C, 0x007C, L::BreakAfter),
+    (0x007D, 0x007D, L::ClosePunctuation),
+    (0x007E, 0x007E, L::Alphabetic),
+    (0x007F, 0x0084, L::CombiningMark),
+    (0x0085, 0x0085, L::NextLine),
+    (0x0086, 0x009F, L::CombiningMark),
+    (0x00A0, 0x00A0, L::Glue),
+    (0x00A1, 0x00A1, L::OpenPunctuation),
+    (0x00A2, 0x00A2, L::PostfixNumeric),
+    (0x00A3, 0x00A5, L::PrefixNumeric),
+    (0x00A6, 0x00A6, L::Alphabetic),
+    (0x00A7, 0x00A8, L::Ambiguous),
+    (0x00A9, 0x00A9, L::Alphabetic),
+    (0x00AA, 0x00AA, L::Ambiguous),
+    (0x00AB, 0x00AB, L::Quotation),
+    (0x00AC, 0x00AC, L::Alphabetic),
+    (0x00AD, 0x00AD, L::BreakAfter),
+    (0x00AE, 0x00AF, L::Alphabetic),
+    (0x00B0, 0x00B0, L::PostfixNumeric),
+    (0x00B1, 0x00B1, L::PrefixNumeric),
+    (0x00B2, 0x00B3, L::Ambiguous),
+    (0x00B4, 0x00B4, L::BreakBefore),
+    (0x00B5, 0x00B5, L::Alphabetic),
+    (0x00B6, 0x00BA, L::Ambiguous),
+    (0x00BB, 0x00BB, L::Quotation),
+    (0x00BC, 0x00BE, L::Ambiguous),
+    (0x00BF, 0x00BF, L::OpenPunctuation),
+    (0x00C0, 0x00D6, L::Alphabetic),
+    (0x00D7, 0x00D7, L::Ambiguous),
+    (0x00D8, 0x00F6, L::Alphabetic),
+    (0x00F7, 0x00F7, L::Ambiguous),
+    (0x00F8, 0x02C6, L::Alphabetic),
+    (0x02C7, 0x02C7, L::Ambiguous),
+    (0x02C8, 0x02C8, L::BreakBefore),
+    (0x02C9, 0x02CB, L::Ambiguous),
+    (0x02CC, 0x02CC, L::BreakBefore),
+    (0x02CD, 0x02CD, L::Ambiguous),
+    (0x02CE, 0x02CF, L::Alphabetic),
+    (0x02D0, 0x02D0, L::Ambiguous),
+    (0x02D1, 0x02D7, L::Alphabetic),
+    (0x02D8, 0x02DB, L::Ambiguous),
+    (0x02DC, 0x02DC, L::Alphabetic),
+    (0x02DD, 0x02DD, L::Ambiguous),
+    (0x02DE, 0x02DE, L::Alphabetic),
+    (0x02DF, 0x02DF, L::BreakBefore),
+    (0x02E0, 0x02FF, L::Alphabetic),
+    (0x0300, 0x034E, L::CombiningMark),
+    (0x034F, 0x034F, L::Glue),
+    (0x0350, 0x035B, L::CombiningMark),
+    (0x035C, 0x0362, L::Glue),
+    (0x0363, 0x036F, L::CombiningMark),
+    (0x0370, 0x0377, L::Alphabetic),
+    (0x037A, 0x037D, L::Alphabetic),
+    (0x037E, 0x037E, L::InfixNumeric),
+    (0x037F, 0x037F, L::Alphabetic),
+    (0x0384, 0x038A, L::Alphabetic),
+    (0x038C, 0x038C, L::Alphabetic),
+    (0x038E, 0x03A1, L::Alphabetic),
+    (0x03A3, 0x0482, L::Alphabetic),
+    (0x0483, 0x0489, L::CombiningMark),
+    (0x048A, 0x052F, L::Alphabetic),
+    (0x0531, 0x0556, L::Alphabetic),
+    (0x0559, 0x0588, L::Alphabetic),
+    (0x0589, 0x0589, L::InfixNumeric),
+    (0x058A, 0x058A, L::BreakAfter),
+    (0x058D, 0x058E, L::Alphabetic),
+    (0x058F, 0x058F, L::PrefixNumeric),
+    (0x0591, 0x05BD, L::CombiningMark),
+    (0x05BE, 0x05BE, L::BreakAfter),
+    (0x05BF, 0x05BF, L::CombiningMark),
+    (0x05C0, 0x05C0, L::Alphabetic),
+    (0x05C1, 0x05C2, L::CombiningMark),
+    (0x05C3, 0x05C3, L::Alphabetic),
+    (0x05C4, 0x05C5, L::CombiningMark),
+    (0x05C6, 0x05C6, L::Exclamation),
+    (0x05C7, 0x05C7, L::CombiningMark),
+    (0x05D0, 0x05EA, L::HebrewLetter),
+    (0x05EF, 0x05F2, L::HebrewLetter),
+    (0x05F3, 0x05F4, L::Alphabetic),
+    (0x0600, 0x0608, L::Alphabetic),
+    (0x0609, 0x060B, L::PostfixNumeric),
+    (0x060C, 0x060D, L::InfixNumeric),
+    (0x060E, 0x060F, L::Alphabetic),
+    (0x0610, 0x061A, L::CombiningMark),
+    (0x061B, 0x061B, L::Exclamation),
+    (0x061C, 0x061C, L::CombiningMark),
+    (0x061D, 0x061F, L::Exclamation),
+    (0x0620, 0x064A, L::Alphabetic),
+    (0x064B, 0x065F, L::CombiningMark),
+    (0x0660, 0x0669, L::Numeric),
+    (0x066A, 0x066A, L::PostfixNumeric),
+    (0x066B, 0x066C, L::Numeric),
+    (0x066D, 0x066F, L::Alphabetic),
+    (0x0670, 0x0670, L::CombiningMark),
+    (0x0671, 0x06D3, L::Alphabetic),
+    (0x06D4, 0x06D4, L::Exclamation),
+    (0x06D5, 0x06D5, L::Alphabetic),
+    (0x06D6, 0x06DC, L::CombiningMark),
+    (0x06DD, 0x06DE, L::Alphabetic),
+    (0x06DF, 0x06E4, L::CombiningMark),
+    (0x06E5, 0x06E6, L::Alphabetic),
+    (0x06E7, 0x06E8, L::CombiningMark),
+    (0x06E9, 0x06E9, L::Alphabetic),
+    (0x06EA, 0x06ED, L::CombiningMark),
+    (0x06EE, 0x06EF, L::Alphabetic),
+    (0x06F0, 0x06F9, L::Numeric),
+    (0x06FA, 0x070D, L::Alphabetic),
+    (0x070F, 0x0710, L::Alphabetic),
+    (0x0711, 0x0711, L::CombiningMark),
+    (0x0712, 0x072F, L::Alphabetic),
+    (0x0730, 0x074A, L::CombiningMark),
+    (0x074D, 0x07A5, L::Alphabetic),
+    (0x07A6, 0x07B0, L::CombiningMark),
+    (0x07B1, 0x07B1, L::Alphabetic),
+    (0x07C0, 0x07C9, L::Numeric),
+    (0x07CA, 0x07EA, L::Alphabetic),
+    (0x07EB, 0x07F3, L::CombiningMark),
+    (0x07F4, 0x07F7, L::Alphabetic),
+    (0x07F8, 0x07F8, L::InfixNumeric),
+    (0x07F9, 0x07F9, L::Exclamation),
+    (0x07FA, 0x07FA, L::Alphabetic),
+    (0x07FD, 0x07FD, L::CombiningMark),
+    (0x07FE, 0x07FF, L::PrefixNumeric),
+    (0x0800, 0x0815, L::Alphabetic),
+    (0x0816, 0x0819, L::CombiningMark),
+    (0x081A, 0x081A, L::Alphabetic),
+    (0x081B, 0x0823, L::CombiningMark),
+    (0x0824, 0x0824, L::Alphabetic),
+    (0x0825, 0x0827, L::CombiningMark),
+    (0x0828, 0x0828, L::Alphabetic),
+    (0x0829, 0x082D, L::CombiningMark),
+    (0x0830, 0x083E, L::Alphabetic),
+    (0x0840, 0x0858, L::Alphabetic),
+    (0x0859, 0x085B, L::CombiningMark),
+    (0x085E, 0x085E, L::Alphabetic),
+    (0x0860, 0x086A, L::Alphabetic),
+    (0x0870, 0x088E, L::Alphabetic),
+    (0x0890, 0x0891, L::Alphabetic),
+    (0x0898, 0x089F, L::CombiningMark),
+    (0x08A0, 0x08C9, L::Alphabetic),
+    (0x08CA, 0x08E1, L::CombiningMark),
+    (0x08E2, 0x08E2, L::Alphabetic),
+    (0x08E3, 0x0903, L::CombiningMark),
+    (0x0904, 0x0939, L::Alphabetic),
+    (0x093A, 0x093C, L::CombiningMark),
+    (0x093D, 0x093D, L::Alphabetic),
+    (0x093E, 0x094F, L::CombiningMark),
+    (0x0950, 0x0950, L::Alphabetic),
+    (0x0951, 0x0957, L::CombiningMark),
+    (0x0958, 0x0961, L::Alphabetic),
+    (0x0962, 0x0963, L::CombiningMark),
+    (0x0964, 0x0965, L::BreakAfter),
+    (0x0966, 0x096F, L::Numeric),
+    (0x0970, 0x0980, L::Alphabetic),
+    (0x0981, 0x0983, L::CombiningMark),
+    (0x0985, 0x098C, L::Alphabetic),
+    (0x098F, 0x0990, L::Alphabetic),
+    (0x0993, 0x09A8, L::Alphabetic),
+    (0x09AA, 0x09B0, L::Alphabetic),
+    (0x09B2, 0x09B2, L::Alphabetic),
+    (0x09B6, 0x09B9, L::Alphabetic),
+    (0x09BC, 0x09BC, L::CombiningMark),
+    (0x09BD, 0x09BD, L::Alphabetic),
+    (0x09BE, 0x09C4, L::CombiningMark),
+    (0x09C7, 0x09C8, L::CombiningMark),
+    (0x09CB, 0x09CD, L::CombiningMark),
+    (0x09CE, 0x09CE, L::Alphabetic),
+    (0x09D7, 0x09D7, L::CombiningMark),
+    (0x09DC, 0x09DD, L::Alphabetic),
+    (0x09DF, 0x09E1, L::Alphabetic),
+    (0x09E2, 0x09E3, L::CombiningMark),
+    (0x09E6, 0x09EF, L::Numeric),
+    (0x09F0, 0x09F1, L::Alphabetic),
+    (0x09F2, 0x09F3, L::PostfixNumeric),
+    (0x09F4, 0x09F8, L::Alphabetic),
+    (0x09F9, 0x09F9, L::PostfixNumeric),
+    (0x09FA, 0x09FA, L::Alphabetic),
+    (0x09FB, 0x09FB, L::PrefixNumeric),
+    (0x09FC, 0x09FD, L::Alphabetic),
+    (0x09FE, 0x09FE, L::CombiningMark),
+    (0x0A01, 0x0A03, L::CombiningMark),
+    (0x0A05, 0x0A0A, L::Alphabetic),
+    (0x0A0F, 0x0A10, L::Alphabetic),
+    (0x0A13, 0x0A28, L::Alphabetic),
+    (0x0A2A, 0x0A30, L::Alphabetic),
+    (0x0A32, 0x0A33, L::Alphabetic),
+    (0x0A35, 0x0A36, L::Alphabetic),
+    (0x0A38, 0x0A39, L::Alphabetic),
+    (0x0A3C, 0x0A3C, L::CombiningMark),
+    (0x0A3E, 0x0A42, L::CombiningMark),
+    (0x0A47, 0x0A48, L::CombiningMark),
+    (0x0A4B, 0x0A4D, L::CombiningMark),
+    (0x0A51, 0x0A51, L::CombiningMark),
+    (0x0A59, 0x0A5C, L::Alphabetic),
+    (0x0A5E, 0x0A5E, L::Alphabetic),
+    (0x0A66, 0x0A6F, L::Numeric),
+    (0x0A70, 0x0A71, L::CombiningMark),
+    (0x0A72, 0x0A74, L::Alphabetic),
+    (0x0A75, 0x0A75, L::CombiningMark),
+    (0x0A76, 0x0A76, L::Alphabetic),
+    (0x0A81, 0x0A83, L::CombiningMark),
+    (0x0A85, 0x0A8D, L::Alphabetic),
+    (0x0A8F, 0x0A91, L::Alphabetic),
+    (0x0A93, 0x0AA8, L::Alphabetic),
+    (0x0AAA, 0x0AB0, L::Alphabetic),
+    (0x0AB2, 0x0AB3, L::Alphabetic),
+    (0x0AB5, 0x0AB9, L::Alphabetic),
+    (0x0ABC, 0x0ABC, L::CombiningMark),
+    (0x0ABD, 0x0ABD, L::Alphabetic),
+    (0x0ABE, 0x0AC5, L::CombiningMark),
+    (0x0AC7, 0x0AC9, L::CombiningMark),
+    (0x0ACB, 0x0ACD, L::CombiningMark),
+    (0x0AD0, 0x0AD0, L::Alphabetic),
+    (0x0AE0, 0x0AE1, L::Alphabetic),
+    (0x0AE2, 0x0AE3, L::CombiningMark),
+    (0x0AE6, 0x0AEF, L::Numeric),
+    (0x0AF0, 0x0AF0, L::Alphabetic),
+    (0x0AF1, 0x0AF1, L::PrefixNumeric),
+    (0x0AF9, 0x0AF9, L::Alphabetic),
+    (0x0AFA, 0x0AFF, L::CombiningMark),
+    (0x0B01, 0x0B03, L::CombiningMark),
+    (0x0B05, 0x0B0C, L::Alphabetic),
+    (0x0B0F, 0x0B10, L::Alphabetic),
+    (0x0B13, 0x0B28, L::Alphabetic),
+    (0x0B2A, 0x0B30, L::Alphabetic),
+    (0x0B32, 0x0B33, L::Alphabetic),
+    (0x0B35, 0x0B39, L::Alphabetic),
+    (0x0B3C, 0x0B3C, L::CombiningMark),
+    (0x0B3D, 0x0B3D, L::Alphabetic),
+    (0x0B3E, 0x0B44, L::CombiningMark),
+    (0x0B47, 0x0B48, L::CombiningMark),
+    (0x0B4B, 0x0B4D, L::CombiningMark),
+    (0x0B55, 0x0B57, L::CombiningMark),
+    (0x0B5C, 0x0B5D, L::Alphabetic),
+    (0x0B5F, 0x0B61, L::Alphabetic),
+    (0x0B62, 0x0B63, L::CombiningMark),
+    (0x0B66, 0x0B6F, L::Numeric),
+    (0x0B70, 0x0B77, L::Alphabetic),
+    (0x0B82, 0x0B82, L::CombiningMark),
+    (0x0B83, 0x0B83, L::Alphabetic),
+    (0x0B85, 0x0B8A, L::Alphabetic),
+    (0x0B8E, 0x0B90, L::Alphabetic),
+    (0x0B92, 0x0B95, L::Alphabetic),
+    (0x0B99, 0x0B9A, L::Alphabetic),
+    (0x0B9C, 0x0B9C, L::Alphabetic),
+    (0x0B9E, 0x0B9F, L::Alphabetic),
+    (0x0BA3, 0x0BA4, L::Alphabetic),
+    (0x0BA8, 0x0BAA, L::Alphabetic),
+    (0x0BAE, 0x0BB9, L::Alphabetic),
+    (0x0BBE, 0x0BC2, L::CombiningMark),
+    (0x0BC6, 0x0BC8, L::CombiningMark),
+    (0x0BCA, 0x0BCD, L::CombiningMark),
+    (0x0BD0, 0x0BD0, L::Alphabetic),
+    (0x0BD7, 0x0BD7, L::CombiningMark),
+    (0x0BE6, 0x0BEF, L::Numeric),
+    (0x0BF0, 0x0BF8, L::Alphabetic),
+    (0x0BF9, 0x0BF9, L::PrefixNumeric),
+    (0x0BFA, 0x0BFA, L::Alphabetic),
+    (0x0C00, 0x0C04, L::CombiningMark),
+    (0x0C05, 0x0C0C, L::Alphabetic),
+    (0x0C0E, 0x0C10, L::Alphabetic),
+    (0x0C12, 0x0C28, L::Alphabetic),
+    (0x0C2A, 0x0C39, L::Alphabetic),
+    (0x0C3C, 0x0C3C, L::CombiningMark),
+    (0x0C3D, 0x0C3D, L::Alphabetic),
+    (0x0C3E, 0x0C44, L::CombiningMark),
+    (0x0C46, 0x0C48, L::CombiningMark),
+    (0x0C4A, 0x0C4D, L::CombiningMark),
+    (0x0C55, 0x0C56, L::CombiningMark),
+    (0x0C58, 0x0C5A, L::Alphabetic),
+    (0x0C5D, 0x0C5D, L::Alphabetic),
+    (0x0C60, 0x0C61, L::Alphabetic),
+    (0x0C62, 0x0C63, L::CombiningMark),
+    (0x0C66, 0x0C6F, L::Numeric),
+    (0x0C77, 0x0C77, L::BreakBefore),
+    (0x0C78, 0x0C80, L::Alphabetic),
+    (0x0C81, 0x0C83, L::CombiningMark),
+    (0x0C84, 0x0C84, L::BreakBefore),
+    (0x0C85, 0x0C8C, L::Alphabetic),
+    (0x0C8E, 0x0C90, L::Alphabetic),
+    (0x0C92, 0x0CA8, L::Alphabetic),
+    (0x0CAA, 0x0CB3, L::Alphabetic),
+    (0x0CB5, 0x0CB9, L::Alphabetic),
+    (0x0CBC, 0x0CBC, L::CombiningMark),
+    (0x0CBD, 0x0CBD, L::Alphabetic),
+    (0x0CBE, 0x0CC4, L::CombiningMark),
+    (0x0CC6, 0x0CC8, L::CombiningMark),
+    (0x0CCA, 0x0CCD, L::CombiningMark),
+    (0x0CD5, 0x0CD6, L::CombiningMark),
+    (0x0CDD, 0x0CDE, L::Alphabetic),
+    (0x0CE0, 0x0CE1, L::Alphabetic),
+    (0x0CE2, 0x0CE3, L::CombiningMark),
+    (0x0CE6, 0x0CEF, L::Numeric),
+    (0x0CF1, 0x0CF2, L::Alphabetic),
+    (0x0D00, 0x0D03, L::CombiningMark),
+    (0x0D04, 0x0D0C, L::Alphabetic),
+    (0x0D0E, 0x0D10, L::Alphabetic),
+    (0x0D12, 0x0D3A, L::Alphabetic),
+    (0x0D3B, 0x0D3C, L::CombiningMark),
+    (0x0D3D, 0x0D3D, L::Alphabetic),
+    (0x0D3E, 0x0D44, L::CombiningMark),
+    (0x0D46, 0x0D48, L::CombiningMark),
+    (0x0D4A, 0x0D4D, L::CombiningMark),
+    (0x0D4E, 0x0D4F, L::Alphabetic),
+    (0x0D54, 0x0D56, L::Alphabetic),
+    (0x0D57, 0x0D57, L::CombiningMark),
+    (0x0D58, 0x0D61, L::Alphabetic),
+    (0x0D62, 0x0D63, L::CombiningMark),
+    (0x0D66, 0x0D6F, L::Numeric),
+    (0x0D70, 0x0D78, L::Alphabetic),
+    (0x0D79, 0x0D79, L::PostfixNumeric),
+    (0x0D7A, 0x0D7F, L::Alphabetic),
+    (0x0D81, 0x0D83, L::CombiningMark),
+    (0x0D85, 0x0D96, L::Alphabetic),
+    (0x0D9A, 0x0DB1, L::Alphabetic),
+    (0x0DB3, 0x0DBB, L::Alphabetic),
+    (0x0DBD, 0x0DBD, L::Alphabetic),
+    (0x0DC0, 0x0DC6, L::Alphabetic),
+    (0x0DCA, 0x0DCA, L::CombiningMark),
+    (0x0DCF, 0x0DD4, L::CombiningMark),
+    (0x0DD6, 0x0DD6, L::CombiningMark),
+    (0x0DD8, 0x0DDF, L::CombiningMark),
+    (0x0DE6, 0x0DEF, L::Numeric),
+    (0x0DF2, 0x0DF3, L::CombiningMark),
+    (0x0DF4, 0x0DF4, L::Alphabetic),
+    (0x0E01, 0x0E3A, L::ComplexContext),
+    (0x0E3F, 0x0E3F, L::PrefixNumeric),
+    (0x0E40, 0x0E4E, L::ComplexContext),
+    (0x0E4F, 0x0E4F, L::Alphabetic),
+    (0x0E50, 0x0E59, L::Numeric),
+    (0x0E5A, 0x0E5B, L::BreakAfter),
+    (0x0E81, 0x0E82, L::ComplexContext),
+    (0x0E84, 0x0E84, L::ComplexContext),
+    (0x0E86, 0x0E8A, L::ComplexContext),
+    (0x0E8C, 0x0EA3, L::ComplexContext),
+    (0x0EA5, 0x0EA5, L::ComplexContext),
+    (0x0EA7, 0x0EBD, L::ComplexContext),
+    (0x0EC0, 0x0EC4, L::ComplexContext),
+    (0x0EC6, 0x0EC6, L::ComplexContext),
+    (0x0EC8, 0x0ECD, L::ComplexContext),
+    (0x0ED0, 0x0ED9, L::Numeric),
+    (0x0EDC, 0x0EDF, L::ComplexContext),
+    (0x0F00, 0x0F00, L::Alphabetic),
+    (0x0F01, 0x0F04, L::BreakBefore),
+    (0x0F05, 0x0F05, L::Alphabetic),
+    (0x0F06, 0x0F07, L::BreakBefore),
+    (0x0F08, 0x0F08, L::Glue),
+    (0x0F09, 0x0F0A, L::BreakBefore),
+    (0x0F0B, 0x0F0B, L::BreakAfter),
+    (0x0F0C, 0x0F0C, L::Glue),
+    (0x0F0D, 0x0F11, L::Exclamation),
+    (0x0F12, 0x0F12, L::Glue),
+    (0x0F13, 0x0F13, L::Alphabetic),
+    (0x0F14, 0x0F14, L::Exclamation),
+    (0x0F15, 0x0F17, L::Alphabetic),
+    (0x0F18, 0x0F19, L::CombiningMark),
+    (0x0F1A, 0x0F1F, L::Alphabetic),
+    (0x0F20, 0x0F29, L::Numeric),
+    (0x0F2A, 0x0F33, L::Alphabetic),
+    (0x0F34, 0x0F34, L::BreakAfter),
+    (0x0F35, 0x0F35, L::CombiningMark),
+    (0x0F36, 0x0F36, L::Alphabetic),
+    (0x0F37, 0x0F37, L::CombiningMark),
+    (0x0F38, 0x0F38, L::Alphabetic),
+    (0x0F39, 0x0F39, L::CombiningMark),
+    (0x0F3A, 0x0F3A, L::OpenPunctuation),
+    (0x0F3B, 0x0F3B, L::ClosePunctuation),
+    (0x0F3C, 0x0F3C, L::OpenPunctuation),
+    (0x0F3D, 0x0F3D, L::ClosePunctuation),
+    (0x0F3E, 0x0F3F, L::CombiningMark),
+    (0x0F40, 0x0F47, L::Alphabetic),
+    (0x0F49, 0x0F6C, L::Alphabetic),
+    (0x0F71, 0x0F7E, L::CombiningMark),
+    (0x0F7F, 0x0F7F, L::BreakAfter),
+    (0x0F80, 0x0F84, L::CombiningMark),
+    (0x0F85, 0x0F85, L::BreakAfter),
+    (0x0F86, 0x0F87, L::CombiningMark),
+    (0x0F88, 0x0F8C, L::Alphabetic),
+    (0x0F8D, 0x0F97, L::CombiningMark),
+    (0x0F99, 0x0FBC, L::CombiningMark),
+    (0x0FBE, 0x0FBF, L::BreakAfter),
+    (0x0FC0, 0x0FC5, L::Alphabetic),
+    (0x0FC6, 0x0FC6, L::CombiningMark),
+    (0x0FC7, 0x0FCC, L::Alphabetic),
+    (0x0FCE, 0x0FCF, L::Alphabetic),
+    (0x0FD0, 0x0FD1, L::BreakBefore),
+    (0x0FD2, 0x0FD2, L::BreakAfter),
+    (0x0FD3, 0x0FD3, L::BreakBefore),
+    (0x0FD4, 0x0FD8, L::Alphabetic),
+    (0x0FD9, 0x0FDA, L::Glue),
+    (0x1000, 0x103F, L::ComplexContext),
+    (0x1040, 0x1049, L::Numeric),
+    (0x104A, 0x104B, L::BreakAfter),
+    (0x104C, 0x104F, L::Alphabetic),
+    (0x1050, 0x108F, L::ComplexContext),
+    (0x1090, 0x1099, L::Numeric),
+    (0x109A, 0x109F, L::ComplexContext),
+    (0x10A0, 0x10C5, L::Alphabetic),
+    (0x10C7, 0x10C7, L::Alphabetic),
+    (0x10CD, 0x10CD, L::Alphabetic),
+    (0x10D0, 0x10FF, L::Alphabetic),
+    (0x1100, 0x115F, L::Jl),
+    (0x1160, 0x11A7, L::Jv),
+    (0x11A8, 0x11FF, L::Jt),
+    (0x1200, 0x1248, L::Alphabetic),
+    (0x124A, 0x124D, L::Alphabetic),
+    (0x1250, 0x1256, L::Alphabetic),
+    (0x1258, 0x1258, L::Alphabetic),
+    (0x125A, 0x125D, L::Alphabetic),
+    (0x1260, 0x1288, L::Alphabetic),
+    (0x128A, 0x128D, L::Alphabetic),
+    (0x1290, 0x12B0, L::Alphabetic),
+    (0x12B2, 0x12B5, L::Alphabetic),
+    (0x12B8, 0x12BE, L::Alphabetic),
+    (0x12C0, 0x12C0, L::Alphabetic),
+    (0x12C2, 0x12C5, L::Alphabetic),
+    (0x12C8, 0x12D6, L::Alphabetic),
+    (0x12D8, 0x1310, L::Alphabetic),
+    (0x1312, 0x1315, L::Alphabetic),
+    (0x1318, 0x135A, L::Alphabetic),
+    (0x135D, 0x135F, L::CombiningMark),
+    (0x1360, 0x1360, L::Alphabetic),
+    (0x1361, 0x1361, L::BreakAfter),
+    (0x1362, 0x137C, L::Alphabetic),
+    (0x1380, 0x1399, L::Alphabetic),
+    (0x13A0, 0x13F5, L::Alphabetic),
+    (0x13F8, 0x13FD, L::Alphabetic),
+    (0x1400, 0x1400, L::BreakAfter),
+    (0x1401, 0x167F, L::Alphabetic),
+    (0x1680, 0x1680, L::BreakAfter),
+    (0x1681, 0x169A, L::Alphabetic),
+    (0x169B, 0x169B, L::OpenPunctuation),
+    (0x169C, 0x169C, L::ClosePunctuation),
+    (0x16A0, 0x16EA, L::Alphabetic),
+    (0x16EB, 0x16ED, L::BreakAfter),
+    (0x16EE, 0x16F8, L::Alphabetic),
+    (0x1700, 0x1711, L::Alphabetic),
+    (0x1712, 0x1715, L::CombiningMark),
+    (0x171F, 0x1731, L::Alphabetic),
+    (0x1732, 0x1734, L::CombiningMark),
+    (0x1735, 0x1736, L::BreakAfter),
+    (0x1740, 0x1751, L::Alphabetic),
+    (0x1752, 0x1753, L::CombiningMark),
+    (0x1760, 0x176C, L::Alphabetic),
+    (0x176E, 0x1770, L::Alphabetic),
+    (0x1772, 0x1773, L::CombiningMark),
+    (0x1780, 0x17D3, L::ComplexContext),
+    (0x17D4, 0x17D5, L::BreakAfter),
+    (0x17D6, 0x17D6, L::Nonstarter),
+    (0x17D7, 0x17D7, L::ComplexContext),
+    (0x17D8, 0x17D8, L::BreakAfter),
+    (0x17D9, 0x17D9, L::Alphabetic),
+    (0x17DA, 0x17DA, L::BreakAfter),
+    (0x17DB, 0x17DB, L::PrefixNumeric),
+    (0x17DC, 0x17DD, L::ComplexContext),
+    (0x17E0, 0x17E9, L::Numeric),
+    (0x17F0, 0x17F9, L::Alphabetic),
+    (0x1800, 0x1801, L::Alphabetic),
+    (0x1802, 0x1803, L::Exclamation),
+    (0x1804, 0x1805, L::BreakAfter),
+    (0x1806, 0x1806, L::BreakBefore),
+    (0x1807, 0x1807, L::Alphabetic),
+    (0x1808, 0x1809, L::Exclamation),
+    (0x180A, 0x180A, L::Alphabetic),
+    (0x180B, 0x180D, L::CombiningMark),
+    (0x180E, 0x180E, L::Glue),
+    (0x180F, 0x180F, L::CombiningMark),
+    (0x1810, 0x1819, L::Numeric),
+    (0x1820, 0x1878, L::Alphabetic),
+    (0x1880, 0x1884, L::Alphabetic),
+    (0x1885, 0x1886, L::CombiningMark),
+    (0x1887, 0x18A8, L::Alphabetic),
+    (0x18A9, 0x18A9, L::CombiningMark),
+    (0x18AA, 0x18AA, L::Alphabetic),
+    (0x18B0, 0x18F5, L::Alphabetic),
+    (0x1900, 0x191E, L::Alphabetic),
+    (0x1920, 0x192B, L::CombiningMark),
+    (0x1930, 0x193B, L::CombiningMark),
+    (0x1940, 0x1940, L::Alphabetic),
+    (0x1944, 0x1945, L::Exclamation),
+    (0x1946, 0x194F, L::Numeric),
+    (0x1950, 0x196D, L::ComplexContext),
+    (0x1970, 0x1974, L::ComplexContext),
+    (0x1980, 0x19AB, L::ComplexContext),
+    (0x19B0, 0x19C9, L::ComplexContext),
+    (0x19D0, 0x19D9, L::Numeric),
+    (0x19DA, 0x19DA, L::ComplexContext),
+    (0x19DE, 0x19DF, L::ComplexContext),
+    (0x19E0, 0x1A16, L::Alphabetic),
+    (0x1A17, 0x1A1B, L::CombiningMark),
+    (0x1A1E, 0x1A1F, L::Alphabetic),
+    (0x1A20, 0x1A5E, L::ComplexContext),
+    (0x1A60, 0x1A7C, L::ComplexContext),
+    (0x1A7F, 0x1A7F, L::CombiningMark),
+    (0x1A80, 0x1A89, L::Numeric),
+    (0x1A90, 0x1A99, L::Numeric),
+    (0x1AA0, 0x1AAD, L::ComplexContext),
+    (0x1AB0, 0x1ACE, L::CombiningMark),
+    (0x1B00, 0x1B04, L::CombiningMark),
+    (0x1B05, 0x1B33, L::Alphabetic),
+    (0x1B34, 0x1B44, L::CombiningMark),
+    (0x1B45, 0x1B4C, L::Alphabetic),
+    (0x1B50, 0x1B59, L::Numeric),
+    (0x1B5A, 0x1B5B, L::BreakAfter),
+    (0x1B5C, 0x1B5C, L::Alphabetic),
+    (0x1B5D, 0x1B60, L::BreakAfter),
+    (0x1B61, 0x1B6A, L::Alphabetic),
+    (0x1B6B, 0x1B73, L::CombiningMark),
+    (0x1B74, 0x1B7C, L::Alphabetic),
+    (0x1B7D, 0x1B7E, L::BreakAfter),
+    (0x1B80, 0x1B82, L::CombiningMark),
+    (0x1B83, 0x1BA0, L::Alphabetic),
+    (0x1BA1, 0x1BAD, L::CombiningMark),
+    (0x1BAE, 0x1BAF, L::Alphabetic),
+    (0x1BB0, 0x1BB9, L::Numeric),
+    (0x1BBA, 0x1BE5, L::Alphabetic),
+    (0x1BE6, 0x1BF3, L::CombiningMark),
+    (0x1BFC, 0x1C23, L::Alphabetic),
+    (0x1C24, 0x1C37, L::CombiningMark),
+    (0x1C3B, 0x1C3F, L::BreakAfter),
+    (0x1C40, 0x1C49, L::Numeric),
+    (0x1C4D, 0x1C4F, L::Alphabetic),
+    (0x1C50, 0x1C59, L::Numeric),
+    (0x1C5A, 0x1C7D, L::Alphabetic),
+    (0x1C7E, 0x1C7F, L::BreakAfter),
+    (0x1C80, 0x1C88, L::Alphabetic),
+    (0x1C90, 0x1CBA, L::Alphabetic),
+    (0x1CBD, 0x1CC7, L::Alphabetic),
+    (0x1CD0, 0x1CD2, L::CombiningMark),
+    (0x1CD3, 0x1CD3, L::Alphabetic),
+    (0x1CD4, 0x1CE8, L::CombiningMark),
+    (0x1CE9, 0x1CEC, L::Alphabetic),
+    (0x1CED, 0x1CED, L::CombiningMark),
+    (0x1CEE, 0x1CF3, L::Alphabetic),
+    (0x1CF4, 0x1CF4, L::CombiningMark),
+    (0x1CF5, 0x1CF6, L::Alphabetic),
+    (0x1CF7, 0x1CF9, L::CombiningMark),
+    (0x1CFA, 0x1CFA, L::Alphabetic),
+    (0x1D00, 0x1DBF, L::Alphabetic),
+    (0x1DC0, 0x1DFF, L::CombiningMark),
+    (0x1E00, 0x1F15, L::Alphabetic),
+    (0x1F18, 0x1F1D, L::Alphabetic),
+    (0x1F20, 0x1F45, L::Alphabetic),
+    (0x1F48, 0x1F4D, L::Alphabetic),
+    (0x1F50, 0x1F57, L::Alphabetic),
+    (0x1F59, 0x1F59, L::Alphabetic),
+    (0x1F5B, 0x1F5B, L::Alphabetic),
+    (0x1F5D, 0x1F5D, L::Alphabetic),
+    (0x1F5F, 0x1F7D, L::Alphabetic),
+    (0x1F80, 0x1FB4, L::Alphabetic),
+    (0x1FB6, 0x1FC4, L::Alphabetic),
+    (0x1FC6, 0x1FD3, L::Alphabetic),
+    (0x1FD6, 0x1FDB, L::Alphabetic),
+    (0x1FDD, 0x1FEF, L::Alphabetic),
+    (0x1FF2, 0x1FF4, L::Alphabetic),
+    (0x1FF6, 0x1FFC, L::Alphabetic),
+    (0x1FFD, 0x1FFD, L::BreakBefore),
+    (0x1FFE, 0x1FFE, L::Alphabetic),
+    (0x2000, 0x2006, L::BreakAfter),
+    (0x2007, 0x2007, L::Glue),
+    (0x2008, 0x200A, L::BreakAfter),
+    (0x200B, 0x200B, L::ZwSpace),
+    (0x200C, 0x200C, L::CombiningMark),
+    (0x200D, 0x200D, L::Zwj),
+    (0x200E, 0x200F, L::CombiningMark),
+    (0x2010, 0x2010, L::BreakAfter),
+    (0x2011, 0x2011, L::Glue),
+    (0x2012, 0x2013, L::BreakAfter),
+    (0x2014, 0x2014, L::BreakBoth),
+    (0x2015, 0x2016, L::Ambiguous),
+    (0x2017, 0x2017, L::Alphabetic),
+    (0x2018, 0x2019, L::Quotation),
+    (0x201A, 0x201A, L::OpenPunctuation),
+    (0x201B, 0x201D, L::Quotation),
+    (0x201E, 0x201E, L::OpenPunctuation),
+    (0x201F, 0x201F, L::Quotation),
+    (0x2020, 0x2021, L::Ambiguous),
+    (0x2022, 0x2023, L::Alphabetic),
+    (0x2024, 0x2026, L::Inseparable),
+    (0x2027, 0x2027, L::BreakAfter),
+    (0x2028, 0x2029, L::MandatoryBreak),
+    (0x202A, 0x202E, L::CombiningMark),
+    (0x202F, 0x202F, L::Glue),
+    (0x2030, 0x2037, L::PostfixNumeric),
+    (0x2038, 0x2038, L::Alphabetic),
+    (0x2039, 0x203A, L::Quotation),
+    (0x203B, 0x203B, L::Ambiguous),
+    (0x203C, 0x203D, L::Nonstarter),
+    (0x203E, 0x2043, L::Alphabetic),
+    (0x2044, 0x2044, L::InfixNumeric),
+    (0x2045, 0x2045, L::OpenPunctuation),
+    (0x2046, 0x2046, L::ClosePunctuation),
+    (0x2047, 0x2049, L::Nonstarter),
+    (0x204A, 0x2055, L::Alphabetic),
+    (0x2056, 0x2056, L::BreakAfter),
+    (0x2057, 0x2057, L::Alphabetic),
+    (0x2058, 0x205B, L::BreakAfter),
+    (0x205C, 0x205C, L::Alphabetic),
+    (0x205D, 0x205F, L::BreakAfter),
+    (0x2060, 0x2060, L::WordJoiner),
+    (0x2061, 0x2064, L::Alphabetic),
+    (0x2066, 0x206F, L::CombiningMark),
+    (0x2070, 0x2071, L::Alphabetic),
+    (0x2074, 0x2074, L::Ambiguous),
+    (0x2075, 0x207C, L::Alphabetic),
+    (0x207D, 0x207D, L::OpenPunctuation),
+    (0x207E, 0x207E, L::ClosePunctuation),
+    (0x207F, 0x207F, L::Ambiguous),
+    (0x2080, 0x2080, L::Alphabetic),
+    (0x2081, 0x2084, L::Ambiguous),
+    (0x2085, 0x208C, L::Alphabetic),
+    (0x208D, 0x208D, L::OpenPunctuation),
+    (0x208E, 0x208E, L::ClosePunctuation),
+    (0x2090, 0x209C, L::Alphabetic),
+    (0x20A0, 0x20A6, L::PrefixNumeric),
+    (0x20A7, 0x20A7, L::PostfixNumeric),
+    (0x20A8, 0x20B5, L::PrefixNumeric),
+    (0x20B6, 0x20B6, L::PostfixNumeric),
+    (0x20B7, 0x20BA, L::PrefixNumeric),
+    (0x20BB, 0x20BB, L::PostfixNumeric),
+    (0x20BC, 0x20BD, L::PrefixNumeric),
+    (0x20BE, 0x20BE, L::PostfixNumeric),
+    (0x20BF, 0x20BF, L::PrefixNumeric),
+    (0x20C0, 0x20C0, L::PostfixNumeric),
+    (0x20C1, 0x20CF, L::PrefixNumeric),
+    (0x20D0, 0x20F0, L::CombiningMark),
+    (0x2100, 0x2102, L::Alphabetic),
+    (0x2103, 0x2103, L::PostfixNumeric),
+    (0x2104, 0x2104, L::Alphabetic),
+    (0x2105, 0x2105, L::Ambiguous),
+    (0x2106, 0x2108, L::Alphabetic),
+    (0x2109, 0x2109, L::PostfixNumeric),
+    (0x210A, 0x2112, L::Alphabetic),
+    (0x2113, 0x2113, L::Ambiguous),
+    (0x2114, 0x2115, L::Alphabetic),
+    (0x2116, 0x2116, L::PrefixNumeric),
+    (0x2117, 0x2120, L::Alphabetic),
+    (0x2121, 0x2122, L::Ambiguous),
+    (0x2123, 0x212A, L::Alphabetic),
+    (0x212B, 0x212B, L::Ambiguous),
+    (0x212C, 0x2153, L::Alphabetic),
+    (0x2154, 0x2155, L::Ambiguous),
+    (0x2156, 0x215A, L::Alphabetic),
+    (0x215B, 0x215B, L::Ambiguous),
+    (0x215C, 0x215D, L::Alphabetic),
+    (0x215E, 0x215E, L::Ambiguous),
+    (0x215F, 0x215F, L::Alphabetic),
+    (0x2160, 0x216B, L::Ambiguous),
+    (0x216C, 0x216F, L::Alphabetic),
+    (0x2170, 0x2179, L::Ambiguous),
+    (0x217A, 0x2188, L::Alphabetic),
+    (0x2189, 0x2189, L::Ambiguous),
+    (0x218A, 0x218B, L::Alphabetic),
+    (0x2190, 0x2199, L::Ambiguous),
+    (0x219A, 0x21D1, L::Alphabetic),
+    (0x21D2, 0x21D2, L::Ambiguous),
+    (0x21D3, 0x21D3, L::Alphabetic),
+    (0x21D4, 0x21D4, L::Ambiguous),
+    (0x21D5, 0x21FF, L::Alphabetic),
+    (0x2200, 0x2200, L::Ambiguous),
+    (0x2201, 0x2201, L::Alphabetic),
+    (0x2202, 0x2203, L::Ambiguous),
+    (0x2204, 0x2206, L::Alphabetic),
+    (0x2207, 0x2208, L::Ambiguous),
+    (0x2209, 0x220A, L::Alphabetic),
+    (0x220B, 0x220B, L::Ambiguous),
+    (0x220C, 0x220E, L::Alphabetic),
+    (0x220F, 0x220F, L::Ambiguous),
+    (0x2210, 0x2210, L::Alphabetic),
+    (0x2211, 0x2211, L::Ambiguous),
+    (0x2212, 0x2213, L::PrefixNumeric),
+    (0x2214, 0x2214, L::Alphabetic),
+    (0x2215, 0x2215, L::Ambiguous),
+    (0x2216, 0x2219, L::Alphabetic),
+    (0x221A, 0x221A, L::Ambiguous),
+    (0x221B, 0x221C, L::Alphabetic),
+    (0x221D, 0x2220, L::Ambiguous),
+    (0x2221, 0x2222, L::Alphabetic),
+    (0x2223, 0x2223, L::Ambiguous),
+    (0x2224, 0x2224, L::Alphabetic),
+    (0x2225, 0x2225, L::Ambiguous),
+    (0x2226, 0x2226, L::Alphabetic),
+    (0x2227, 0x222C, L::Ambiguous),
+    (0x222D, 0x222D, L::Alphabetic),
+    (0x222E, 0x222E, L::Ambiguous),
+    (0x222F, 0x2233, L::Alphabetic),
+    (0x2234, 0x2237, L::Ambiguous),
+    (0x2238, 0x223B, L::Alphabetic),
+    (0x223C, 0x223D, L::Ambiguous),
+    (0x223E, 0x2247, L::Alphabetic),
+    (0x2248, 0x2248, L::Ambiguous),
+    (0x2249, 0x224B, L::Alphabetic),
+    (0x224C, 0x224C, L::Ambiguous),
+    (0x224D, 0x2251, L::Alphabetic),
+    (0x2252, 0x2252, L::Ambiguous),
+    (0x2253, 0x225F, L::Alphabetic),
+    (0x2260, 0x2261, L::Ambiguous),
+    (0x2262, 0x2263, L::Alphabetic),
+    (0x2264, 0x2267, L::Ambiguous),
+    (0x2268, 0x2269, L::Alphabetic),
+    (0x226A, 0x226B, L::Ambiguous),
+    (0x226C, 0x226D, L::Alphabetic),
+    (0x226E, 0x226F, L::Ambiguous),
+    (0x2270, 0x2281, L::Alphabetic),
+    (0x2282, 0x2283, L::Ambiguous),
+    (0x2284, 0x2285, L::Alphabetic),
+    (0x2286, 0x2287, L::Ambiguous),
+    (0x2288, 0x2294, L::Alphabetic),
+    (0x2295, 0x2295, L::Ambiguous),
+    (0x2296, 0x2298, L::Alphabetic),
+    (0x2299, 0x2299, L::Ambiguous),
+    (0x229A, 0x22A4, L::Alphabetic),
+    (0x22A5, 0x22A5, L::Ambiguous),
+    (0x22A6, 0x22BE, L::Alphabetic),
+    (0x22BF, 0x22BF, L::Ambiguous),
+    (0x22C0, 0x22EE, L::Alphabetic),
+    (0x22EF, 0x22EF, L::Inseparable),
+    (0x22F0, 0x2307, L::Alphabetic),
+    (0x2308, 0x2308, L::OpenPunctuation),
+    (0x2309, 0x2309, L::ClosePunctuation),
+    (0x230A, 0x230A, L::OpenPunctuation),
+    (0x230B, 0x230B, L::ClosePunctuation),
+    (0x230C, 0x2311, L::Alphabetic),
+    (0x2312, 0x2312, L::Ambiguous),
+    (0x2313, 0x2319, L::Alphabetic),
+    (0x231A, 0x231B, L::Ideographic),
+    (0x231C, 0x2328, L::Alphabetic),
+    (0x2329, 0x2329, L::OpenPunctuation),
+    (0x232A, 0x232A, L::ClosePunctuation),
+    (0x232B, 0x23EF, L::Alphabetic),
+    (0x23F0, 0x23F3, L::Ideographic),
+    (0x23F4, 0x2426, L::Alphabetic),
+    (0x2440, 0x244A, L::Alphabetic),
+    (0x2460, 0x24FE, L::Ambiguous),
+    (0x24FF, 0x24FF, L::Alphabetic),
+    (0x2500, 0x254B, L::Ambiguous),
+    (0x254C, 0x254F, L::Alphabetic),
+    (0x2550, 0x2574, L::Ambiguous),
+    (0x2575, 0x257F, L::Alphabetic),
+    (0x2580, 0x258F, L::Ambiguous),
+    (0x2590, 0x2591, L::Alphabetic),
+    (0x2592, 0x2595, L::Ambiguous),
+    (0x2596, 0x259F, L::Alphabetic),
+    (0x25A0, 0x25A1, L::Ambiguous),
+    (0x25A2, 0x25A2, L::Alphabetic),
+    (0x25A3, 0x25A9, L::Ambiguous),
+    (0x25AA, 0x25B1, L::Alphabetic),
+    (0x25B2, 0x25B3, L::Ambiguous),
+    (0x25B4, 0x25B5, L::Alphabetic),
+    (0x25B6, 0x25B7, L::Ambiguous),
+    (0x25B8, 0x25BB, L::Alphabetic),
+    (0x25BC, 0x25BD, L::Ambiguous),
+    (0x25BE, 0x25BF, L::Alphabetic),
+    (0x25C0, 0x25C1, L::Ambiguous),
+    (0x25C2, 0x25C5, L::Alphabetic),
+    (0x25C6, 0x25C8, L::Ambiguous),
+    (0x25C9, 0x25CA, L::Alphabetic),
+    (0x25CB, 0x25CB, L::Ambiguous),
+    (0x25CC, 0x25CD, L::Alphabetic),
+    (0x25CE, 0x25D1, L::Ambiguous),
+    (0x25D2, 0x25E1, L::Alphabetic),
+    (0x25E2, 0x25E5, L::Ambiguous),
+    (0x25E6, 0x25EE, L::Alphabetic),
+    (0x25EF, 0x25EF, L::Ambiguous),
+    (0x25F0, 0x25FF, L::Alphabetic),
+    (0x2600, 0x2603, L::Ideographic),
+    (0x2604, 0x2604, L::Alphabetic),
+    (0x2605, 0x2606, L::Ambiguous),
+    (0x2607, 0x2608, L::Alphabetic),
+    (0x2609, 0x2609, L::Ambiguous),
+    (0x260A, 0x260D, L::Alphabetic),
+    (0x260E, 0x260F, L::Ambiguous),
+    (0x2610, 0x2613, L::Alphabetic),
+    (0x2614, 0x2615, L::Ideographic),
+    (0x2616, 0x2617, L::Ambiguous),
+    (0x2618, 0x2618, L::Ideographic),
+    (0x2619, 0x2619, L::Alphabetic),
+    (0x261A, 0x261C, L::Ideographic),
+    (0x261D, 0x261D, L::EBase),
+    (0x261E, 0x261F, L::Ideographic),
+    (0x2620, 0x2638, L::Alphabetic),
+    (0x2639, 0x263B, L::Ideographic),
+    (0x263C, 0x263F, L::Alphabetic),
+    (0x2640, 0x2640, L::Ambiguous),
+    (0x2641, 0x2641, L::Alphabetic),
+    (0x2642, 0x2642, L::Ambiguous),
+    (0x2643, 0x265F, L::Alphabetic),
+    (0x2660, 0x2661, L::Ambiguous),
+    (0x2662, 0x2662, L::Alphabetic),
+    (0x2663, 0x2665, L::Ambiguous),
+    (0x2666, 0x2666, L::Alphabetic),
+    (0x2667, 0x2667, L::Ambiguous),
+    (0x2668, 0x2668, L::Ideographic),
+    (0x2669, 0x266A, L::Ambiguous),
+    (0x266B, 0x266B, L::Alphabetic),
+    (0x266C, 0x266D, L::Ambiguous),
+    (0x266E, 0x266E, L::Alphabetic),
+    (0x266F, 0x266F, L::Ambiguous),
+    (0x2670, 0x267E, L::Alphabetic),
+    (0x267F, 0x267F, L::Ideographic),
+    (0x2680, 0x269D, L::Alphabetic),
+    (0x269E, 0x269F, L::Ambiguous),
+    (0x26A0, 0x26BC, L::Alphabetic),
+    (0x26BD, 0x26C8, L::Ideographic),
+    (0x26C9, 0x26CC, L::Ambiguous),
+    (0x26CD, 0x26CD, L::Ideographic),
+    (0x26CE, 0x26CE, L::Alphabetic),
+    (0x26CF, 0x26D1, L::Ideographic),
+    (0x26D2, 0x26D2, L::Ambiguous),
+    (0x26D3, 0x26D4, L::Ideographic),
+    (0x26D5, 0x26D7, L::Ambiguous),
+    (0x26D8, 0x26D9, L::Ideographic),
+    (0x26DA, 0x26DB, L::Ambiguous),
+    (0x26DC, 0x26DC, L::Ideographic),
+    (0x26DD, 0x26DE, L::Ambiguous),
+    (0x26DF, 0x26E1, L::Ideographic),
+    (0x26E2, 0x26E2, L::Alphabetic),
+    (0x26E3, 0x26E3, L::Ambiguous),
+    (0x26E4, 0x26E7, L::Alphabetic),
+    (0x26E8, 0x26E9, L::Ambiguous),
+    (0x26EA, 0x26EA, L::Ideographic),
+    (0x26EB, 0x26F0, L::Ambiguous),
+    (0x26F1, 0x26F5, L::Ideographic),
+    (0x26F6, 0x26F6, L::Ambiguous),
+    (0x26F7, 0x26F8, L::Ideographic),
+    (0x26F9, 0x26F9, L::EBase),
+    (0x26FA, 0x26FA, L::Ideographic),
+    (0x26FB, 0x26FC, L::Ambiguous),
+    (0x26FD, 0x2704, L::Ideographic),
+    (0x2705, 0x2707, L::Alphabetic),
+    (0x2708, 0x2709, L::Ideographic),
+    (0x270A, 0x270D, L::EBase),
+    (0x270E, 0x2756, L::Alphabetic),
+    (0x2757, 0x2757, L::Ambiguous),
+    (0x2758, 0x275A, L::Alphabetic),
+    (0x275B, 0x2760, L::Quotation),
+    (0x2761, 0x2761, L::Alphabetic),
+    (0x2762, 0x2763, L::Exclamation),
+    (0x2764, 0x2764, L::Ideographic),
+    (0x2765, 0x2767, L::Alphabetic),
+    (0x2768, 0x2768, L::OpenPunctuation),
+    (0x2769, 0x2769, L::ClosePunctuation),
+    (0x276A, 0x276A, L::OpenPunctuation),
+    (0x276B, 0x276B, L::ClosePunctuation),
+    (0x276C, 0x276C, L::OpenPunctuation),
+    (0x276D, 0x276D, L::ClosePunctuation),
+    (0x276E, 0x276E, L::OpenPunctuation),
+    (0x276F, 0x276F, L::ClosePunctuation),
+    (0x2770, 0x2770, L::OpenPunctuation),
+    (0x2771, 0x2771, L::ClosePunctuation),
+    (0x2772, 0x2772, L::OpenPunctuation),
+    (0x2773, 0x2773, L::ClosePunctuation),
+    (0x2774, 0x2774, L::OpenPunctuation),
+    (0x2775, 0x2775, L::ClosePunctuation),
+    (0x2776, 0x2793, L::Ambiguous),
+    (0x2794, 0x27C4, L::Alphabetic),
+    (0x27C5, 0x27C5, L::OpenPunctuation),
+    (0x27C6, 0x27C6, L::ClosePunctuation),
+    (0x27C7, 0x27E5, L::Alphabetic),
+    (0x27E6, 0x27E6, L::OpenPunctuation),
+    (0x27E7, 0x27E7, L::ClosePunctuation),
+    (0x27E8, 0x27E8, L::OpenPunctuation),
+    (0x27E9, 0x27E9, L::ClosePunctuation),
+    (0x27EA, 0x27EA, L::OpenPunctuation),
+    (0x27EB, 0x27EB, L::ClosePunctuation),
+    (0x27EC, 0x27EC, L::OpenPunctuation),
+    (0x27ED, 0x27ED, L::ClosePunctuation),
+    (0x27EE, 0x27EE, L::OpenPunctuation),
+    (0x27EF, 0x27EF, L::ClosePunctuation),
+    (0x27F0, 0x2982, L::Alphabetic),
+    (0x2983, 0x2983, L::OpenPunctuation),
+    (0x2984, 0x2984, L::ClosePunctuation),
+    (0x2985, 0x2985, L::OpenPunctuation),
+    (0x2986, 0x2986, L::ClosePunctuation),
+    (0x2987, 0x2987, L::OpenPunctuation),
+    (0x2988, 0x2988, L::ClosePunctuation),
+    (0x2989, 0x2989, L::OpenPunctuation),
+    (0x298A, 0x298A, L::ClosePunctuation),
+    (0x298B, 0x298B, L::OpenPunctuation),
+    (0x298C, 0x298C, L::ClosePunctuation),
+    (0x298D, 0x298D, L::OpenPunctuation),
+    (0x298E, 0x298E, L::ClosePunctuation),
+    (0x298F, 0x298F, L::OpenPunctuation),
+    (0x2990, 0x2990, L::ClosePunctuation),
+    (0x2991, 0x2991, L::OpenPunctuation),
+    (0x2992, 0x2992, L::ClosePunctuation),
+    (0x2993, 0x2993, L::OpenPunctuation),
+    (0x2994, 0x2994, L::ClosePunctuation),
+    (0x2995, 0x2995, L::OpenPunctuation),
+    (0x2996, 0x2996, L::ClosePunctuation),
+    (0x2997, 0x2997, L::OpenPunctuation),
+    (0x2998, 0x2998, L::ClosePunctuation),
+    (0x2999, 0x29D7, L::Alphabetic),
+    (0x29D8, 0x29D8, L::OpenPunctuation),
+    (0x29D9, 0x29D9, L::ClosePunctuation),
+    (0x29DA, 0x29DA, L::OpenPunctuation),
+    (0x29DB, 0x29DB, L::ClosePunctuation),
+    (0x29DC, 0x29FB, L::Alphabetic),
+    (0x29FC, 0x29FC, L::OpenPunctuation),
+    (0x29FD, 0x29FD, L::ClosePunctuation),
+    (0x29FE, 0x2B54, L::Alphabetic),
+    (0x2B55, 0x2B59, L::Ambiguous),
+    (0x2B5A, 0x2B73, L::Alphabetic),
+    (0x2B76, 0x2B95, L::Alphabetic),
+    (0x2B97, 0x2CEE, L::Alphabetic),
+    (0x2CEF, 0x2CF1, L::CombiningMark),
+    (0x2CF2, 0x2CF3, L::Alphabetic),
+    (0x2CF9, 0x2CF9, L::Exclamation),
+    (0x2CFA, 0x2CFC, L::BreakAfter),
+    (0x2CFD, 0x2CFD, L::Alphabetic),
+    (0x2CFE, 0x2CFE, L::Exclamation),
+    (0x2CFF, 0x2CFF, L::BreakAfter),
+    (0x2D00, 0x2D25, L::Alphabetic),
+    (0x2D27, 0x2D27, L::Alphabetic),
+    (0x2D2D, 0x2D2D, L::Alphabetic),
+    (0x2D30, 0x2D67, L::Alphabetic),
+    (0x2D6F, 0x2D6F, L::Alphabetic),
+    (0x2D70, 0x2D70, L::BreakAfter),
+    (0x2D7F, 0x2D7F, L::CombiningMark),
+    (0x2D80, 0x2D96, L::Alphabetic),
+    (0x2DA0, 0x2DA6, L::Alphabetic),
+    (0x2DA8, 0x2DAE, L::Alphabetic),
+    (0x2DB0, 0x2DB6, L::Alphabetic),
+    (0x2DB8, 0x2DBE, L::Alphabetic),
+    (0x2DC0, 0x2DC6, L::Alphabetic),
+    (0x2DC8, 0x2DCE, L::Alphabetic),
+    (0x2DD0, 0x2DD6, L::Alphabetic),
+    (0x2DD8, 0x2DDE, L::Alphabetic),
+    (0x2DE0, 0x2DFF, L::CombiningMark),
+    (0x2E00, 0x2E0D, L::Quotation),
+    (0x2E0E, 0x2E15, L::BreakAfter),
+    (0x2E16, 0x2E16, L::Alphabetic),
+    (0x2E17, 0x2E17, L::BreakAfter),
+    (0x2E18, 0x2E18, L::OpenPunctuation),
+    (0x2E19, 0x2E19, L::BreakAfter),
+    (0x2E1A, 0x2E1B, L::Alphabetic),
+    (0x2E1C, 0x2E1D, L::Quotation),
+    (0x2E1E, 0x2E1F, L::Alphabetic),
+    (0x2E20, 0x2E21, L::Quotation),
+    (0x2E22, 0x2E22, L::OpenPunctuation),
+    (0x2E23, 0x2E23, L::ClosePunctuation),
+    (0x2E24, 0x2E24, L::OpenPunctuation),
+    (0x2E25, 0x2E25, L::ClosePunctuation),
+    (0x2E26, 0x2E26, L::OpenPunctuation),
+    (0x2E27, 0x2E27, L::ClosePunctuation),
+    (0x2E28, 0x2E28, L::OpenPunctuation),
+    (0x2E29, 0x2E29, L::ClosePunctuation),
+    (0x2E2A, 0x2E2D, L::BreakAfter),
+    (0x2E2E, 0x2E2E, L::Exclamation),
+    (0x2E2F, 0x2E2F, L::Alphabetic),
+    (0x2E30, 0x2E31, L::BreakAfter),
+    (0x2E32, 0x2E32, L::Alphabetic),
+    (0x2E33, 0x2E34, L::BreakAfter),
+    (0x2E35, 0x2E39, L::Alphabetic),
+    (0x2E3A, 0x2E3B, L::BreakBoth),
+    (0x2E3C, 0x2E3E, L::BreakAfter),
+    (0x2E3F, 0x2E3F, L::Alphabetic),
+    (0x2E40, 0x2E41, L::BreakAfter),
+    (0x2E42, 0x2E42, L::OpenPunctuation),
+    (0x2E43, 0x2E4A, L::BreakAfter),
+    (0x2E4B, 0x2E4B, L::Alphabetic),
+    (0x2E4C, 0x2E4C, L::BreakAfter),
+    (0x2E4D, 0x2E4D, L::Alphabetic),
+    (0x2E4E, 0x2E4F, L::BreakAfter),
+    (0x2E50, 0x2E52, L::Alphabetic),
+    (0x2E53, 0x2E54, L::Exclamation),
+    (0x2E55, 0x2E55, L::OpenPunctuation),
+    (0x2E56, 0x2E56, L::ClosePunctuation),
+    (0x2E57, 0x2E57, L::OpenPunctuation),
+    (0x2E58, 0x2E58, L::ClosePunctuation),
+    (0x2E59, 0x2E59, L::OpenPunctuation),
+    (0x2E5A, 0x2E5A, L::ClosePunctuation),
+    (0x2E5B, 0x2E5B, L::OpenPunctuation),
+    (0x2E5C, 0x2E5C, L::ClosePunctuation),
+    (0x2E5D, 0x2E5D, L::BreakAfter),
+    (0x2E80, 0x2E99, L::Ideographic),
+    (0x2E9B, 0x2EF3, L::Ideographic),
+    (0x2F00, 0x2FD5, L::Ideographic),
+    (0x2FF0, 0x2FFB, L::Ideographic),
+    (0x3000, 0x3000, L::BreakAfter),
+    (0x3001, 0x3002, L::ClosePunctuation),
+    (0x3003, 0x3004, L::Ideographic),
+    (0x3005, 0x3005, L::Nonstarter),
+    (0x3006, 0x3007, L::Ideographic),
+    (0x3008, 0x3008, L::OpenPunctuation),
+    (0x3009, 0x3009, L::ClosePunctuation),
+    (0x300A, 0x300A, L::OpenPunctuation),
+    (0x300B, 0x300B, L::ClosePunctuation),
+    (0x300C, 0x300C, L::OpenPunctuation),
+    (0x300D, 0x300D, L::ClosePunctuation),
+    (0x300E, 0x300E, L::OpenPunctuation),
+    (0x300F, 0x300F, L::ClosePunctuation),
+    (0x3010, 0x3010, L::OpenPunctuation),
+    (0x3011, 0x3011, L::ClosePunctuation),
+    (0x3012, 0x3013, L::Ideographic),
+    (0x3014, 0x3014, L::OpenPunctuation),
+    (0x3015, 0x3015, L::ClosePunctuation),
+    (0x3016, 0x3016, L::OpenPunctuation),
+    (0x3017, 0x3017, L::ClosePunctuation),
+    (0x3018, 0x3018, L::OpenPunctuation),
+    (0x3019, 0x3019, L::ClosePunctuation),
+    (0x301A, 0x301A, L::OpenPunctuation),
+    (0x301B, 0x301B, L::ClosePunctuation),
+    (0x301C, 0x301C, L::Nonstarter),
+    (0x301D, 0x301D, L::OpenPunctuation),
+    (0x301E, 0x301F, L::ClosePunctuation),
+    (0x3020, 0x3029, L::Ideographic),
+    (0x302A, 0x302F, L::CombiningMark),
+    (0x3030, 0x3034, L::Ideographic),
+    (0x3035, 0x3035, L::CombiningMark),
+    (0x3036, 0x303A, L::Ideographic),
+    (0x303B, 0x303C, L::Nonstarter),
+    (0x303D, 0x303F, L::Ideographic),
+    (0x3041, 0x3041, L::ConditionalJapaneseStarter),
+    (0x3042, 0x3042, L::Ideographic),
+    (0x3043, 0x3043, L::ConditionalJapaneseStarter),
+    (0x3044, 0x3044, L::Ideographic),
+    (0x3045, 0x3045, L::ConditionalJapaneseStarter),
+    (0x3046, 0x3046, L::Ideographic),
+    (0x3047, 0x3047, L::ConditionalJapaneseStarter),
+    (0x3048, 0x3048, L::Ideographic),
+    (0x3049, 0x3049, L::ConditionalJapaneseStarter),
+    (0x304A, 0x3062, L::Ideographic),
+    (0x3063, 0x3063, L::ConditionalJapaneseStarter),
+    (0x3064, 0x3082, L::Ideographic),
+    (0x3083, 0x3083, L::ConditionalJapaneseStarter),
+    (0x3084, 0x3084, L::Ideographic),
+    (0x3085, 0x3085, L::ConditionalJapaneseStarter),
+    (0x3086, 0x3086, L::Ideographic),
+    (0x3087, 0x3087, L::ConditionalJapaneseStarter),
+    (0x3088, 0x308D, L::Ideographic),
+    (0x308E, 0x308E, L::ConditionalJapaneseStarter),
+    (0x308F, 0x3094, L::Ideographic),
+    (0x3095, 0x3096, L::ConditionalJapaneseStarter),
+    (0x3099, 0x309A, L::CombiningMark),
+    (0x309B, 0x309E, L::Nonstarter),
+    (0x309F, 0x309F, L::Ideographic),
+    (0x30A0, 0x30A0, L::Nonstarter),
+    (0x30A1, 0x30A1, L::ConditionalJapaneseStarter),
+    (0x30A2, 0x30A2, L::Ideographic),
+    (0x30A3, 0x30A3, L::ConditionalJapaneseStarter),
+    (0x30A4, 0x30A4, L::Ideographic),
+    (0x30A5, 0x30A5, L::ConditionalJapaneseStarter),
+    (0x30A6, 0x30A6, L::Ideographic),
+    (0x30A7, 0x30A7, L::ConditionalJapaneseStarter),
+    (0x30A8, 0x30A8, L::Ideographic),
+    (0x30A9, 0x30A9, L::ConditionalJapaneseStarter),
+    (0x30AA, 0x30C2, L::Ideographic),
+    (0x30C3, 0x30C3, L::ConditionalJapaneseStarter),
+    (0x30C4, 0x30E2, L::Ideographic),
+    (0x30E3, 0x30E3, L::ConditionalJapaneseStarter),
+    (0x30E4, 0x30E4, L::Ideographic),
+    (0x30E5, 0x30E5, L::ConditionalJapaneseStarter),
+    (0x30E6, 0x30E6, L::Ideographic),
+    (0x30E7, 0x30E7, L::ConditionalJapaneseStarter),
+    (0x30E8, 0x30ED, L::Ideographic),
+    (0x30EE, 0x30EE, L::ConditionalJapaneseStarter),
+    (0x30EF, 0x30F4, L::Ideographic),
+    (0x30F5, 0x30F6, L::ConditionalJapaneseStarter),
+    (0x30F7, 0x30FA, L::Ideographic),
+    (0x30FB, 0x30FB, L::Nonstarter),
+    (0x30FC, 0x30FC, L::ConditionalJapaneseStarter),
+    (0x30FD, 0x30FE, L::Nonstarter),
+    (0x30FF, 0x30FF, L::Ideographic),
+    (0x3105, 0x312F, L::Ideographic),
+    (0x3131, 0x318E, L::Ideographic),
+    (0x3190, 0x31E3, L::Ideographic),
+    (0x31F0, 0x31FF, L::ConditionalJapaneseStarter),
+    (0x3200, 0x321E, L::Ideographic),
+    (0x3220, 0x3247, L::Ideographic),
+    (0x3248, 0x324F, L::Ambiguous),
+    (0x3250, 0x4DBF, L::Ideographic),
+    (0x4DC0, 0x4DFF, L::Alphabetic),
+    (0x4E00, 0xA014, L::Ideographic),
+    (0xA015, 0xA015, L::Nonstarter),
+    (0xA016, 0xA48C, L::Ideographic),
+    (0xA490, 0xA4C6, L::Ideographic),
+    (0xA4D0, 0xA4FD, L::Alphabetic),
+    (0xA4FE, 0xA4FF, L::BreakAfter),
+    (0xA500, 0xA60C, L::Alphabetic),
+    (0xA60D, 0xA60D, L::BreakAfter),
+    (0xA60E, 0xA60E, L::Exclamation),
+    (0xA60F, 0xA60F, L::BreakAfter),
+    (0xA610, 0xA61F, L::Alphabetic),
+    (0xA620, 0xA629, L::Numeric),
+    (0xA62A, 0xA62B, L::Alphabetic),
+    (0xA640, 0xA66E, L::Alphabetic),
+    (0xA66F, 0xA672, L::CombiningMark),
+    (0xA673, 0xA673, L::Alphabetic),
+    (0xA674, 0xA67D, L::CombiningMark),
+    (0xA67E, 0xA69D, L::Alphabetic),
+    (0xA69E, 0xA69F, L::CombiningMark),
+    (0xA6A0, 0xA6EF, L::Alphabetic),
+    (0xA6F0, 0xA6F1, L::CombiningMark),
+    (0xA6F2, 0xA6F2, L::Alphabetic),
+    (0xA6F3, 0xA6F7, L::BreakAfter),
+    (0xA700, 0xA7CA, L::Alphabetic),
+    (0xA7D0, 0xA7D1, L::Alphabetic),
+    (0xA7D3, 0xA7D3, L::Alphabetic),
+    (0xA7D5, 0xA7D9, L::Alphabetic),
+    (0xA7F2, 0xA801, L::Alphabetic),
+    (0xA802, 0xA802, L::CombiningMark),
+    (0xA803, 0xA805, L::Alphabetic),
+    (0xA806, 0xA806, L::CombiningMark),
+    (0xA807, 0xA80A, L::Alphabetic),
+    (0xA80B, 0xA80B, L::CombiningMark),
+    (0xA80C, 0xA822, L::Alphabetic),
+    (0xA823, 0xA827, L::CombiningMark),
+    (0xA828, 0xA82B, L::Alphabetic),
+    (0xA82C, 0xA82C, L::CombiningMark),
+    (0xA830, 0xA837, L::Alphabetic),
+    (0xA838, 0xA838, L::PostfixNumeric),
+    (0xA839, 0xA839, L::Alphabetic),
+    (0xA840, 0xA873, L::Alphabetic),
+    (0xA874, 0xA875, L::BreakBefore),
+    (0xA876, 0xA877, L::Exclamation),
+    (0xA880, 0xA881, L::CombiningMark),
+    (0xA882, 0xA8B3, L::Alphabetic),
+    (0xA8B4, 0xA8C5, L::CombiningMark),
+    (0xA8CE, 0xA8CF, L::BreakAfter),
+    (0xA8D0, 0xA8D9, L::Numeric),
+    (0xA8E0, 0xA8F1, L::CombiningMark),
+    (0xA8F2, 0xA8FB, L::Alphabetic),
+    (0xA8FC, 0xA8FC, L::BreakBefore),
+    (0xA8FD, 0xA8FE, L::Alphabetic),
+    (0xA8FF, 0xA8FF, L::CombiningMark),
+    (0xA900, 0xA909, L::Numeric),
+    (0xA90A, 0xA925, L::Alphabetic),
+    (0xA926, 0xA92D, L::CombiningMark),
+    (0xA92E, 0xA92F, L::BreakAfter),
+    (0xA930, 0xA946, L::Alphabetic),
+    (0xA947, 0xA953, L::CombiningMark),
+    (0xA95F, 0xA95F, L::Alphabetic),
+    (0xA960, 0xA97C, L::Jl),
+    (0xA980, 0xA983, L::CombiningMark),
+    (0xA984, 0xA9B2, L::Alphabetic),
+    (0xA9B3, 0xA9C0, L::CombiningMark),
+    (0xA9C1, 0xA9C6, L::Alphabetic),
+    (0xA9C7, 0xA9C9, L::BreakAfter),
+    (0xA9CA, 0xA9CD, L::Alphabetic),
+    (0xA9CF, 0xA9CF, L::Alphabetic),
+    (0xA9D0, 0xA9D9, L::Numeric),
+    (0xA9DE, 0xA9DF, L::Alphabetic),
+    (0xA9E0, 0xA9EF, L::ComplexContext),
+    (0xA9F0, 0xA9F9, L::Numeric),
+    (0xA9FA, 0xA9FE, L::ComplexContext),
+    (0xAA00, 0xAA28, L::Alphabetic),
+    (0xAA29, 0xAA36, L::CombiningMark),
+    (0xAA40, 0xAA42, L::Alphabetic),
+    (0xAA43, 0xAA43, L::CombiningMark),
+    (0xAA44, 0xAA4B, L::Alphabetic),
+    (0xAA4C, 0xAA4D, L::CombiningMark),
+    (0xAA50, 0xAA59, L::Numeric),
+    (0xAA5C, 0xAA5C, L::Alphabetic),
+    (0xAA5D, 0xAA5F, L::BreakAfter),
+    (0xAA60, 0xAAC2, L::ComplexContext),
+    (0xAADB, 0xAADF, L::ComplexContext),
+    (0xAAE0, 0xAAEA, L::Alphabetic),
+    (0xAAEB, 0xAAEF, L::CombiningMark),
+    (0xAAF0, 0xAAF1, L::BreakAfter),
+    (0xAAF2, 0xAAF4, L::Alphabetic),
+    (0xAAF5, 0xAAF6, L::CombiningMark),
+    (0xAB01, 0xAB06, L::Alphabetic),
+    (0xAB09, 0xAB0E, L::Alphabetic),
+    (0xAB11, 0xAB16, L::Alphabetic),
+    (0xAB20, 0xAB26, L::Alphabetic),
+    (0xAB28, 0xAB2E, L::Alphabetic),
+    (0xAB30, 0xAB6B, L::Alphabetic),
+    (0xAB70, 0xABE2, L::Alphabetic),
+    (0xABE3, 0xABEA, L::CombiningMark),
+    (0xABEB, 0xABEB, L::BreakAfter),
+    (0xABEC, 0xABED, L::CombiningMark),
+    (0xABF0, 0xABF9, L::Numeric),
+    (0xAC00, 0xAC00, L::H2),
+    (0xAC01, 0xAC1B, L::H3),
+    (0xAC1C, 0xAC1C, L::H2),
+    (0xAC1D, 0xAC37, L::H3),
+    (0xAC38, 0xAC38, L::H2),
+    (0xAC39, 0xAC53, L::H3),
+    (0xAC54, 0xAC54, L::H2),
+    (0xAC55, 0xAC6F, L::H3),
+    (0xAC70, 0xAC70, L::H2),
+    (0xAC71, 0xAC8B, L::H3),
+    (0xAC8C, 0xAC8C, L::H2),
+    (0xAC8D, 0xACA7, L::H3),
+    (0xACA8, 0xACA8, L::H2),
+    (0xACA9, 0xACC3, L::H3),
+    (0xACC4, 0xACC4, L::H2),
+    (0xACC5, 0xACDF, L::H3),
+    (0xACE0, 0xACE0, L::H2),
+    (0xACE1, 0xACFB, L::H3),
+    (0xACFC, 0xACFC, L::H2),
+    (0xACFD, 0xAD17, L::H3),
+    (0xAD18, 0xAD18, L::H2),
+    (0xAD19, 0xAD33, L::H3),
+    (0xAD34, 0xAD34, L::H2),
+    (0xAD35, 0xAD4F, L::H3),
+    (0xAD50, 0xAD50, L::H2),
+    (0xAD51, 0xAD6B, L::H3),
+    (0xAD6C, 0xAD6C, L::H2),
+    (0xAD6D, 0xAD87, L::H3),
+    (0xAD88, 0xAD88, L::H2),
+    (0xAD89, 0xADA3, L::H3),
+    (0xADA4, 0xADA4, L::H2),
+    (0xADA5, 0xADBF, L::H3),
+    (0xADC0, 0xADC0, L::H2),
+    (0xADC1, 0xADDB, L::H3),
+    (0xADDC, 0xADDC, L::H2),
+    (0xADDD, 0xADF7, L::H3),
+    (0xADF8, 0xADF8, L::H2),
+    (0xADF9, 0xAE13, L::H3),
+    (0xAE14, 0xAE14, L::H2),
+    (0xAE15, 0xAE2F, L::H3),
+    (0xAE30, 0xAE30, L::H2),
+    (0xAE31, 0xAE4B, L::H3),
+    (0xAE4C, 0xAE4C, L::H2),
+    (0xAE4D, 0xAE67, L::H3),
+    (0xAE68, 0xAE68, L::H2),
+    (0xAE69, 0xAE83, L::H3),
+    (0xAE84, 0xAE84, L::H2),
+    (0xAE85, 0xAE9F, L::H3),
+    (0xAEA0, 0xAEA0, L::H2),
+    (0xAEA1, 0xAEBB, L::H3),
+    (0xAEBC, 0xAEBC, L::H2),
+    (0xAEBD, 0xAED7, L::H3),
+    (0xAED8, 0xAED8, L::H2),
+    (0xAED9, 0xAEF3, L::H3),
+    (0xAEF4, 0xAEF4, L::H2),
+    (0xAEF5, 0xAF0F, L::H3),
+    (0xAF10, 0xAF10, L::H2),
+    (0xAF11, 0xAF2B, L::H3),
+    (0xAF2C, 0xAF2C, L::H2),
+    (0xAF2D, 0xAF47, L::H3),
+    (0xAF48, 0xAF48, L::H2),
+    (0xAF49, 0xAF63, L::H3),
+    (0xAF64, 0xAF64, L::H2),
+    (0xAF65, 0xAF7F, L::H3),
+    (0xAF80, 0xAF80, L::H2),
+    (0xAF81, 0xAF9B, L::H3),
+    (0xAF9C, 0xAF9C, L::H2),
+    (0xAF9D, 0xAFB7, L::H3),
+    (0xAFB8, 0xAFB8, L::H2),
+    (0xAFB9, 0xAFD3, L::H3),
+    (0xAFD4, 0xAFD4, L::H2),
+    (0xAFD5, 0xAFEF, L::H3),
+    (0xAFF0, 0xAFF0, L::H2),
+    (0xAFF1, 0xB00B, L::H3),
+    (0xB00C, 0xB00C, L::H2),
+    (0xB00D, 0xB027, L::H3),
+    (0xB028, 0xB028, L::H2),
+    (0xB029, 0xB043, L::H3),
+    (0xB044, 0xB044, L::H2),
+    (0xB045, 0xB05F, L::H3),
+    (0xB060, 0xB060, L::H2),
+    (0xB061, 0xB07B, L::H3),
+    (0xB07C, 0xB07C, L::H2),
+    (0xB07D, 0xB097, L::H3),
+    (0xB098, 0xB098, L::H2),
+    (0xB099, 0xB0B3, L::H3),
+    (0xB0B4, 0xB0B4, L::H2),
+    (0xB0B5, 0xB0CF, L::H3),
+    (0xB0D0, 0xB0D0, L::H2),
+    (0xB0D1, 0xB0EB, L::H3),
+    (0xB0EC, 0xB0EC, L::H2),
+    (0xB0ED, 0xB107, L::H3),
+    (0xB108, 0xB108, L::H2),
+    (0xB109, 0xB123, L::H3),
+    (0xB124, 0xB124, L::H2),
+    (0xB125, 0xB13F, L::H3),
+    (0xB140, 0xB140, L::H2),
+    (0xB141, 0xB15B, L::H3),
+    (0xB15C, 0xB15C, L::H2),
+    (0xB15D, 0xB177, L::H3),
+    (0xB178, 0xB178, L::H2),
+    (0xB179, 0xB193, L::H3),
+    (0xB194, 0xB194, L::H2),
+    (0xB195, 0xB1AF, L::H3),
+    (0xB1B0, 0xB1B0, L::H2),
+    (0xB1B1, 0xB1CB, L::H3),
+    (0xB1CC, 0xB1CC, L::H2),
+    (0xB1CD, 0xB1E7, L::H3),
+    (0xB1E8, 0xB1E8, L::H2),
+    (0xB1E9, 0xB203, L::H3),
+    (0xB204, 0xB204, L::H2),
+    (0xB205, 0xB21F, L::H3),
+    (0xB220, 0xB220, L::H2),
+    (0xB221, 0xB23B, L::H3),
+    (0xB23C, 0xB23C, L::H2),
+    (0xB23D, 0xB257, L::H3),
+    (0xB258, 0xB258, L::H2),
+    (0xB259, 0xB273, L::H3),
+    (0xB274, 0xB274, L::H2),
+    (0xB275, 0xB28F, L::H3),
+    (0xB290, 0xB290, L::H2),
+    (0xB291, 0xB2AB, L::H3),
+    (0xB2AC, 0xB2AC, L::H2),
+    (0xB2AD, 0xB2C7, L::H3),
+    (0xB2C8, 0xB2C8, L::H2),
+    (0xB2C9, 0xB2E3, L::H3),
+    (0xB2E4, 0xB2E4, L::H2),
+    (0xB2E5, 0xB2FF, L::H3),
+    (0xB300, 0xB300, L::H2),
+    (0xB301, 0xB31B, L::H3),
+    (0xB31C, 0xB31C, L::H2),
+    (0xB31D, 0xB337, L::H3),
+    (0xB338, 0xB338, L::H2),
+    (0xB339, 0xB353, L::H3),
+    (0xB354, 0xB354, L::H2),
+    (0xB355, 0xB36F, L::H3),
+    (0xB370, 0xB370, L::H2),
+    (0xB371, 0xB38B, L::H3),
+    (0xB38C, 0xB38C, L::H2),
+    (0xB38D, 0xB3A7, L::H3),
+    (0xB3A8, 0xB3A8, L::H2),
+    (0xB3A9, 0xB3C3, L::H3),
+    (0xB3C4, 0xB3C4, L::H2),
+    (0xB3C5, 0xB3DF, L::H3),
+    (0xB3E0, 0xB3E0, L::H2),
+    (0xB3E1, 0xB3FB, L::H3),
+    (0xB3FC, 0xB3FC, L::H2),
+    (0xB3FD, 0xB417, L::H3),
+    (0xB418, 0xB418, L::H2),
+    (0xB419, 0xB433, L::H3),
+    (0xB434, 0xB434, L::H2),
+    (0xB435, 0xB44F, L::H3),
+    (0xB450, 0xB450, L::H2),
+    (0xB451, 0xB46B, L::H3),
+    (0xB46C, 0xB46C, L::H2),
+    (0xB46D, 0xB487, L::H3),
+    (0xB488, 0xB488, L::H2),
+    (0xB489, 0xB4A3, L::H3),
+    (0xB4A4, 0xB4A4, L::H2),
+    (0xB4A5, 0xB4BF, L::H3),
+    (0xB4C0, 0xB4C0, L::H2),
+    (0xB4C1, 0xB4DB, L::H3),
+    (0xB4DC, 0xB4DC, L::H2),
+    (0xB4DD, 0xB4F7, L::H3),
+    (0xB4F8, 0xB4F8, L::H2),
+    (0xB4F9, 0xB513, L::H3),
+    (0xB514, 0xB514, L::H2),
+    (0xB515, 0xB52F, L::H3),
+    (0xB530, 0xB530, L::H2),
+    (0xB531, 0xB54B, L::H3),
+    (0xB54C, 0xB54C, L::H2),
+    (0xB54D, 0xB567, L::H3),
+    (0xB568, 0xB568, L::H2),
+    (0xB569, 0xB583, L::H3),
+    (0xB584, 0xB584, L::H2),
+    (0xB585, 0xB59F, L::H3),
+    (0xB5A0, 0xB5A0, L::H2),
+    (0xB5A1, 0xB5BB, L::H3),
+    (0xB5BC, 0xB5BC, L::H2),
+    (0xB5BD, 0xB5D7, L::H3),
+    (0xB5D8, 0xB5D8, L::H2),
+    (0xB5D9, 0xB5F3, L::H3),
+    (0xB5F4, 0xB5F4, L::H2),
+    (0xB5F5, 0xB60F, L::H3),
+    (0xB610, 0xB610, L::H2),
+    (0xB611, 0xB62B, L::H3),
+    (0xB62C, 0xB62C, L::H2),
+    (0xB62D, 0xB647, L::H3),
+    (0xB648, 0xB648, L::H2),
+    (0xB649, 0xB663, L::H3),
+    (0xB664, 0xB664, L::H2),
+    (0xB665, 0xB67F, L::H3),
+    (0xB680, 0xB680, L::H2),
+    (0xB681, 0xB69B, L::H3),
+    (0xB69C, 0xB69C, L::H2),
+    (0xB69D, 0xB6B7, L::H3),
+    (0xB6B8, 0xB6B8, L::H2),
+    (0xB6B9, 0xB6D3, L::H3),
+    (0xB6D4, 0xB6D4, L::H2),
+    (0xB6D5, 0xB6EF, L::H3),
+    (0xB6F0, 0xB6F0, L::H2),
+    (0xB6F1, 0xB70B, L::H3),
+    (0xB70C, 0xB70C, L::H2),
+    (0xB70D, 0xB727, L::H3),
+    (0xB728, 0xB728, L::H2),
+    (0xB729, 0xB743, L::H3),
+    (0xB744, 0xB744, L::H2),
+    (0xB745, 0xB75F, L::H3),
+    (0xB760, 0xB760, L::H2),
+    (0xB761, 0xB77B, L::H3),
+    (0xB77C, 0xB77C, L::H2),
+    (0xB77D, 0xB797, L::H3),
+    (0xB798, 0xB798, L::H2),
+    (0xB799, 0xB7B3, L::H3),
+    (0xB7B4, 0xB7B4, L::H2),
+    (0xB7B5, 0xB7CF, L::H3),
+    (0xB7D0, 0xB7D0, L::H2),
+    (0xB7D1, 0xB7EB, L::H3),
+    (0xB7EC, 0xB7EC, L::H2),
+    (0xB7ED, 0xB807, L::H3),
+    (0xB808, 0xB808, L::H2),
+    (0xB809, 0xB823, L::H3),
+    (0xB824, 0xB824, L::H2),
+    (0xB825, 0xB83F, L::H3),
+    (0xB840, 0xB840, L::H2),
+    (0xB841, 0xB85B, L::H3),
+    (0xB85C, 0xB85C, L::H2),
+    (0xB85D, 0xB877, L::H3),
+    (0xB878, 0xB878, L::H2),
+    (0xB879, 0xB893, L::H3),
+    (0xB894, 0xB894, L::H2),
+    (0xB895, 0xB8AF, L::H3),
+    (0xB8B0, 0xB8B0, L::H2),
+    (0xB8B1, 0xB8CB, L::H3),
+    (0xB8CC, 0xB8CC, L::H2),
+    (0xB8CD, 0xB8E7, L::H3),
+    (0xB8E8, 0xB8E8, L::H2),
+    (0xB8E9, 0xB903, L::H3),
+    (0xB904, 0xB904, L::H2),
+    (0xB905, 0xB91F, L::H3),
+    (0xB920, 0xB920, L::H2),
+    (0xB921, 0xB93B, L::H3),
+    (0xB93C, 0xB93C, L::H2),
+    (0xB93D, 0xB957, L::H3),
+    (0xB958, 0xB958, L::H2),
+    (0xB959, 0xB973, L::H3),
+    (0xB974, 0xB974, L::H2),
+    (0xB975, 0xB98F, L::H3),
+    (0xB990, 0xB990, L::H2),
+    (0xB991, 0xB9AB, L::H3),
+    (0xB9AC, 0xB9AC, L::H2),
+    (0xB9AD, 0xB9C7, L::H3),
+    (0xB9C8, 0xB9C8, L::H2),
+    (0xB9C9, 0xB9E3, L::H3),
+    (0xB9E4, 0xB9E4, L::H2),
+    (0xB9E5, 0xB9FF, L::H3),
+    (0xBA00, 0xBA00, L::H2),
+    (0xBA01, 0xBA1B, L::H3),
+    (0xBA1C, 0xBA1C, L::H2),
+    (0xBA1D, 0xBA37, L::H3),
+    (0xBA38, 0xBA38, L::H2),
+    (0xBA39, 0xBA53, L::H3),
+    (0xBA54, 0xBA54, L::H2),
+    (0xBA55, 0xBA6F, L::H3),
+    (0xBA70, 0xBA70, L::H2),
+    (0xBA71, 0xBA8B, L::H3),
+    (0xBA8C, 0xBA8C, L::H2),
+    (0xBA8D, 0xBAA7, L::H3),
+    (0xBAA8, 0xBAA8, L::H2),
+    (0xBAA9, 0xBAC3, L::H3),
+    (0xBAC4, 0xBAC4, L::H2),
+    (0xBAC5, 0xBADF, L::H3),
+    (0xBAE0, 0xBAE0, L::H2),
+    (0xBAE1, 0xBAFB, L::H3),
+    (0xBAFC, 0xBAFC, L::H2),
+    (0xBAFD, 0xBB17, L::H3),
+    (0xBB18, 0xBB18, L::H2),
+    (0xBB19, 0xBB33, L::H3),
+    (0xBB34, 0xBB34, L::H2),
+    (0xBB35, 0xBB4F, L::H3),
+    (0xBB50, 0xBB50, L::H2),
+    (0xBB51, 0xBB6B, L::H3),
+    (0xBB6C, 0xBB6C, L::H2),
+    (0xBB6D, 0xBB87, L::H3),
+    (0xBB88, 0xBB88, L::H2),
+    (0xBB89, 0xBBA3, L::H3),
+    (0xBBA4, 0xBBA4, L::H2),
+    (0xBBA5, 0xBBBF, L::H3),
+    (0xBBC0, 0xBBC0, L::H2),
+    (0xBBC1, 0xBBDB, L::H3),
+    (0xBBDC, 0xBBDC, L::H2),
+    (0xBBDD, 0xBBF7, L::H3),
+    (0xBBF8, 0xBBF8, L::H2),
+    (0xBBF9, 0xBC13, L::H3),
+    (0xBC14, 0xBC14, L::H2),
+    (0xBC15, 0xBC2F, L::H3),
+    (0xBC30, 0xBC30, L::H2),
+    (0xBC31, 0xBC4B, L::H3),
+    (0xBC4C, 0xBC4C, L::H2),
+    (0xBC4D, 0xBC67, L::H3),
+    (0xBC68, 0xBC68, L::H2),
+    (0xBC69, 0xBC83, L::H3),
+    (0xBC84, 0xBC84, L::H2),
+    (0xBC85, 0xBC9F, L::H3),
+    (0xBCA0, 0xBCA0, L::H2),
+    (0xBCA1, 0xBCBB, L::H3),
+    (0xBCBC, 0xBCBC, L::H2),
+    (0xBCBD, 0xBCD7, L::H3),
+    (0xBCD8, 0xBCD8, L::H2),
+    (0xBCD9, 0xBCF3, L::H3),
+    (0xBCF4, 0xBCF4, L::H2),
+    (0xBCF5, 0xBD0F, L::H3),
+    (0xBD10, 0xBD10, L::H2),
+    (0xBD11, 0xBD2B, L::H3),
+    (0xBD2C, 0xBD2C, L::H2),
+    (0xBD2D, 0xBD47, L::H3),
+    (0xBD48, 0xBD48, L::H2),
+    (0xBD49, 0xBD63, L::H3),
+    (0xBD64, 0xBD64, L::H2),
+    (0xBD65, 0xBD7F, L::H3),
+    (0xBD80, 0xBD80, L::H2),
+    (0xBD81, 0xBD9B, L::H3),
+    (0xBD9C, 0xBD9C, L::H2),
+    (0xBD9D, 0xBDB7, L::H3),
+    (0xBDB8, 0xBDB8, L::H2),
+    (0xBDB9, 0xBDD3, L::H3),
+    (0xBDD4, 0xBDD4, L::H2),
+    (0xBDD5, 0xBDEF, L::H3),
+    (0xBDF0, 0xBDF0, L::H2),
+    (0xBDF1, 0xBE0B, L::H3),
+    (0xBE0C, 0xBE0C, L::H2),
+    (0xBE0D, 0xBE27, L::H3),
+    (0xBE28, 0xBE28, L::H2),
+    (0xBE29, 0xBE43, L::H3),
+    (0xBE44, 0xBE44, L::H2),
+    (0xBE45, 0xBE5F, L::H3),
+    (0xBE60, 0xBE60, L::H2),
+    (0xBE61, 0xBE7B, L::H3),
+    (0xBE7C, 0xBE7C, L::H2),
+    (0xBE7D, 0xBE97, L::H3),
+    (0xBE98, 0xBE98, L::H2),
+    (0xBE99, 0xBEB3, L::H3),
+    (0xBEB4, 0xBEB4, L::H2),
+    (0xBEB5, 0xBECF, L::H3),
+    (0xBED0, 0xBED0, L::H2),
+    (0xBED1, 0xBEEB, L::H3),
+    (0xBEEC, 0xBEEC, L::H2),
+    (0xBEED, 0xBF07, L::H3),
+    (0xBF08, 0xBF08, L::H2),
+    (0xBF09, 0xBF23, L::H3),
+    (0xBF24, 0xBF24, L::H2),
+    (0xBF25, 0xBF3F, L::H3),
+    (0xBF40, 0xBF40, L::H2),
+    (0xBF41, 0xBF5B, L::H3),
+    (0xBF5C, 0xBF5C, L::H2),
+    (0xBF5D, 0xBF77, L::H3),
+    (0xBF78, 0xBF78, L::H2),
+    (0xBF79, 0xBF93, L::H3),
+    (0xBF94, 0xBF94, L::H2),
+    (0xBF95, 0xBFAF, L::H3),
+    (0xBFB0, 0xBFB0, L::H2),
+    (0xBFB1, 0xBFCB, L::H3),
+    (0xBFCC, 0xBFCC, L::H2),
+    (0xBFCD, 0xBFE7, L::H3),
+    (0xBFE8, 0xBFE8, L::H2),
+    (0xBFE9, 0xC003, L::H3),
+    (0xC004, 0xC004, L::H2),
+    (0xC005, 0xC01F, L::H3),
+    (0xC020, 0xC020, L::H2),
+    (0xC021, 0xC03B, L::H3),
+    (0xC03C, 0xC03C, L::H2),
+    (0xC03D, 0xC057, L::H3),
+    (0xC058, 0xC058, L::H2),
+    (0xC059, 0xC073, L::H3),
+    (0xC074, 0xC074, L::H2),
+    (0xC075, 0xC08F, L::H3),
+    (0xC090, 0xC090, L::H2),
+    (0xC091, 0xC0AB, L::H3),
+    (0xC0AC, 0xC0AC, L::H2),
+    (0xC0AD, 0xC0C7, L::H3),
+    (0xC0C8, 0xC0C8, L::H2),
+    (0xC0C9, 0xC0E3, L::H3),
+    (0xC0E4, 0xC0E4, L::H2),
+    (0xC0E5, 0xC0FF, L::H3),
+    (0xC100, 0xC100, L::H2),
+    (0xC101, 0xC11B, L::H3),
+    (0xC11C, 0xC11C, L::H2),
+    (0xC11D, 0xC137, L::H3),
+    (0xC138, 0xC138, L::H2),
+    (0xC139, 0xC153, L::H3),
+    (0xC154, 0xC154, L::H2),
+    (0xC155, 0xC16F, L::H3),
+    (0xC170, 0xC170, L::H2),
+    (0xC171, 0xC18B, L::H3),
+    (0xC18C, 0xC18C, L::H2),
+    (0xC18D, 0xC1A7, L::H3),
+    (0xC1A8, 0xC1A8, L::H2),
+    (0xC1A9, 0xC1C3, L::H3),
+    (0xC1C4, 0xC1C4, L::H2),
+    (0xC1C5, 0xC1DF, L::H3),
+    (0xC1E0, 0xC1E0, L::H2),
+    (0xC1E1, 0xC1FB, L::H3),
+    (0xC1FC, 0xC1FC, L::H2),
+    (0xC1FD, 0xC217, L::H3),
+    (0xC218, 0xC218, L::H2),
+    (0xC219, 0xC233, L::H3),
+    (0xC234, 0xC234, L::H2),
+    (0xC235, 0xC24F, L::H3),
+    (0xC250, 0xC250, L::H2),
+    (0xC251, 0xC26B, L::H3),
+    (0xC26C, 0xC26C, L::H2),
+    (0xC26D, 0xC287, L::H3),
+    (0xC288, 0xC288, L::H2),
+    (0xC289, 0xC2A3, L::H3),
+    (0xC2A4, 0xC2A4, L::H2),
+    (0xC2A5, 0xC2BF, L::H3),
+    (0xC2C0, 0xC2C0, L::H2),
+    (0xC2C1, 0xC2DB, L::H3),
+    (0xC2DC, 0xC2DC, L::H2),
+    (0xC2DD, 0xC2F7, L::H3),
+    (0xC2F8, 0xC2F8, L::H2),
+    (0xC2F9, 0xC313, L::H3),
+    (0xC314, 0xC314, L::H2),
+    (0xC315, 0xC32F, L::H3),
+    (0xC330, 0xC330, L::H2),
+    (0xC331, 0xC34B, L::H3),
+    (0xC34C, 0xC34C, L::H2),
+    (0xC34D, 0xC367, L::H3),
+    (0xC368, 0xC368, L::H2),
+    (0xC369, 0xC383, L::H3),
+    (0xC384, 0xC384, L::H2),
+    (0xC385, 0xC39F, L::H3),
+    (0xC3A0, 0xC3A0, L::H2),
+    (0xC3A1, 0xC3BB, L::H3),
+    (0xC3BC, 0xC3BC, L::H2),
+    (0xC3BD, 0xC3D7, L::H3),
+    (0xC3D8, 0xC3D8, L::H2),
+    (0xC3D9, 0xC3F3, L::H3),
+    (0xC3F4, 0xC3F4, L::H2),
+    (0xC3F5, 0xC40F, L::H3),
+    (0xC410, 0xC410, L::H2),
+    (0xC411, 0xC42B, L::H3),
+    (0xC42C, 0xC42C, L::H2),
+    (0xC42D, 0xC447, L::H3),
+    (0xC448, 0xC448, L::H2),
+    (0xC449, 0xC463, L::H3),
+    (0xC464, 0xC464, L::H2),
+    (0xC465, 0xC47F, L::H3),
+    (0xC480, 0xC480, L::H2),
+    (0xC481, 0xC49B, L::H3),
+    (0xC49C, 0xC49C, L::H2),
+    (0xC49D, 0xC4B7, L::H3),
+    (0xC4B8, 0xC4B8, L::H2),
+    (0xC4B9, 0xC4D3, L::H3),
+    (0xC4D4, 0xC4D4, L::H2),
+    (0xC4D5, 0xC4EF, L::H3),
+    (0xC4F0, 0xC4F0, L::H2),
+    (0xC4F1, 0xC50B, L::H3),
+    (0xC50C, 0xC50C, L::H2),
+    (0xC50D, 0xC527, L::H3),
+    (0xC528, 0xC528, L::H2),
+    (0xC529, 0xC543, L::H3),
+    (0xC544, 0xC544, L::H2),
+    (0xC545, 0xC55F, L::H3),
+    (0xC560, 0xC560, L::H2),
+    (0xC561, 0xC57B, L::H3),
+    (0xC57C, 0xC57C, L::H2),
+    (0xC57D, 0xC597, L::H3),
+    (0xC598, 0xC598, L::H2),
+    (0xC599, 0xC5B3, L::H3),
+    (0xC5B4, 0xC5B4, L::H2),
+    (0xC5B5, 0xC5CF, L::H3),
+    (0xC5D0, 0xC5D0, L::H2),
+    (0xC5D1, 0xC5EB, L::H3),
+    (0xC5EC, 0xC5EC, L::H2),
+    (0xC5ED, 0xC607, L::H3),
+    (0xC608, 0xC608, L::H2),
+    (0xC609, 0xC623, L::H3),
+    (0xC624, 0xC624, L::H2),
+    (0xC625, 0xC63F, L::H3),
+    (0xC640, 0xC640, L::H2),
+    (0xC641, 0xC65B, L::H3),
+    (0xC65C, 0xC65C, L::H2),
+    (0xC65D, 0xC677, L::H3),
+    (0xC678, 0xC678, L::H2),
+    (0xC679, 0xC693, L::H3),
+    (0xC694, 0xC694, L::H2),
+    (0xC695, 0xC6AF, L::H3),
+    (0xC6B0, 0xC6B0, L::H2),
+    (0xC6B1, 0xC6CB, L::H3),
+    (0xC6CC, 0xC6CC, L::H2),
+    (0xC6CD, 0xC6E7, L::H3),
+    (0xC6E8, 0xC6E8, L::H2),
+    (0xC6E9, 0xC703, L::H3),
+    (0xC704, 0xC704, L::H2),
+    (0xC705, 0xC71F, L::H3),
+    (0xC720, 0xC720, L::H2),
+    (0xC721, 0xC73B, L::H3),
+    (0xC73C, 0xC73C, L::H2),
+    (0xC73D, 0xC757, L::H3),
+    (0xC758, 0xC758, L::H2),
+    (0xC759, 0xC773, L::H3),
+    (0xC774, 0xC774, L::H2),
+    (0xC775, 0xC78F, L::H3),
+    (0xC790, 0xC790, L::H2),
+    (0xC791, 0xC7AB, L::H3),
+    (0xC7AC, 0xC7AC, L::H2),
+    (0xC7AD, 0xC7C7, L::H3),
+    (0xC7C8, 0xC7C8, L::H2),
+    (0xC7C9, 0xC7E3, L::H3),
+    (0xC7E4, 0xC7E4, L::H2),
+    (0xC7E5, 0xC7FF, L::H3),
+    (0xC800, 0xC800, L::H2),
+    (0xC801, 0xC81B, L::H3),
+    (0xC81C, 0xC81C, L::H2),
+    (0xC81D, 0xC837, L::H3),
+    (0xC838, 0xC838, L::H2),
+    (0xC839, 0xC853, L::H3),
+    (0xC854, 0xC854, L::H2),
+    (0xC855, 0xC86F, L::H3),
+    (0xC870, 0xC870, L::H2),
+    (0xC871, 0xC88B, L::H3),
+    (0xC88C, 0xC88C, L::H2),
+    (0xC88D, 0xC8A7, L::H3),
+    (0xC8A8, 0xC8A8, L::H2),
+    (0xC8A9, 0xC8C3, L::H3),
+    (0xC8C4, 0xC8C4, L::H2),
+    (0xC8C5, 0xC8DF, L::H3),
+    (0xC8E0, 0xC8E0, L::H2),
+    (0xC8E1, 0xC8FB, L::H3),
+    (0xC8FC, 0xC8FC, L::H2),
+    (0xC8FD, 0xC917, L::H3),
+    (0xC918, 0xC918, L::H2),
+    (0xC919, 0xC933, L::H3),
+    (0xC934, 0xC934, L::H2),
+    (0xC935, 0xC94F, L::H3),
+    (0xC950, 0xC950, L::H2),
+    (0xC951, 0xC96B, L::H3),
+    (0xC96C, 0xC96C, L::H2),
+    (0xC96D, 0xC987, L::H3),
+    (0xC988, 0xC988, L::H2),
+    (0xC989, 0xC9A3, L::H3),
+    (0xC9A4, 0xC9A4, L::H2),
+    (0xC9A5, 0xC9BF, L::H3),
+    (0xC9C0, 0xC9C0, L::H2),
+    (0xC9C1, 0xC9DB, L::H3),
+    (0xC9DC, 0xC9DC, L::H2),
+    (0xC9DD, 0xC9F7, L::H3),
+    (0xC9F8, 0xC9F8, L::H2),
+    (0xC9F9, 0xCA13, L::H3),
+    (0xCA14, 0xCA14, L::H2),
+    (0xCA15, 0xCA2F, L::H3),
+    (0xCA30, 0xCA30, L::H2),
+    (0xCA31, 0xCA4B, L::H3),
+    (0xCA4C, 0xCA4C, L::H2),
+    (0xCA4D, 0xCA67, L::H3),
+    (0xCA68, 0xCA68, L::H2),
+    (0xCA69, 0xCA83, L::H3),
+    (0xCA84, 0xCA84, L::H2),
+    (0xCA85, 0xCA9F, L::H3),
+    (0xCAA0, 0xCAA0, L::H2),
+    (0xCAA1, 0xCABB, L::H3),
+    (0xCABC, 0xCABC, L::H2),
+    (0xCABD, 0xCAD7, L::H3),
+    (0xCAD8, 0xCAD8, L::H2),
+    (0xCAD9, 0xCAF3, L::H3),
+    (0xCAF4, 0xCAF4, L::H2),
+    (0xCAF5, 0xCB0F, L::H3),
+    (0xCB10, 0xCB10, L::H2),
+    (0xCB11, 0xCB2B, L::H3),
+    (0xCB2C, 0xCB2C, L::H2),
+    (0xCB2D, 0xCB47, L::H3),
+    (0xCB48, 0xCB48, L::H2),
+    (0xCB49, 0xCB63, L::H3),
+    (0xCB64, 0xCB64, L::H2),
+    (0xCB65, 0xCB7F, L::H3),
+    (0xCB80, 0xCB80, L::H2),
+    (0xCB81, 0xCB9B, L::H3),
+    (0xCB9C, 0xCB9C, L::H2),
+    (0xCB9D, 0xCBB7, L::H3),
+    (0xCBB8, 0xCBB8, L::H2),
+    (0xCBB9, 0xCBD3, L::H3),
+    (0xCBD4, 0xCBD4, L::H2),
+    (0xCBD5, 0xCBEF, L::H3),
+    (0xCBF0, 0xCBF0, L::H2),
+    (0xCBF1, 0xCC0B, L::H3),
+    (0xCC0C, 0xCC0C, L::H2),
+    (0xCC0D, 0xCC27, L::H3),
+    (0xCC28, 0xCC28, L::H2),
+    (0xCC29, 0xCC43, L::H3),
+    (0xCC44, 0xCC44, L::H2),
+    (0xCC45, 0xCC5F, L::H3),
+    (0xCC60, 0xCC60, L::H2),
+    (0xCC61, 0xCC7B, L::H3),
+    (0xCC7C, 0xCC7C, L::H2),
+    (0xCC7D, 0xCC97, L::H3),
+    (0xCC98, 0xCC98, L::H2),
+    (0xCC99, 0xCCB3, L::H3),
+    (0xCCB4, 0xCCB4, L::H2),
+    (0xCCB5, 0xCCCF, L::H3),
+    (0xCCD0, 0xCCD0, L::H2),
+    (0xCCD1, 0xCCEB, L::H3),
+    (0xCCEC, 0xCCEC, L::H2),
+    (0xCCED, 0xCD07, L::H3),
+    (0xCD08, 0xCD08, L::H2),
+    (0xCD09, 0xCD23, L::H3),
+    (0xCD24, 0xCD24, L::H2),
+    (0xCD25, 0xCD3F, L::H3),
+    (0xCD40, 0xCD40, L::H2),
+    (0xCD41, 0xCD5B, L::H3),
+    (0xCD5C, 0xCD5C, L::H2),
+    (0xCD5D, 0xCD77, L::H3),
+    (0xCD78, 0xCD78, L::H2),
+    (0xCD79, 0xCD93, L::H3),
+    (0xCD94, 0xCD94, L::H2),
+    (0xCD95, 0xCDAF, L::H3),
+    (0xCDB0, 0xCDB0, L::H2),
+    (0xCDB1, 0xCDCB, L::H3),
+    (0xCDCC, 0xCDCC, L::H2),
+    (0xCDCD, 0xCDE7, L::H3),
+    (0xCDE8, 0xCDE8, L::H2),
+    (0xCDE9, 0xCE03, L::H3),
+    (0xCE04, 0xCE04, L::H2),
+    (0xCE05, 0xCE1F, L::H3),
+    (0xCE20, 0xCE20, L::H2),
+    (0xCE21, 0xCE3B, L::H3),
+    (0xCE3C, 0xCE3C, L::H2),
+    (0xCE3D, 0xCE57, L::H3),
+    (0xCE58, 0xCE58, L::H2),
+    (0xCE59, 0xCE73, L::H3),
+    (0xCE74, 0xCE74, L::H2),
+    (0xCE75, 0xCE8F, L::H3),
+    (0xCE90, 0xCE90, L::H2),
+    (0xCE91, 0xCEAB, L::H3),
+    (0xCEAC, 0xCEAC, L::H2),
+    (0xCEAD, 0xCEC7, L::H3),
+    (0xCEC8, 0xCEC8, L::H2),
+    (0xCEC9, 0xCEE3, L::H3),
+    (0xCEE4, 0xCEE4, L::H2),
+    (0xCEE5, 0xCEFF, L::H3),
+    (0xCF00, 0xCF00, L::H2),
+    (0xCF01, 0xCF1B, L::H3),
+    (0xCF1C, 0xCF1C, L::H2),
+    (0xCF1D, 0xCF37, L::H3),
+    (0xCF38, 0xCF38, L::H2),
+    (0xCF39, 0xCF53, L::H3),
+    (0xCF54, 0xCF54, L::H2),
+    (0xCF55, 0xCF6F, L::H3),
+    (0xCF70, 0xCF70, L::H2),
+    (0xCF71, 0xCF8B, L::H3),
+    (0xCF8C, 0xCF8C, L::H2),
+    (0xCF8D, 0xCFA7, L::H3),
+    (0xCFA8, 0xCFA8, L::H2),
+    (0xCFA9, 0xCFC3, L::H3),
+    (0xCFC4, 0xCFC4, L::H2),
+    (0xCFC5, 0xCFDF, L::H3),
+    (0xCFE0, 0xCFE0, L::H2),
+    (0xCFE1, 0xCFFB, L::H3),
+    (0xCFFC, 0xCFFC, L::H2),
+    (0xCFFD, 0xD017, L::H3),
+    (0xD018, 0xD018, L::H2),
+    (0xD019, 0xD033, L::H3),
+    (0xD034, 0xD034, L::H2),
+    (0xD035, 0xD04F, L::H3),
+    (0xD050, 0xD050, L::H2),
+    (0xD051, 0xD06B, L::H3),
+    (0xD06C, 0xD06C, L::H2),
+    (0xD06D, 0xD087, L::H3),
+    (0xD088, 0xD088, L::H2),
+    (0xD089, 0xD0A3, L::H3),
+    (0xD0A4, 0xD0A4, L::H2),
+    (0xD0A5, 0xD0BF, L::H3),
+    (0xD0C0, 0xD0C0, L::H2),
+    (0xD0C1, 0xD0DB, L::H3),
+    (0xD0DC, 0xD0DC, L::H2),
+    (0xD0DD, 0xD0F7, L::H3),
+    (0xD0F8, 0xD0F8, L::H2),
+    (0xD0F9, 0xD113, L::H3),
+    (0xD114, 0xD114, L::H2),
+    (0xD115, 0xD12F, L::H3),
+    (0xD130, 0xD130, L::H2),
+    (0xD131, 0xD14B, L::H3),
+    (0xD14C, 0xD14C, L::H2),
+    (0xD14D, 0xD167, L::H3),
+    (0xD168, 0xD168, L::H2),
+    (0xD169, 0xD183, L::H3),
+    (0xD184, 0xD184, L::H2),
+    (0xD185, 0xD19F, L::H3),
+    (0xD1A0, 0xD1A0, L::H2),
+    (0xD1A1, 0xD1BB, L::H3),
+    (0xD1BC, 0xD1BC, L::H2),
+    (0xD1BD, 0xD1D7, L::H3),
+    (0xD1D8, 0xD1D8, L::H2),
+    (0xD1D9, 0xD1F3, L::H3),
+    (0xD1F4, 0xD1F4, L::H2),
+    (0xD1F5, 0xD20F, L::H3),
+    (0xD210, 0xD210, L::H2),
+    (0xD211, 0xD22B, L::H3),
+    (0xD22C, 0xD22C, L::H2),
+    (0xD22D, 0xD247, L::H3),
+    (0xD248, 0xD248, L::H2),
+    (0xD249, 0xD263, L::H3),
+    (0xD264, 0xD264, L::H2),
+    (0xD265, 0xD27F, L::H3),
+    (0xD280, 0xD280, L::H2),
+    (0xD281, 0xD29B, L::H3),
+    (0xD29C, 0xD29C, L::H2),
+    (0xD29D, 0xD2B7, L::H3),
+    (0xD2B8, 0xD2B8, L::H2),
+    (0xD2B9, 0xD2D3, L::H3),
+    (0xD2D4, 0xD2D4, L::H2),
+    (0xD2D5, 0xD2EF, L::H3),
+    (0xD2F0, 0xD2F0, L::H2),
+    (0xD2F1, 0xD30B, L::H3),
+    (0xD30C, 0xD30C, L::H2),
+    (0xD30D, 0xD327, L::H3),
+    (0xD328, 0xD328, L::H2),
+    (0xD329, 0xD343, L::H3),
+    (0xD344, 0xD344, L::H2),
+    (0xD345, 0xD35F, L::H3),
+    (0xD360, 0xD360, L::H2),
+    (0xD361, 0xD37B, L::H3),
+    (0xD37C, 0xD37C, L::H2),
+    (0xD37D, 0xD397, L::H3),
+    (0xD398, 0xD398, L::H2),
+    (0xD399, 0xD3B3, L::H3),
+    (0xD3B4, 0xD3B4, L::H2),
+    (0xD3B5, 0xD3CF, L::H3),
+    (0xD3D0, 0xD3D0, L::H2),
+    (0xD3D1, 0xD3EB, L::H3),
+    (0xD3EC, 0xD3EC, L::H2),
+    (0xD3ED, 0xD407, L::H3),
+    (0xD408, 0xD408, L::H2),
+    (0xD409, 0xD423, L::H3),
+    (0xD424, 0xD424, L::H2),
+    (0xD425, 0xD43F, L::H3),
+    (0xD440, 0xD440, L::H2),
+    (0xD441, 0xD45B, L::H3),
+    (0xD45C, 0xD45C, L::H2),
+    (0xD45D, 0xD477, L::H3),
+    (0xD478, 0xD478, L::H2),
+    (0xD479, 0xD493, L::H3),
+    (0xD494, 0xD494, L::H2),
+    (0xD495, 0xD4AF, L::H3),
+    (0xD4B0, 0xD4B0, L::H2),
+    (0xD4B1, 0xD4CB, L::H3),
+    (0xD4CC, 0xD4CC, L::H2),
+    (0xD4CD, 0xD4E7, L::H3),
+    (0xD4E8, 0xD4E8, L::H2),
+    (0xD4E9, 0xD503, L::H3),
+    (0xD504, 0xD504, L::H2),
+    (0xD505, 0xD51F, L::H3),
+    (0xD520, 0xD520, L::H2),
+    (0xD521, 0xD53B, L::H3),
+    (0xD53C, 0xD53C, L::H2),
+    (0xD53D, 0xD557, L::H3),
+    (0xD558, 0xD558, L::H2),
+    (0xD559, 0xD573, L::H3),
+    (0xD574, 0xD574, L::H2),
+    (0xD575, 0xD58F, L::H3),
+    (0xD590, 0xD590, L::H2),
+    (0xD591, 0xD5AB, L::H3),
+    (0xD5AC, 0xD5AC, L::H2),
+    (0xD5AD, 0xD5C7, L::H3),
+    (0xD5C8, 0xD5C8, L::H2),
+    (0xD5C9, 0xD5E3, L::H3),
+    (0xD5E4, 0xD5E4, L::H2),
+    (0xD5E5, 0xD5FF, L::H3),
+    (0xD600, 0xD600, L::H2),
+    (0xD601, 0xD61B, L::H3),
+    (0xD61C, 0xD61C, L::H2),
+    (0xD61D, 0xD637, L::H3),
+    (0xD638, 0xD638, L::H2),
+    (0xD639, 0xD653, L::H3),
+    (0xD654, 0xD654, L::H2),
+    (0xD655, 0xD66F, L::H3),
+    (0xD670, 0xD670, L::H2),
+    (0xD671, 0xD68B, L::H3),
+    (0xD68C, 0xD68C, L::H2),
+    (0xD68D, 0xD6A7, L::H3),
+    (0xD6A8, 0xD6A8, L::H2),
+    (0xD6A9, 0xD6C3, L::H3),
+    (0xD6C4, 0xD6C4, L::H2),
+    (0xD6C5, 0xD6DF, L::H3),
+    (0xD6E0, 0xD6E0, L::H2),
+    (0xD6E1, 0xD6FB, L::H3),
+    (0xD6FC, 0xD6FC, L::H2),
+    (0xD6FD, 0xD717, L::H3),
+    (0xD718, 0xD718, L::H2),
+    (0xD719, 0xD733, L::H3),
+    (0xD734, 0xD734, L::H2),
+    (0xD735, 0xD74F, L::H3),
+    (0xD750, 0xD750, L::H2),
+    (0xD751, 0xD76B, L::H3),
+    (0xD76C, 0xD76C, L::H2),
+    (0xD76D, 0xD787, L::H3),
+    (0xD788, 0xD788, L::H2),
+    (0xD789, 0xD7A3, L::H3),
+    (0xD7B0, 0xD7C6, L::Jv),
+    (0xD7CB, 0xD7FB, L::Jt),
+    (0xD800, 0xDFFF, L::Surrogate),
+    (0xF900, 0xFAFF, L::Ideographic),
+    (0xFB00, 0xFB06, L::Alphabetic),
+    (0xFB13, 0xFB17, L::Alphabetic),
+    (0xFB1D, 0xFB1D, L::HebrewLetter),
+    (0xFB1E, 0xFB1E, L::CombiningMark),
+    (0xFB1F, 0xFB28, L::HebrewLetter),
+    (0xFB29, 0xFB29, L::Alphabetic),
+    (0xFB2A, 0xFB36, L::HebrewLetter),
+    (0xFB38, 0xFB3C, L::HebrewLetter),
+    (0xFB3E, 0xFB3E, L::HebrewLetter),
+    (0xFB40, 0xFB41, L::HebrewLetter),
+    (0xFB43, 0xFB44, L::HebrewLetter),
+    (0xFB46, 0xFB4F, L::HebrewLetter),
+    (0xFB50, 0xFBC2, L::Alphabetic),
+    (0xFBD3, 0xFD3D, L::Alphabetic),
+    (0xFD3E, 0xFD3E, L::ClosePunctuation),
+    (0xFD3F, 0xFD3F, L::OpenPunctuation),
+    (0xFD40, 0xFD8F, L::Alphabetic),
+    (0xFD92, 0xFDC7, L::Alphabetic),
+    (0xFDCF, 0xFDCF, L::Alphabetic),
+    (0xFDF0, 0xFDFB, L::Alphabetic),
+    (0xFDFC, 0xFDFC, L::PostfixNumeric),
+    (0xFDFD, 0xFDFF, L::Alphabetic),
+    (0xFE00, 0xFE0F, L::CombiningMark),
+    (0xFE10, 0xFE10, L::InfixNumeric),
+    (0xFE11, 0xFE12, L::ClosePunctuation),
+    (0xFE13, 0xFE14, L::InfixNumeric),
+    (0xFE15, 0xFE16, L::Exclamation),
+    (0xFE17, 0xFE17, L::OpenPunctuation),
+    (0xFE18, 0xFE18, L::ClosePunctuation),
+    (0xFE19, 0xFE19, L::Inseparable),
+    (0xFE20, 0xFE2F, L::CombiningMark),
+    (0xFE30, 0xFE34, L::Ideographic),
+    (0xFE35, 0xFE35, L::OpenPunctuation),
+    (0xFE36, 0xFE36, L::ClosePunctuation),
+    (0xFE37, 0xFE37, L::OpenPunctuation),
+    (0xFE38, 0xFE38, L::ClosePunctuation),
+    (0xFE39, 0xFE39, L::OpenPunctuation),
+    (0xFE3A, 0xFE3A, L::ClosePunctuation),
+    (0xFE3B, 0xFE3B, L::OpenPunctuation),
+    (0xFE3C, 0xFE3C, L::ClosePunctuation),
+    (0xFE3D, 0xFE3D, L::OpenPunctuation),
+    (0xFE3E, 0xFE3E, L::ClosePunctuation),
+    (0xFE3F, 0xFE3F, L::OpenPunctuation),
+    (0xFE40, 0xFE40, L::ClosePunctuation),
+    (0xFE41, 0xFE41, L::OpenPunctuation),
+    (0xFE42, 0xFE42, L::ClosePunctuation),
+    (0xFE43, 0xFE43, L::OpenPunctuation),
+    (0xFE44, 0xFE44, L::ClosePunctuation),
+    (0xFE45, 0xFE46, L::Ideographic),
+    (0xFE47, 0xFE47, L::OpenPunctuation),
+    (0xFE48, 0xFE48, L::ClosePunctuation),
+    (0xFE49, 0xFE4F, L::Ideographic),
+    (0xFE50, 0xFE50, L::ClosePunctuation),
+    (0xFE51, 0xFE51, L::Ideographic),
+    (0xFE52, 0xFE52, L::ClosePunctuation),
+    (0xFE54, 0xFE55, L::Nonstarter),
+    (0xFE56, 0xFE57, L::Exclamation),
+    (0xFE58, 0xFE58, L::Ideographic),
+    (0xFE59, 0xFE59, L::OpenPunctuation),
+    (0xFE5A, 0xFE5A, L::ClosePunctuation),
+    (0xFE5B, 0xFE5B, L::OpenPunctuation),
+    (0xFE5C, 0xFE5C, L::ClosePunctuation),
+    (0xFE5D, 0xFE5D, L::OpenPunctuation),
+    (0xFE5E, 0xFE5E, L::ClosePunctuation),
+    (0xFE5F, 0xFE66, L::Ideographic),
+    (0xFE68, 0xFE68, L::Ideographic),
+    (0xFE69, 0xFE69, L::PrefixNumeric),
+    (0xFE6A, 0xFE6A, L::PostfixNumeric),
+    (0xFE6B, 0xFE6B, L::Ideographic),
+    (0xFE70, 0xFE74, L::Alphabetic),
+    (0xFE76, 0xFEFC, L::Alphabetic),
+    (0xFEFF, 0xFEFF, L::WordJoiner),
+    (0xFF01, 0xFF01, L::Exclamation),
+    (0xFF02, 0xFF03, L::Ideographic),
+    (0xFF04, 0xFF04, L::PrefixNumeric),
+    (0xFF05, 0xFF05, L::PostfixNumeric),
+    (0xFF06, 0xFF07, L::Ideographic),
+    (0xFF08, 0xFF08, L::OpenPunctuation),
+    (0xFF09, 0xFF09, L::ClosePunctuation),
+    (0xFF0A, 0xFF0B, L::Ideographic),
+    (0xFF0C, 0xFF0C, L::ClosePunctuation),
+    (0xFF0D, 0xFF0D, L::Ideographic),
+    (0xFF0E, 0xFF0E, L::ClosePunctuation),
+    (0xFF0F, 0xFF19, L::Ideographic),
+    (0xFF1A, 0xFF1B, L::Nonstarter),
+    (0xFF1C, 0xFF1E, L::Ideographic),
+    (0xFF1F, 0xFF1F, L::Exclamation),
+    (0xFF20, 0xFF3A, L::Ideographic),
+    (0xFF3B, 0xFF3B, L::OpenPunctuation),
+    (0xFF3C, 0xFF3C, L::Ideographic),
+    (0xFF3D, 0xFF3D, L::ClosePunctuation),
+    (0xFF3E, 0xFF5A, L::Ideographic),
+    (0xFF5B, 0xFF5B, L::OpenPunctuation),
+    (0xFF5C, 0xFF5C, L::Ideographic),
+    (0xFF5D, 0xFF5D, L::ClosePunctuation),
+    (0xFF5E, 0xFF5E, L::Ideographic),
+    (0xFF5F, 0xFF5F, L::OpenPunctuation),
+    (0xFF60, 0xFF61, L::ClosePunctuation),
+    (0xFF62, 0xFF62, L::OpenPunctuation),
+    (0xFF63, 0xFF64, L::ClosePunctuation),
+    (0xFF65, 0xFF65, L::Nonstarter),
+    (0xFF66, 0xFF66, L::Ideographic),
+    (0xFF67, 0xFF70, L::ConditionalJapaneseStarter),
+    (0xFF71, 0xFF9D, L::Ideographic),
+    (0xFF9E, 0xFF9F, L::Nonstarter),
+    (0xFFA0, 0xFFBE, L::Ideographic),
+    (0xFFC2, 0xFFC7, L::Ideographic),
+    (0xFFCA, 0xFFCF, L::Ideographic),
+    (0xFFD2, 0xFFD7, L::Ideographic),
+    (0xFFDA, 0xFFDC, L::Ideographic),
+    (0xFFE0, 0xFFE0, L::PostfixNumeric),
+    (0xFFE1, 0xFFE1, L::PrefixNumeric),
+    (0xFFE2, 0xFFE4, L::Ideographic),
+    (0xFFE5, 0xFFE6, L::PrefixNumeric),
+    (0xFFE8, 0xFFEE, L::Alphabetic),
+    (0xFFF9, 0xFFFB, L::CombiningMark),
+    (0xFFFC, 0xFFFC, L::ContingentBreak),
+    (0xFFFD, 0xFFFD, L::Ambiguous),
+    (0x10000, 0x1000B, L::Alphabetic),
+    (0x1000D, 0x10026, L::Alphabetic),
+    (0x10028, 0x1003A, L::Alphabetic),
+    (0x1003C, 0x1003D, L::Alphabetic),
+    (0x1003F, 0x1004D, L::Alphabetic),
+    (0x10050, 0x1005D, L::Alphabetic),
+    (0x10080, 0x100FA, L::Alphabetic),
+    (0x10100, 0x10102, L::BreakAfter),
+    (0x10107, 0x10133, L::Alphabetic),
+    (0x10137, 0x1018E, L::Alphabetic),
+    (0x10190, 0x1019C, L::Alphabetic),
+    (0x101A0, 0x101A0, L::Alphabetic),
+    (0x101D0, 0x101FC, L::Alphabetic),
+    (0x101FD, 0x101FD, L::CombiningMark),
+    (0x10280, 0x1029C, L::Alphabetic),
+    (0x102A0, 0x102D0, L::Alphabetic),
+    (0x102E0, 0x102E0, L::CombiningMark),
+    (0x102E1, 0x102FB, L::Alphabetic),
+    (0x10300, 0x10323, L::Alphabetic),
+    (0x1032D, 0x1034A, L::Alphabetic),
+    (0x10350, 0x10375, L::Alphabetic),
+    (0x10376, 0x1037A, L::CombiningMark),
+    (0x10380, 0x1039D, L::Alphabetic),
+    (0x1039F, 0x1039F, L::BreakAfter),
+    (0x103A0, 0x103C3, L::Alphabetic),
+    (0x103C8, 0x103CF, L::Alphabetic),
+    (0x103D0, 0x103D0, L::BreakAfter),
+    (0x103D1, 0x103D5, L::Alphabetic),
+    (0x10400, 0x1049D, L::Alphabetic),
+    (0x104A0, 0x104A9, L::Numeric),
+    (0x104B0, 0x104D3, L::Alphabetic),
+    (0x104D8, 0x104FB, L::Alphabetic),
+    (0x10500, 0x10527, L::Alphabetic),
+    (0x10530, 0x10563, L::Alphabetic),
+    (0x1056F, 0x1057A, L::Alphabetic),
+    (0x1057C, 0x1058A, L::Alphabetic),
+    (0x1058C, 0x10592, L::Alphabetic),
+    (0x10594, 0x10595, L::Alphabetic),
+    (0x10597, 0x105A1, L::Alphabetic),
+    (0x105A3, 0x105B1, L::Alphabetic),
+    (0x105B3, 0x105B9, L::Alphabetic),
+    (0x105BB, 0x105BC, L::Alphabetic),
+    (0x10600, 0x10736, L::Alphabetic),
+    (0x10740, 0x10755, L::Alphabetic),
+    (0x10760, 0x10767, L::Alphabetic),
+    (0x10780, 0x10785, L::Alphabetic),
+    (0x10787, 0x107B0, L::Alphabetic),
+    (0x107B2, 0x107BA, L::Alphabetic),
+    (0x10800, 0x10805, L::Alphabetic),
+    (0x10808, 0x10808, L::Alphabetic),
+    (0x1080A, 0x10835, L::Alphabetic),
+    (0x10837, 0x10838, L::Alphabetic),
+    (0x1083C, 0x1083C, L::Alphabetic),
+    (0x1083F, 0x10855, L::Alphabetic),
+    (0x10857, 0x10857, L::BreakAfter),
+    (0x10858, 0x1089E, L::Alphabetic),
+    (0x108A7, 0x108AF, L::Alphabetic),
+    (0x108E0, 0x108F2, L::Alphabetic),
+    (0x108F4, 0x108F5, L::Alphabetic),
+    (0x108FB, 0x1091B, L::Alphabetic),
+    (0x1091F, 0x1091F, L::BreakAfter),
+    (0x10920, 0x10939, L::Alphabetic),
+    (0x1093F, 0x1093F, L::Alphabetic),
+    (0x10980, 0x109B7, L::Alphabetic),
+    (0x109BC, 0x109CF, L::Alphabetic),
+    (0x109D2, 0x10A00, L::Alphabetic),
+    (0x10A01, 0x10A03, L::CombiningMark),
+    (0x10A05, 0x10A06, L::CombiningMark),
+    (0x10A0C, 0x10A0F, L::CombiningMark),
+    (0x10A10, 0x10A13, L::Alphabetic),
+    (0x10A15, 0x10A17, L::Alphabetic),
+    (0x10A19, 0x10A35, L::Alphabetic),
+    (0x10A38, 0x10A3A, L::CombiningMark),
+    (0x10A3F, 0x10A3F, L::CombiningMark),
+    (0x10A40, 0x10A48, L::Alphabetic),
+    (0x10A50, 0x10A57, L::BreakAfter),
+    (0x10A58, 0x10A58, L::Alphabetic),
+    (0x10A60, 0x10A9F, L::Alphabetic),
+    (0x10AC0, 0x10AE4, L::Alphabetic),
+    (0x10AE5, 0x10AE6, L::CombiningMark),
+    (0x10AEB, 0x10AEF, L::Alphabetic),
+    (0x10AF0, 0x10AF5, L::BreakAfter),
+    (0x10AF6, 0x10AF6, L::Inseparable),
+    (0x10B00, 0x10B35, L::Alphabetic),
+    (0x10B39, 0x10B3F, L::BreakAfter),
+    (0x10B40, 0x10B55, L::Alphabetic),
+    (0x10B58, 0x10B72, L::Alphabetic),
+    (0x10B78, 0x10B91, L::Alphabetic),
+    (0x10B99, 0x10B9C, L::Alphabetic),
+    (0x10BA9, 0x10BAF, L::Alphabetic),
+    (0x10C00, 0x10C48, L::Alphabetic),
+    (0x10C80, 0x10CB2, L::Alphabetic),
+    (0x10CC0, 0x10CF2, L::Alphabetic),
+    (0x10CFA, 0x10D23, L::Alphabetic),
+    (0x10D24, 0x10D27, L::CombiningMark),
+    (0x10D30, 0x10D39, L::Numeric),
+    (0x10E60, 0x10E7E, L::Alphabetic),
+    (0x10E80, 0x10EA9, L::Alphabetic),
+    (0x10EAB, 0x10EAC, L::CombiningMark),
+    (0x10EAD, 0x10EAD, L::BreakAfter),
+    (0x10EB0, 0x10EB1, L::Alphabetic),
+    (0x10F00, 0x10F27, L::Alphabetic),
+    (0x10F30, 0x10F45, L::Alphabetic),
+    (0x10F46, 0x10F50, L::CombiningMark),
+    (0x10F51, 0x10F59, L::Alphabetic),
+    (0x10F70, 0x10F81, L::Alphabetic),
+    (0x10F82, 0x10F85, L::CombiningMark),
+    (0x10F86, 0x10F89, L::Alphabetic),
+    (0x10FB0, 0x10FCB, L::Alphabetic),
+    (0x10FE0, 0x10FF6, L::Alphabetic),
+    (0x11000, 0x11002, L::CombiningMark),
+    (0x11003, 0x11037, L::Alphabetic),
+    (0x11038, 0x11046, L::CombiningMark),
+    (0x11047, 0x11048, L::BreakAfter),
+    (0x11049, 0x1104D, L::Alphabetic),
+    (0x11052, 0x11065, L::Alphabetic),
+    (0x11066, 0x1106F, L::Numeric),
+    (0x11070, 0x11070, L::CombiningMark),
+    (0x11071, 0x11072, L::Alphabetic),
+    (0x11073, 0x11074, L::CombiningMark),
+    (0x11075, 0x11075, L::Alphabetic),
+    (0x1107F, 0x11082, L::CombiningMark),
+    (0x11083, 0x110AF, L::Alphabetic),
+    (0x110B0, 0x110BA, L::CombiningMark),
+    (0x110BB, 0x110BD, L::Alphabetic),
+    (0x110BE, 0x110C1, L::BreakAfter),
+    (0x110C2, 0x110C2, L::CombiningMark),
+    (0x110CD, 0x110CD, L::Alphabetic),
+    (0x110D0, 0x110E8, L::Alphabetic),
+    (0x110F0, 0x110F9, L::Numeric),
+    (0x11100, 0x11102, L::CombiningMark),
+    (0x11103, 0x11126, L::Alphabetic),
+    (0x11127, 0x11134, L::CombiningMark),
+    (0x11136, 0x1113F, L::Numeric),
+    (0x11140, 0x11143, L::BreakAfter),
+    (0x11144, 0x11144, L::Alphabetic),
+    (0x11145, 0x11146, L::CombiningMark),
+    (0x11147, 0x11147, L::Alphabetic),
+    (0x11150, 0x11172, L::Alphabetic),
+    (0x11173, 0x11173, L::CombiningMark),
+    (0x11174, 0x11174, L::Alphabetic),
+    (0x11175, 0x11175, L::BreakBefore),
+    (0x11176, 0x11176, L::Alphabetic),
+    (0x11180, 0x11182, L::CombiningMark),
+    (0x11183, 0x111B2, L::Alphabetic),
+    (0x111B3, 0x111C0, L::CombiningMark),
+    (0x111C1, 0x111C4, L::Alphabetic),
+    (0x111C5, 0x111C6, L::BreakAfter),
+    (0x111C7, 0x111C7, L::Alphabetic),
+    (0x111C8, 0x111C8, L::BreakAfter),
+    (0x111C9, 0x111CC, L::CombiningMark),
+    (0x111CD, 0x111CD, L::Alphabetic),
+    (0x111CE, 0x111CF, L::CombiningMark),
+    (0x111D0, 0x111D9, L::Numeric),
+    (0x111DA, 0x111DA, L::Alphabetic),
+    (0x111DB, 0x111DB, L::BreakBefore),
+    (0x111DC, 0x111DC, L::Alphabetic),
+    (0x111DD, 0x111DF, L::BreakAfter),
+    (0x111E1, 0x111F4, L::Alphabetic),
+    (0x11200, 0x11211, L::Alphabetic),
+    (0x11213, 0x1122B, L::Alphabetic),
+    (0x1122C, 0x11237, L::CombiningMark),
+    (0x11238, 0x11239, L::BreakAfter),
+    (0x1123A, 0x1123A, L::Alphabetic),
+    (0x1123B, 0x1123C, L::BreakAfter),
+    (0x1123D, 0x1123D, L::Alphabetic),
+    (0x1123E, 0x1123E, L::CombiningMark),
+    (0x11280, 0x11286, L::Alphabetic),
+    (0x11288, 0x11288, L::Alphabetic),
+    (0x1128A, 0x1128D, L::Alphabetic),
+    (0x1128F, 0x1129D, L::Alphabetic),
+    (0x1129F, 0x112A8, L::Alphabetic),
+    (0x112A9, 0x112A9, L::BreakAfter),
+    (0x112B0, 0x112DE, L::Alphabetic),
+    (0x112DF, 0x112EA, L::CombiningMark),
+    (0x112F0, 0x112F9, L::Numeric),
+    (0x11300, 0x11303, L::CombiningMark),
+    (0x11305, 0x1130C, L::Alphabetic),
+    (0x1130F, 0x11310, L::Alphabetic),
+    (0x11313, 0x11328, L::Alphabetic),
+    (0x1132A, 0x11330, L::Alphabetic),
+    (0x11332, 0x11333, L::Alphabetic),
+    (0x11335, 0x11339, L::Alphabetic),
+    (0x1133B, 0x1133C, L::CombiningMark),
+    (0x1133D, 0x1133D, L::Alphabetic),
+    (0x1133E, 0x11344, L::CombiningMark),
+    (0x11347, 0x11348, L::CombiningMark),
+    (0x1134B, 0x1134D, L::CombiningMark),
+    (0x11350, 0x11350, L::Alphabetic),
+    (0x11357, 0x11357, L::CombiningMark),
+    (0x1135D, 0x11361, L::Alphabetic),
+    (0x11362, 0x11363, L::CombiningMark),
+    (0x11366, 0x1136C, L::CombiningMark),
+    (0x11370, 0x11374, L::CombiningMark),
+    (0x11400, 0x11434, L::Alphabetic),
+    (0x11435, 0x11446, L::CombiningMark),
+    (0x11447, 0x1144A, L::Alphabetic),
+    (0x1144B, 0x1144E, L::BreakAfter),
+    (0x1144F, 0x1144F, L::Alphabetic),
+    (0x11450, 0x11459, L::Numeric),
+    (0x1145A, 0x1145B, L::BreakAfter),
+    (0x1145D, 0x1145D, L::Alphabetic),
+    (0x1145E, 0x1145E, L::CombiningMark),
+    (0x1145F, 0x11461, L::Alphabetic),
+    (0x11480, 0x114AF, L::Alphabetic),
+    (0x114B0, 0x114C3, L::CombiningMark),
+    (0x114C4, 0x114C7, L::Alphabetic),
+    (0x114D0, 0x114D9, L::Numeric),
+    (0x11580, 0x115AE, L::Alphabetic),
+    (0x115AF, 0x115B5, L::CombiningMark),
+    (0x115B8, 0x115C0, L::CombiningMark),
+    (0x115C1, 0x115C1, L::BreakBefore),
+    (0x115C2, 0x115C3, L::BreakAfter),
+    (0x115C4, 0x115C5, L::Exclamation),
+    (0x115C6, 0x115C8, L::Alphabetic),
+    (0x115C9, 0x115D7, L::BreakAfter),
+    (0x115D8, 0x115DB, L::Alphabetic),
+    (0x115DC, 0x115DD, L::CombiningMark),
+    (0x11600, 0x1162F, L::Alphabetic),
+    (0x11630, 0x11640, L::CombiningMark),
+    (0x11641, 0x11642, L::BreakAfter),
+    (0x11643, 0x11644, L::Alphabetic),
+    (0x11650, 0x11659, L::Numeric),
+    (0x11660, 0x1166C, L::BreakBefore),
+    (0x11680, 0x116AA, L::Alphabetic),
+    (0x116AB, 0x116B7, L::CombiningMark),
+    (0x116B8, 0x116B9, L::Alphabetic),
+    (0x116C0, 0x116C9, L::Numeric),
+    (0x11700, 0x1171A, L::ComplexContext),
+    (0x1171D, 0x1172B, L::ComplexContext),
+    (0x11730, 0x11739, L::Numeric),
+    (0x1173A, 0x1173B, L::ComplexContext),
+    (0x1173C, 0x1173E, L::BreakAfter),
+    (0x1173F, 0x11746, L::ComplexContext),
+    (0x11800, 0x1182B, L::Alphabetic),
+    (0x1182C, 0x1183A, L::CombiningMark),
+    (0x1183B, 0x1183B, L::Alphabetic),
+    (0x118A0, 0x118DF, L::Alphabetic),
+    (0x118E0, 0x118E9, L::Numeric),
+    (0x118EA, 0x118F2, L::Alphabetic),
+    (0x118FF, 0x11906, L::Alphabetic),
+    (0x11909, 0x11909, L::Alphabetic),
+    (0x1190C, 0x11913, L::Alphabetic),
+    (0x11915, 0x11916, L::Alphabetic),
+    (0x11918, 0x1192F, L::Alphabetic),
+    (0x11930, 0x11935, L::CombiningMark),
+    (0x11937, 0x11938, L::CombiningMark),
+    (0x1193B, 0x1193E, L::CombiningMark),
+    (0x1193F, 0x1193F, L::Alphabetic),
+    (0x11940, 0x11940, L::CombiningMark),
+    (0x11941, 0x11941, L::Alphabetic),
+    (0x11942, 0x11943, L::CombiningMark),
+    (0x11944, 0x11946, L::BreakAfter),
+    (0x11950, 0x11959, L::Numeric),
+    (0x119A0, 0x119A7, L::Alphabetic),
+    (0x119AA, 0x119D0, L::Alphabetic),
+    (0x119D1, 0x119D7, L::CombiningMark),
+    (0x119DA, 0x119E0, L::CombiningMark),
+    (0x119E1, 0x119E1, L::Alphabetic),
+    (0x119E2, 0x119E2, L::BreakBefore),
+    (0x119E3, 0x119E3, L::Alphabetic),
+    (0x119E4, 0x119E4, L::CombiningMark),
+    (0x11A00, 0x11A00, L::Alphabetic),
+    (0x11A01, 0x11A0A, L::CombiningMark),
+    (0x11A0B, 0x11A32, L::Alphabetic),
+    (0x11A33, 0x11A39, L::CombiningMark),
+    (0x11A3A, 0x11A3A, L::Alphabetic),
+    (0x11A3B, 0x11A3E, L::CombiningMark),
+    (0x11A3F, 0x11A3F, L::BreakBefore),
+    (0x11A40, 0x11A40, L::Alphabetic),
+    (0x11A41, 0x11A44, L::BreakAfter),
+    (0x11A45, 0x11A45, L::BreakBefore),
+    (0x11A46, 0x11A46, L::Alphabetic),
+    (0x11A47, 0x11A47, L::CombiningMark),
+    (0x11A50, 0x11A50, L::Alphabetic),
+    (0x11A51, 0x11A5B, L::CombiningMark),
+    (0x11A5C, 0x11A89, L::Alphabetic),
+    (0x11A8A, 0x11A99, L::CombiningMark),
+    (0x11A9A, 0x11A9C, L::BreakAfter),
+    (0x11A9D, 0x11A9D, L::Alphabetic),
+    (0x11A9E, 0x11AA0, L::BreakBefore),
+    (0x11AA1, 0x11AA2, L::BreakAfter),
+    (0x11AB0, 0x11AF8, L::Alphabetic),
+    (0x11C00, 0x11C08, L::Alphabetic),
+    (0x11C0A, 0x11C2E, L::Alphabetic),
+    (0x11C2F, 0x11C36, L::CombiningMark),
+    (0x11C38, 0x11C3F, L::CombiningMark),
+    (0x11C40, 0x11C40, L::Alphabetic),
+    (0x11C41, 0x11C45, L::BreakAfter),
+    (0x11C50, 0x11C59, L::Numeric),
+    (0x11C5A, 0x11C6C, L::Alphabetic),
+    (0x11C70, 0x11C70, L::BreakBefore),
+    (0x11C71, 0x11C71, L::Exclamation),
+    (0x11C72, 0x11C8F, L::Alphabetic),
+    (0x11C92, 0x11CA7, L::CombiningMark),
+    (0x11CA9, 0x11CB6, L::CombiningMark),
+    (0x11D00, 0x11D06, L::Alphabetic),
+    (0x11D08, 0x11D09, L::Alphabetic),
+    (0x11D0B, 0x11D30, L::Alphabetic),
+    (0x11D31, 0x11D36, L::CombiningMark),
+    (0x11D3A, 0x11D3A, L::CombiningMark),
+    (0x11D3C, 0x11D3D, L::CombiningMark),
+    (0x11D3F, 0x11D45, L::CombiningMark),
+    (0x11D46, 0x11D46, L::Alphabetic),
+    (0x11D47, 0x11D47, L::CombiningMark),
+    (0x11D50, 0x11D59, L::Numeric),
+    (0x11D60, 0x11D65, L::Alphabetic),
+    (0x11D67, 0x11D68, L::Alphabetic),
+    (0x11D6A, 0x11D89, L::Alphabetic),
+    (0x11D8A, 0x11D8E, L::CombiningMark),
+    (0x11D90, 0x11D91, L::CombiningMark),
+    (0x11D93, 0x11D97, L::CombiningMark),
+    (0x11D98, 0x11D98, L::Alphabetic),
+    (0x11DA0, 0x11DA9, L::Numeric),
+    (0x11EE0, 0x11EF2, L::Alphabetic),
+    (0x11EF3, 0x11EF6, L::CombiningMark),
+    (0x11EF7, 0x11EF8, L::Alphabetic),
+    (0x11FB0, 0x11FB0, L::Alphabetic),
+    (0x11FC0, 0x11FDC, L::Alphabetic),
+    (0x11FDD, 0x11FE0, L::PostfixNumeric),
+    (0x11FE1, 0x11FF1, L::Alphabetic),
+    (0x11FFF, 0x11FFF, L::BreakAfter),
+    (0x12000, 0x12399, L::Alphabetic),
+    (0x12400, 0x1246E, L::Alphabetic),
+    (0x12470, 0x12474, L::BreakAfter),
+    (0x12480, 0x12543, L::Alphabetic),
+    (0x12F90, 0x12FF2, L::Alphabetic),
+    (0x13000, 0x13257, L::Alphabetic),
+    (0x13258, 0x1325A, L::OpenPunctuation),
+    (0x1325B, 0x1325D, L::ClosePunctuation),
+    (0x1325E, 0x13281, L::Alphabetic),
+    (0x13282, 0x13282, L::ClosePunctuation),
+    (0x13283, 0x13285, L::Alphabetic),
+    (0x13286, 0x13286, L::OpenPunctuation),
+    (0x13287, 0x13287, L::ClosePunctuation),
+    (0x13288, 0x13288, L::OpenPunctuation),
+    (0x13289, 0x13289, L::ClosePunctuation),
+    (0x1328A, 0x13378, L::Alphabetic),
+    (0x13379, 0x13379, L::OpenPunctuation),
+    (0x1337A, 0x1337B, L::ClosePunctuation),
+    (0x1337C, 0x1342E, L::Alphabetic),
+    (0x13430, 0x13436, L::Glue),
+    (0x13437, 0x13437, L::OpenPunctuation),
+    (0x13438, 0x13438, L::ClosePunctuation),
+    (0x14400, 0x145CD, L::Alphabetic),
+    (0x145CE, 0x145CE, L::OpenPunctuation),
+    (0x145CF, 0x145CF, L::ClosePunctuation),
+    (0x145D0, 0x14646, L::Alphabetic),
+    (0x16800, 0x16A38, L::Alphabetic),
+    (0x16A40, 0x16A5E, L::Alphabetic),
+    (0x16A60, 0x16A69, L::Numeric),
+    (0x16A6E, 0x16A6F, L::BreakAfter),
+    (0x16A70, 0x16ABE, L::Alphabetic),
+    (0x16AC0, 0x16AC9, L::Numeric),
+    (0x16AD0, 0x16AED, L::Alphabetic),
+    (0x16AF0, 0x16AF4, L::CombiningMark),
+    (0x16AF5, 0x16AF5, L::BreakAfter),
+    (0x16B00, 0x16B2F, L::Alphabetic),
+    (0x16B30, 0x16B36, L::CombiningMark),
+    (0x16B37, 0x16B39, L::BreakAfter),
+    (0x16B3A, 0x16B43, L::Alphabetic),
+    (0x16B44, 0x16B44, L::BreakAfter),
+    (0x16B45, 0x16B45, L::Alphabetic),
+    (0x16B50, 0x16B59, L::Numeric),
+    (0x16B5B, 0x16B61, L::Alphabetic),
+    (0x16B63, 0x16B77, L::Alphabetic),
+    (0x16B7D, 0x16B8F, L::Alphabetic),
+    (0x16E40, 0x16E96, L::Alphabetic),
+    (0x16E97, 0x16E98, L::BreakAfter),
+    (0x16E99, 0x16E9A, L::Alphabetic),
+    (0x16F00, 0x16F4A, L::Alphabetic),
+    (0x16F4F, 0x16F4F, L::CombiningMark),
+    (0x16F50, 0x16F50, L::Alphabetic),
+    (0x16F51, 0x16F87, L::CombiningMark),
+    (0x16F8F, 0x16F92, L::CombiningMark),
+    (0x16F93, 0x16F9F, L::Alphabetic),
+    (0x16FE0, 0x16FE3, L::Nonstarter),
+    (0x16FE4, 0x16FE4, L::Glue),
+    (0x16FF0, 0x16FF1, L::CombiningMark),
+    (0x17000, 0x187F7, L::Ideographic),
+    (0x18800, 0x18AFF, L::Ideographic),
+    (0x18B00, 0x18CD5, L::Alphabetic),
+    (0x18D00, 0x18D08, L::Ideographic),
+    (0x1AFF0, 0x1AFF3, L::Alphabetic),
+    (0x1AFF5, 0x1AFFB, L::Alphabetic),
+    (0x1AFFD, 0x1AFFE, L::Alphabetic),
+    (0x1B000, 0x1B122, L::Ideographic),
+    (0x1B150, 0x1B152, L::ConditionalJapaneseStarter),
+    (0x1B164, 0x1B167, L::ConditionalJapaneseStarter),
+    (0x1B170, 0x1B2FB, L::Ideographic),
+    (0x1BC00, 0x1BC6A, L::Alphabetic),
+    (0x1BC70, 0x1BC7C, L::Alphabetic),
+    (0x1BC80, 0x1BC88, L::Alphabetic),
+    (0x1BC90, 0x1BC99, L::Alphabetic),
+    (0x1BC9C, 0x1BC9C, L::Alphabetic),
+    (0x1BC9D, 0x1BC9E, L::CombiningMark),
+    (0x1BC9F, 0x1BC9F, L::BreakAfter),
+    (0x1BCA0, 0x1BCA3, L::CombiningMark),
+    (0x1CF00, 0x1CF2D, L::CombiningMark),
+    (0x1CF30, 0x1CF46, L::CombiningMark),
+    (0x1CF50, 0x1CFC3, L::Alphabetic),
+    (0x1D000, 0x1D0F5, L::Alphabetic),
+    (0x1D100, 0x1D126, L::Alphabetic),
+    (0x1D129, 0x1D164, L::Alphabetic),
+    (0x1D165, 0x1D169, L::CombiningMark),
+    (0x1D16A, 0x1D16C, L::Alphabetic),
+    (0x1D16D, 0x1D182, L::CombiningMark),
+    (0x1D183, 0x1D184, L::Alphabetic),
+    (0x1D185, 0x1D18B, L::CombiningMark),
+    (0x1D18C, 0x1D1A9, L::Alphabetic),
+    (0x1D1AA, 0x1D1AD, L::CombiningMark),
+    (0x1D1AE, 0x1D1EA, L::Alphabetic),
+    (0x1D200, 0x1D241, L::Alphabetic),
+    (0x1D242, 0x1D244, L::CombiningMark),
+    (0x1D245, 0x1D245, L::Alphabetic),
+    (0x1D2E0, 0x1D2F3, L::Alphabetic),
+    (0x1D300, 0x1D356, L::Alphabetic),
+    (0x1D360, 0x1D378, L::Alphabetic),
+    (0x1D400, 0x1D454, L::Alphabetic),
+    (0x1D456, 0x1D49C, L::Alphabetic),
+    (0x1D49E, 0x1D49F, L::Alphabetic),
+    (0x1D4A2, 0x1D4A2, L::Alphabetic),
+    (0x1D4A5, 0x1D4A6, L::Alphabetic),
+    (0x1D4A9, 0x1D4AC, L::Alphabetic),
+    (0x1D4AE, 0x1D4B9, L::Alphabetic),
+    (0x1D4BB, 0x1D4BB, L::Alphabetic),
+    (0x1D4BD, 0x1D4C3, L::Alphabetic),
+    (0x1D4C5, 0x1D505, L::Alphabetic),
+    (0x1D507, 0x1D50A, L::Alphabetic),
+    (0x1D50D, 0x1D514, L::Alphabetic),
+    (0x1D516, 0x1D51C, L::Alphabetic),
+    (0x1D51E, 0x1D539, L::Alphabetic),
+    (0x1D53B, 0x1D53E, L::Alphabetic),
+    (0x1D540, 0x1D544, L::Alphabetic),
+    (0x1D546, 0x1D546, L::Alphabetic),
+    (0x1D54A, 0x1D550, L::Alphabetic),
+    (0x1D552, 0x1D6A5, L::Alphabetic),
+    (0x1D6A8, 0x1D7CB, L::Alphabetic),
+    (0x1D7CE, 0x1D7FF, L::Numeric),
+    (0x1D800, 0x1D9FF, L::Alphabetic),
+    (0x1DA00, 0x1DA36, L::CombiningMark),
+    (0x1DA37, 0x1DA3A, L::Alphabetic),
+    (0x1DA3B, 0x1DA6C, L::CombiningMark),
+    (0x1DA6D, 0x1DA74, L::Alphabetic),
+    (0x1DA75, 0x1DA75, L::CombiningMark),
+    (0x1DA76, 0x1DA83, L::Alphabetic),
+    (0x1DA84, 0x1DA84, L::CombiningMark),
+    (0x1DA85, 0x1DA86, L::Alphabetic),
+    (0x1DA87, 0x1DA8A, L::BreakAfter),
+    (0x1DA8B, 0x1DA8B, L::Alphabetic),
+    (0x1DA9B, 0x1DA9F, L::CombiningMark),
+    (0x1DAA1, 0x1DAAF, L::CombiningMark),
+    (0x1DF00, 0x1DF1E, L::Alphabetic),
+    (0x1E000, 0x1E006, L::CombiningMark),
+    (0x1E008, 0x1E018, L::CombiningMark),
+    (0x1E01B, 0x1E021, L::CombiningMark),
+    (0x1E023, 0x1E024, L::CombiningMark),
+    (0x1E026, 0x1E02A, L::CombiningMark),
+    (0x1E100, 0x1E12C, L::Alphabetic),
+    (0x1E130, 0x1E136, L::CombiningMark),
+    (0x1E137, 0x1E13D, L::Alphabetic),
+    (0x1E140, 0x1E149, L::Numeric),
+    (0x1E14E, 0x1E14F, L::Alphabetic),
+    (0x1E290, 0x1E2AD, L::Alphabetic),
+    (0x1E2AE, 0x1E2AE, L::CombiningMark),
+    (0x1E2C0, 0x1E2EB, L::Alphabetic),
+    (0x1E2EC, 0x1E2EF, L::CombiningMark),
+    (0x1E2F0, 0x1E2F9, L::Numeric),
+    (0x1E2FF, 0x1E2FF, L::PrefixNumeric),
+    (0x1E7E0, 0x1E7E6, L::Alphabetic),
+    (0x1E7E8, 0x1E7EB, L::Alphabetic),
+    (0x1E7ED, 0x1E7EE, L::Alphabetic),
+    (0x1E7F0, 0x1E7FE, L::Alphabetic),
+    (0x1E800, 0x1E8C4, L::Alphabetic),
+    (0x1E8C7, 0x1E8CF, L::Alphabetic),
+    (0x1E8D0, 0x1E8D6, L::CombiningMark),
+    (0x1E900, 0x1E943, L::Alphabetic),
+    (0x1E944, 0x1E94A, L::CombiningMark),
+    (0x1E94B, 0x1E94B, L::Alphabetic),
+    (0x1E950, 0x1E959, L::Numeric),
+    (0x1E95E, 0x1E95F, L::OpenPunctuation),
+    (0x1EC71, 0x1ECAB, L::Alphabetic),
+    (0x1ECAC, 0x1ECAC, L::PostfixNumeric),
+    (0x1ECAD, 0x1ECAF, L::Alphabetic),
+    (0x1ECB0, 0x1ECB0, L::PostfixNumeric),
+    (0x1ECB1, 0x1ECB4, L::Alphabetic),
+    (0x1ED01, 0x1ED3D, L::Alphabetic),
+    (0x1EE00, 0x1EE03, L::Alphabetic),
+    (0x1EE05, 0x1EE1F, L::Alphabetic),
+    (0x1EE21, 0x1EE22, L::Alphabetic),
+    (0x1EE24, 0x1EE24, L::Alphabetic),
+    (0x1EE27, 0x1EE27, L::Alphabetic),
+    (0x1EE29, 0x1EE32, L::Alphabetic),
+    (0x1EE34, 0x1EE37, L::Alphabetic),
+    (0x1EE39, 0x1EE39, L::Alphabetic),
+    (0x1EE3B, 0x1EE3B, L::Alphabetic),
+    (0x1EE42, 0x1EE42, L::Alphabetic),
+    (0x1EE47, 0x1EE47, L::Alphabetic),
+    (0x1EE49, 0x1EE49, L::Alphabetic),
+    (0x1EE4B, 0x1EE4B, L::Alphabetic),
+    (0x1EE4D, 0x1EE4F, L::Alphabetic),
+    (0x1EE51, 0x1EE52, L::Alphabetic),
+    (0x1EE54, 0x1EE54, L::Alphabetic),
+    (0x1EE57, 0x1EE57, L::Alphabetic),
+    (0x1EE59, 0x1EE59, L::Alphabetic),
+    (0x1EE5B, 0x1EE5B, L::Alphabetic),
+    (0x1EE5D, 0x1EE5D, L::Alphabetic),
+    (0x1EE5F, 0x1EE5F, L::Alphabetic),
+    (0x1EE61, 0x1EE62, L::Alphabetic),
+    (0x1EE64, 0x1EE64, L::Alphabetic),
+    (0x1EE67, 0x1EE6A, L::Alphabetic),
+    (0x1EE6C, 0x1EE72, L::Alphabetic),
+    (0x1EE74, 0x1EE77, L::Alphabetic),
+    (0x1EE79, 0x1EE7C, L::Alphabetic),
+    (0x1EE7E, 0x1EE7E, L::Alphabetic),
+    (0x1EE80, 0x1EE89, L::Alphabetic),
+    (0x1EE8B, 0x1EE9B, L::Alphabetic),
+    (0x1EEA1, 0x1EEA3, L::Alphabetic),
+    (0x1EEA5, 0x1EEA9, L::Alphabetic),
+    (0x1EEAB, 0x1EEBB, L::Alphabetic),
+    (0x1EEF0, 0x1EEF1, L::Alphabetic),
+    (0x1F000, 0x1F0FF, L::Ideographic),
+    (0x1F100, 0x1F10C, L::Ambiguous),
+    (0x1F10D, 0x1F10F, L::Ideographic),
+    (0x1F110, 0x1F12D, L::Ambiguous),
+    (0x1F12E, 0x1F12F, L::Alphabetic),
+    (0x1F130, 0x1F169, L::Ambiguous),
+    (0x1F16A, 0x1F16C, L::Alphabetic),
+    (0x1F16D, 0x1F16F, L::Ideographic),
+    (0x1F170, 0x1F1AC, L::Ambiguous),
+    (0x1F1AD, 0x1F1E5, L::Ideographic),
+    (0x1F1E6, 0x1F1FF, L::RegionalIndicator),
+    (0x1F200, 0x1F384, L::Ideographic),
+    (0x1F385, 0x1F385, L::EBase),
+    (0x1F386, 0x1F39B, L::Ideographic),
+    (0x1F39C, 0x1F39D, L::Alphabetic),
+    (0x1F39E, 0x1F3B4, L::Ideographic),
+    (0x1F3B5, 0x1F3B6, L::Alphabetic),
+    (0x1F3B7, 0x1F3BB, L::Ideographic),
+    (0x1F3BC, 0x1F3BC, L::Alphabetic),
+    (0x1F3BD, 0x1F3C1, L::Ideographic),
+    (0x1F3C2, 0x1F3C4, L::EBase),
+    (0x1F3C5, 0x1F3C6, L::Ideographic),
+    (0x1F3C7, 0x1F3C7, L::EBase),
+    (0x1F3C8, 0x1F3C9, L::Ideographic),
+    (0x1F3CA, 0x1F3CC, L::EBase),
+    (0x1F3CD, 0x1F3FA, L::Ideographic),
+    (0x1F3FB, 0x1F3FF, L::EModifier),
+    (0x1F400, 0x1F441, L::Ideographic),
+    (0x1F442, 0x1F443, L::EBase),
+    (0x1F444, 0x1F445, L::Ideographic),
+    (0x1F446, 0x1F450, L::EBase),
+    (0x1F451, 0x1F465, L::Ideographic),
+    (0x1F466, 0x1F478, L::EBase),
+    (0x1F479, 0x1F47B, L::Ideographic),
+    (0x1F47C, 0x1F47C, L::EBase),
+    (0x1F47D, 0x1F480, L::Ideographic),
+    (0x1F481, 0x1F483, L::EBase),
+    (0x1F484, 0x1F484, L::Ideographic),
+    (0x1F485, 0x1F487, L::EBase),
+    (0x1F488, 0x1F48E, L::Ideographic),
+    (0x1F48F, 0x1F48F, L::EBase),
+    (0x1F490, 0x1F490, L::Ideographic),
+    (0x1F491, 0x1F491, L::EBase),
+    (0x1F492, 0x1F49F, L::Ideographic),
+    (0x1F4A0, 0x1F4A0, L::Alphabetic),
+    (0x1F4A1, 0x1F4A1, L::Ideographic),
+    (0x1F4A2, 0x1F4A2, L::Alphabetic),
+    (0x1F4A3, 0x1F4A3, L::Ideographic),
+    (0x1F4A4, 0x1F4A4, L::Alphabetic),
+    (0x1F4A5, 0x1F4A9, L::Ideographic),
+    (0x1F4AA, 0x1F4AA, L::EBase),
+    (0x1F4AB, 0x1F4AE, L::Ideographic),
+    (0x1F4AF, 0x1F4AF, L::Alphabetic),
+    (0x1F4B0, 0x1F4B0, L::Ideographic),
+    (0x1F4B1, 0x1F4B2, L::Alphabetic),
+    (0x1F4B3, 0x1F4FF, L::Ideographic),
+    (0x1F500, 0x1F506, L::Alphabetic),
+    (0x1F507, 0x1F516, L::Ideographic),
+    (0x1F517, 0x1F524, L::Alphabetic),
+    (0x1F525, 0x1F531, L::Ideographic),
+    (0x1F532, 0x1F549, L::Alphabetic),
+    (0x1F54A, 0x1F573, L::Ideographic),
+    (0x1F574, 0x1F575, L::EBase),
+    (0x1F576, 0x1F579, L::Ideographic),
+    (0x1F57A, 0x1F57A, L::EBase),
+    (0x1F57B, 0x1F58F, L::Ideographic),
+    (0x1F590, 0x1F590, L::EBase),
+    (0x1F591, 0x1F594, L::Ideographic),
+    (0x1F595, 0x1F596, L::EBase),
+    (0x1F597, 0x1F5D3, L::Ideographic),
+    (0x1F5D4, 0x1F5DB, L::Alphabetic),
+    (0x1F5DC, 0x1F5F3, L::Ideographic),
+    (0x1F5F4, 0x1F5F9, L::Alphabetic),
+    (0x1F5FA, 0x1F644, L::Ideographic),
+    (0x1F645, 0x1F647, L::EBase),
+    (0x1F648, 0x1F64A, L::Ideographic),
+    (0x1F64B, 0x1F64F, L::EBase),
+    (0x1F650, 0x1F675, L::Alphabetic),
+    (0x1F676, 0x1F678, L::Quotation),
+    (0x1F679, 0x1F67B, L::Nonstarter),
+    (0x1F67C, 0x1F67F, L::Alphabetic),
+    (0x1F680, 0x1F6A2, L::Ideographic),
+    (0x1F6A3, 0x1F6A3, L::EBase),
+    (0x1F6A4, 0x1F6B3, L::Ideographic),
+    (0x1F6B4, 0x1F6B6, L::EBase),
+    (0x1F6B7, 0x1F6BF, L::Ideographic),
+    (0x1F6C0, 0x1F6C0, L::EBase),
+    (0x1F6C1, 0x1F6CB, L::Ideographic),
+    (0x1F6CC, 0x1F6CC, L::EBase),
+    (0x1F6CD, 0x1F6FF, L::Ideographic),
+    (0x1F700, 0x1F773, L::Alphabetic),
+    (0x1F774, 0x1F77F, L::Ideographic),
+    (0x1F780, 0x1F7D4, L::Alphabetic),
+    (0x1F7D5, 0x1F7FF, L::Ideographic),
+    (0x1F800, 0x1F80B, L::Alphabetic),
+    (0x1F80C, 0x1F80F, L::Ideographic),
+    (0x1F810, 0x1F847, L::Alphabetic),
+    (0x1F848, 0x1F84F, L::Ideographic),
+    (0x1F850, 0x1F859, L::Alphabetic),
+    (0x1F85A, 0x1F85F, L::Ideographic),
+    (0x1F860, 0x1F887, L::Alphabetic),
+    (0x1F888, 0x1F88F, L::Ideographic),
+    (0x1F890, 0x1F8AD, L::Alphabetic),
+    (0x1F8AE, 0x1F8FF, L::Ideographic),
+    (0x1F900, 0x1F90B, L::Alphabetic),
+    (0x1F90C, 0x1F90C, L::EBase),
+    (0x1F90D, 0x1F90E, L::Ideographic),
+    (0x1F90F, 0x1F90F, L::EBase),
+    (0x1F910, 0x1F917, L::Ideographic),
+    (0x1F918, 0x1F91F, L::EBase),
+    (0x1F920, 0x1F925, L::Ideographic),
+    (0x1F926, 0x1F926, L::EBase),
+    (0x1F927, 0x1F92F, L::Ideographic),
+    (0x1F930, 0x1F939, L::EBase),
+    (0x1F93A, 0x1F93B, L::Ideographic),
+    (0x1F93C, 0x1F93E, L::EBase),
+    (0x1F93F, 0x1F976, L::Ideographic),
+    (0x1F977, 0x1F977, L::EBase),
+    (0x1F978, 0x1F9B4, L::Ideographic),
+    (0x1F9B5, 0x1F9B6, L::EBase),
+    (0x1F9B7, 0x1F9B7, L::Ideographic),
+    (0x1F9B8, 0x1F9B9, L::EBase),
+    (0x1F9BA, 0x1F9BA, L::Ideographic),
+    (0x1F9BB, 0x1F9BB, L::EBase),
+    (0x1F9BC, 0x1F9CC, L::Ideographic),
+    (0x1F9CD, 0x1F9CF, L::EBase),
+    (0x1F9D0, 0x1F9D0, L::Ideographic),
+    (0x1F9D1, 0x1F9DD, L::EBase),
+    (0x1F9DE, 0x1F9FF, L::Ideographic),
+    (0x1FA00, 0x1FA53, L::Alphabetic),
+    (0x1FA54, 0x1FAC2, L::Ideographic),
+    (0x1FAC3, 0x1FAC5, L::EBase),
+    (0x1FAC6, 0x1FAEF, L::Ideographic),
+    (0x1FAF0, 0x1FAF6, L::EBase),
+    (0x1FAF7, 0x1FAFF, L::Ideographic),
+    (0x1FB00, 0x1FB92, L::Alphabetic),
+    (0x1FB94, 0x1FBCA, L::Alphabetic),
+    (0x1FBF0, 0x1FBF9, L::Numeric),
+    (0x1FC00, 0x1FFFD, L::Ideographic),
+    (0x20000, 0x2FFFD, L::Ideographic),
+    (0x30000, 0x3FFFD, L::Ideographic),
+    (0xE0001, 0xE0001, L::CombiningMark),
+    (0xE0020, 0xE007F, L::CombiningMark),
+    (0xE0100, 0xE01EF, L::CombiningMark),
+];
@@ -0,0 +1,87 @@
+/*!
+Tables generated from the Unicode Character Database.
+
+Every table of ranges is sorted by the first code point of the ranges, and the ranges do not overlap.
+The tables are generated by `src/bin/gen_tables.rs` from a local copy of the files of the Unicode Character Database:
+
+```text
+cargo run --release --bin gen_tables -- <ucd directory> src/tables
+```
+*/
+
+pub(crate) mod age;
+pub(crate) mod bidi;
+pub(crate) mod block;
+pub(crate) mod case;
+pub(crate) mod collate;
+pub(crate) mod east_asian_width;
+pub(crate) mod emoji;
+pub(crate) mod general_category;
+pub(crate) mod identifier;
+pub(crate) mod line_break;
+pub(crate) mod names;
+pub(crate) mod normalization;
+pub(crate) mod numeric;
+pub(crate) mod script;
+pub(crate) mod security;
+pub(crate) mod segment;
+pub(crate) mod version;
+pub(crate) mod whitespace;
+
+use std::cmp::Ordering;
+
+/// Find the range containing a code point in a table of ranges.
+///
+/// # Parameters
+/// * `table`: [`&[(u32, u32, T)]`] - A table of ranges, sorted by their first code point.
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The index of the range containing the code point, if any.
+pub(crate) fn find_range<T>(table: &[(u32, u32, T)], code_point: u32) -> Option<usize> {
+    table
+        .binary_search_by(|(first, last, _)| {
+            if code_point < *first {
+                Ordering::Greater
+            } else if code_point > *last {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+}
+
+/// Find the value of a code point in a table of ranges.
+///
+/// # Parameters
+/// * `table`: [`&[(u32, u32, T)]`] - A table of ranges, sorted by their first code point.
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The value of the range containing the code point, if any.
+pub(crate) fn lookup_range<T: Copy>(table: &[(u32, u32, T)], code_point: u32) -> Option<T> {
+    find_range(table, code_point).map(|i| table[i].2)
+}
+
+/// Check if a code point is in a set of ranges.
+///
+/// # Parameters
+/// * `table`: [`&[(u32, u32)]`] - A set of ranges, sorted by their first code point.
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// `true` if a range contains the code point.
+pub(crate) fn in_ranges(table: &[(u32, u32)], code_point: u32) -> bool {
+    table
+        .binary_search_by(|(first, last)| {
+            if code_point < *first {
+                Ordering::Greater
+            } else if code_point > *last {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
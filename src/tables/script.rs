@@ -0,0 +1,1046 @@
+// Generated from the Unicode Character Database 14.0.0 (Scripts.txt, ScriptExtensions.txt). Do not edit.
+
+use crate::unicode::Script as S;
+
+#[rustfmt::skip]
+pub(crate) const SCRIPT: &[(u32, u32, S)] = &[
+    (0x0000, 0x0040, S::Common),
+    (0x0041, 0x005A, S::Latin),
+    (0x005B, 0x0060, S::Common),
+    (0x0061, 0x007A, S::Latin),
+    (0x007B, 0x00A9, S::Common),
+    (0x00AA, 0x00AA, S::Latin),
+    (0x00AB, 0x00B9, S::Common),
+    (0x00BA, 0x00BA, S::Latin),
+    (0x00BB, 0x00BF, S::Common),
+    (0x00C0, 0x00D6, S::Latin),
+    (0x00D7, 0x00D7, S::Common),
+    (0x00D8, 0x00F6, S::Latin),
+    (0x00F7, 0x00F7, S::Common),
+    (0x00F8, 0x02B8, S::Latin),
+    (0x02B9, 0x02DF, S::Common),
+    (0x02E0, 0x02E4, S::Latin),
+    (0x02E5, 0x02E9, S::Common),
+    (0x02EA, 0x02EB, S::Bopomofo),
+    (0x02EC, 0x02FF, S::Common),
+    (0x0300, 0x036F, S::Inherited),
+    (0x0370, 0x0373, S::Greek),
+    (0x0374, 0x0374, S::Common),
+    (0x0375, 0x0377, S::Greek),
+    (0x037A, 0x037D, S::Greek),
+    (0x037E, 0x037E, S::Common),
+    (0x037F, 0x037F, S::Greek),
+    (0x0384, 0x0384, S::Greek),
+    (0x0385, 0x0385, S::Common),
+    (0x0386, 0x0386, S::Greek),
+    (0x0387, 0x0387, S::Common),
+    (0x0388, 0x038A, S::Greek),
+    (0x038C, 0x038C, S::Greek),
+    (0x038E, 0x03A1, S::Greek),
+    (0x03A3, 0x03E1, S::Greek),
+    (0x03E2, 0x03EF, S::Coptic),
+    (0x03F0, 0x03FF, S::Greek),
+    (0x0400, 0x0484, S::Cyrillic),
+    (0x0485, 0x0486, S::Inherited),
+    (0x0487, 0x052F, S::Cyrillic),
+    (0x0531, 0x0556, S::Armenian),
+    (0x0559, 0x058A, S::Armenian),
+    (0x058D, 0x058F, S::Armenian),
+    (0x0591, 0x05C7, S::Hebrew),
+    (0x05D0, 0x05EA, S::Hebrew),
+    (0x05EF, 0x05F4, S::Hebrew),
+    (0x0600, 0x0604, S::Arabic),
+    (0x0605, 0x0605, S::Common),
+    (0x0606, 0x060B, S::Arabic),
+    (0x060C, 0x060C, S::Common),
+    (0x060D, 0x061A, S::Arabic),
+    (0x061B, 0x061B, S::Common),
+    (0x061C, 0x061E, S::Arabic),
+    (0x061F, 0x061F, S::Common),
+    (0x0620, 0x063F, S::Arabic),
+    (0x0640, 0x0640, S::Common),
+    (0x0641, 0x064A, S::Arabic),
+    (0x064B, 0x0655, S::Inherited),
+    (0x0656, 0x066F, S::Arabic),
+    (0x0670, 0x0670, S::Inherited),
+    (0x0671, 0x06DC, S::Arabic),
+    (0x06DD, 0x06DD, S::Common),
+    (0x06DE, 0x06FF, S::Arabic),
+    (0x0700, 0x070D, S::Syriac),
+    (0x070F, 0x074A, S::Syriac),
+    (0x074D, 0x074F, S::Syriac),
+    (0x0750, 0x077F, S::Arabic),
+    (0x0780, 0x07B1, S::Thaana),
+    (0x07C0, 0x07FA, S::Nko),
+    (0x07FD, 0x07FF, S::Nko),
+    (0x0800, 0x082D, S::Samaritan),
+    (0x0830, 0x083E, S::Samaritan),
+    (0x0840, 0x085B, S::Mandaic),
+    (0x085E, 0x085E, S::Mandaic),
+    (0x0860, 0x086A, S::Syriac),
+    (0x0870, 0x088E, S::Arabic),
+    (0x0890, 0x0891, S::Arabic),
+    (0x0898, 0x08E1, S::Arabic),
+    (0x08E2, 0x08E2, S::Common),
+    (0x08E3, 0x08FF, S::Arabic),
+    (0x0900, 0x0950, S::Devanagari),
+    (0x0951, 0x0954, S::Inherited),
+    (0x0955, 0x0963, S::Devanagari),
+    (0x0964, 0x0965, S::Common),
+    (0x0966, 0x097F, S::Devanagari),
+    (0x0980, 0x0983, S::Bengali),
+    (0x0985, 0x098C, S::Bengali),
+    (0x098F, 0x0990, S::Bengali),
+    (0x0993, 0x09A8, S::Bengali),
+    (0x09AA, 0x09B0, S::Bengali),
+    (0x09B2, 0x09B2, S::Bengali),
+    (0x09B6, 0x09B9, S::Bengali),
+    (0x09BC, 0x09C4, S::Bengali),
+    (0x09C7, 0x09C8, S::Bengali),
+    (0x09CB, 0x09CE, S::Bengali),
+    (0x09D7, 0x09D7, S::Bengali),
+    (0x09DC, 0x09DD, S::Bengali),
+    (0x09DF, 0x09E3, S::Bengali),
+    (0x09E6, 0x09FE, S::Bengali),
+    (0x0A01, 0x0A03, S::Gurmukhi),
+    (0x0A05, 0x0A0A, S::Gurmukhi),
+    (0x0A0F, 0x0A10, S::Gurmukhi),
+    (0x0A13, 0x0A28, S::Gurmukhi),
+    (0x0A2A, 0x0A30, S::Gurmukhi),
+    (0x0A32, 0x0A33, S::Gurmukhi),
+    (0x0A35, 0x0A36, S::Gurmukhi),
+    (0x0A38, 0x0A39, S::Gurmukhi),
+    (0x0A3C, 0x0A3C, S::Gurmukhi),
+    (0x0A3E, 0x0A42, S::Gurmukhi),
+    (0x0A47, 0x0A48, S::Gurmukhi),
+    (0x0A4B, 0x0A4D, S::Gurmukhi),
+    (0x0A51, 0x0A51, S::Gurmukhi),
+    (0x0A59, 0x0A5C, S::Gurmukhi),
+    (0x0A5E, 0x0A5E, S::Gurmukhi),
+    (0x0A66, 0x0A76, S::Gurmukhi),
+    (0x0A81, 0x0A83, S::Gujarati),
+    (0x0A85, 0x0A8D, S::Gujarati),
+    (0x0A8F, 0x0A91, S::Gujarati),
+    (0x0A93, 0x0AA8, S::Gujarati),
+    (0x0AAA, 0x0AB0, S::Gujarati),
+    (0x0AB2, 0x0AB3, S::Gujarati),
+    (0x0AB5, 0x0AB9, S::Gujarati),
+    (0x0ABC, 0x0AC5, S::Gujarati),
+    (0x0AC7, 0x0AC9, S::Gujarati),
+    (0x0ACB, 0x0ACD, S::Gujarati),
+    (0x0AD0, 0x0AD0, S::Gujarati),
+    (0x0AE0, 0x0AE3, S::Gujarati),
+    (0x0AE6, 0x0AF1, S::Gujarati),
+    (0x0AF9, 0x0AFF, S::Gujarati),
+    (0x0B01, 0x0B03, S::Oriya),
+    (0x0B05, 0x0B0C, S::Oriya),
+    (0x0B0F, 0x0B10, S::Oriya),
+    (0x0B13, 0x0B28, S::Oriya),
+    (0x0B2A, 0x0B30, S::Oriya),
+    (0x0B32, 0x0B33, S::Oriya),
+    (0x0B35, 0x0B39, S::Oriya),
+    (0x0B3C, 0x0B44, S::Oriya),
+    (0x0B47, 0x0B48, S::Oriya),
+    (0x0B4B, 0x0B4D, S::Oriya),
+    (0x0B55, 0x0B57, S::Oriya),
+    (0x0B5C, 0x0B5D, S::Oriya),
+    (0x0B5F, 0x0B63, S::Oriya),
+    (0x0B66, 0x0B77, S::Oriya),
+    (0x0B82, 0x0B83, S::Tamil),
+    (0x0B85, 0x0B8A, S::Tamil),
+    (0x0B8E, 0x0B90, S::Tamil),
+    (0x0B92, 0x0B95, S::Tamil),
+    (0x0B99, 0x0B9A, S::Tamil),
+    (0x0B9C, 0x0B9C, S::Tamil),
+    (0x0B9E, 0x0B9F, S::Tamil),
+    (0x0BA3, 0x0BA4, S::Tamil),
+    (0x0BA8, 0x0BAA, S::Tamil),
+    (0x0BAE, 0x0BB9, S::Tamil),
+    (0x0BBE, 0x0BC2, S::Tamil),
+    (0x0BC6, 0x0BC8, S::Tamil),
+    (0x0BCA, 0x0BCD, S::Tamil),
+    (0x0BD0, 0x0BD0, S::Tamil),
+    (0x0BD7, 0x0BD7, S::Tamil),
+    (0x0BE6, 0x0BFA, S::Tamil),
+    (0x0C00, 0x0C0C, S::Telugu),
+    (0x0C0E, 0x0C10, S::Telugu),
+    (0x0C12, 0x0C28, S::Telugu),
+    (0x0C2A, 0x0C39, S::Telugu),
+    (0x0C3C, 0x0C44, S::Telugu),
+    (0x0C46, 0x0C48, S::Telugu),
+    (0x0C4A, 0x0C4D, S::Telugu),
+    (0x0C55, 0x0C56, S::Telugu),
+    (0x0C58, 0x0C5A, S::Telugu),
+    (0x0C5D, 0x0C5D, S::Telugu),
+    (0x0C60, 0x0C63, S::Telugu),
+    (0x0C66, 0x0C6F, S::Telugu),
+    (0x0C77, 0x0C7F, S::Telugu),
+    (0x0C80, 0x0C8C, S::Kannada),
+    (0x0C8E, 0x0C90, S::Kannada),
+    (0x0C92, 0x0CA8, S::Kannada),
+    (0x0CAA, 0x0CB3, S::Kannada),
+    (0x0CB5, 0x0CB9, S::Kannada),
+    (0x0CBC, 0x0CC4, S::Kannada),
+    (0x0CC6, 0x0CC8, S::Kannada),
+    (0x0CCA, 0x0CCD, S::Kannada),
+    (0x0CD5, 0x0CD6, S::Kannada),
+    (0x0CDD, 0x0CDE, S::Kannada),
+    (0x0CE0, 0x0CE3, S::Kannada),
+    (0x0CE6, 0x0CEF, S::Kannada),
+    (0x0CF1, 0x0CF2, S::Kannada),
+    (0x0D00, 0x0D0C, S::Malayalam),
+    (0x0D0E, 0x0D10, S::Malayalam),
+    (0x0D12, 0x0D44, S::Malayalam),
+    (0x0D46, 0x0D48, S::Malayalam),
+    (0x0D4A, 0x0D4F, S::Malayalam),
+    (0x0D54, 0x0D63, S::Malayalam),
+    (0x0D66, 0x0D7F, S::Malayalam),
+    (0x0D81, 0x0D83, S::Sinhala),
+    (0x0D85, 0x0D96, S::Sinhala),
+    (0x0D9A, 0x0DB1, S::Sinhala),
+    (0x0DB3, 0x0DBB, S::Sinhala),
+    (0x0DBD, 0x0DBD, S::Sinhala),
+    (0x0DC0, 0x0DC6, S::Sinhala),
+    (0x0DCA, 0x0DCA, S::Sinhala),
+    (0x0DCF, 0x0DD4, S::Sinhala),
+    (0x0DD6, 0x0DD6, S::Sinhala),
+    (0x0DD8, 0x0DDF, S::Sinhala),
+    (0x0DE6, 0x0DEF, S::Sinhala),
+    (0x0DF2, 0x0DF4, S::Sinhala),
+    (0x0E01, 0x0E3A, S::Thai),
+    (0x0E3F, 0x0E3F, S::Common),
+    (0x0E40, 0x0E5B, S::Thai),
+    (0x0E81, 0x0E82, S::Lao),
+    (0x0E84, 0x0E84, S::Lao),
+    (0x0E86, 0x0E8A, S::Lao),
+    (0x0E8C, 0x0EA3, S::Lao),
+    (0x0EA5, 0x0EA5, S::Lao),
+    (0x0EA7, 0x0EBD, S::Lao),
+    (0x0EC0, 0x0EC4, S::Lao),
+    (0x0EC6, 0x0EC6, S::Lao),
+    (0x0EC8, 0x0ECD, S::Lao),
+    (0x0ED0, 0x0ED9, S::Lao),
+    (0x0EDC, 0x0EDF, S::Lao),
+    (0x0F00, 0x0F47, S::Tibetan),
+    (0x0F49, 0x0F6C, S::Tibetan),
+    (0x0F71, 0x0F97, S::Tibetan),
+    (0x0F99, 0x0FBC, S::Tibetan),
+    (0x0FBE, 0x0FCC, S::Tibetan),
+    (0x0FCE, 0x0FD4, S::Tibetan),
+    (0x0FD5, 0x0FD8, S::Common),
+    (0x0FD9, 0x0FDA, S::Tibetan),
+    (0x1000, 0x109F, S::Myanmar),
+    (0x10A0, 0x10C5, S::Georgian),
+    (0x10C7, 0x10C7, S::Georgian),
+    (0x10CD, 0x10CD, S::Georgian),
+    (0x10D0, 0x10FA, S::Georgian),
+    (0x10FB, 0x10FB, S::Common),
+    (0x10FC, 0x10FF, S::Georgian),
+    (0x1100, 0x11FF, S::Hangul),
+    (0x1200, 0x1248, S::Ethiopic),
+    (0x124A, 0x124D, S::Ethiopic),
+    (0x1250, 0x1256, S::Ethiopic),
+    (0x1258, 0x1258, S::Ethiopic),
+    (0x125A, 0x125D, S::Ethiopic),
+    (0x1260, 0x1288, S::Ethiopic),
+    (0x128A, 0x128D, S::Ethiopic),
+    (0x1290, 0x12B0, S::Ethiopic),
+    (0x12B2, 0x12B5, S::Ethiopic),
+    (0x12B8, 0x12BE, S::Ethiopic),
+    (0x12C0, 0x12C0, S::Ethiopic),
+    (0x12C2, 0x12C5, S::Ethiopic),
+    (0x12C8, 0x12D6, S::Ethiopic),
+    (0x12D8, 0x1310, S::Ethiopic),
+    (0x1312, 0x1315, S::Ethiopic),
+    (0x1318, 0x135A, S::Ethiopic),
+    (0x135D, 0x137C, S::Ethiopic),
+    (0x1380, 0x1399, S::Ethiopic),
+    (0x13A0, 0x13F5, S::Cherokee),
+    (0x13F8, 0x13FD, S::Cherokee),
+    (0x1400, 0x167F, S::CanadianAboriginal),
+    (0x1680, 0x169C, S::Ogham),
+    (0x16A0, 0x16EA, S::Runic),
+    (0x16EB, 0x16ED, S::Common),
+    (0x16EE, 0x16F8, S::Runic),
+    (0x1700, 0x1715, S::Tagalog),
+    (0x171F, 0x171F, S::Tagalog),
+    (0x1720, 0x1734, S::Hanunoo),
+    (0x1735, 0x1736, S::Common),
+    (0x1740, 0x1753, S::Buhid),
+    (0x1760, 0x176C, S::Tagbanwa),
+    (0x176E, 0x1770, S::Tagbanwa),
+    (0x1772, 0x1773, S::Tagbanwa),
+    (0x1780, 0x17DD, S::Khmer),
+    (0x17E0, 0x17E9, S::Khmer),
+    (0x17F0, 0x17F9, S::Khmer),
+    (0x1800, 0x1801, S::Mongolian),
+    (0x1802, 0x1803, S::Common),
+    (0x1804, 0x1804, S::Mongolian),
+    (0x1805, 0x1805, S::Common),
+    (0x1806, 0x1819, S::Mongolian),
+    (0x1820, 0x1878, S::Mongolian),
+    (0x1880, 0x18AA, S::Mongolian),
+    (0x18B0, 0x18F5, S::CanadianAboriginal),
+    (0x1900, 0x191E, S::Limbu),
+    (0x1920, 0x192B, S::Limbu),
+    (0x1930, 0x193B, S::Limbu),
+    (0x1940, 0x1940, S::Limbu),
+    (0x1944, 0x194F, S::Limbu),
+    (0x1950, 0x196D, S::TaiLe),
+    (0x1970, 0x1974, S::TaiLe),
+    (0x1980, 0x19AB, S::NewTaiLue),
+    (0x19B0, 0x19C9, S::NewTaiLue),
+    (0x19D0, 0x19DA, S::NewTaiLue),
+    (0x19DE, 0x19DF, S::NewTaiLue),
+    (0x19E0, 0x19FF, S::Khmer),
+    (0x1A00, 0x1A1B, S::Buginese),
+    (0x1A1E, 0x1A1F, S::Buginese),
+    (0x1A20, 0x1A5E, S::TaiTham),
+    (0x1A60, 0x1A7C, S::TaiTham),
+    (0x1A7F, 0x1A89, S::TaiTham),
+    (0x1A90, 0x1A99, S::TaiTham),
+    (0x1AA0, 0x1AAD, S::TaiTham),
+    (0x1AB0, 0x1ACE, S::Inherited),
+    (0x1B00, 0x1B4C, S::Balinese),
+    (0x1B50, 0x1B7E, S::Balinese),
+    (0x1B80, 0x1BBF, S::Sundanese),
+    (0x1BC0, 0x1BF3, S::Batak),
+    (0x1BFC, 0x1BFF, S::Batak),
+    (0x1C00, 0x1C37, S::Lepcha),
+    (0x1C3B, 0x1C49, S::Lepcha),
+    (0x1C4D, 0x1C4F, S::Lepcha),
+    (0x1C50, 0x1C7F, S::OlChiki),
+    (0x1C80, 0x1C88, S::Cyrillic),
+    (0x1C90, 0x1CBA, S::Georgian),
+    (0x1CBD, 0x1CBF, S::Georgian),
+    (0x1CC0, 0x1CC7, S::Sundanese),
+    (0x1CD0, 0x1CD2, S::Inherited),
+    (0x1CD3, 0x1CD3, S::Common),
+    (0x1CD4, 0x1CE0, S::Inherited),
+    (0x1CE1, 0x1CE1, S::Common),
+    (0x1CE2, 0x1CE8, S::Inherited),
+    (0x1CE9, 0x1CEC, S::Common),
+    (0x1CED, 0x1CED, S::Inherited),
+    (0x1CEE, 0x1CF3, S::Common),
+    (0x1CF4, 0x1CF4, S::Inherited),
+    (0x1CF5, 0x1CF7, S::Common),
+    (0x1CF8, 0x1CF9, S::Inherited),
+    (0x1CFA, 0x1CFA, S::Common),
+    (0x1D00, 0x1D25, S::Latin),
+    (0x1D26, 0x1D2A, S::Greek),
+    (0x1D2B, 0x1D2B, S::Cyrillic),
+    (0x1D2C, 0x1D5C, S::Latin),
+    (0x1D5D, 0x1D61, S::Greek),
+    (0x1D62, 0x1D65, S::Latin),
+    (0x1D66, 0x1D6A, S::Greek),
+    (0x1D6B, 0x1D77, S::Latin),
+    (0x1D78, 0x1D78, S::Cyrillic),
+    (0x1D79, 0x1DBE, S::Latin),
+    (0x1DBF, 0x1DBF, S::Greek),
+    (0x1DC0, 0x1DFF, S::Inherited),
+    (0x1E00, 0x1EFF, S::Latin),
+    (0x1F00, 0x1F15, S::Greek),
+    (0x1F18, 0x1F1D, S::Greek),
+    (0x1F20, 0x1F45, S::Greek),
+    (0x1F48, 0x1F4D, S::Greek),
+    (0x1F50, 0x1F57, S::Greek),
+    (0x1F59, 0x1F59, S::Greek),
+    (0x1F5B, 0x1F5B, S::Greek),
+    (0x1F5D, 0x1F5D, S::Greek),
+    (0x1F5F, 0x1F7D, S::Greek),
+    (0x1F80, 0x1FB4, S::Greek),
+    (0x1FB6, 0x1FC4, S::Greek),
+    (0x1FC6, 0x1FD3, S::Greek),
+    (0x1FD6, 0x1FDB, S::Greek),
+    (0x1FDD, 0x1FEF, S::Greek),
+    (0x1FF2, 0x1FF4, S::Greek),
+    (0x1FF6, 0x1FFE, S::Greek),
+    (0x2000, 0x200B, S::Common),
+    (0x200C, 0x200D, S::Inherited),
+    (0x200E, 0x2064, S::Common),
+    (0x2066, 0x2070, S::Common),
+    (0x2071, 0x2071, S::Latin),
+    (0x2074, 0x207E, S::Common),
+    (0x207F, 0x207F, S::Latin),
+    (0x2080, 0x208E, S::Common),
+    (0x2090, 0x209C, S::Latin),
+    (0x20A0, 0x20C0, S::Common),
+    (0x20D0, 0x20F0, S::Inherited),
+    (0x2100, 0x2125, S::Common),
+    (0x2126, 0x2126, S::Greek),
+    (0x2127, 0x2129, S::Common),
+    (0x212A, 0x212B, S::Latin),
+    (0x212C, 0x2131, S::Common),
+    (0x2132, 0x2132, S::Latin),
+    (0x2133, 0x214D, S::Common),
+    (0x214E, 0x214E, S::Latin),
+    (0x214F, 0x215F, S::Common),
+    (0x2160, 0x2188, S::Latin),
+    (0x2189, 0x218B, S::Common),
+    (0x2190, 0x2426, S::Common),
+    (0x2440, 0x244A, S::Common),
+    (0x2460, 0x27FF, S::Common),
+    (0x2800, 0x28FF, S::Braille),
+    (0x2900, 0x2B73, S::Common),
+    (0x2B76, 0x2B95, S::Common),
+    (0x2B97, 0x2BFF, S::Common),
+    (0x2C00, 0x2C5F, S::Glagolitic),
+    (0x2C60, 0x2C7F, S::Latin),
+    (0x2C80, 0x2CF3, S::Coptic),
+    (0x2CF9, 0x2CFF, S::Coptic),
+    (0x2D00, 0x2D25, S::Georgian),
+    (0x2D27, 0x2D27, S::Georgian),
+    (0x2D2D, 0x2D2D, S::Georgian),
+    (0x2D30, 0x2D67, S::Tifinagh),
+    (0x2D6F, 0x2D70, S::Tifinagh),
+    (0x2D7F, 0x2D7F, S::Tifinagh),
+    (0x2D80, 0x2D96, S::Ethiopic),
+    (0x2DA0, 0x2DA6, S::Ethiopic),
+    (0x2DA8, 0x2DAE, S::Ethiopic),
+    (0x2DB0, 0x2DB6, S::Ethiopic),
+    (0x2DB8, 0x2DBE, S::Ethiopic),
+    (0x2DC0, 0x2DC6, S::Ethiopic),
+    (0x2DC8, 0x2DCE, S::Ethiopic),
+    (0x2DD0, 0x2DD6, S::Ethiopic),
+    (0x2DD8, 0x2DDE, S::Ethiopic),
+    (0x2DE0, 0x2DFF, S::Cyrillic),
+    (0x2E00, 0x2E5D, S::Common),
+    (0x2E80, 0x2E99, S::Han),
+    (0x2E9B, 0x2EF3, S::Han),
+    (0x2F00, 0x2FD5, S::Han),
+    (0x2FF0, 0x2FFB, S::Common),
+    (0x3000, 0x3004, S::Common),
+    (0x3005, 0x3005, S::Han),
+    (0x3006, 0x3006, S::Common),
+    (0x3007, 0x3007, S::Han),
+    (0x3008, 0x3020, S::Common),
+    (0x3021, 0x3029, S::Han),
+    (0x302A, 0x302D, S::Inherited),
+    (0x302E, 0x302F, S::Hangul),
+    (0x3030, 0x3037, S::Common),
+    (0x3038, 0x303B, S::Han),
+    (0x303C, 0x303F, S::Common),
+    (0x3041, 0x3096, S::Hiragana),
+    (0x3099, 0x309A, S::Inherited),
+    (0x309B, 0x309C, S::Common),
+    (0x309D, 0x309F, S::Hiragana),
+    (0x30A0, 0x30A0, S::Common),
+    (0x30A1, 0x30FA, S::Katakana),
+    (0x30FB, 0x30FC, S::Common),
+    (0x30FD, 0x30FF, S::Katakana),
+    (0x3105, 0x312F, S::Bopomofo),
+    (0x3131, 0x318E, S::Hangul),
+    (0x3190, 0x319F, S::Common),
+    (0x31A0, 0x31BF, S::Bopomofo),
+    (0x31C0, 0x31E3, S::Common),
+    (0x31F0, 0x31FF, S::Katakana),
+    (0x3200, 0x321E, S::Hangul),
+    (0x3220, 0x325F, S::Common),
+    (0x3260, 0x327E, S::Hangul),
+    (0x327F, 0x32CF, S::Common),
+    (0x32D0, 0x32FE, S::Katakana),
+    (0x32FF, 0x32FF, S::Common),
+    (0x3300, 0x3357, S::Katakana),
+    (0x3358, 0x33FF, S::Common),
+    (0x3400, 0x4DBF, S::Han),
+    (0x4DC0, 0x4DFF, S::Common),
+    (0x4E00, 0x9FFF, S::Han),
+    (0xA000, 0xA48C, S::Yi),
+    (0xA490, 0xA4C6, S::Yi),
+    (0xA4D0, 0xA4FF, S::Lisu),
+    (0xA500, 0xA62B, S::Vai),
+    (0xA640, 0xA69F, S::Cyrillic),
+    (0xA6A0, 0xA6F7, S::Bamum),
+    (0xA700, 0xA721, S::Common),
+    (0xA722, 0xA787, S::Latin),
+    (0xA788, 0xA78A, S::Common),
+    (0xA78B, 0xA7CA, S::Latin),
+    (0xA7D0, 0xA7D1, S::Latin),
+    (0xA7D3, 0xA7D3, S::Latin),
+    (0xA7D5, 0xA7D9, S::Latin),
+    (0xA7F2, 0xA7FF, S::Latin),
+    (0xA800, 0xA82C, S::SylotiNagri),
+    (0xA830, 0xA839, S::Common),
+    (0xA840, 0xA877, S::PhagsPa),
+    (0xA880, 0xA8C5, S::Saurashtra),
+    (0xA8CE, 0xA8D9, S::Saurashtra),
+    (0xA8E0, 0xA8FF, S::Devanagari),
+    (0xA900, 0xA92D, S::KayahLi),
+    (0xA92E, 0xA92E, S::Common),
+    (0xA92F, 0xA92F, S::KayahLi),
+    (0xA930, 0xA953, S::Rejang),
+    (0xA95F, 0xA95F, S::Rejang),
+    (0xA960, 0xA97C, S::Hangul),
+    (0xA980, 0xA9CD, S::Javanese),
+    (0xA9CF, 0xA9CF, S::Common),
+    (0xA9D0, 0xA9D9, S::Javanese),
+    (0xA9DE, 0xA9DF, S::Javanese),
+    (0xA9E0, 0xA9FE, S::Myanmar),
+    (0xAA00, 0xAA36, S::Cham),
+    (0xAA40, 0xAA4D, S::Cham),
+    (0xAA50, 0xAA59, S::Cham),
+    (0xAA5C, 0xAA5F, S::Cham),
+    (0xAA60, 0xAA7F, S::Myanmar),
+    (0xAA80, 0xAAC2, S::TaiViet),
+    (0xAADB, 0xAADF, S::TaiViet),
+    (0xAAE0, 0xAAF6, S::MeeteiMayek),
+    (0xAB01, 0xAB06, S::Ethiopic),
+    (0xAB09, 0xAB0E, S::Ethiopic),
+    (0xAB11, 0xAB16, S::Ethiopic),
+    (0xAB20, 0xAB26, S::Ethiopic),
+    (0xAB28, 0xAB2E, S::Ethiopic),
+    (0xAB30, 0xAB5A, S::Latin),
+    (0xAB5B, 0xAB5B, S::Common),
+    (0xAB5C, 0xAB64, S::Latin),
+    (0xAB65, 0xAB65, S::Greek),
+    (0xAB66, 0xAB69, S::Latin),
+    (0xAB6A, 0xAB6B, S::Common),
+    (0xAB70, 0xABBF, S::Cherokee),
+    (0xABC0, 0xABED, S::MeeteiMayek),
+    (0xABF0, 0xABF9, S::MeeteiMayek),
+    (0xAC00, 0xD7A3, S::Hangul),
+    (0xD7B0, 0xD7C6, S::Hangul),
+    (0xD7CB, 0xD7FB, S::Hangul),
+    (0xF900, 0xFA6D, S::Han),
+    (0xFA70, 0xFAD9, S::Han),
+    (0xFB00, 0xFB06, S::Latin),
+    (0xFB13, 0xFB17, S::Armenian),
+    (0xFB1D, 0xFB36, S::Hebrew),
+    (0xFB38, 0xFB3C, S::Hebrew),
+    (0xFB3E, 0xFB3E, S::Hebrew),
+    (0xFB40, 0xFB41, S::Hebrew),
+    (0xFB43, 0xFB44, S::Hebrew),
+    (0xFB46, 0xFB4F, S::Hebrew),
+    (0xFB50, 0xFBC2, S::Arabic),
+    (0xFBD3, 0xFD3D, S::Arabic),
+    (0xFD3E, 0xFD3F, S::Common),
+    (0xFD40, 0xFD8F, S::Arabic),
+    (0xFD92, 0xFDC7, S::Arabic),
+    (0xFDCF, 0xFDCF, S::Arabic),
+    (0xFDF0, 0xFDFF, S::Arabic),
+    (0xFE00, 0xFE0F, S::Inherited),
+    (0xFE10, 0xFE19, S::Common),
+    (0xFE20, 0xFE2D, S::Inherited),
+    (0xFE2E, 0xFE2F, S::Cyrillic),
+    (0xFE30, 0xFE52, S::Common),
+    (0xFE54, 0xFE66, S::Common),
+    (0xFE68, 0xFE6B, S::Common),
+    (0xFE70, 0xFE74, S::Arabic),
+    (0xFE76, 0xFEFC, S::Arabic),
+    (0xFEFF, 0xFEFF, S::Common),
+    (0xFF01, 0xFF20, S::Common),
+    (0xFF21, 0xFF3A, S::Latin),
+    (0xFF3B, 0xFF40, S::Common),
+    (0xFF41, 0xFF5A, S::Latin),
+    (0xFF5B, 0xFF65, S::Common),
+    (0xFF66, 0xFF6F, S::Katakana),
+    (0xFF70, 0xFF70, S::Common),
+    (0xFF71, 0xFF9D, S::Katakana),
+    (0xFF9E, 0xFF9F, S::Common),
+    (0xFFA0, 0xFFBE, S::Hangul),
+    (0xFFC2, 0xFFC7, S::Hangul),
+    (0xFFCA, 0xFFCF, S::Hangul),
+    (0xFFD2, 0xFFD7, S::Hangul),
+    (0xFFDA, 0xFFDC, S::Hangul),
+    (0xFFE0, 0xFFE6, S::Common),
+    (0xFFE8, 0xFFEE, S::Common),
+    (0xFFF9, 0xFFFD, S::Common),
+    (0x10000, 0x1000B, S::LinearB),
+    (0x1000D, 0x10026, S::LinearB),
+    (0x10028, 0x1003A, S::LinearB),
+    (0x1003C, 0x1003D, S::LinearB),
+    (0x1003F, 0x1004D, S::LinearB),
+    (0x10050, 0x1005D, S::LinearB),
+    (0x10080, 0x100FA, S::LinearB),
+    (0x10100, 0x10102, S::Common),
+    (0x10107, 0x10133, S::Common),
+    (0x10137, 0x1013F, S::Common),
+    (0x10140, 0x1018E, S::Greek),
+    (0x10190, 0x1019C, S::Common),
+    (0x101A0, 0x101A0, S::Greek),
+    (0x101D0, 0x101FC, S::Common),
+    (0x101FD, 0x101FD, S::Inherited),
+    (0x10280, 0x1029C, S::Lycian),
+    (0x102A0, 0x102D0, S::Carian),
+    (0x102E0, 0x102E0, S::Inherited),
+    (0x102E1, 0x102FB, S::Common),
+    (0x10300, 0x10323, S::OldItalic),
+    (0x1032D, 0x1032F, S::OldItalic),
+    (0x10330, 0x1034A, S::Gothic),
+    (0x10350, 0x1037A, S::OldPermic),
+    (0x10380, 0x1039D, S::Ugaritic),
+    (0x1039F, 0x1039F, S::Ugaritic),
+    (0x103A0, 0x103C3, S::OldPersian),
+    (0x103C8, 0x103D5, S::OldPersian),
+    (0x10400, 0x1044F, S::Deseret),
+    (0x10450, 0x1047F, S::Shavian),
+    (0x10480, 0x1049D, S::Osmanya),
+    (0x104A0, 0x104A9, S::Osmanya),
+    (0x104B0, 0x104D3, S::Osage),
+    (0x104D8, 0x104FB, S::Osage),
+    (0x10500, 0x10527, S::Elbasan),
+    (0x10530, 0x10563, S::CaucasianAlbanian),
+    (0x1056F, 0x1056F, S::CaucasianAlbanian),
+    (0x10570, 0x1057A, S::Vithkuqi),
+    (0x1057C, 0x1058A, S::Vithkuqi),
+    (0x1058C, 0x10592, S::Vithkuqi),
+    (0x10594, 0x10595, S::Vithkuqi),
+    (0x10597, 0x105A1, S::Vithkuqi),
+    (0x105A3, 0x105B1, S::Vithkuqi),
+    (0x105B3, 0x105B9, S::Vithkuqi),
+    (0x105BB, 0x105BC, S::Vithkuqi),
+    (0x10600, 0x10736, S::LinearA),
+    (0x10740, 0x10755, S::LinearA),
+    (0x10760, 0x10767, S::LinearA),
+    (0x10780, 0x10785, S::Latin),
+    (0x10787, 0x107B0, S::Latin),
+    (0x107B2, 0x107BA, S::Latin),
+    (0x10800, 0x10805, S::Cypriot),
+    (0x10808, 0x10808, S::Cypriot),
+    (0x1080A, 0x10835, S::Cypriot),
+    (0x10837, 0x10838, S::Cypriot),
+    (0x1083C, 0x1083C, S::Cypriot),
+    (0x1083F, 0x1083F, S::Cypriot),
+    (0x10840, 0x10855, S::ImperialAramaic),
+    (0x10857, 0x1085F, S::ImperialAramaic),
+    (0x10860, 0x1087F, S::Palmyrene),
+    (0x10880, 0x1089E, S::Nabataean),
+    (0x108A7, 0x108AF, S::Nabataean),
+    (0x108E0, 0x108F2, S::Hatran),
+    (0x108F4, 0x108F5, S::Hatran),
+    (0x108FB, 0x108FF, S::Hatran),
+    (0x10900, 0x1091B, S::Phoenician),
+    (0x1091F, 0x1091F, S::Phoenician),
+    (0x10920, 0x10939, S::Lydian),
+    (0x1093F, 0x1093F, S::Lydian),
+    (0x10980, 0x1099F, S::MeroiticHieroglyphs),
+    (0x109A0, 0x109B7, S::MeroiticCursive),
+    (0x109BC, 0x109CF, S::MeroiticCursive),
+    (0x109D2, 0x109FF, S::MeroiticCursive),
+    (0x10A00, 0x10A03, S::Kharoshthi),
+    (0x10A05, 0x10A06, S::Kharoshthi),
+    (0x10A0C, 0x10A13, S::Kharoshthi),
+    (0x10A15, 0x10A17, S::Kharoshthi),
+    (0x10A19, 0x10A35, S::Kharoshthi),
+    (0x10A38, 0x10A3A, S::Kharoshthi),
+    (0x10A3F, 0x10A48, S::Kharoshthi),
+    (0x10A50, 0x10A58, S::Kharoshthi),
+    (0x10A60, 0x10A7F, S::OldSouthArabian),
+    (0x10A80, 0x10A9F, S::OldNorthArabian),
+    (0x10AC0, 0x10AE6, S::Manichaean),
+    (0x10AEB, 0x10AF6, S::Manichaean),
+    (0x10B00, 0x10B35, S::Avestan),
+    (0x10B39, 0x10B3F, S::Avestan),
+    (0x10B40, 0x10B55, S::InscriptionalParthian),
+    (0x10B58, 0x10B5F, S::InscriptionalParthian),
+    (0x10B60, 0x10B72, S::InscriptionalPahlavi),
+    (0x10B78, 0x10B7F, S::InscriptionalPahlavi),
+    (0x10B80, 0x10B91, S::PsalterPahlavi),
+    (0x10B99, 0x10B9C, S::PsalterPahlavi),
+    (0x10BA9, 0x10BAF, S::PsalterPahlavi),
+    (0x10C00, 0x10C48, S::OldTurkic),
+    (0x10C80, 0x10CB2, S::OldHungarian),
+    (0x10CC0, 0x10CF2, S::OldHungarian),
+    (0x10CFA, 0x10CFF, S::OldHungarian),
+    (0x10D00, 0x10D27, S::HanifiRohingya),
+    (0x10D30, 0x10D39, S::HanifiRohingya),
+    (0x10E60, 0x10E7E, S::Arabic),
+    (0x10E80, 0x10EA9, S::Yezidi),
+    (0x10EAB, 0x10EAD, S::Yezidi),
+    (0x10EB0, 0x10EB1, S::Yezidi),
+    (0x10F00, 0x10F27, S::OldSogdian),
+    (0x10F30, 0x10F59, S::Sogdian),
+    (0x10F70, 0x10F89, S::OldUyghur),
+    (0x10FB0, 0x10FCB, S::Chorasmian),
+    (0x10FE0, 0x10FF6, S::Elymaic),
+    (0x11000, 0x1104D, S::Brahmi),
+    (0x11052, 0x11075, S::Brahmi),
+    (0x1107F, 0x1107F, S::Brahmi),
+    (0x11080, 0x110C2, S::Kaithi),
+    (0x110CD, 0x110CD, S::Kaithi),
+    (0x110D0, 0x110E8, S::SoraSompeng),
+    (0x110F0, 0x110F9, S::SoraSompeng),
+    (0x11100, 0x11134, S::Chakma),
+    (0x11136, 0x11147, S::Chakma),
+    (0x11150, 0x11176, S::Mahajani),
+    (0x11180, 0x111DF, S::Sharada),
+    (0x111E1, 0x111F4, S::Sinhala),
+    (0x11200, 0x11211, S::Khojki),
+    (0x11213, 0x1123E, S::Khojki),
+    (0x11280, 0x11286, S::Multani),
+    (0x11288, 0x11288, S::Multani),
+    (0x1128A, 0x1128D, S::Multani),
+    (0x1128F, 0x1129D, S::Multani),
+    (0x1129F, 0x112A9, S::Multani),
+    (0x112B0, 0x112EA, S::Khudawadi),
+    (0x112F0, 0x112F9, S::Khudawadi),
+    (0x11300, 0x11303, S::Grantha),
+    (0x11305, 0x1130C, S::Grantha),
+    (0x1130F, 0x11310, S::Grantha),
+    (0x11313, 0x11328, S::Grantha),
+    (0x1132A, 0x11330, S::Grantha),
+    (0x11332, 0x11333, S::Grantha),
+    (0x11335, 0x11339, S::Grantha),
+    (0x1133B, 0x1133B, S::Inherited),
+    (0x1133C, 0x11344, S::Grantha),
+    (0x11347, 0x11348, S::Grantha),
+    (0x1134B, 0x1134D, S::Grantha),
+    (0x11350, 0x11350, S::Grantha),
+    (0x11357, 0x11357, S::Grantha),
+    (0x1135D, 0x11363, S::Grantha),
+    (0x11366, 0x1136C, S::Grantha),
+    (0x11370, 0x11374, S::Grantha),
+    (0x11400, 0x1145B, S::Newa),
+    (0x1145D, 0x11461, S::Newa),
+    (0x11480, 0x114C7, S::Tirhuta),
+    (0x114D0, 0x114D9, S::Tirhuta),
+    (0x11580, 0x115B5, S::Siddham),
+    (0x115B8, 0x115DD, S::Siddham),
+    (0x11600, 0x11644, S::Modi),
+    (0x11650, 0x11659, S::Modi),
+    (0x11660, 0x1166C, S::Mongolian),
+    (0x11680, 0x116B9, S::Takri),
+    (0x116C0, 0x116C9, S::Takri),
+    (0x11700, 0x1171A, S::Ahom),
+    (0x1171D, 0x1172B, S::Ahom),
+    (0x11730, 0x11746, S::Ahom),
+    (0x11800, 0x1183B, S::Dogra),
+    (0x118A0, 0x118F2, S::WarangCiti),
+    (0x118FF, 0x118FF, S::WarangCiti),
+    (0x11900, 0x11906, S::DivesAkuru),
+    (0x11909, 0x11909, S::DivesAkuru),
+    (0x1190C, 0x11913, S::DivesAkuru),
+    (0x11915, 0x11916, S::DivesAkuru),
+    (0x11918, 0x11935, S::DivesAkuru),
+    (0x11937, 0x11938, S::DivesAkuru),
+    (0x1193B, 0x11946, S::DivesAkuru),
+    (0x11950, 0x11959, S::DivesAkuru),
+    (0x119A0, 0x119A7, S::Nandinagari),
+    (0x119AA, 0x119D7, S::Nandinagari),
+    (0x119DA, 0x119E4, S::Nandinagari),
+    (0x11A00, 0x11A47, S::ZanabazarSquare),
+    (0x11A50, 0x11AA2, S::Soyombo),
+    (0x11AB0, 0x11ABF, S::CanadianAboriginal),
+    (0x11AC0, 0x11AF8, S::PauCinHau),
+    (0x11C00, 0x11C08, S::Bhaiksuki),
+    (0x11C0A, 0x11C36, S::Bhaiksuki),
+    (0x11C38, 0x11C45, S::Bhaiksuki),
+    (0x11C50, 0x11C6C, S::Bhaiksuki),
+    (0x11C70, 0x11C8F, S::Marchen),
+    (0x11C92, 0x11CA7, S::Marchen),
+    (0x11CA9, 0x11CB6, S::Marchen),
+    (0x11D00, 0x11D06, S::MasaramGondi),
+    (0x11D08, 0x11D09, S::MasaramGondi),
+    (0x11D0B, 0x11D36, S::MasaramGondi),
+    (0x11D3A, 0x11D3A, S::MasaramGondi),
+    (0x11D3C, 0x11D3D, S::MasaramGondi),
+    (0x11D3F, 0x11D47, S::MasaramGondi),
+    (0x11D50, 0x11D59, S::MasaramGondi),
+    (0x11D60, 0x11D65, S::GunjalaGondi),
+    (0x11D67, 0x11D68, S::GunjalaGondi),
+    (0x11D6A, 0x11D8E, S::GunjalaGondi),
+    (0x11D90, 0x11D91, S::GunjalaGondi),
+    (0x11D93, 0x11D98, S::GunjalaGondi),
+    (0x11DA0, 0x11DA9, S::GunjalaGondi),
+    (0x11EE0, 0x11EF8, S::Makasar),
+    (0x11FB0, 0x11FB0, S::Lisu),
+    (0x11FC0, 0x11FF1, S::Tamil),
+    (0x11FFF, 0x11FFF, S::Tamil),
+    (0x12000, 0x12399, S::Cuneiform),
+    (0x12400, 0x1246E, S::Cuneiform),
+    (0x12470, 0x12474, S::Cuneiform),
+    (0x12480, 0x12543, S::Cuneiform),
+    (0x12F90, 0x12FF2, S::CyproMinoan),
+    (0x13000, 0x1342E, S::EgyptianHieroglyphs),
+    (0x13430, 0x13438, S::EgyptianHieroglyphs),
+    (0x14400, 0x14646, S::AnatolianHieroglyphs),
+    (0x16800, 0x16A38, S::Bamum),
+    (0x16A40, 0x16A5E, S::Mro),
+    (0x16A60, 0x16A69, S::Mro),
+    (0x16A6E, 0x16A6F, S::Mro),
+    (0x16A70, 0x16ABE, S::Tangsa),
+    (0x16AC0, 0x16AC9, S::Tangsa),
+    (0x16AD0, 0x16AED, S::BassaVah),
+    (0x16AF0, 0x16AF5, S::BassaVah),
+    (0x16B00, 0x16B45, S::PahawhHmong),
+    (0x16B50, 0x16B59, S::PahawhHmong),
+    (0x16B5B, 0x16B61, S::PahawhHmong),
+    (0x16B63, 0x16B77, S::PahawhHmong),
+    (0x16B7D, 0x16B8F, S::PahawhHmong),
+    (0x16E40, 0x16E9A, S::Medefaidrin),
+    (0x16F00, 0x16F4A, S::Miao),
+    (0x16F4F, 0x16F87, S::Miao),
+    (0x16F8F, 0x16F9F, S::Miao),
+    (0x16FE0, 0x16FE0, S::Tangut),
+    (0x16FE1, 0x16FE1, S::Nushu),
+    (0x16FE2, 0x16FE3, S::Han),
+    (0x16FE4, 0x16FE4, S::KhitanSmallScript),
+    (0x16FF0, 0x16FF1, S::Han),
+    (0x17000, 0x187F7, S::Tangut),
+    (0x18800, 0x18AFF, S::Tangut),
+    (0x18B00, 0x18CD5, S::KhitanSmallScript),
+    (0x18D00, 0x18D08, S::Tangut),
+    (0x1AFF0, 0x1AFF3, S::Katakana),
+    (0x1AFF5, 0x1AFFB, S::Katakana),
+    (0x1AFFD, 0x1AFFE, S::Katakana),
+    (0x1B000, 0x1B000, S::Katakana),
+    (0x1B001, 0x1B11F, S::Hiragana),
+    (0x1B120, 0x1B122, S::Katakana),
+    (0x1B150, 0x1B152, S::Hiragana),
+    (0x1B164, 0x1B167, S::Katakana),
+    (0x1B170, 0x1B2FB, S::Nushu),
+    (0x1BC00, 0x1BC6A, S::Duployan),
+    (0x1BC70, 0x1BC7C, S::Duployan),
+    (0x1BC80, 0x1BC88, S::Duployan),
+    (0x1BC90, 0x1BC99, S::Duployan),
+    (0x1BC9C, 0x1BC9F, S::Duployan),
+    (0x1BCA0, 0x1BCA3, S::Common),
+    (0x1CF00, 0x1CF2D, S::Inherited),
+    (0x1CF30, 0x1CF46, S::Inherited),
+    (0x1CF50, 0x1CFC3, S::Common),
+    (0x1D000, 0x1D0F5, S::Common),
+    (0x1D100, 0x1D126, S::Common),
+    (0x1D129, 0x1D166, S::Common),
+    (0x1D167, 0x1D169, S::Inherited),
+    (0x1D16A, 0x1D17A, S::Common),
+    (0x1D17B, 0x1D182, S::Inherited),
+    (0x1D183, 0x1D184, S::Common),
+    (0x1D185, 0x1D18B, S::Inherited),
+    (0x1D18C, 0x1D1A9, S::Common),
+    (0x1D1AA, 0x1D1AD, S::Inherited),
+    (0x1D1AE, 0x1D1EA, S::Common),
+    (0x1D200, 0x1D245, S::Greek),
+    (0x1D2E0, 0x1D2F3, S::Common),
+    (0x1D300, 0x1D356, S::Common),
+    (0x1D360, 0x1D378, S::Common),
+    (0x1D400, 0x1D454, S::Common),
+    (0x1D456, 0x1D49C, S::Common),
+    (0x1D49E, 0x1D49F, S::Common),
+    (0x1D4A2, 0x1D4A2, S::Common),
+    (0x1D4A5, 0x1D4A6, S::Common),
+    (0x1D4A9, 0x1D4AC, S::Common),
+    (0x1D4AE, 0x1D4B9, S::Common),
+    (0x1D4BB, 0x1D4BB, S::Common),
+    (0x1D4BD, 0x1D4C3, S::Common),
+    (0x1D4C5, 0x1D505, S::Common),
+    (0x1D507, 0x1D50A, S::Common),
+    (0x1D50D, 0x1D514, S::Common),
+    (0x1D516, 0x1D51C, S::Common),
+    (0x1D51E, 0x1D539, S::Common),
+    (0x1D53B, 0x1D53E, S::Common),
+    (0x1D540, 0x1D544, S::Common),
+    (0x1D546, 0x1D546, S::Common),
+    (0x1D54A, 0x1D550, S::Common),
+    (0x1D552, 0x1D6A5, S::Common),
+    (0x1D6A8, 0x1D7CB, S::Common),
+    (0x1D7CE, 0x1D7FF, S::Common),
+    (0x1D800, 0x1DA8B, S::SignWriting),
+    (0x1DA9B, 0x1DA9F, S::SignWriting),
+    (0x1DAA1, 0x1DAAF, S::SignWriting),
+    (0x1DF00, 0x1DF1E, S::Latin),
+    (0x1E000, 0x1E006, S::Glagolitic),
+    (0x1E008, 0x1E018, S::Glagolitic),
+    (0x1E01B, 0x1E021, S::Glagolitic),
+    (0x1E023, 0x1E024, S::Glagolitic),
+    (0x1E026, 0x1E02A, S::Glagolitic),
+    (0x1E100, 0x1E12C, S::NyiakengPuachueHmong),
+    (0x1E130, 0x1E13D, S::NyiakengPuachueHmong),
+    (0x1E140, 0x1E149, S::NyiakengPuachueHmong),
+    (0x1E14E, 0x1E14F, S::NyiakengPuachueHmong),
+    (0x1E290, 0x1E2AE, S::Toto),
+    (0x1E2C0, 0x1E2F9, S::Wancho),
+    (0x1E2FF, 0x1E2FF, S::Wancho),
+    (0x1E7E0, 0x1E7E6, S::Ethiopic),
+    (0x1E7E8, 0x1E7EB, S::Ethiopic),
+    (0x1E7ED, 0x1E7EE, S::Ethiopic),
+    (0x1E7F0, 0x1E7FE, S::Ethiopic),
+    (0x1E800, 0x1E8C4, S::MendeKikakui),
+    (0x1E8C7, 0x1E8D6, S::MendeKikakui),
+    (0x1E900, 0x1E94B, S::Adlam),
+    (0x1E950, 0x1E959, S::Adlam),
+    (0x1E95E, 0x1E95F, S::Adlam),
+    (0x1EC71, 0x1ECB4, S::Common),
+    (0x1ED01, 0x1ED3D, S::Common),
+    (0x1EE00, 0x1EE03, S::Arabic),
+    (0x1EE05, 0x1EE1F, S::Arabic),
+    (0x1EE21, 0x1EE22, S::Arabic),
+    (0x1EE24, 0x1EE24, S::Arabic),
+    (0x1EE27, 0x1EE27, S::Arabic),
+    (0x1EE29, 0x1EE32, S::Arabic),
+    (0x1EE34, 0x1EE37, S::Arabic),
+    (0x1EE39, 0x1EE39, S::Arabic),
+    (0x1EE3B, 0x1EE3B, S::Arabic),
+    (0x1EE42, 0x1EE42, S::Arabic),
+    (0x1EE47, 0x1EE47, S::Arabic),
+    (0x1EE49, 0x1EE49, S::Arabic),
+    (0x1EE4B, 0x1EE4B, S::Arabic),
+    (0x1EE4D, 0x1EE4F, S::Arabic),
+    (0x1EE51, 0x1EE52, S::Arabic),
+    (0x1EE54, 0x1EE54, S::Arabic),
+    (0x1EE57, 0x1EE57, S::Arabic),
+    (0x1EE59, 0x1EE59, S::Arabic),
+    (0x1EE5B, 0x1EE5B, S::Arabic),
+    (0x1EE5D, 0x1EE5D, S::Arabic),
+    (0x1EE5F, 0x1EE5F, S::Arabic),
+    (0x1EE61, 0x1EE62, S::Arabic),
+    (0x1EE64, 0x1EE64, S::Arabic),
+    (0x1EE67, 0x1EE6A, S::Arabic),
+    (0x1EE6C, 0x1EE72, S::Arabic),
+    (0x1EE74, 0x1EE77, S::Arabic),
+    (0x1EE79, 0x1EE7C, S::Arabic),
+    (0x1EE7E, 0x1EE7E, S::Arabic),
+    (0x1EE80, 0x1EE89, S::Arabic),
+    (0x1EE8B, 0x1EE9B, S::Arabic),
+    (0x1EEA1, 0x1EEA3, S::Arabic),
+    (0x1EEA5, 0x1EEA9, S::Arabic),
+    (0x1EEAB, 0x1EEBB, S::Arabic),
+    (0x1EEF0, 0x1EEF1, S::Arabic),
+    (0x1F000, 0x1F02B, S::Common),
+    (0x1F030, 0x1F093, S::Common),
+    (0x1F0A0, 0x1F0AE, S::Common),
+    (0x1F0B1, 0x1F0BF, S::Common),
+    (0x1F0C1, 0x1F0CF, S::Common),
+    (0x1F0D1, 0x1F0F5, S::Common),
+    (0x1F100, 0x1F1AD, S::Common),
+    (0x1F1E6, 0x1F1FF, S::Common),
+    (0x1F200, 0x1F200, S::Hiragana),
+    (0x1F201, 0x1F202, S::Common),
+    (0x1F210, 0x1F23B, S::Common),
+    (0x1F240, 0x1F248, S::Common),
+    (0x1F250, 0x1F251, S::Common),
+    (0x1F260, 0x1F265, S::Common),
+    (0x1F300, 0x1F6D7, S::Common),
+    (0x1F6DD, 0x1F6EC, S::Common),
+    (0x1F6F0, 0x1F6FC, S::Common),
+    (0x1F700, 0x1F773, S::Common),
+    (0x1F780, 0x1F7D8, S::Common),
+    (0x1F7E0, 0x1F7EB, S::Common),
+    (0x1F7F0, 0x1F7F0, S::Common),
+    (0x1F800, 0x1F80B, S::Common),
+    (0x1F810, 0x1F847, S::Common),
+    (0x1F850, 0x1F859, S::Common),
+    (0x1F860, 0x1F887, S::Common),
+    (0x1F890, 0x1F8AD, S::Common),
+    (0x1F8B0, 0x1F8B1, S::Common),
+    (0x1F900, 0x1FA53, S::Common),
+    (0x1FA60, 0x1FA6D, S::Common),
+    (0x1FA70, 0x1FA74, S::Common),
+    (0x1FA78, 0x1FA7C, S::Common),
+    (0x1FA80, 0x1FA86, S::Common),
+    (0x1FA90, 0x1FAAC, S::Common),
+    (0x1FAB0, 0x1FABA, S::Common),
+    (0x1FAC0, 0x1FAC5, S::Common),
+    (0x1FAD0, 0x1FAD9, S::Common),
+    (0x1FAE0, 0x1FAE7, S::Common),
+    (0x1FAF0, 0x1FAF6, S::Common),
+    (0x1FB00, 0x1FB92, S::Common),
+    (0x1FB94, 0x1FBCA, S::Common),
+    (0x1FBF0, 0x1FBF9, S::Common),
+    (0x20000, 0x2A6DF, S::Han),
+    (0x2A700, 0x2B738, S::Han),
+    (0x2B740, 0x2B81D, S::Han),
+    (0x2B820, 0x2CEA1, S::Han),
+    (0x2CEB0, 0x2EBE0, S::Han),
+    (0x2F800, 0x2FA1D, S::Han),
+    (0x30000, 0x3134A, S::Han),
+    (0xE0001, 0xE0001, S::Common),
+    (0xE0020, 0xE007F, S::Common),
+    (0xE0100, 0xE01EF, S::Inherited),
+];
+
+#[rustfmt::skip]
+pub(crate) const SCRIPT_EXTENSIONS: &[(u32, u32, &[S])] = &[
+    (0x0483, 0x0483, &[S::Cyrillic, S::OldPermic]),
+    (0x0484, 0x0484, &[S::Cyrillic, S::Glagolitic]),
+    (0x0485, 0x0486, &[S::Cyrillic, S::Latin]),
+    (0x0487, 0x0487, &[S::Cyrillic, S::Glagolitic]),
+    (0x060C, 0x060C, &[S::Arabic, S::Nko, S::HanifiRohingya, S::Syriac, S::Thaana, S::Yezidi]),
+    (0x061B, 0x061B, &[S::Arabic, S::Nko, S::HanifiRohingya, S::Syriac, S::Thaana, S::Yezidi]),
+    (0x061C, 0x061C, &[S::Arabic, S::Syriac, S::Thaana]),
+    (0x061F, 0x061F, &[S::Adlam, S::Arabic, S::Nko, S::HanifiRohingya, S::Syriac, S::Thaana, S::Yezidi]),
+    (0x0640, 0x0640, &[S::Adlam, S::Arabic, S::Mandaic, S::Manichaean, S::OldUyghur, S::PsalterPahlavi, S::HanifiRohingya, S::Sogdian, S::Syriac]),
+    (0x064B, 0x0655, &[S::Arabic, S::Syriac]),
+    (0x0660, 0x0669, &[S::Arabic, S::Thaana, S::Yezidi]),
+    (0x0670, 0x0670, &[S::Arabic, S::Syriac]),
+    (0x06D4, 0x06D4, &[S::Arabic, S::HanifiRohingya]),
+    (0x0951, 0x0951, &[S::Bengali, S::Devanagari, S::Grantha, S::Gujarati, S::Gurmukhi, S::Kannada, S::Latin, S::Malayalam, S::Oriya, S::Sharada, S::Tamil, S::Telugu, S::Tirhuta]),
+    (0x0952, 0x0952, &[S::Bengali, S::Devanagari, S::Grantha, S::Gujarati, S::Gurmukhi, S::Kannada, S::Latin, S::Malayalam, S::Oriya, S::Tamil, S::Telugu, S::Tirhuta]),
+    (0x0964, 0x0964, &[S::Bengali, S::Devanagari, S::Dogra, S::GunjalaGondi, S::MasaramGondi, S::Grantha, S::Gujarati, S::Gurmukhi, S::Kannada, S::Mahajani, S::Malayalam, S::Nandinagari, S::Oriya, S::Khudawadi, S::Sinhala, S::SylotiNagri, S::Takri, S::Tamil, S::Telugu, S::Tirhuta]),
+    (0x0965, 0x0965, &[S::Bengali, S::Devanagari, S::Dogra, S::GunjalaGondi, S::MasaramGondi, S::Grantha, S::Gujarati, S::Gurmukhi, S::Kannada, S::Limbu, S::Mahajani, S::Malayalam, S::Nandinagari, S::Oriya, S::Khudawadi, S::Sinhala, S::SylotiNagri, S::Takri, S::Tamil, S::Telugu, S::Tirhuta]),
+    (0x0966, 0x096F, &[S::Devanagari, S::Dogra, S::Kaithi, S::Mahajani]),
+    (0x09E6, 0x09EF, &[S::Bengali, S::Chakma, S::SylotiNagri]),
+    (0x0A66, 0x0A6F, &[S::Gurmukhi, S::Multani]),
+    (0x0AE6, 0x0AEF, &[S::Gujarati, S::Khojki]),
+    (0x0BE6, 0x0BF3, &[S::Grantha, S::Tamil]),
+    (0x0CE6, 0x0CEF, &[S::Kannada, S::Nandinagari]),
+    (0x1040, 0x1049, &[S::Chakma, S::Myanmar, S::TaiLe]),
+    (0x10FB, 0x10FB, &[S::Georgian, S::Latin]),
+    (0x1735, 0x1736, &[S::Buhid, S::Hanunoo, S::Tagbanwa, S::Tagalog]),
+    (0x1802, 0x1803, &[S::Mongolian, S::PhagsPa]),
+    (0x1805, 0x1805, &[S::Mongolian, S::PhagsPa]),
+    (0x1CD0, 0x1CD0, &[S::Bengali, S::Devanagari, S::Grantha, S::Kannada]),
+    (0x1CD2, 0x1CD2, &[S::Bengali, S::Devanagari, S::Grantha, S::Kannada]),
+    (0x1CD3, 0x1CD3, &[S::Devanagari, S::Grantha]),
+    (0x1CD5, 0x1CD6, &[S::Bengali, S::Devanagari]),
+    (0x1CD7, 0x1CD7, &[S::Devanagari, S::Sharada]),
+    (0x1CD8, 0x1CD8, &[S::Bengali, S::Devanagari]),
+    (0x1CD9, 0x1CD9, &[S::Devanagari, S::Sharada]),
+    (0x1CDA, 0x1CDA, &[S::Devanagari, S::Kannada, S::Malayalam, S::Oriya, S::Tamil, S::Telugu]),
+    (0x1CDC, 0x1CDD, &[S::Devanagari, S::Sharada]),
+    (0x1CE0, 0x1CE0, &[S::Devanagari, S::Sharada]),
+    (0x1CE1, 0x1CE1, &[S::Bengali, S::Devanagari]),
+    (0x1CE9, 0x1CE9, &[S::Devanagari, S::Nandinagari]),
+    (0x1CEA, 0x1CEA, &[S::Bengali, S::Devanagari]),
+    (0x1CED, 0x1CED, &[S::Bengali, S::Devanagari]),
+    (0x1CF2, 0x1CF2, &[S::Bengali, S::Devanagari, S::Grantha, S::Kannada, S::Nandinagari, S::Oriya, S::Telugu, S::Tirhuta]),
+    (0x1CF3, 0x1CF3, &[S::Devanagari, S::Grantha]),
+    (0x1CF4, 0x1CF4, &[S::Devanagari, S::Grantha, S::Kannada]),
+    (0x1CF5, 0x1CF6, &[S::Bengali, S::Devanagari]),
+    (0x1CF8, 0x1CF9, &[S::Devanagari, S::Grantha]),
+    (0x1DF8, 0x1DF8, &[S::Cyrillic, S::Syriac]),
+    (0x202F, 0x202F, &[S::Latin, S::Mongolian]),
+    (0x20F0, 0x20F0, &[S::Devanagari, S::Grantha, S::Latin]),
+    (0x2E43, 0x2E43, &[S::Cyrillic, S::Glagolitic]),
+    (0x3001, 0x3002, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana, S::Yi]),
+    (0x3003, 0x3003, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana]),
+    (0x3008, 0x3011, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana, S::Yi]),
+    (0x3013, 0x3013, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana]),
+    (0x3014, 0x301B, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana, S::Yi]),
+    (0x301C, 0x301F, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana]),
+    (0x302A, 0x302D, &[S::Bopomofo, S::Han]),
+    (0x3030, 0x3030, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana]),
+    (0x3031, 0x3035, &[S::Hiragana, S::Katakana]),
+    (0x3037, 0x3037, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana]),
+    (0x303C, 0x303D, &[S::Han, S::Hiragana, S::Katakana]),
+    (0x3099, 0x309C, &[S::Hiragana, S::Katakana]),
+    (0x30A0, 0x30A0, &[S::Hiragana, S::Katakana]),
+    (0x30FB, 0x30FB, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana, S::Yi]),
+    (0x30FC, 0x30FC, &[S::Hiragana, S::Katakana]),
+    (0xA66F, 0xA66F, &[S::Cyrillic, S::Glagolitic]),
+    (0xA700, 0xA707, &[S::Han, S::Latin]),
+    (0xA830, 0xA832, &[S::Devanagari, S::Dogra, S::Gujarati, S::Gurmukhi, S::Khojki, S::Kannada, S::Kaithi, S::Mahajani, S::Malayalam, S::Modi, S::Nandinagari, S::Khudawadi, S::Takri, S::Tirhuta]),
+    (0xA833, 0xA835, &[S::Devanagari, S::Dogra, S::Gujarati, S::Gurmukhi, S::Khojki, S::Kannada, S::Kaithi, S::Mahajani, S::Modi, S::Nandinagari, S::Khudawadi, S::Takri, S::Tirhuta]),
+    (0xA836, 0xA839, &[S::Devanagari, S::Dogra, S::Gujarati, S::Gurmukhi, S::Khojki, S::Kaithi, S::Mahajani, S::Modi, S::Khudawadi, S::Takri, S::Tirhuta]),
+    (0xA8F1, 0xA8F1, &[S::Bengali, S::Devanagari]),
+    (0xA8F3, 0xA8F3, &[S::Devanagari, S::Tamil]),
+    (0xA92E, 0xA92E, &[S::KayahLi, S::Latin, S::Myanmar]),
+    (0xA9CF, 0xA9CF, &[S::Buginese, S::Javanese]),
+    (0xFD3E, 0xFD3F, &[S::Arabic, S::Nko]),
+    (0xFDF2, 0xFDF2, &[S::Arabic, S::Thaana]),
+    (0xFDFD, 0xFDFD, &[S::Arabic, S::Thaana]),
+    (0xFE45, 0xFE46, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana]),
+    (0xFF61, 0xFF65, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana, S::Yi]),
+    (0xFF70, 0xFF70, &[S::Hiragana, S::Katakana]),
+    (0xFF9E, 0xFF9F, &[S::Hiragana, S::Katakana]),
+    (0x10100, 0x10101, &[S::CyproMinoan, S::Cypriot, S::LinearB]),
+    (0x10102, 0x10102, &[S::Cypriot, S::LinearB]),
+    (0x10107, 0x10133, &[S::Cypriot, S::LinearA, S::LinearB]),
+    (0x10137, 0x1013F, &[S::Cypriot, S::LinearB]),
+    (0x102E0, 0x102FB, &[S::Arabic, S::Coptic]),
+    (0x10AF2, 0x10AF2, &[S::Manichaean, S::OldUyghur]),
+    (0x11301, 0x11301, &[S::Grantha, S::Tamil]),
+    (0x11303, 0x11303, &[S::Grantha, S::Tamil]),
+    (0x1133B, 0x1133C, &[S::Grantha, S::Tamil]),
+    (0x11FD0, 0x11FD1, &[S::Grantha, S::Tamil]),
+    (0x11FD3, 0x11FD3, &[S::Grantha, S::Tamil]),
+];
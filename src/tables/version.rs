@@ -0,0 +1,3 @@
+// Generated from the Unicode Character Database 14.0.0. Do not edit.
+
+pub(crate) const UNICODE_VERSION: (u8, u8, u8) = (14, 0, 0);
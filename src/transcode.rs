@@ -0,0 +1,240 @@
+/*!
+Direct transcoding between UTF-8, UTF-16 and UCS-2.
+
+# Transcoding
+The functions of this module convert between two encodings in a single pass, without an intermediate vector of unicode code points:
+* The length of the output is computed in advance from the input, so that the output vector is allocated exactly once.
+* The ASCII code points, which are the same in every encoding, are copied without being decoded.
+* Every other code point is decoded from the input encoding and directly encoded in the output encoding.
+
+## Output length
+
+**UTF-8 to UTF-16 (or UCS-2)**: Every UTF-8 code point is one UTF-16 code point, except for the four bytes ones (prefix `11110`), which are a surrogate pair.
+So the length is the number of bytes that are not continuation bytes (prefix `10`), plus the number of four bytes prefixes.
+
+**UTF-16 (or UCS-2) to UTF-8**: Every UTF-16 code point is one, two or three bytes, depending on its value. A surrogate pair is four bytes, so each surrogate counts as two bytes.
+
+**Note**:
+
+* The input is validated with the same rules used by [`crate::utf8::decode_from_utf8`] and [`crate::utf16::decode_from_utf16`], and the functions panic on invalid input.
+*/
+
+/// Compute the number of UTF-16 code points needed to transcode a slice of UTF-8 code points.
+///
+/// # Parameters
+/// * `utf8_cp`: [`&[u8]`] - A slice of UTF-8 code points.
+///
+/// # Returns
+/// The number of UTF-16 code points, assuming the input is valid UTF-8.
+fn utf16_len(utf8_cp: &[u8]) -> usize {
+    utf8_cp
+        .iter()
+        .map(|byte| match byte {
+            // Continuation byte
+            0x80..=0xBF => 0,
+            // Four bytes prefix, encoded as a surrogate pair
+            0xF0..=0xFF => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Compute the number of UTF-8 code points needed to transcode a slice of UTF-16 code points.
+///
+/// # Parameters
+/// * `utf16_cp`: [`&[u16]`] - A slice of UTF-16 code points.
+///
+/// # Returns
+/// The number of UTF-8 code points, assuming the input is valid UTF-16.
+fn utf8_len(utf16_cp: &[u16]) -> usize {
+    utf16_cp
+        .iter()
+        .map(|unit| match unit {
+            0x0000..=0x007F => 1,
+            0x0080..=0x07FF => 2,
+            // Half of a four bytes code point
+            0xD800..=0xDFFF => 2,
+            _ => 3,
+        })
+        .sum()
+}
+
+/// Transcode a slice of UTF-8 code points into UTF-16 code points.
+///
+/// # Parameters
+/// * `utf8_cp`: [`&[u8]`] - A slice of UTF-8 code points.
+/// * `surrogates`: [`bool`] - A flag to allow the code points greater than `0xFFFF`, encoded as surrogate pairs (UTF-16) or not (UCS-2).
+///
+/// # Returns
+/// A [`Vec<u16>`] containing the UTF-16 code points.
+///
+/// # Panics
+/// * If the input slice (`utf8_cp`) of UTF-8 code points is invalid.
+/// * If `surrogates` is `false` and the input slice contains code points greater than `0xFFFF`.
+fn utf8_to_utf16_units(utf8_cp: &[u8], surrogates: bool) -> Vec<u16> {
+    let mut utf16_cp: Vec<u16> = Vec::with_capacity(utf16_len(utf8_cp));
+    let mut i: usize = 0;
+    while i < utf8_cp.len() {
+        if utf8_cp[i] < 0x80 {
+            utf16_cp.push(utf8_cp[i] as u16);
+            i += 1;
+            continue;
+        }
+        let (code_point, offset) = crate::utf8::decode_symbol(utf8_cp, i).unwrap();
+        i += offset;
+        if code_point <= 0xFFFF {
+            utf16_cp.push(code_point as u16);
+        } else if surrogates {
+            // See the surrogate pair encoding in the utf16 module
+            let extra: u32 = code_point - 0x10000;
+            utf16_cp.push((((extra >> 10) & 0x3FF) + 0xD800) as u16);
+            utf16_cp.push(((extra & 0x3FF) | 0xDC00) as u16);
+        } else {
+            panic!("Invalid UCS-2 sequence");
+        }
+    }
+    utf16_cp
+}
+
+/// Transcode a slice of UTF-16 code points into UTF-8 code points.
+///
+/// # Parameters
+/// * `utf16_cp`: [`&[u16]`] - A slice of UTF-16 code points.
+/// * `surrogates`: [`bool`] - A flag to decode the surrogate pairs (UTF-16) or reject them (UCS-2).
+///
+/// # Returns
+/// A [`Vec<u8>`] containing the UTF-8 code points.
+///
+/// # Panics
+/// * If the input slice (`utf16_cp`) of UTF-16 code points contains a lone surrogate.
+/// * If `surrogates` is `false` and the input slice contains a surrogate.
+fn utf16_units_to_utf8(utf16_cp: &[u16], surrogates: bool) -> Vec<u8> {
+    let mut utf8_cp: Vec<u8> = Vec::with_capacity(utf8_len(utf16_cp));
+    let mut i: usize = 0;
+    while i < utf16_cp.len() {
+        let unit: u32 = utf16_cp[i] as u32;
+        i += 1;
+        // See the encodings in the utf8 module
+        match unit {
+            0x0000..=0x007F => utf8_cp.push(unit as u8),
+            0x0080..=0x07FF => {
+                utf8_cp.push((((unit >> 6) & 0x1F) | 0xC0) as u8);
+                utf8_cp.push(((unit & 0x3F) | 0x80) as u8);
+            }
+            0xD800..=0xDBFF if surrogates => {
+                let low: u32 = match utf16_cp.get(i) {
+                    Some(low @ 0xDC00..=0xDFFF) => *low as u32,
+                    _ => panic!("Invalid UTF-16 sequence"),
+                };
+                i += 1;
+                let code_point: u32 = (((unit & 0x3FF) << 10) | (low & 0x3FF)) + 0x10000;
+                utf8_cp.push((((code_point >> 18) & 0x07) | 0xF0) as u8);
+                utf8_cp.push((((code_point >> 12) & 0x3F) | 0x80) as u8);
+                utf8_cp.push((((code_point >> 6) & 0x3F) | 0x80) as u8);
+                utf8_cp.push(((code_point & 0x3F) | 0x80) as u8);
+            }
+            0xD800..=0xDFFF if surrogates => panic!("Invalid UTF-16 sequence"),
+            0xD800..=0xDFFF => panic!("Invalid UCS-2 sequence"),
+            _ => {
+                utf8_cp.push((((unit >> 12) & 0x0F) | 0xE0) as u8);
+                utf8_cp.push((((unit >> 6) & 0x3F) | 0x80) as u8);
+                utf8_cp.push(((unit & 0x3F) | 0x80) as u8);
+            }
+        }
+    }
+    utf8_cp
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// Transcode a slice of UTF-8 code points into a vector of UTF-16 code points.
+///
+/// # Parameters
+/// * `utf8_cp`: [`&[u8]`] - A slice of UTF-8 code points.
+///
+/// # Returns
+/// A [`Vec<u16>`] containing the UTF-16 code points.
+///
+/// # Panics
+/// * If the input slice (`utf8_cp`) of UTF-8 code points contains invalid code points.
+/// * If the input slice (`utf8_cp`) of UTF-8 code points contains invalid continuation bytes.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u8> = vec![0x41, 0xf0, 0x90, 0x80, 0x81]; // Array of code points in UTF-8
+/// let enc: Vec<u16> = utf8_to_utf16(&v);
+/// assert_eq!(enc, vec![0x41, 0xD800, 0xDC01]);
+/// ```
+pub fn utf8_to_utf16(utf8_cp: &[u8]) -> Vec<u16> {
+    utf8_to_utf16_units(utf8_cp, true)
+}
+
+/// Transcode a slice of UTF-16 code points into a vector of UTF-8 code points.
+///
+/// # Parameters
+/// * `utf16_cp`: [`&[u16]`] - A slice of UTF-16 code points.
+///
+/// # Returns
+/// A [`Vec<u8>`] containing the UTF-8 code points.
+///
+/// # Panics
+/// * If the input slice (`utf16_cp`) of UTF-16 code points contains a surrogate that is not part of a surrogate pair.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u16> = vec![0x41, 0xD800, 0xDC01]; // Array of code points in UTF-16
+/// let enc: Vec<u8> = utf16_to_utf8(&v);
+/// assert_eq!(enc, vec![0x41, 0xf0, 0x90, 0x80, 0x81]);
+/// ```
+pub fn utf16_to_utf8(utf16_cp: &[u16]) -> Vec<u8> {
+    utf16_units_to_utf8(utf16_cp, true)
+}
+
+/// Transcode a slice of UTF-8 code points into a vector of UCS-2 code points.
+///
+/// # Parameters
+/// * `utf8_cp`: [`&[u8]`] - A slice of UTF-8 code points.
+///
+/// # Returns
+/// A [`Vec<u16>`] containing the UCS-2 code points.
+///
+/// # Panics
+/// * If the input slice (`utf8_cp`) of UTF-8 code points contains invalid code points.
+/// * If the input slice (`utf8_cp`) of UTF-8 code points contains code points greater than `0xFFFF`.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u8> = vec![0x41, 0xef, 0xbf, 0xae]; // Array of code points in UTF-8
+/// let enc: Vec<u16> = utf8_to_ucs2(&v);
+/// assert_eq!(enc, vec![0x41, 0xFFEE]);
+/// ```
+pub fn utf8_to_ucs2(utf8_cp: &[u8]) -> Vec<u16> {
+    utf8_to_utf16_units(utf8_cp, false)
+}
+
+/// Transcode a slice of UCS-2 code points into a vector of UTF-8 code points.
+///
+/// # Parameters
+/// * `ucs2_cp`: [`&[u16]`] - A slice of UCS-2 code points.
+///
+/// # Returns
+/// A [`Vec<u8>`] containing the UTF-8 code points.
+///
+/// # Panics
+/// * If the input slice (`ucs2_cp`) of UCS-2 code points contains surrogates.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u16> = vec![0x41, 0xFFEE]; // Array of code points in UCS-2
+/// let enc: Vec<u8> = ucs2_to_utf8(&v);
+/// assert_eq!(enc, vec![0x41, 0xef, 0xbf, 0xae]);
+/// ```
+pub fn ucs2_to_utf8(ucs2_cp: &[u16]) -> Vec<u8> {
+    utf16_units_to_utf8(ucs2_cp, false)
+}
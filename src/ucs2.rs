@@ -24,6 +24,8 @@ A UCS-2 code point is decoded into a unicode code point using the the first [two
 * UTF-16 code point: `xxxxxxxx|xxxxxxxx`
 */
 
+use crate::unicode;
+
 /// Pretty print the UCS-2 code points in hexadecimal, (binary) and decimal.
 ///
 /// # Parameters
@@ -49,7 +51,9 @@ fn print_ucs2_vec<T: AsRef<Vec<u16>>>(ucs2_cp: T, binary_flag: bool) {
     println!(
         "{}{}",
         "-".repeat(44),
-        "-".repeat(string_repr.chars().count())
+        "-".repeat(unicode::display_width(
+            &string_repr.chars().map(|c| c as u32).collect::<Vec<u32>>()
+        ))
     );
     println!();
 }
@@ -6,19 +6,455 @@ Unicode utilities.
 Unicode, formally The Unicode Standard, is a text encoding standard maintained by the Unicode Consortium designed to support the use of text written in all of the world's major writing systems.
 
 Unicode text is processed and stored as binary data using one of several encodings, which define how to translate the standard's abstracted codes for characters into sequences of bytes. The Unicode Standard itself defines three encodings: UTF-8, UTF-16, and UTF-32, though several others exist. Of these, UTF-8 is the most widely used by a large margin, in part due to its backwards-compatibility with ASCII.
+
+# Character properties
+
+The properties of the code points are looked up in tables generated from the Unicode Character Database (version 14.0.0, see [`UNICODE_VERSION`]):
+* [`name`]: The name of a code point (`UnicodeData.txt`), including the names derived by algorithm for the Hangul syllables and the ideographs.
+* [`general_category`]: The general category of a code point (`UnicodeData.txt`).
+* [`age`]: The version of Unicode in which a code point was assigned (`DerivedAge.txt`).
+* [`block`]: The block containing a code point (`Blocks.txt`).
+* [`east_asian_width`]: The East Asian width of a code point (`EastAsianWidth.txt`).
+* [`script`]: The script of a code point (`Scripts.txt`).
+* [`script_extensions`]: The scripts a code point is used with (`ScriptExtensions.txt`).
+* [`numeric_type`]: The numeric type of a code point (`DerivedNumericType.txt`).
+* [`numeric_value`]: The numeric value of a code point, including the fractions and the Roman numerals (`DerivedNumericValues.txt`).
+* [`jamo_short_name`]: The short name of a conjoining Hangul jamo (`Jamo.txt`).
+
+# Scripts
+
+[`resolved_script_set`] finds the scripts shared by all the code points of a text, as defined by UTS #39 (Unicode Security Mechanisms).
+A text whose resolved script set is empty mixes scripts, like a Latin word with a Cyrillic letter that looks the same as a Latin one.
+
+# Numbers
+
+[`parse_decimal`] parses the decimal digits of any script (`Numeric_Type=Decimal`, e.g. `٣٤` in Arabic-Indic digits or `３４` in full-width digits), which come in contiguous runs from 0 to 9.
+The digits of a number must come from the same run: a number mixing the digits of two scripts (e.g. `1٢`), which could be used to spoof a number, is rejected.
+
+# Hangul
+
+The Hangul syllables (U+AC00 to U+D7A3) are made of a leading consonant, a vowel and an optional trailing consonant, in the order of the conjoining jamos (U+1100 to U+11FF).
+[`compose_hangul`] and [`decompose_hangul`] convert between the syllables and the jamos by algorithm, like the canonical composition and decomposition of [`crate::normalize`], but without changing the other code points.
+
+# Display width
+
+[`display_width`] counts the columns taken by a text in a terminal, one extended grapheme cluster at a time, so that the combining marks and the emoji ZWJ sequences are not counted more than once.
+The ideographs, the full-width forms and the emoji with an emoji presentation take two columns.
+The characters with an ambiguous East Asian width (like the Greek and the Cyrillic letters, or the box drawing characters) are narrow, unless [`display_width_ambiguous`] is used with [`AmbiguousWidth::Wide`].
+The printers use the display width to align their rulers.
+
+**Note**:
+
+* The control characters, the private use characters, the surrogates, the noncharacters and the unassigned code points have no name. The printers show their code point label instead (e.g. `<control-000A>`).
 */
 
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::segment;
+use crate::tables;
 use crate::utf8;
 
+/// The short names of the leading consonants of the Hangul syllables.
+const JAMO_L: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+
+/// The short names of the vowels of the Hangul syllables.
+const JAMO_V: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
+    "WI", "YU", "EU", "YI", "I",
+];
+
+/// The short names of the trailing consonants of the Hangul syllables (the first one is the absent consonant).
+const JAMO_T: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+/// The first Hangul syllable.
+const HANGUL_BASE: u32 = 0xAC00;
+
+/// The number of Hangul syllables.
+const HANGUL_COUNT: u32 = 11172;
+
+/// The first leading consonant jamo.
+const L_BASE: u32 = 0x1100;
+
+/// The first vowel jamo.
+const V_BASE: u32 = 0x1161;
+
+/// The code point before the first trailing consonant jamo (a syllable without a trailing consonant).
+const T_BASE: u32 = 0x11A7;
+
+/// The number of leading consonant jamos.
+const L_COUNT: u32 = 19;
+
+/// The number of vowel jamos.
+const V_COUNT: u32 = 21;
+
+/// The number of trailing consonant jamos, plus one for the syllables without a trailing consonant.
+const T_COUNT: u32 = 28;
+
+/// The number of Hangul syllables with the same leading consonant.
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+
+/// Get the name of a Hangul syllable, derived from the short names of its jamos.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A Hangul syllable.
+///
+/// # Returns
+/// A [`String`] containing the name of the Hangul syllable.
+fn hangul_syllable_name(code_point: u32) -> String {
+    let index: u32 = code_point - HANGUL_BASE;
+    format!(
+        "HANGUL SYLLABLE {}{}{}",
+        JAMO_L[(index / N_COUNT) as usize],
+        JAMO_V[((index % N_COUNT) / T_COUNT) as usize],
+        JAMO_T[(index % T_COUNT) as usize]
+    )
+}
+
+/// Compose a leading consonant and a vowel, or an LV syllable and a trailing consonant, into a Hangul syllable.
+///
+/// # Parameters
+/// * `first`: [`u32`] - A leading consonant jamo or a Hangul LV syllable.
+/// * `second`: [`u32`] - A vowel jamo or a trailing consonant jamo.
+///
+/// # Returns
+/// The Hangul syllable, or [`None`] if the code points are not composed by algorithm.
+pub(crate) fn compose_jamos(first: u32, second: u32) -> Option<u32> {
+    // Hangul LV syllable
+    if (L_BASE..L_BASE + L_COUNT).contains(&first) && (V_BASE..V_BASE + V_COUNT).contains(&second) {
+        return Some(HANGUL_BASE + ((first - L_BASE) * V_COUNT + (second - V_BASE)) * T_COUNT);
+    }
+    // Hangul LVT syllable
+    if (HANGUL_BASE..HANGUL_BASE + HANGUL_COUNT).contains(&first)
+        && (first - HANGUL_BASE).is_multiple_of(T_COUNT)
+        && (T_BASE + 1..T_BASE + T_COUNT).contains(&second)
+    {
+        return Some(first + (second - T_BASE));
+    }
+    None
+}
+
+/// Decompose a Hangul syllable into its jamos, appending them to a vector.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+/// * `decomposed`: [`&mut Vec<u32>`] - The vector the jamos are appended to.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is a Hangul syllable (nothing is appended otherwise).
+pub(crate) fn decompose_syllable_into(code_point: u32, decomposed: &mut Vec<u32>) -> bool {
+    if !(HANGUL_BASE..HANGUL_BASE + HANGUL_COUNT).contains(&code_point) {
+        return false;
+    }
+    let index: u32 = code_point - HANGUL_BASE;
+    decomposed.push(L_BASE + index / N_COUNT);
+    decomposed.push(V_BASE + (index % N_COUNT) / T_COUNT);
+    if !index.is_multiple_of(T_COUNT) {
+        decomposed.push(T_BASE + index % T_COUNT);
+    }
+    true
+}
+
+/// Get the name of a code point listed in the names table.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`String`] containing the name, if the code point is in the names table.
+fn table_name(code_point: u32) -> Option<String> {
+    let i: usize = tables::find_range(tables::names::NAME_RANGES, code_point)?;
+    let (first, _, offset) = tables::names::NAME_RANGES[i];
+    let mut data = tables::names::NAME_DATA[offset as usize..].iter();
+    // Skip the names of the previous code points of the run
+    for _ in first..code_point {
+        for word in data.by_ref() {
+            if word & 0x8000 != 0 {
+                break;
+            }
+        }
+    }
+    let mut words: Vec<&str> = Vec::new();
+    for word in data {
+        words.push(tables::names::NAME_WORDS[(word & 0x7FFF) as usize]);
+        if word & 0x8000 != 0 {
+            break;
+        }
+    }
+    Some(words.join(" "))
+}
+
+/// A name (or an alias) of a code point, with its loose matching key.
+struct NameEntry {
+    code_point: u32,
+    name: String,
+    key: String,
+}
+
+/// The names and the aliases of the code points, with the index of their loose matching keys.
+struct NameIndex {
+    entries: Vec<NameEntry>,
+    keys: HashMap<String, u32>,
+}
+
+/// Get the loose matching key of a name, as defined by UAX #44 (UAX44-LM2).
+/// The case, the whitespaces, the underscores and the medial hyphens are ignored, except the hyphen of `HANGUL JUNGSEONG O-E`.
+///
+/// # Parameters
+/// * `name`: [`&str`] - A name.
+///
+/// # Returns
+/// A [`String`] containing the loose matching key of the name.
+fn loose_key(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut key: String = String::with_capacity(chars.len());
+    let mut medial_hyphen: bool = false;
+    for (i, c) in chars.iter().enumerate() {
+        match c {
+            c if c.is_whitespace() || *c == '_' => {}
+            '-' if i > 0
+                && chars[i - 1].is_ascii_alphanumeric()
+                && chars.get(i + 1).is_some_and(|n| n.is_ascii_alphanumeric()) =>
+            {
+                medial_hyphen = true;
+            }
+            c => key.push(c.to_ascii_uppercase()),
+        }
+    }
+    // U+1180 HANGUL JUNGSEONG O-E would collide with U+116C HANGUL JUNGSEONG OE
+    if medial_hyphen && key == "HANGULJUNGSEONGOE" && name.to_ascii_uppercase().contains("O-E") {
+        key = String::from("HANGULJUNGSEONGO-E");
+    }
+    key
+}
+
+/// Get the index of the names and the aliases, built the first time it is used.
+///
+/// # Returns
+/// A reference to the [`NameIndex`].
+fn name_index() -> &'static NameIndex {
+    static INDEX: OnceLock<NameIndex> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let mut entries: Vec<NameEntry> = Vec::new();
+        let mut data = tables::names::NAME_DATA.iter();
+        for (first, last, _) in tables::names::NAME_RANGES {
+            for code_point in *first..=*last {
+                let mut words: Vec<&str> = Vec::new();
+                for word in data.by_ref() {
+                    words.push(tables::names::NAME_WORDS[(word & 0x7FFF) as usize]);
+                    if word & 0x8000 != 0 {
+                        break;
+                    }
+                }
+                let name: String = words.join(" ");
+                entries.push(NameEntry {
+                    code_point,
+                    key: loose_key(&name),
+                    name,
+                });
+            }
+        }
+        for (code_point, alias) in tables::names::NAME_ALIASES {
+            entries.push(NameEntry {
+                code_point: *code_point,
+                name: alias.to_string(),
+                key: loose_key(alias),
+            });
+        }
+        let mut keys: HashMap<String, u32> = HashMap::with_capacity(entries.len());
+        for entry in &entries {
+            // The names take precedence over the aliases
+            keys.entry(entry.key.clone()).or_insert(entry.code_point);
+        }
+        NameIndex { entries, keys }
+    })
+}
+
+/// Find the code point of a name derived by algorithm (a Hangul syllable or an ideograph).
+///
+/// # Parameters
+/// * `key`: [`&str`] - The loose matching key of a name.
+///
+/// # Returns
+/// The code point with the derived name, if any.
+fn lookup_derived_name(key: &str) -> Option<u32> {
+    if let Some(jamos) = key.strip_prefix("HANGULSYLLABLE") {
+        return lookup_hangul_syllable(jamos);
+    }
+    tables::names::DERIVED_NAME_RANGES
+        .iter()
+        .find_map(|(first, last, prefix)| {
+            let hex: &str = key.strip_prefix(loose_key(prefix.trim_end_matches('-')).as_str())?;
+            let code_point: u32 = u32::from_str_radix(hex, 16).ok()?;
+            // Reject the zero padded and the signed spellings (e.g. `04E00`, `+4E00`)
+            if format!("{:04X}", code_point) != hex {
+                return None;
+            }
+            (*first..=*last).contains(&code_point).then_some(code_point)
+        })
+}
+
+/// Find the Hangul syllable with the given short names of its jamos.
+///
+/// # Parameters
+/// * `jamos`: [`&str`] - The loose matching key of the name after `HANGUL SYLLABLE` (e.g. `GAG`).
+///
+/// # Returns
+/// The Hangul syllable, if the short names are a leading consonant, a vowel and an optional trailing consonant.
+fn lookup_hangul_syllable(jamos: &str) -> Option<u32> {
+    JAMO_L.iter().enumerate().find_map(|(l, short_l)| {
+        let rest: &str = jamos.strip_prefix(short_l)?;
+        JAMO_V.iter().enumerate().find_map(|(v, short_v)| {
+            let short_t: &str = rest.strip_prefix(short_v)?;
+            let t: usize = JAMO_T.iter().position(|t| *t == short_t)?;
+            Some(HANGUL_BASE + (l as u32 * V_COUNT + v as u32) * T_COUNT + t as u32)
+        })
+    })
+}
+
+/// Rank how well a loose matching key matches a query.
+///
+/// # Parameters
+/// * `entry`: [`&NameEntry`] - A name.
+/// * `query`: [`&str`] - The loose matching key of the query.
+/// * `words`: [`&[String]`] - The loose matching keys of the words of the query.
+///
+/// # Returns
+/// The rank of the match (lower is better), or [`None`] if the name does not match.
+fn rank_name(entry: &NameEntry, query: &str, words: &[String]) -> Option<u8> {
+    if entry.key == query {
+        Some(0)
+    } else if entry.key.starts_with(query) {
+        Some(1)
+    } else if entry
+        .name
+        .split([' ', '-'])
+        .any(|word| loose_key(word).starts_with(query))
+    {
+        Some(2)
+    } else if entry.key.contains(query) {
+        Some(3)
+    } else if words.len() > 1 && words.iter().all(|w| entry.key.contains(w.as_str())) {
+        Some(4)
+    } else {
+        None
+    }
+}
+
+/// Get the label of a code point without a name, as defined by the Unicode Standard (e.g. `<control-000A>`).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`String`] containing the name or the label of the code point.
+fn name_or_label(code_point: u32) -> String {
+    if let Some(name) = name(code_point) {
+        return name;
+    }
+    let kind: &str = match general_category(code_point) {
+        GeneralCategory::Control => "control",
+        GeneralCategory::PrivateUse => "private-use",
+        GeneralCategory::Surrogate => "surrogate",
+        _ if (0xFDD0..=0xFDEF).contains(&code_point) || code_point & 0xFFFE == 0xFFFE => {
+            "noncharacter"
+        }
+        _ => "reserved",
+    };
+    format!("<{}-{:04X}>", kind, code_point)
+}
+
+/// Check if a code point takes no column on its own: the combining marks, the format characters and the Hangul vowels and trailing consonants, which join a leading consonant.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point has no width.
+fn is_zero_width(code_point: u32) -> bool {
+    matches!(
+        general_category(code_point),
+        GeneralCategory::NonspacingMark | GeneralCategory::EnclosingMark | GeneralCategory::Format
+    ) || (0x1160..=0x11FF).contains(&code_point)
+        || (0xD7B0..=0xD7FF).contains(&code_point)
+}
+
+/// Get the number of columns taken by an extended grapheme cluster in a terminal.
+///
+/// # Parameters
+/// * `cluster`: [`&[u32]`] - The code points of an extended grapheme cluster.
+/// * `ambiguous`: [`AmbiguousWidth`] - The width of the characters with an ambiguous East Asian width.
+///
+/// # Returns
+/// The width of the cluster: 0 for the controls and the clusters without a visible code point, 2 if a visible code point is wide or the cluster has an emoji presentation, 1 otherwise.
+fn cluster_width(cluster: &[u32], ambiguous: AmbiguousWidth) -> usize {
+    if general_category(cluster[0]) == GeneralCategory::Control {
+        return 0;
+    }
+    let mut visible = cluster.iter().filter(|cp| !is_zero_width(**cp)).peekable();
+    if visible.peek().is_none() {
+        return 0;
+    }
+    let wide: bool = visible.any(|cp| match east_asian_width(*cp) {
+        EastAsianWidth::Wide | EastAsianWidth::Fullwidth => true,
+        EastAsianWidth::Ambiguous => ambiguous == AmbiguousWidth::Wide,
+        _ => false,
+    });
+    // An emoji is shown with an emoji presentation by default, or when followed by U+FE0F VARIATION SELECTOR-16
+    let emoji: bool = tables::in_ranges(tables::emoji::EMOJI_PRESENTATION, cluster[0])
+        || (cluster.contains(&0xFE0F) && tables::in_ranges(tables::emoji::EMOJI, cluster[0]));
+    if wide || emoji {
+        2
+    } else {
+        1
+    }
+}
+
+/// Get the augmented script extensions of a code point (UTS #39), with the scripts of Han, Japanese and Korean writing added.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A sorted [`Vec<Script>`], or [`None`] if the code point is used with all the scripts (`Common` or `Inherited`).
+fn augmented_script_extensions(code_point: u32) -> Option<Vec<Script>> {
+    let mut scripts: Vec<Script> = script_extensions(code_point);
+    if scripts == [Script::Common] || scripts == [Script::Inherited] {
+        return None;
+    }
+    for script in scripts.clone() {
+        match script {
+            Script::Han => {
+                scripts.extend([Script::HanWithBopomofo, Script::Japanese, Script::Korean])
+            }
+            Script::Hiragana | Script::Katakana => scripts.push(Script::Japanese),
+            Script::Hangul => scripts.push(Script::Korean),
+            Script::Bopomofo => scripts.push(Script::HanWithBopomofo),
+            _ => {}
+        }
+    }
+    scripts.sort();
+    scripts.dedup();
+    Some(scripts)
+}
+
 /// Pretty print the unicode code points in hexadecimal, (binary) and decimal of a vector of unicode code points.
 ///
 /// # Parameters
 /// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
 /// * `binary_flag`: [`bool`] - A flag to print the binary representation of the unicode code points.
+/// * `name_flag`: [`bool`] - A flag to print the name of the unicode code points.
 ///
 /// # Note
 /// The bytes printed in hexadecimal are code points in unicode.
-fn print_unicode_vec<T: AsRef<Vec<u32>>>(unicode_cp: T, binary_flag: bool) {
+fn print_unicode_vec<T: AsRef<Vec<u32>>>(unicode_cp: T, binary_flag: bool, name_flag: bool) {
     let v: Vec<u32> = unicode_cp.as_ref().to_vec();
     let binary_repr: Vec<String> = v.iter().map(|x| format!("{:08b}", x)).collect();
     let string_repr: String = String::from_utf8(utf8::encode_in_utf8(&v)).unwrap();
@@ -32,19 +468,1295 @@ fn print_unicode_vec<T: AsRef<Vec<u32>>>(unicode_cp: T, binary_flag: bool) {
         println!("Bin: {:?}", binary_repr);
     }
     println!("Dec: {:?}", v);
-    println!(
-        "{}{}",
-        "-".repeat(45),
-        "-".repeat(string_repr.chars().count())
-    );
+    if name_flag {
+        let names: Vec<String> = v.iter().map(|x| name_or_label(*x)).collect();
+        println!("Name: {:?}", names);
+    }
+    println!("{}{}", "-".repeat(45), "-".repeat(display_width(&v)));
 
     println!();
 }
 
+/// Get the value of a decimal digit.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The value of the digit, from 0 to 9, if the code point is a decimal digit (`Numeric_Type=Decimal`).
+fn decimal_digit(code_point: u32) -> Option<u32> {
+    match numeric_type(code_point) {
+        Some(NumericType::Decimal) => {
+            tables::lookup_range(tables::numeric::NUMERIC_VALUE, code_point).map(|(n, _)| n as u32)
+        }
+        _ => None,
+    }
+}
+
 // ============================================================================
 // ================================ Public API ================================
 // ============================================================================
 
+/// The version of the Unicode Character Database the tables of the crate are generated from (major, minor, update).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(UNICODE_VERSION, (14, 0, 0));
+/// assert!(age(0x1F979).unwrap() <= (UNICODE_VERSION.0, UNICODE_VERSION.1));
+/// ```
+pub const UNICODE_VERSION: (u8, u8, u8) = tables::version::UNICODE_VERSION;
+
+/// The general category of a unicode code point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeneralCategory {
+    /// An uppercase letter (`Lu`).
+    UppercaseLetter,
+    /// A lowercase letter (`Ll`).
+    LowercaseLetter,
+    /// A digraph encoded as a single character, with the first part uppercase (`Lt`).
+    TitlecaseLetter,
+    /// A modifier letter (`Lm`).
+    ModifierLetter,
+    /// Other letters, including syllables and ideographs (`Lo`).
+    OtherLetter,
+    /// A nonspacing combining mark (`Mn`).
+    NonspacingMark,
+    /// A spacing combining mark (`Mc`).
+    SpacingMark,
+    /// An enclosing combining mark (`Me`).
+    EnclosingMark,
+    /// A decimal digit (`Nd`).
+    DecimalNumber,
+    /// A letterlike numeric character (`Nl`).
+    LetterNumber,
+    /// A numeric character of other type (`No`).
+    OtherNumber,
+    /// A connecting punctuation mark, like a tie (`Pc`).
+    ConnectorPunctuation,
+    /// A dash or hyphen punctuation mark (`Pd`).
+    DashPunctuation,
+    /// An opening punctuation mark (`Ps`).
+    OpenPunctuation,
+    /// A closing punctuation mark (`Pe`).
+    ClosePunctuation,
+    /// An initial quotation mark (`Pi`).
+    InitialPunctuation,
+    /// A final quotation mark (`Pf`).
+    FinalPunctuation,
+    /// A punctuation mark of other type (`Po`).
+    OtherPunctuation,
+    /// A symbol of mathematical use (`Sm`).
+    MathSymbol,
+    /// A currency sign (`Sc`).
+    CurrencySymbol,
+    /// A non-letterlike modifier symbol (`Sk`).
+    ModifierSymbol,
+    /// A symbol of other type (`So`).
+    OtherSymbol,
+    /// A space character of non-zero width (`Zs`).
+    SpaceSeparator,
+    /// The line separator U+2028 (`Zl`).
+    LineSeparator,
+    /// The paragraph separator U+2029 (`Zp`).
+    ParagraphSeparator,
+    /// A C0 or C1 control code (`Cc`).
+    Control,
+    /// A format control character (`Cf`).
+    Format,
+    /// A surrogate code point (`Cs`).
+    Surrogate,
+    /// A private use character (`Co`).
+    PrivateUse,
+    /// A reserved unassigned code point or a noncharacter (`Cn`).
+    Unassigned,
+}
+
+impl GeneralCategory {
+    /// Get the abbreviation of the general category, as used in the Unicode Character Database.
+    ///
+    /// # Returns
+    /// A [`&str`] containing the two letters abbreviation (e.g. `Lu`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use ende::prelude::*;
+    /// assert_eq!(GeneralCategory::UppercaseLetter.abbreviation(), "Lu");
+    /// ```
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            GeneralCategory::UppercaseLetter => "Lu",
+            GeneralCategory::LowercaseLetter => "Ll",
+            GeneralCategory::TitlecaseLetter => "Lt",
+            GeneralCategory::ModifierLetter => "Lm",
+            GeneralCategory::OtherLetter => "Lo",
+            GeneralCategory::NonspacingMark => "Mn",
+            GeneralCategory::SpacingMark => "Mc",
+            GeneralCategory::EnclosingMark => "Me",
+            GeneralCategory::DecimalNumber => "Nd",
+            GeneralCategory::LetterNumber => "Nl",
+            GeneralCategory::OtherNumber => "No",
+            GeneralCategory::ConnectorPunctuation => "Pc",
+            GeneralCategory::DashPunctuation => "Pd",
+            GeneralCategory::OpenPunctuation => "Ps",
+            GeneralCategory::ClosePunctuation => "Pe",
+            GeneralCategory::InitialPunctuation => "Pi",
+            GeneralCategory::FinalPunctuation => "Pf",
+            GeneralCategory::OtherPunctuation => "Po",
+            GeneralCategory::MathSymbol => "Sm",
+            GeneralCategory::CurrencySymbol => "Sc",
+            GeneralCategory::ModifierSymbol => "Sk",
+            GeneralCategory::OtherSymbol => "So",
+            GeneralCategory::SpaceSeparator => "Zs",
+            GeneralCategory::LineSeparator => "Zl",
+            GeneralCategory::ParagraphSeparator => "Zp",
+            GeneralCategory::Control => "Cc",
+            GeneralCategory::Format => "Cf",
+            GeneralCategory::Surrogate => "Cs",
+            GeneralCategory::PrivateUse => "Co",
+            GeneralCategory::Unassigned => "Cn",
+        }
+    }
+}
+
+/// The East Asian width of a unicode code point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EastAsianWidth {
+    /// A character that is wide in East Asian contexts and narrow otherwise, like the Greek letters (`A`).
+    Ambiguous,
+    /// A full-width compatibility character, like U+FF21 FULLWIDTH LATIN CAPITAL LETTER A (`F`).
+    Fullwidth,
+    /// A half-width compatibility character, like the half-width Katakana (`H`).
+    Halfwidth,
+    /// A character that does not occur in East Asian typography (`N`).
+    Neutral,
+    /// A narrow character with a full-width counterpart, like the ASCII characters (`Na`).
+    Narrow,
+    /// A wide character, like the ideographs and the emoji with emoji presentation (`W`).
+    Wide,
+}
+
+/// The width of the characters with an ambiguous East Asian width in a terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmbiguousWidth {
+    /// One column, as in most western terminals.
+    Narrow,
+    /// Two columns, as in the terminals configured for an East Asian locale.
+    Wide,
+}
+
+/// The numeric type of a unicode code point (values of the `Numeric_Type` property).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumericType {
+    /// A decimal digit, in a run of digits from 0 to 9 (`De`).
+    Decimal,
+    /// A digit which is not used in decimal numbers, like the superscripts and the circled digits (`Di`).
+    Digit,
+    /// Another character with a numeric value, like the fractions, the Roman numerals and the numeric ideographs (`Nu`).
+    Numeric,
+}
+
+/// The kind of a [`ParseDecimalError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseDecimalErrorKind {
+    /// There are no digits.
+    Empty,
+    /// The code point is not a decimal digit.
+    InvalidDigit(u32),
+    /// The digit does not belong to the same run of digits as the first one.
+    MixedDigits(u32),
+    /// The number is greater than [`u64::MAX`].
+    Overflow,
+}
+
+/// A number that cannot be parsed by [`parse_decimal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseDecimalError {
+    /// The kind of the error.
+    pub kind: ParseDecimalErrorKind,
+    /// The index of the code point where the error was found.
+    pub position: usize,
+}
+
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseDecimalErrorKind::Empty => write!(f, "Empty number")?,
+            ParseDecimalErrorKind::InvalidDigit(cp) => write!(f, "Invalid digit {:x}", cp)?,
+            ParseDecimalErrorKind::MixedDigits(cp) => write!(f, "Mixed digit {:x}", cp)?,
+            ParseDecimalErrorKind::Overflow => write!(f, "Number too large")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for ParseDecimalError {}
+
+/// The script of a unicode code point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Script {
+    /// The Adlam script (`Adlm`).
+    Adlam,
+    /// The Ahom script (`Ahom`).
+    Ahom,
+    /// The Anatolian Hieroglyphs script (`Hluw`).
+    AnatolianHieroglyphs,
+    /// The Arabic script (`Arab`).
+    Arabic,
+    /// The Armenian script (`Armn`).
+    Armenian,
+    /// The Avestan script (`Avst`).
+    Avestan,
+    /// The Balinese script (`Bali`).
+    Balinese,
+    /// The Bamum script (`Bamu`).
+    Bamum,
+    /// The Bassa Vah script (`Bass`).
+    BassaVah,
+    /// The Batak script (`Batk`).
+    Batak,
+    /// The Bengali script (`Beng`).
+    Bengali,
+    /// The Bhaiksuki script (`Bhks`).
+    Bhaiksuki,
+    /// The Bopomofo script (`Bopo`).
+    Bopomofo,
+    /// The Brahmi script (`Brah`).
+    Brahmi,
+    /// The Braille script (`Brai`).
+    Braille,
+    /// The Buginese script (`Bugi`).
+    Buginese,
+    /// The Buhid script (`Buhd`).
+    Buhid,
+    /// The Canadian Aboriginal script (`Cans`).
+    CanadianAboriginal,
+    /// The Carian script (`Cari`).
+    Carian,
+    /// The Caucasian Albanian script (`Aghb`).
+    CaucasianAlbanian,
+    /// The Chakma script (`Cakm`).
+    Chakma,
+    /// The Cham script (`Cham`).
+    Cham,
+    /// The Cherokee script (`Cher`).
+    Cherokee,
+    /// The Chorasmian script (`Chrs`).
+    Chorasmian,
+    /// The characters used with several scripts, like the punctuation and the digits (`Zyyy`).
+    Common,
+    /// The Coptic script (`Copt`).
+    Coptic,
+    /// The Cuneiform script (`Xsux`).
+    Cuneiform,
+    /// The Cypriot script (`Cprt`).
+    Cypriot,
+    /// The Cypro Minoan script (`Cpmn`).
+    CyproMinoan,
+    /// The Cyrillic script (`Cyrl`).
+    Cyrillic,
+    /// The Deseret script (`Dsrt`).
+    Deseret,
+    /// The Devanagari script (`Deva`).
+    Devanagari,
+    /// The Dives Akuru script (`Diak`).
+    DivesAkuru,
+    /// The Dogra script (`Dogr`).
+    Dogra,
+    /// The Duployan script (`Dupl`).
+    Duployan,
+    /// The Egyptian Hieroglyphs script (`Egyp`).
+    EgyptianHieroglyphs,
+    /// The Elbasan script (`Elba`).
+    Elbasan,
+    /// The Elymaic script (`Elym`).
+    Elymaic,
+    /// The Ethiopic script (`Ethi`).
+    Ethiopic,
+    /// The Georgian script (`Geor`).
+    Georgian,
+    /// The Glagolitic script (`Glag`).
+    Glagolitic,
+    /// The Gothic script (`Goth`).
+    Gothic,
+    /// The Grantha script (`Gran`).
+    Grantha,
+    /// The Greek script (`Grek`).
+    Greek,
+    /// The Gujarati script (`Gujr`).
+    Gujarati,
+    /// The Gunjala Gondi script (`Gong`).
+    GunjalaGondi,
+    /// The Gurmukhi script (`Guru`).
+    Gurmukhi,
+    /// The Han script (`Hani`).
+    Han,
+    /// The Hangul script (`Hang`).
+    Hangul,
+    /// The Hanifi Rohingya script (`Rohg`).
+    HanifiRohingya,
+    /// The Hanunoo script (`Hano`).
+    Hanunoo,
+    /// The Hatran script (`Hatr`).
+    Hatran,
+    /// The Hebrew script (`Hebr`).
+    Hebrew,
+    /// The Hiragana script (`Hira`).
+    Hiragana,
+    /// The Imperial Aramaic script (`Armi`).
+    ImperialAramaic,
+    /// The combining marks that take the script of their base character (`Zinh`).
+    Inherited,
+    /// The Inscriptional Pahlavi script (`Phli`).
+    InscriptionalPahlavi,
+    /// The Inscriptional Parthian script (`Prti`).
+    InscriptionalParthian,
+    /// The Javanese script (`Java`).
+    Javanese,
+    /// The Kaithi script (`Kthi`).
+    Kaithi,
+    /// The Kannada script (`Knda`).
+    Kannada,
+    /// The Katakana script (`Kana`).
+    Katakana,
+    /// The Kayah Li script (`Kali`).
+    KayahLi,
+    /// The Kharoshthi script (`Khar`).
+    Kharoshthi,
+    /// The Khitan Small Script script (`Kits`).
+    KhitanSmallScript,
+    /// The Khmer script (`Khmr`).
+    Khmer,
+    /// The Khojki script (`Khoj`).
+    Khojki,
+    /// The Khudawadi script (`Sind`).
+    Khudawadi,
+    /// The Lao script (`Laoo`).
+    Lao,
+    /// The Latin script (`Latn`).
+    Latin,
+    /// The Lepcha script (`Lepc`).
+    Lepcha,
+    /// The Limbu script (`Limb`).
+    Limbu,
+    /// The Linear A script (`Lina`).
+    LinearA,
+    /// The Linear B script (`Linb`).
+    LinearB,
+    /// The Lisu script (`Lisu`).
+    Lisu,
+    /// The Lycian script (`Lyci`).
+    Lycian,
+    /// The Lydian script (`Lydi`).
+    Lydian,
+    /// The Mahajani script (`Mahj`).
+    Mahajani,
+    /// The Makasar script (`Maka`).
+    Makasar,
+    /// The Malayalam script (`Mlym`).
+    Malayalam,
+    /// The Mandaic script (`Mand`).
+    Mandaic,
+    /// The Manichaean script (`Mani`).
+    Manichaean,
+    /// The Marchen script (`Marc`).
+    Marchen,
+    /// The Masaram Gondi script (`Gonm`).
+    MasaramGondi,
+    /// The Medefaidrin script (`Medf`).
+    Medefaidrin,
+    /// The Meetei Mayek script (`Mtei`).
+    MeeteiMayek,
+    /// The Mende Kikakui script (`Mend`).
+    MendeKikakui,
+    /// The Meroitic Cursive script (`Merc`).
+    MeroiticCursive,
+    /// The Meroitic Hieroglyphs script (`Mero`).
+    MeroiticHieroglyphs,
+    /// The Miao script (`Plrd`).
+    Miao,
+    /// The Modi script (`Modi`).
+    Modi,
+    /// The Mongolian script (`Mong`).
+    Mongolian,
+    /// The Mro script (`Mroo`).
+    Mro,
+    /// The Multani script (`Mult`).
+    Multani,
+    /// The Myanmar script (`Mymr`).
+    Myanmar,
+    /// The Nabataean script (`Nbat`).
+    Nabataean,
+    /// The Nandinagari script (`Nand`).
+    Nandinagari,
+    /// The New Tai Lue script (`Talu`).
+    NewTaiLue,
+    /// The Newa script (`Newa`).
+    Newa,
+    /// The Nko script (`Nkoo`).
+    Nko,
+    /// The Nushu script (`Nshu`).
+    Nushu,
+    /// The Nyiakeng Puachue Hmong script (`Hmnp`).
+    NyiakengPuachueHmong,
+    /// The Ogham script (`Ogam`).
+    Ogham,
+    /// The Ol Chiki script (`Olck`).
+    OlChiki,
+    /// The Old Hungarian script (`Hung`).
+    OldHungarian,
+    /// The Old Italic script (`Ital`).
+    OldItalic,
+    /// The Old North Arabian script (`Narb`).
+    OldNorthArabian,
+    /// The Old Permic script (`Perm`).
+    OldPermic,
+    /// The Old Persian script (`Xpeo`).
+    OldPersian,
+    /// The Old Sogdian script (`Sogo`).
+    OldSogdian,
+    /// The Old South Arabian script (`Sarb`).
+    OldSouthArabian,
+    /// The Old Turkic script (`Orkh`).
+    OldTurkic,
+    /// The Old Uyghur script (`Ougr`).
+    OldUyghur,
+    /// The Oriya script (`Orya`).
+    Oriya,
+    /// The Osage script (`Osge`).
+    Osage,
+    /// The Osmanya script (`Osma`).
+    Osmanya,
+    /// The Pahawh Hmong script (`Hmng`).
+    PahawhHmong,
+    /// The Palmyrene script (`Palm`).
+    Palmyrene,
+    /// The Pau Cin Hau script (`Pauc`).
+    PauCinHau,
+    /// The Phags Pa script (`Phag`).
+    PhagsPa,
+    /// The Phoenician script (`Phnx`).
+    Phoenician,
+    /// The Psalter Pahlavi script (`Phlp`).
+    PsalterPahlavi,
+    /// The Rejang script (`Rjng`).
+    Rejang,
+    /// The Runic script (`Runr`).
+    Runic,
+    /// The Samaritan script (`Samr`).
+    Samaritan,
+    /// The Saurashtra script (`Saur`).
+    Saurashtra,
+    /// The Sharada script (`Shrd`).
+    Sharada,
+    /// The Shavian script (`Shaw`).
+    Shavian,
+    /// The Siddham script (`Sidd`).
+    Siddham,
+    /// The SignWriting script (`Sgnw`).
+    SignWriting,
+    /// The Sinhala script (`Sinh`).
+    Sinhala,
+    /// The Sogdian script (`Sogd`).
+    Sogdian,
+    /// The Sora Sompeng script (`Sora`).
+    SoraSompeng,
+    /// The Soyombo script (`Soyo`).
+    Soyombo,
+    /// The Sundanese script (`Sund`).
+    Sundanese,
+    /// The Syloti Nagri script (`Sylo`).
+    SylotiNagri,
+    /// The Syriac script (`Syrc`).
+    Syriac,
+    /// The Tagalog script (`Tglg`).
+    Tagalog,
+    /// The Tagbanwa script (`Tagb`).
+    Tagbanwa,
+    /// The Tai Le script (`Tale`).
+    TaiLe,
+    /// The Tai Tham script (`Lana`).
+    TaiTham,
+    /// The Tai Viet script (`Tavt`).
+    TaiViet,
+    /// The Takri script (`Takr`).
+    Takri,
+    /// The Tamil script (`Taml`).
+    Tamil,
+    /// The Tangsa script (`Tnsa`).
+    Tangsa,
+    /// The Tangut script (`Tang`).
+    Tangut,
+    /// The Telugu script (`Telu`).
+    Telugu,
+    /// The Thaana script (`Thaa`).
+    Thaana,
+    /// The Thai script (`Thai`).
+    Thai,
+    /// The Tibetan script (`Tibt`).
+    Tibetan,
+    /// The Tifinagh script (`Tfng`).
+    Tifinagh,
+    /// The Tirhuta script (`Tirh`).
+    Tirhuta,
+    /// The Toto script (`Toto`).
+    Toto,
+    /// The Ugaritic script (`Ugar`).
+    Ugaritic,
+    /// The Vai script (`Vaii`).
+    Vai,
+    /// The Vithkuqi script (`Vith`).
+    Vithkuqi,
+    /// The Wancho script (`Wcho`).
+    Wancho,
+    /// The Warang Citi script (`Wara`).
+    WarangCiti,
+    /// The Yezidi script (`Yezi`).
+    Yezidi,
+    /// The Yi script (`Yiii`).
+    Yi,
+    /// The Zanabazar Square script (`Zanb`).
+    ZanabazarSquare,
+    /// The unassigned, private use and surrogate code points, and the noncharacters (`Zzzz`).
+    Unknown,
+    /// Han with Bopomofo, only found in the resolved script sets (`Hanb`).
+    HanWithBopomofo,
+    /// Japanese (Han, Hiragana and Katakana), only found in the resolved script sets (`Jpan`).
+    Japanese,
+    /// Korean (Han and Hangul), only found in the resolved script sets (`Kore`).
+    Korean,
+}
+
+impl Script {
+    /// Get the ISO 15924 code of the script, as used in the Unicode Character Database.
+    ///
+    /// # Returns
+    /// A [`&str`] containing the four letters code (e.g. `Latn`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use ende::prelude::*;
+    /// assert_eq!(Script::Cyrillic.abbreviation(), "Cyrl");
+    /// ```
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Script::Adlam => "Adlm",
+            Script::Ahom => "Ahom",
+            Script::AnatolianHieroglyphs => "Hluw",
+            Script::Arabic => "Arab",
+            Script::Armenian => "Armn",
+            Script::Avestan => "Avst",
+            Script::Balinese => "Bali",
+            Script::Bamum => "Bamu",
+            Script::BassaVah => "Bass",
+            Script::Batak => "Batk",
+            Script::Bengali => "Beng",
+            Script::Bhaiksuki => "Bhks",
+            Script::Bopomofo => "Bopo",
+            Script::Brahmi => "Brah",
+            Script::Braille => "Brai",
+            Script::Buginese => "Bugi",
+            Script::Buhid => "Buhd",
+            Script::CanadianAboriginal => "Cans",
+            Script::Carian => "Cari",
+            Script::CaucasianAlbanian => "Aghb",
+            Script::Chakma => "Cakm",
+            Script::Cham => "Cham",
+            Script::Cherokee => "Cher",
+            Script::Chorasmian => "Chrs",
+            Script::Common => "Zyyy",
+            Script::Coptic => "Copt",
+            Script::Cuneiform => "Xsux",
+            Script::Cypriot => "Cprt",
+            Script::CyproMinoan => "Cpmn",
+            Script::Cyrillic => "Cyrl",
+            Script::Deseret => "Dsrt",
+            Script::Devanagari => "Deva",
+            Script::DivesAkuru => "Diak",
+            Script::Dogra => "Dogr",
+            Script::Duployan => "Dupl",
+            Script::EgyptianHieroglyphs => "Egyp",
+            Script::Elbasan => "Elba",
+            Script::Elymaic => "Elym",
+            Script::Ethiopic => "Ethi",
+            Script::Georgian => "Geor",
+            Script::Glagolitic => "Glag",
+            Script::Gothic => "Goth",
+            Script::Grantha => "Gran",
+            Script::Greek => "Grek",
+            Script::Gujarati => "Gujr",
+            Script::GunjalaGondi => "Gong",
+            Script::Gurmukhi => "Guru",
+            Script::Han => "Hani",
+            Script::Hangul => "Hang",
+            Script::HanifiRohingya => "Rohg",
+            Script::Hanunoo => "Hano",
+            Script::Hatran => "Hatr",
+            Script::Hebrew => "Hebr",
+            Script::Hiragana => "Hira",
+            Script::ImperialAramaic => "Armi",
+            Script::Inherited => "Zinh",
+            Script::InscriptionalPahlavi => "Phli",
+            Script::InscriptionalParthian => "Prti",
+            Script::Javanese => "Java",
+            Script::Kaithi => "Kthi",
+            Script::Kannada => "Knda",
+            Script::Katakana => "Kana",
+            Script::KayahLi => "Kali",
+            Script::Kharoshthi => "Khar",
+            Script::KhitanSmallScript => "Kits",
+            Script::Khmer => "Khmr",
+            Script::Khojki => "Khoj",
+            Script::Khudawadi => "Sind",
+            Script::Lao => "Laoo",
+            Script::Latin => "Latn",
+            Script::Lepcha => "Lepc",
+            Script::Limbu => "Limb",
+            Script::LinearA => "Lina",
+            Script::LinearB => "Linb",
+            Script::Lisu => "Lisu",
+            Script::Lycian => "Lyci",
+            Script::Lydian => "Lydi",
+            Script::Mahajani => "Mahj",
+            Script::Makasar => "Maka",
+            Script::Malayalam => "Mlym",
+            Script::Mandaic => "Mand",
+            Script::Manichaean => "Mani",
+            Script::Marchen => "Marc",
+            Script::MasaramGondi => "Gonm",
+            Script::Medefaidrin => "Medf",
+            Script::MeeteiMayek => "Mtei",
+            Script::MendeKikakui => "Mend",
+            Script::MeroiticCursive => "Merc",
+            Script::MeroiticHieroglyphs => "Mero",
+            Script::Miao => "Plrd",
+            Script::Modi => "Modi",
+            Script::Mongolian => "Mong",
+            Script::Mro => "Mroo",
+            Script::Multani => "Mult",
+            Script::Myanmar => "Mymr",
+            Script::Nabataean => "Nbat",
+            Script::Nandinagari => "Nand",
+            Script::NewTaiLue => "Talu",
+            Script::Newa => "Newa",
+            Script::Nko => "Nkoo",
+            Script::Nushu => "Nshu",
+            Script::NyiakengPuachueHmong => "Hmnp",
+            Script::Ogham => "Ogam",
+            Script::OlChiki => "Olck",
+            Script::OldHungarian => "Hung",
+            Script::OldItalic => "Ital",
+            Script::OldNorthArabian => "Narb",
+            Script::OldPermic => "Perm",
+            Script::OldPersian => "Xpeo",
+            Script::OldSogdian => "Sogo",
+            Script::OldSouthArabian => "Sarb",
+            Script::OldTurkic => "Orkh",
+            Script::OldUyghur => "Ougr",
+            Script::Oriya => "Orya",
+            Script::Osage => "Osge",
+            Script::Osmanya => "Osma",
+            Script::PahawhHmong => "Hmng",
+            Script::Palmyrene => "Palm",
+            Script::PauCinHau => "Pauc",
+            Script::PhagsPa => "Phag",
+            Script::Phoenician => "Phnx",
+            Script::PsalterPahlavi => "Phlp",
+            Script::Rejang => "Rjng",
+            Script::Runic => "Runr",
+            Script::Samaritan => "Samr",
+            Script::Saurashtra => "Saur",
+            Script::Sharada => "Shrd",
+            Script::Shavian => "Shaw",
+            Script::Siddham => "Sidd",
+            Script::SignWriting => "Sgnw",
+            Script::Sinhala => "Sinh",
+            Script::Sogdian => "Sogd",
+            Script::SoraSompeng => "Sora",
+            Script::Soyombo => "Soyo",
+            Script::Sundanese => "Sund",
+            Script::SylotiNagri => "Sylo",
+            Script::Syriac => "Syrc",
+            Script::Tagalog => "Tglg",
+            Script::Tagbanwa => "Tagb",
+            Script::TaiLe => "Tale",
+            Script::TaiTham => "Lana",
+            Script::TaiViet => "Tavt",
+            Script::Takri => "Takr",
+            Script::Tamil => "Taml",
+            Script::Tangsa => "Tnsa",
+            Script::Tangut => "Tang",
+            Script::Telugu => "Telu",
+            Script::Thaana => "Thaa",
+            Script::Thai => "Thai",
+            Script::Tibetan => "Tibt",
+            Script::Tifinagh => "Tfng",
+            Script::Tirhuta => "Tirh",
+            Script::Toto => "Toto",
+            Script::Ugaritic => "Ugar",
+            Script::Vai => "Vaii",
+            Script::Vithkuqi => "Vith",
+            Script::Wancho => "Wcho",
+            Script::WarangCiti => "Wara",
+            Script::Yezidi => "Yezi",
+            Script::Yi => "Yiii",
+            Script::ZanabazarSquare => "Zanb",
+            Script::Unknown => "Zzzz",
+            Script::HanWithBopomofo => "Hanb",
+            Script::Japanese => "Jpan",
+            Script::Korean => "Kore",
+        }
+    }
+}
+
+/// A set of scripts, as resolved by [`resolved_script_set`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ScriptSet {
+    /// All the scripts, for a text without characters of a specific script (e.g. only digits and punctuation).
+    All,
+    /// The scripts shared by all the characters, sorted (empty if the text mixes scripts).
+    Scripts(Vec<Script>),
+}
+
+impl ScriptSet {
+    /// Check if the set is empty, i.e. if no script is shared by all the characters.
+    ///
+    /// # Returns
+    /// A [`bool`] indicating if the set is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ende::prelude::*;
+    /// assert!(ScriptSet::Scripts(Vec::new()).is_empty());
+    /// assert!(!ScriptSet::All.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        matches!(self, ScriptSet::Scripts(scripts) if scripts.is_empty())
+    }
+
+    /// Check if the set contains a script.
+    ///
+    /// # Parameters
+    /// * `script`: [`Script`] - A script.
+    ///
+    /// # Returns
+    /// A [`bool`] indicating if the set contains the script.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ende::prelude::*;
+    /// assert!(ScriptSet::All.contains(Script::Latin));
+    /// assert!(!ScriptSet::Scripts(vec![Script::Cyrillic]).contains(Script::Latin));
+    /// ```
+    pub fn contains(&self, script: Script) -> bool {
+        match self {
+            ScriptSet::All => true,
+            ScriptSet::Scripts(scripts) => scripts.contains(&script),
+        }
+    }
+}
+
+/// Get the name of a unicode code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`String`] containing the name of the code point, or [`None`] if the code point has no name (control characters, private use characters, surrogates, noncharacters and unassigned code points).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(name(0x41), Some(String::from("LATIN CAPITAL LETTER A")));
+/// assert_eq!(name(0x4E00), Some(String::from("CJK UNIFIED IDEOGRAPH-4E00")));
+/// assert_eq!(name(0xAC01), Some(String::from("HANGUL SYLLABLE GAG")));
+/// assert_eq!(name(0x0A), None);
+/// ```
+pub fn name(code_point: u32) -> Option<String> {
+    if (HANGUL_BASE..HANGUL_BASE + HANGUL_COUNT).contains(&code_point) {
+        return Some(hangul_syllable_name(code_point));
+    }
+    if let Some(prefix) = tables::lookup_range(tables::names::DERIVED_NAME_RANGES, code_point) {
+        return Some(format!("{}{:04X}", prefix, code_point));
+    }
+    table_name(code_point)
+}
+
+/// Find the code point of a unicode character name or alias.
+///
+/// The name is matched loosely, as defined by UAX #44 (UAX44-LM2): the case, the whitespaces, the underscores and the medial hyphens are ignored.
+/// The names derived by algorithm (e.g. `CJK UNIFIED IDEOGRAPH-4E00`, `HANGUL SYLLABLE GAG`) and the aliases of `NameAliases.txt` are found too.
+///
+/// # Parameters
+/// * `name`: [`&str`] - A unicode character name or alias.
+///
+/// # Returns
+/// The code point with the name, or [`None`] if there is no code point with the name.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(lookup_name("GREEK SMALL LETTER ALPHA"), Some(0x3b1));
+/// assert_eq!(lookup_name("greek_small_letter_alpha"), Some(0x3b1));
+/// assert_eq!(lookup_name("CJK UNIFIED IDEOGRAPH-4E00"), Some(0x4e00));
+/// assert_eq!(lookup_name("LINE FEED"), Some(0x0a));
+/// assert_eq!(lookup_name("NOT A CHARACTER NAME"), None);
+/// ```
+pub fn lookup_name(name: &str) -> Option<u32> {
+    let key: String = loose_key(name);
+    if let Some(code_point) = name_index().keys.get(&key) {
+        return Some(*code_point);
+    }
+    lookup_derived_name(&key)
+}
+
+/// Search the unicode character names and aliases matching a query, ranked from the best match.
+///
+/// The names are ranked in this order, and then from the shortest one:
+/// 1. The names matching the query (see [`lookup_name`]).
+/// 2. The names starting with the query.
+/// 3. The names with a word starting with the query.
+/// 4. The names containing the query.
+/// 5. The names containing every word of the query.
+///
+/// The names derived by algorithm are found only if they match the query.
+///
+/// # Parameters
+/// * `query`: [`&str`] - A part of a unicode character name, matched loosely (see [`lookup_name`]).
+/// * `limit`: [`usize`] - The maximum number of candidates.
+///
+/// # Returns
+/// A [`Vec<(u32, String)>`] containing the code points and their matching name (or alias), without duplicated code points.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let candidates: Vec<(u32, String)> = search_names("greek alpha", 3);
+/// assert_eq!(candidates[0], (0x3b1, String::from("GREEK SMALL LETTER ALPHA")));
+/// ```
+pub fn search_names(query: &str, limit: usize) -> Vec<(u32, String)> {
+    let key: String = loose_key(query);
+    if key.is_empty() || limit == 0 {
+        return Vec::new();
+    }
+    let words: Vec<String> = query
+        .split_whitespace()
+        .map(loose_key)
+        .filter(|w| !w.is_empty())
+        .collect();
+    let mut ranked: Vec<(u8, &NameEntry)> = name_index()
+        .entries
+        .iter()
+        .filter_map(|entry| rank_name(entry, &key, &words).map(|rank| (rank, entry)))
+        .collect();
+    ranked.sort_by(|(rank_a, a), (rank_b, b)| {
+        rank_a
+            .cmp(rank_b)
+            .then(a.name.len().cmp(&b.name.len()))
+            .then(a.code_point.cmp(&b.code_point))
+    });
+    let mut candidates: Vec<(u32, String)> = Vec::new();
+    if let Some(code_point) = lookup_derived_name(&key) {
+        candidates.push((code_point, name(code_point).unwrap()));
+    }
+    for (_, entry) in ranked {
+        if candidates.len() == limit {
+            break;
+        }
+        if candidates.iter().all(|(cp, _)| *cp != entry.code_point) {
+            candidates.push((entry.code_point, entry.name.clone()));
+        }
+    }
+    candidates
+}
+
+/// Get the general category of a unicode code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The [`GeneralCategory`] of the code point ([`GeneralCategory::Unassigned`] for the unassigned code points and the values greater than `0x10FFFF`).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(general_category(0x41), GeneralCategory::UppercaseLetter);
+/// assert_eq!(general_category(0x20AC), GeneralCategory::CurrencySymbol);
+/// assert_eq!(general_category(0x0378), GeneralCategory::Unassigned);
+/// ```
+pub fn general_category(code_point: u32) -> GeneralCategory {
+    tables::lookup_range(tables::general_category::GENERAL_CATEGORY, code_point)
+        .unwrap_or(GeneralCategory::Unassigned)
+}
+
+/// Get the version of Unicode in which a code point was assigned.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A tuple containing the major and minor version, or [`None`] if the code point is unassigned.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(age(0x41), Some((1, 1)));
+/// assert_eq!(age(0x20AC), Some((2, 1)));
+/// assert_eq!(age(0x0378), None);
+/// ```
+pub fn age(code_point: u32) -> Option<(u8, u8)> {
+    tables::lookup_range(tables::age::AGE, code_point)
+}
+
+/// Get the name of the block containing a unicode code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`&str`] containing the name of the block, or [`None`] if the code point is not in a block.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(block(0x41), Some("Basic Latin"));
+/// assert_eq!(block(0x1F600), Some("Emoticons"));
+/// assert_eq!(block(0x2FE0), None);
+/// ```
+pub fn block(code_point: u32) -> Option<&'static str> {
+    tables::lookup_range(tables::block::BLOCK, code_point)
+}
+
+/// Get the East Asian width of a unicode code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The [`EastAsianWidth`] of the code point ([`EastAsianWidth::Neutral`] if it has none).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(east_asian_width(0x41), EastAsianWidth::Narrow);
+/// assert_eq!(east_asian_width(0x4E00), EastAsianWidth::Wide);
+/// assert_eq!(east_asian_width(0xFF21), EastAsianWidth::Fullwidth);
+/// assert_eq!(east_asian_width(0x3B1), EastAsianWidth::Ambiguous);
+/// assert_eq!(east_asian_width(0x5D0), EastAsianWidth::Neutral);
+/// ```
+pub fn east_asian_width(code_point: u32) -> EastAsianWidth {
+    tables::lookup_range(tables::east_asian_width::EAST_ASIAN_WIDTH, code_point)
+        .unwrap_or(EastAsianWidth::Neutral)
+}
+
+/// Get the numeric type of a unicode code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The [`NumericType`] of the code point, or [`None`] if the code point has no numeric value.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(numeric_type(0x37), Some(NumericType::Decimal)); // 7
+/// assert_eq!(numeric_type(0x967), Some(NumericType::Decimal)); // १
+/// assert_eq!(numeric_type(0xB2), Some(NumericType::Digit)); // ²
+/// assert_eq!(numeric_type(0xBD), Some(NumericType::Numeric)); // ½
+/// assert_eq!(numeric_type(0x41), None);
+/// ```
+pub fn numeric_type(code_point: u32) -> Option<NumericType> {
+    tables::lookup_range(tables::numeric::NUMERIC_TYPE, code_point)
+}
+
+/// Get the numeric value of a unicode code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The numeric value of the code point, or [`None`] if the code point has no numeric value.
+/// The values are integers (up to 10<sup>12</sup>), or fractions (e.g. 1/2, 3/16 or -1/2).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(numeric_value(0x37), Some(7.0)); // 7
+/// assert_eq!(numeric_value(0x663), Some(3.0)); // ٣
+/// assert_eq!(numeric_value(0xBD), Some(0.5)); // ½
+/// assert_eq!(numeric_value(0x216B), Some(12.0)); // Ⅻ
+/// assert_eq!(numeric_value(0x4E07), Some(10000.0)); // 万
+/// assert_eq!(numeric_value(0x41), None);
+/// ```
+pub fn numeric_value(code_point: u32) -> Option<f64> {
+    tables::lookup_range(tables::numeric::NUMERIC_VALUE, code_point)
+        .map(|(numerator, denominator)| numerator as f64 / denominator as f64)
+}
+
+/// Parse a number written with the decimal digits of any script.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// The value of the number, or the [`ParseDecimalError`] of the first code point which is not a digit, or not a digit of the same run as the first one.
+/// There is no sign and no separator: every code point must be a decimal digit.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "١٢٣".chars().map(|c| c as u32).collect(); // Arabic-Indic digits
+/// assert_eq!(parse_decimal(&v), Ok(123));
+/// let v: Vec<u32> = "２０２２".chars().map(|c| c as u32).collect(); // full-width digits
+/// assert_eq!(parse_decimal(&v), Ok(2022));
+///
+/// let v: Vec<u32> = "1२3".chars().map(|c| c as u32).collect(); // with a Devanagari "२"
+/// let err: ParseDecimalError = parse_decimal(&v).unwrap_err();
+/// assert_eq!(err.kind, ParseDecimalErrorKind::MixedDigits(0x968));
+/// assert_eq!(err.position, 1);
+/// ```
+pub fn parse_decimal(unicode_cp: &[u32]) -> Result<u64, ParseDecimalError> {
+    if unicode_cp.is_empty() {
+        return Err(ParseDecimalError {
+            kind: ParseDecimalErrorKind::Empty,
+            position: 0,
+        });
+    }
+    // The zero of the run of digits of the number
+    let mut zero: Option<u32> = None;
+    let mut value: u64 = 0;
+    for (position, code_point) in unicode_cp.iter().enumerate() {
+        let error = |kind: ParseDecimalErrorKind| ParseDecimalError { kind, position };
+        let digit: u32 = decimal_digit(*code_point)
+            .ok_or(error(ParseDecimalErrorKind::InvalidDigit(*code_point)))?;
+        if *zero.get_or_insert(code_point - digit) != code_point - digit {
+            return Err(error(ParseDecimalErrorKind::MixedDigits(*code_point)));
+        }
+        value = value
+            .checked_mul(10)
+            .and_then(|v| v.checked_add(digit as u64))
+            .ok_or(error(ParseDecimalErrorKind::Overflow))?;
+    }
+    Ok(value)
+}
+
+/// Get the script of a unicode code point.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The [`Script`] of the code point ([`Script::Unknown`] for the unassigned code points and the values greater than `0x10FFFF`).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(script(0x41), Script::Latin);
+/// assert_eq!(script(0x430), Script::Cyrillic);
+/// assert_eq!(script(0x30), Script::Common);
+/// assert_eq!(script(0x301), Script::Inherited);
+/// assert_eq!(script(0x0378), Script::Unknown);
+/// ```
+pub fn script(code_point: u32) -> Script {
+    tables::lookup_range(tables::script::SCRIPT, code_point).unwrap_or(Script::Unknown)
+}
+
+/// Get the scripts a unicode code point is used with.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`Vec<Script>`] containing the script extensions of the code point, or its [`script`] if it has none.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(script_extensions(0x41), vec![Script::Latin]);
+/// assert_eq!(script_extensions(0x30FC), vec![Script::Hiragana, Script::Katakana]);
+/// ```
+pub fn script_extensions(code_point: u32) -> Vec<Script> {
+    match tables::lookup_range(tables::script::SCRIPT_EXTENSIONS, code_point) {
+        Some(scripts) => scripts.to_vec(),
+        None => vec![script(code_point)],
+    }
+}
+
+/// Get the resolved script set of unicode code points (UTS #39), i.e. the scripts shared by all the code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// The intersection of the augmented script extensions of the code points, as a [`ScriptSet`].
+/// The code points of the `Common` and `Inherited` scripts are used with all the scripts, and the Han characters are also Japanese and Korean.
+/// An empty set means that the text mixes scripts.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "paypal".chars().map(|c| c as u32).collect();
+/// assert_eq!(resolved_script_set(&v), ScriptSet::Scripts(vec![Script::Latin]));
+/// // With a Cyrillic "а"
+/// let v: Vec<u32> = "p\u{430}ypal".chars().map(|c| c as u32).collect();
+/// assert!(resolved_script_set(&v).is_empty());
+/// let v: Vec<u32> = "東京タワー".chars().map(|c| c as u32).collect();
+/// assert_eq!(resolved_script_set(&v), ScriptSet::Scripts(vec![Script::Japanese]));
+/// ```
+pub fn resolved_script_set(unicode_cp: &[u32]) -> ScriptSet {
+    let mut set: ScriptSet = ScriptSet::All;
+    for code_point in unicode_cp {
+        let scripts: Vec<Script> = match augmented_script_extensions(*code_point) {
+            Some(scripts) => scripts,
+            None => continue,
+        };
+        set = match set {
+            ScriptSet::All => ScriptSet::Scripts(scripts),
+            ScriptSet::Scripts(mut resolved) => {
+                resolved.retain(|script| scripts.contains(script));
+                ScriptSet::Scripts(resolved)
+            }
+        };
+    }
+    set
+}
+
+/// Get the number of columns taken by unicode code points in a terminal, with the ambiguous characters narrow.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// The sum of the widths of the extended grapheme clusters (see [`display_width_ambiguous`]).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = "abc日本語e\u{301}👩\u{200D}💻".chars().map(|c| c as u32).collect();
+/// assert_eq!(display_width(&v), 12);
+/// ```
+pub fn display_width(unicode_cp: &[u32]) -> usize {
+    display_width_ambiguous(unicode_cp, AmbiguousWidth::Narrow)
+}
+
+/// Get the number of columns taken by unicode code points in a terminal.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+/// * `ambiguous`: [`AmbiguousWidth`] - The width of the characters with an ambiguous East Asian width.
+///
+/// # Returns
+/// The sum of the widths of the extended grapheme clusters:
+/// * 0 for the control characters and the clusters made only of combining marks, format characters and Hangul vowels or trailing consonants.
+/// * 2 for the clusters with a wide or full-width code point (`EastAsianWidth.txt`), and for the emoji with an emoji presentation, by default or with U+FE0F VARIATION SELECTOR-16 (`emoji-data.txt`).
+/// * 1 otherwise, or 2 for the ambiguous characters with [`AmbiguousWidth::Wide`].
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = "α→β ❤\u{FE0F}".chars().map(|c| c as u32).collect();
+/// assert_eq!(display_width_ambiguous(&v, AmbiguousWidth::Narrow), 6);
+/// assert_eq!(display_width_ambiguous(&v, AmbiguousWidth::Wide), 9);
+/// ```
+pub fn display_width_ambiguous(unicode_cp: &[u32], ambiguous: AmbiguousWidth) -> usize {
+    segment::graphemes(unicode_cp)
+        .map(|cluster| cluster_width(&unicode_cp[cluster], ambiguous))
+        .sum()
+}
+
+/// Get the short name of a conjoining Hangul jamo (`Jamo.txt`), used to derive the names of the Hangul syllables.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// The short name of the leading consonant (U+1100 to U+1112), vowel (U+1161 to U+1175) or trailing consonant (U+11A8 to U+11C2), or [`None`] for the other code points.
+/// The short name of U+110B HANGUL CHOSEONG IEUNG is empty, since it is silent at the beginning of a syllable.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(jamo_short_name(0x1100), Some("G"));
+/// assert_eq!(jamo_short_name(0x1161), Some("A"));
+/// assert_eq!(jamo_short_name(0x11AB), Some("N"));
+/// assert_eq!(jamo_short_name(0x110B), Some(""));
+/// assert_eq!(jamo_short_name(0xAC00), None);
+/// ```
+pub fn jamo_short_name(code_point: u32) -> Option<&'static str> {
+    if (L_BASE..L_BASE + L_COUNT).contains(&code_point) {
+        Some(JAMO_L[(code_point - L_BASE) as usize])
+    } else if (V_BASE..V_BASE + V_COUNT).contains(&code_point) {
+        Some(JAMO_V[(code_point - V_BASE) as usize])
+    } else if (T_BASE + 1..T_BASE + T_COUNT).contains(&code_point) {
+        Some(JAMO_T[(code_point - T_BASE) as usize])
+    } else {
+        None
+    }
+}
+
+/// Compose the conjoining Hangul jamos of a vector of unicode code points into Hangul syllables.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the code points, with every leading consonant followed by a vowel composed into an LV syllable, and every LV syllable followed by a trailing consonant composed into an LVT syllable.
+/// The other code points, including the jamos which are not part of a syllable, are kept unchanged.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = vec![0x1112, 0x1161, 0x11AB, 0x1100, 0x1173, 0x11AF]; // ᄒ ᅡ ᆫ ᄀ ᅳ ᆯ
+/// assert_eq!(compose_hangul(&v), vec![0xD55C, 0xAE00]); // 한글
+/// let v: Vec<u32> = vec![0xAC00, 0x11A8, 0x1161]; // 가 ᆨ ᅡ
+/// assert_eq!(compose_hangul(&v), vec![0xAC01, 0x1161]); // 각 ᅡ
+/// ```
+pub fn compose_hangul<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    let mut composed: Vec<u32> = Vec::with_capacity(unicode_cp.as_ref().len());
+    for code_point in unicode_cp.as_ref() {
+        match composed
+            .last()
+            .and_then(|last| compose_jamos(*last, *code_point))
+        {
+            Some(syllable) => *composed.last_mut().unwrap() = syllable,
+            None => composed.push(*code_point),
+        }
+    }
+    composed
+}
+
+/// Decompose the Hangul syllables of a vector of unicode code points into conjoining jamos.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the code points, with every Hangul syllable (U+AC00 to U+D7A3) replaced by its leading consonant, its vowel and its trailing consonant, if any.
+/// The other code points are kept unchanged.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = "한글!".chars().map(|c| c as u32).collect();
+/// assert_eq!(decompose_hangul(&v), vec![0x1112, 0x1161, 0x11AB, 0x1100, 0x1173, 0x11AF, 0x21]);
+/// assert_eq!(compose_hangul(decompose_hangul(&v)), v);
+/// ```
+pub fn decompose_hangul<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    let mut decomposed: Vec<u32> = Vec::with_capacity(unicode_cp.as_ref().len());
+    for code_point in unicode_cp.as_ref() {
+        if !decompose_syllable_into(*code_point, &mut decomposed) {
+            decomposed.push(*code_point);
+        }
+    }
+    decomposed
+}
+
 /// Check if a unicode code point is valid.
 /// A unicode code point is valid if it is not in the range `0xD800` to `0xDFFF`.
 /// These code points are reserved for UTF-16 surrogate pairs.
@@ -83,7 +1795,7 @@ pub fn check_code_point(code_point: u32) {
 /// ---------------------------------------------------
 /// ```
 pub fn print_unicode_b<T: AsRef<Vec<u32>>>(unicode_cp: T) {
-    print_unicode_vec(unicode_cp, true);
+    print_unicode_vec(unicode_cp, true, false);
 }
 
 /// Pretty print the unicode code points in hexadecimal and decimal of a vector of unicode code points.
@@ -108,5 +1820,60 @@ pub fn print_unicode_b<T: AsRef<Vec<u32>>>(unicode_cp: T) {
 /// ---------------------------------------------------
 /// ```
 pub fn print_unicode<T: AsRef<Vec<u32>>>(unicode_cp: T) {
-    print_unicode_vec(unicode_cp, false);
+    print_unicode_vec(unicode_cp, false, false);
+}
+
+/// Pretty print the unicode code points in hexadecimal, binary, decimal and their names of a vector of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Note
+/// The bytes printed in hexadecimal are code points in unicode.
+/// The code points without a name are printed with their label (e.g. `<control-000A>`).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x10001];
+/// print_unicode_bn(&v);
+/// ```
+/// **Output**
+/// ```text
+/// --------------- UNICODE code points ---------------
+/// Hex: [10001]
+/// Bin: ["10000000000000001"]
+/// Dec: [65537]
+/// Name: ["LINEAR B SYLLABLE B038 E"]
+/// ---------------------------------------------------
+/// ```
+pub fn print_unicode_bn<T: AsRef<Vec<u32>>>(unicode_cp: T) {
+    print_unicode_vec(unicode_cp, true, true);
+}
+
+/// Pretty print the unicode code points in hexadecimal, decimal and their names of a vector of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Note
+/// The bytes printed in hexadecimal are code points in unicode.
+/// The code points without a name are printed with their label (e.g. `<control-000A>`).
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// let v: Vec<u32> = vec![0x41, 0x0A];
+/// print_unicode_n(&v);
+/// ```
+/// **Output**
+/// ```text
+/// --------------- UNICODE code points ---------------
+/// Hex: [41, a]
+/// Dec: [65, 10]
+/// Name: ["LATIN CAPITAL LETTER A", "<control-000A>"]
+/// ---------------------------------------------------
+/// ```
+pub fn print_unicode_n<T: AsRef<Vec<u32>>>(unicode_cp: T) {
+    print_unicode_vec(unicode_cp, false, true);
 }
@@ -3,12 +3,12 @@ UTF-16 encoding and decoding.
 
 # Encoding
 A unicode code point is represented using two or four bytes in UTF-16, depending its value.
-* If the unicode code point is less than 0xFFFF, it is represented using [a word of 16 bits (two bytes)](#two-bytes-one-word).
-* If the unicode code point is greater than or equal to 0xFFFF, it is represented using a surrogate pair [two words of 16 bits (four bytes)](#four-bytes-two-words---surrogate-pair).
+* If the unicode code point is less than or equal to 0xFFFF, it is represented using [a word of 16 bits (two bytes)](#two-bytes-one-word).
+* If the unicode code point is greater than 0xFFFF, it is represented using a surrogate pair [two words of 16 bits (four bytes)](#four-bytes-two-words---surrogate-pair).
 
 # Decoding
 A UTF-16 code point is decoded into a unicode code point using the following rules.
-* If the UTF-16 code point is less than 0xD800 or greater than 0xDBFF and less than 0xFFFF, it is a unicode code point.
+* If the UTF-16 code point is less than 0xD800 or greater than 0xDBFF and less than or equal to 0xFFFF, it is a unicode code point.
 * If the UTF-16 code point is between 0xD800 and 0xDBFF, it is a [surrogate pair](#two-bytes-one-word), so two UTF-16 code points are required to represent a unicode code point.
 
 ## Representation
@@ -20,16 +20,16 @@ A UTF-16 code point is decoded into a unicode code point using the following rul
 
 ### Two bytes (one word)
 
-**Encoding**: If the unicode code point is less than 0xFFFF, the unicode code point is represented in UTF-16 using only the 16 least significant bits.
+**Encoding**: If the unicode code point is less than or equal to 0xFFFF, the unicode code point is represented in UTF-16 using only the 16 least significant bits.
 
-**Decoding**: If the UTF-16 code point is less than 0xD800 or greater than 0xDBFF and less than 0xFFFF, the unicode code point is represented using only the 16 least significant bits.
+**Decoding**: If the UTF-16 code point is less than 0xD800 or greater than 0xDBFF and less than or equal to 0xFFFF, the unicode code point is represented using only the 16 least significant bits.
 
 * Unicode code point: `nnnnnnnn|nnnnnnnn|xxxxxxxx|xxxxxxxx`
 * UTF-16 code point: `xxxxxxxx|xxxxxxxx`
 
 ### Four bytes (two words) - Surrogate pair
 
-**Encoding**: If the unicode code point is greater than or equal to 0xFFFF, the unicode code point is represented in UTF-16 using a surrogate pair.
+**Encoding**: If the unicode code point is greater than 0xFFFF, the unicode code point is represented in UTF-16 using a surrogate pair.
 
 **Decoding**: If the UTF-16 code point is between 0xD800 and 0xDBFF it is a surrogate pair, and the unicode code point is represented using the first 10 bits of the first UTF-16 code point and the first ten least significant bits (excluding the prefix bits).
 
@@ -43,6 +43,8 @@ A UTF-16 code point is decoded into a unicode code point using the following rul
 * The low surrogate is in the range 0xDC00 to 0xDFFF.
 */
 
+use crate::unicode;
+
 /// Encode a unicode code point into a vector of UTF-16 code points.
 ///
 /// # Parameters
@@ -54,7 +56,7 @@ A UTF-16 code point is decoded into a unicode code point using the following rul
 /// # Panics
 /// * If the input unicode code point is invalid.
 fn encode_code_point(unicode_cp: u32) -> Vec<u16> {
-    if unicode_cp < 0xFFFF {
+    if unicode_cp <= 0xFFFF {
         return vec![unicode_cp as u16];
     }
 
@@ -98,7 +100,7 @@ fn encode_code_point(unicode_cp: u32) -> Vec<u16> {
 /// # Panics
 /// * If the index `i` is out of bounds.
 /// * If the UTF-16 code point is invalid.
-fn decode_symbol(utf16_cp: &[u16], i: usize) -> Option<(u32, usize)> {
+pub(crate) fn decode_symbol(utf16_cp: &[u16], i: usize) -> Option<(u32, usize)> {
     if i > utf16_cp.len() {
         panic!("Index out of bounds");
     }
@@ -169,7 +171,9 @@ fn print_utf16_vec<T: AsRef<Vec<u16>>>(utf16_cp: T, binary_flag: bool) {
     println!(
         "{}{}",
         "-".repeat(44),
-        "-".repeat(string_repr.chars().count())
+        "-".repeat(unicode::display_width(
+            &string_repr.chars().map(|c| c as u32).collect::<Vec<u32>>()
+        ))
     );
     println!();
 }
@@ -286,3 +290,33 @@ pub fn decode_from_utf16<T: AsRef<Vec<u16>>>(utf16_cp: T) -> Vec<u32> {
     }
     unicode_cp
 }
+
+/// Validate a vector of UTF-16 code points without decoding it.
+///
+/// # Parameters
+/// * `utf16_cp`: [`Vec<u16>`] - A vector of UTF-16 code points.
+///
+/// # Returns
+/// `Ok(())` if every surrogate is part of a valid surrogate pair, otherwise the index of the first lone surrogate.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(validate_utf16(vec![0x41, 0xD800, 0xDC01]), Ok(()));
+/// assert_eq!(validate_utf16(vec![0x41, 0xDC01, 0xD800]), Err(1));
+/// ```
+pub fn validate_utf16<T: AsRef<Vec<u16>>>(utf16_cp: T) -> Result<(), usize> {
+    let utf16_cp: &Vec<u16> = utf16_cp.as_ref();
+    let mut i: usize = 0;
+    while i < utf16_cp.len() {
+        match utf16_cp[i] {
+            0xD800..=0xDBFF => match utf16_cp.get(i + 1) {
+                Some(0xDC00..=0xDFFF) => i += 2,
+                _ => return Err(i),
+            },
+            0xDC00..=0xDFFF => return Err(i),
+            _ => i += 1,
+        }
+    }
+    Ok(())
+}
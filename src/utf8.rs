@@ -185,7 +185,7 @@ fn read_next_byte(byte_vec: &[u8], i: usize) -> u32 {
 /// * If the index `i` is out of bounds.
 /// * If, after reading the first byte, the continuation bytes are not valid.
 /// * If the UTF-8 code point is invalid.
-fn decode_symbol(utf8_cp: &[u8], i: usize) -> Option<(u32, usize)> {
+pub(crate) fn decode_symbol(utf8_cp: &[u8], i: usize) -> Option<(u32, usize)> {
     if i > utf8_cp.len() {
         panic!("Index out of bounds");
     }
@@ -307,6 +307,37 @@ fn decode_symbol(utf8_cp: &[u8], i: usize) -> Option<(u32, usize)> {
     panic!("Invalid UTF-8 sequence");
 }
 
+/// Compute the length of a valid UTF-8 code point.
+///
+/// # Parameters
+/// * `utf8_cp`: [`&[u8]`] - A slice of UTF-8 code points.
+/// * `i`: [`usize`] - The index of the prefix byte.
+///
+/// # Returns
+/// The number of bytes of the UTF-8 code point starting at index `i`, or [`None`] if it is truncated, overlong, a surrogate or greater than `0x10FFFF`.
+fn valid_symbol_len(utf8_cp: &[u8], i: usize) -> Option<usize> {
+    // The valid range of the second byte depends on the prefix byte, the other continuation bytes are always in 0x80..=0xBF
+    let (len, second): (usize, std::ops::RangeInclusive<u8>) = match utf8_cp[i] {
+        0x00..=0x7F => return Some(1),
+        0xC2..=0xDF => (2, 0x80..=0xBF),
+        0xE0 => (3, 0xA0..=0xBF), // Overlong
+        0xED => (3, 0x80..=0x9F), // Surrogates
+        0xE1..=0xEF => (3, 0x80..=0xBF),
+        0xF0 => (4, 0x90..=0xBF), // Overlong
+        0xF4 => (4, 0x80..=0x8F), // Greater than 0x10FFFF
+        0xF1..=0xF3 => (4, 0x80..=0xBF),
+        _ => return None,
+    };
+    if i + len > utf8_cp.len() || !second.contains(&utf8_cp[i + 1]) {
+        return None;
+    }
+    if utf8_cp[i + 2..i + len].iter().all(|b| (b & 0xC0) == 0x80) {
+        Some(len)
+    } else {
+        None
+    }
+}
+
 /// Pretty print the UTF-8 code points in hexadecimal, (binary) and decimal.
 ///
 /// # Parameters
@@ -332,7 +363,9 @@ fn print_utf8_vec<T: AsRef<Vec<u8>>>(utf8_cp: T, binary_flag: bool) {
     println!(
         "{}{}",
         "-".repeat(43),
-        "-".repeat(string_repr.chars().count())
+        "-".repeat(unicode::display_width(
+            &string_repr.chars().map(|c| c as u32).collect::<Vec<u32>>()
+        ))
     );
     println!();
 }
@@ -449,3 +482,29 @@ pub fn decode_from_utf8<T: AsRef<Vec<u8>>>(utf8_cp: T) -> Vec<u32> {
     }
     unicode_cp
 }
+
+/// Validate a vector of UTF-8 code points without decoding it.
+///
+/// # Parameters
+/// * `utf8_cp`: [`Vec<u8>`] - A vector of UTF-8 code points.
+///
+/// # Returns
+/// `Ok(())` if the vector is valid UTF-8, otherwise the index of the first byte of the first invalid UTF-8 code point.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert_eq!(validate_utf8(vec![0x41, 0xf0, 0x90, 0x80, 0x81]), Ok(()));
+/// assert_eq!(validate_utf8(vec![0x41, 0xed, 0xa0, 0x80]), Err(1)); // Surrogate
+/// ```
+pub fn validate_utf8<T: AsRef<Vec<u8>>>(utf8_cp: T) -> Result<(), usize> {
+    let utf8_cp: &Vec<u8> = utf8_cp.as_ref();
+    let mut i: usize = 0;
+    while i < utf8_cp.len() {
+        match valid_symbol_len(utf8_cp, i) {
+            Some(len) => i += len,
+            None => return Err(i),
+        }
+    }
+    Ok(())
+}
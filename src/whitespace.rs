@@ -0,0 +1,456 @@
+/*!
+Classification of the whitespace, line terminators and control characters, and the line utilities built on it.
+
+# Classification
+* [`is_white_space`]: The `White_Space` property: the spaces of every width (U+0020, U+00A0, U+2000 to U+200A, U+3000, ...) and the line terminators.
+* [`is_pattern_white_space`]: The `Pattern_White_Space` property (UAX #31): a small and stable set of whitespace, which never changes between versions of the Unicode Standard, for the syntax of the programming languages and formats.
+* [`is_line_terminator`]: The mandatory line breaks of the Unicode Standard (UAX #14, `BK`, `CR`, `LF` and `NL`):
+
+| Code point | Name                      |
+|------------|---------------------------|
+| U+000A     | LINE FEED (LF)            |
+| U+000B     | LINE TABULATION (VT)      |
+| U+000C     | FORM FEED (FF)            |
+| U+000D     | CARRIAGE RETURN (CR)      |
+| U+0085     | NEXT LINE (NEL)           |
+| U+2028     | LINE SEPARATOR (LS)       |
+| U+2029     | PARAGRAPH SEPARATOR (PS)  |
+
+* [`is_control`]: The C0 (U+0000 to U+001F) and C1 (U+007F to U+009F) control characters, the general category `Cc`.
+* [`is_default_ignorable`]: The `Default_Ignorable_Code_Point` property: the code points which are not displayed when they are not supported (the format controls, the variation selectors, the ZERO WIDTH JOINER, ...).
+
+# Lines
+* [`trim`]: Remove the `White_Space` code points at the start and at the end of a text.
+* [`split_lines`]: Iterate the lines of a text, without their terminators. `CR` followed by `LF` is a single terminator.
+* [`normalize_newlines`]: Replace every line terminator (and `CR` followed by `LF`) by `LF`.
+
+# Code units
+The line utilities work on vectors of unicode code points and on UTF-8 and UTF-16 buffers, without decoding them first (e.g. [`trim_utf8`], [`split_lines_utf16`]):
+the lines are [`Range`]s of indexes of code units in the source encoding (code points, bytes or UTF-16 code points), so that they can be used to slice the input.
+
+**Note**:
+
+* The UTF-8 and UTF-16 buffers are decoded with the same rules used by [`crate::utf8::decode_from_utf8`] and [`crate::utf16::decode_from_utf16`], and the functions panic on invalid input.
+* Like [`str::lines`], a final line terminator does not start an empty last line: `"a\nb\n"` has two lines.
+* `VT` and `FF` are line terminators: they are split on and normalized to `LF` like the others.
+*/
+
+use std::ops::Range;
+
+use crate::segment::{self, Decoder};
+use crate::tables;
+
+/// U+000A LINE FEED.
+const LF: u32 = 0x0A;
+
+/// U+000D CARRIAGE RETURN.
+const CR: u32 = 0x0D;
+
+/// Find the line terminator at an index of a slice of code units.
+///
+/// # Parameters
+/// * `units`: [`&[T]`] - A slice of code units.
+/// * `decode`: [`Decoder<T>`] - The function decoding a code point from the code units.
+/// * `i`: [`usize`] - The index of a code point in the slice.
+///
+/// # Returns
+/// The number of code units of the code point at the index, and of the line terminator it starts (`CR` followed by `LF` is a single terminator), if any.
+fn terminator<T>(units: &[T], decode: Decoder<T>, i: usize) -> Option<(usize, Option<usize>)> {
+    let (code_point, len) = decode(units, i)?;
+    if !is_line_terminator(code_point) {
+        return Some((len, None));
+    }
+    match decode(units, i + len) {
+        Some((LF, lf_len)) if code_point == CR => Some((len, Some(len + lf_len))),
+        _ => Some((len, Some(len))),
+    }
+}
+
+/// Remove the `White_Space` code points at the start and at the end of a slice of code units.
+///
+/// # Parameters
+/// * `units`: [`&[T]`] - A slice of code units.
+/// * `decode`: [`Decoder<T>`] - The function decoding a code point from the code units.
+///
+/// # Returns
+/// The sub-slice between the first and the last code points which are not `White_Space`.
+fn trim_units<T>(units: &[T], decode: Decoder<T>) -> &[T] {
+    let mut start: Option<usize> = None;
+    let mut end: usize = 0;
+    let mut i: usize = 0;
+    while let Some((code_point, len)) = decode(units, i) {
+        if !is_white_space(code_point) {
+            start.get_or_insert(i);
+            end = i + len;
+        }
+        i += len;
+    }
+    match start {
+        Some(start) => &units[start..end],
+        None => &units[..0],
+    }
+}
+
+/// Replace the line terminators of a slice of code units by `LF`.
+///
+/// # Parameters
+/// * `units`: [`&[T]`] - A slice of code units.
+/// * `decode`: [`Decoder<T>`] - The function decoding a code point from the code units.
+///
+/// # Returns
+/// A [`Vec<T>`] containing the code units, with every line terminator replaced by a single `LF`.
+fn normalize_newlines_units<T: Copy + From<u8>>(units: &[T], decode: Decoder<T>) -> Vec<T> {
+    let mut normalized: Vec<T> = Vec::with_capacity(units.len());
+    let mut i: usize = 0;
+    while let Some((len, terminator_len)) = terminator(units, decode, i) {
+        match terminator_len {
+            Some(terminator_len) => {
+                normalized.push(T::from(LF as u8));
+                i += terminator_len;
+            }
+            None => {
+                normalized.extend_from_slice(&units[i..i + len]);
+                i += len;
+            }
+        }
+    }
+    normalized
+}
+
+// ============================================================================
+// ================================ Public API ================================
+// ============================================================================
+
+/// Check if a unicode code point is a whitespace (`White_Space` property).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is a `White_Space` code point.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_white_space(0x20)); // space
+/// assert!(is_white_space(0xA0)); // no-break space
+/// assert!(is_white_space(0x3000)); // ideographic space
+/// assert!(is_white_space(0x2028)); // line separator
+/// assert!(!is_white_space(0x200B)); // zero width space
+/// ```
+pub fn is_white_space(code_point: u32) -> bool {
+    tables::in_ranges(tables::whitespace::WHITE_SPACE, code_point)
+}
+
+/// Check if a unicode code point is a whitespace of the syntax of the programming languages (`Pattern_White_Space` property).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is a `Pattern_White_Space` code point.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_pattern_white_space(0x09)); // tab
+/// assert!(is_pattern_white_space(0x200E)); // left-to-right mark
+/// assert!(!is_pattern_white_space(0xA0)); // no-break space
+/// ```
+pub fn is_pattern_white_space(code_point: u32) -> bool {
+    tables::in_ranges(tables::whitespace::PATTERN_WHITE_SPACE, code_point)
+}
+
+/// Check if a unicode code point is a line terminator (LF, VT, FF, CR, NEL, LS or PS).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is a mandatory line break.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_line_terminator(0x0A)); // LF
+/// assert!(is_line_terminator(0x85)); // NEL
+/// assert!(is_line_terminator(0x2028)); // LS
+/// assert!(!is_line_terminator(0x09)); // tab
+/// ```
+pub fn is_line_terminator(code_point: u32) -> bool {
+    matches!(code_point, 0x0A..=0x0D | 0x85 | 0x2028 | 0x2029)
+}
+
+/// Check if a unicode code point is a C0 or C1 control character.
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is in U+0000 to U+001F or U+007F to U+009F.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_control(0x00));
+/// assert!(is_control(0x1B)); // escape
+/// assert!(is_control(0x85)); // NEL
+/// assert!(!is_control(0x20));
+/// assert!(!is_control(0x200B)); // zero width space, a format character
+/// ```
+pub fn is_control(code_point: u32) -> bool {
+    matches!(code_point, 0x00..=0x1F | 0x7F..=0x9F)
+}
+
+/// Check if a unicode code point is ignored when it is not supported (`Default_Ignorable_Code_Point` property).
+///
+/// # Parameters
+/// * `code_point`: [`u32`] - A unicode code point.
+///
+/// # Returns
+/// A [`bool`] indicating if the code point is a `Default_Ignorable_Code_Point` code point.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+/// assert!(is_default_ignorable(0xAD)); // soft hyphen
+/// assert!(is_default_ignorable(0x200D)); // zero width joiner
+/// assert!(is_default_ignorable(0xFE0F)); // variation selector-16
+/// assert!(!is_default_ignorable(0x20));
+/// ```
+pub fn is_default_ignorable(code_point: u32) -> bool {
+    tables::in_ranges(tables::whitespace::DEFAULT_IGNORABLE_CODE_POINT, code_point)
+}
+
+/// Remove the whitespace at the start and at the end of a slice of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// The sub-slice without the `White_Space` code points at its start and at its end.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = vec![0x3000, 0x61, 0x20, 0x62, 0x2028, 0x0A]; // "\u{3000}a b\u{2028}\n"
+/// assert_eq!(trim(&v), &[0x61, 0x20, 0x62]);
+/// ```
+pub fn trim(unicode_cp: &[u32]) -> &[u32] {
+    trim_units(unicode_cp, segment::decode_code_point)
+}
+
+/// Remove the whitespace at the start and at the end of a slice of UTF-8 code points.
+///
+/// # Parameters
+/// * `utf8_cp`: [`&[u8]`] - A slice of UTF-8 code points.
+///
+/// # Returns
+/// The sub-slice without the `White_Space` code points at its start and at its end.
+///
+/// # Panics
+/// * If the input slice (`utf8_cp`) of UTF-8 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let s: &str = "\u{A0} text \u{85}";
+/// assert_eq!(trim_utf8(s.as_bytes()), b"text");
+/// ```
+pub fn trim_utf8(utf8_cp: &[u8]) -> &[u8] {
+    trim_units(utf8_cp, crate::utf8::decode_symbol)
+}
+
+/// Remove the whitespace at the start and at the end of a slice of UTF-16 code points.
+///
+/// # Parameters
+/// * `utf16_cp`: [`&[u16]`] - A slice of UTF-16 code points.
+///
+/// # Returns
+/// The sub-slice without the `White_Space` code points at its start and at its end.
+///
+/// # Panics
+/// * If the input slice (`utf16_cp`) of UTF-16 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u16> = vec![0x20, 0xD83D, 0xDE00, 0x2029]; // " 😀\u{2029}"
+/// assert_eq!(trim_utf16(&v), &[0xD83D, 0xDE00]);
+/// ```
+pub fn trim_utf16(utf16_cp: &[u16]) -> &[u16] {
+    trim_units(utf16_cp, crate::utf16::decode_symbol)
+}
+
+/// An iterator over the lines of a slice of code units.
+///
+/// It is created by [`split_lines`], [`split_lines_utf8`] and [`split_lines_utf16`].
+#[derive(Clone)]
+pub struct Lines<'a, T> {
+    units: &'a [T],
+    decode: Decoder<T>,
+    position: usize,
+}
+
+impl<'a, T> Lines<'a, T> {
+    /// Create an iterator over the lines of a slice of code units.
+    fn new(units: &'a [T], decode: Decoder<T>) -> Self {
+        Lines {
+            units,
+            decode,
+            position: 0,
+        }
+    }
+}
+
+impl<T> Iterator for Lines<'_, T> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start: usize = self.position;
+        let mut end: usize = start;
+        let (mut len, mut terminator_len) = terminator(self.units, self.decode, end)?;
+        while terminator_len.is_none() {
+            end += len;
+            match terminator(self.units, self.decode, end) {
+                Some(next) => (len, terminator_len) = next,
+                // The last line, without a terminator
+                None => break,
+            }
+        }
+        self.position = end + terminator_len.unwrap_or(0);
+        Some(start..end)
+    }
+}
+
+/// Iterate the lines of a slice of unicode code points.
+///
+/// # Parameters
+/// * `unicode_cp`: [`&[u32]`] - A slice of unicode code points.
+///
+/// # Returns
+/// A [`Lines`] iterator over the ranges of indexes of the lines in the slice, without their line terminators.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = vec![0x61, 0x0D, 0x0A, 0x62, 0x85, 0x63, 0x2028]; // "a\r\nb\u{85}c\u{2028}"
+/// let lines: Vec<std::ops::Range<usize>> = split_lines(&v).collect();
+/// assert_eq!(lines, vec![0..1, 3..4, 5..6]);
+/// ```
+pub fn split_lines(unicode_cp: &[u32]) -> Lines<'_, u32> {
+    Lines::new(unicode_cp, segment::decode_code_point)
+}
+
+/// Iterate the lines of a slice of UTF-8 code points.
+///
+/// # Parameters
+/// * `utf8_cp`: [`&[u8]`] - A slice of UTF-8 code points.
+///
+/// # Returns
+/// A [`Lines`] iterator over the ranges of indexes of the lines in the slice of bytes, without their line terminators.
+///
+/// # Panics
+/// * If the input slice (`utf8_cp`) of UTF-8 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let s: &str = "first\u{2028}second\r\n\nlast";
+/// let lines: Vec<&str> = split_lines_utf8(s.as_bytes()).map(|r| &s[r]).collect();
+/// assert_eq!(lines, vec!["first", "second", "", "last"]);
+/// ```
+pub fn split_lines_utf8(utf8_cp: &[u8]) -> Lines<'_, u8> {
+    Lines::new(utf8_cp, crate::utf8::decode_symbol)
+}
+
+/// Iterate the lines of a slice of UTF-16 code points.
+///
+/// # Parameters
+/// * `utf16_cp`: [`&[u16]`] - A slice of UTF-16 code points.
+///
+/// # Returns
+/// A [`Lines`] iterator over the ranges of indexes of the lines in the slice of UTF-16 code points, without their line terminators.
+///
+/// # Panics
+/// * If the input slice (`utf16_cp`) of UTF-16 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u16> = vec![0x61, 0x2029, 0xD83D, 0xDE00, 0x0A]; // "a\u{2029}😀\n"
+/// let lines: Vec<std::ops::Range<usize>> = split_lines_utf16(&v).collect();
+/// assert_eq!(lines, vec![0..1, 2..4]);
+/// ```
+pub fn split_lines_utf16(utf16_cp: &[u16]) -> Lines<'_, u16> {
+    Lines::new(utf16_cp, crate::utf16::decode_symbol)
+}
+
+/// Replace the line terminators of a vector of unicode code points by `LF`.
+///
+/// # Parameters
+/// * `unicode_cp`: [`Vec<u32>`] - A vector of unicode code points.
+///
+/// # Returns
+/// A [`Vec<u32>`] containing the code points, with every line terminator (`CR` followed by `LF`, `CR`, `VT`, `FF`, `NEL`, `LS` and `PS`) replaced by a single `LF`.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u32> = vec![0x61, 0x0D, 0x0A, 0x62, 0x0D, 0x63, 0x2028]; // "a\r\nb\rc\u{2028}"
+/// assert_eq!(normalize_newlines(&v), vec![0x61, 0x0A, 0x62, 0x0A, 0x63, 0x0A]);
+/// ```
+pub fn normalize_newlines<T: AsRef<Vec<u32>>>(unicode_cp: T) -> Vec<u32> {
+    normalize_newlines_units(unicode_cp.as_ref(), segment::decode_code_point)
+}
+
+/// Replace the line terminators of a vector of UTF-8 code points by `LF`.
+///
+/// # Parameters
+/// * `utf8_cp`: [`Vec<u8>`] - A vector of UTF-8 code points.
+///
+/// # Returns
+/// A [`Vec<u8>`] containing the UTF-8 code points, with every line terminator replaced by a single `LF` (see [`normalize_newlines`]).
+///
+/// # Panics
+/// * If the input vector (`utf8_cp`) of UTF-8 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let s: &str = "one\r\ntwo\u{85}three\u{2029}";
+/// assert_eq!(normalize_newlines_utf8(s.as_bytes().to_vec()), b"one\ntwo\nthree\n");
+/// ```
+pub fn normalize_newlines_utf8<T: AsRef<Vec<u8>>>(utf8_cp: T) -> Vec<u8> {
+    normalize_newlines_units(utf8_cp.as_ref(), crate::utf8::decode_symbol)
+}
+
+/// Replace the line terminators of a vector of UTF-16 code points by `LF`.
+///
+/// # Parameters
+/// * `utf16_cp`: [`Vec<u16>`] - A vector of UTF-16 code points.
+///
+/// # Returns
+/// A [`Vec<u16>`] containing the UTF-16 code points, with every line terminator replaced by a single `LF` (see [`normalize_newlines`]).
+///
+/// # Panics
+/// * If the input vector (`utf16_cp`) of UTF-16 code points is invalid.
+///
+/// # Example
+/// ```rust
+/// use ende::prelude::*;
+///
+/// let v: Vec<u16> = vec![0x61, 0x0D, 0x0A, 0xD83D, 0xDE00, 0x0D]; // "a\r\n😀\r"
+/// assert_eq!(normalize_newlines_utf16(&v), vec![0x61, 0x0A, 0xD83D, 0xDE00, 0x0A]);
+/// ```
+pub fn normalize_newlines_utf16<T: AsRef<Vec<u16>>>(utf16_cp: T) -> Vec<u16> {
+    normalize_newlines_units(utf16_cp.as_ref(), crate::utf16::decode_symbol)
+}
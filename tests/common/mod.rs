@@ -0,0 +1,21 @@
+// Every test crate uses a part of the helpers only
+#![allow(dead_code)]
+
+/// Collect the code points of a string.
+pub fn code_points(s: &str) -> Vec<u32> {
+    s.chars().map(|c| c as u32).collect()
+}
+
+/// Parse a line of a break test file (e.g. `÷ 0020 × 0308 ÷`) into the code points and the boundaries.
+pub fn parse_break_test(line: &str) -> (Vec<u32>, Vec<usize>) {
+    let mut code_points: Vec<u32> = Vec::new();
+    let mut boundaries: Vec<usize> = Vec::new();
+    for token in line.split_whitespace() {
+        match token {
+            "÷" => boundaries.push(code_points.len()),
+            "×" => {}
+            cp => code_points.push(u32::from_str_radix(cp, 16).unwrap()),
+        }
+    }
+    (code_points, boundaries)
+}
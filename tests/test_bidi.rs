@@ -0,0 +1,290 @@
+mod common;
+
+use common::code_points;
+use ende::prelude::*;
+
+#[test]
+fn test_bidi_bidi_class_1() {
+    assert_eq!(bidi_class(0x61), BidiClass::LeftToRight);
+    assert_eq!(bidi_class(0x5d0), BidiClass::RightToLeft);
+    assert_eq!(bidi_class(0x628), BidiClass::ArabicLetter);
+    assert_eq!(bidi_class(0x660), BidiClass::ArabicNumber);
+    assert_eq!(bidi_class(0x6f0), BidiClass::EuropeanNumber);
+    assert_eq!(bidi_class(0x2d), BidiClass::EuropeanSeparator);
+    assert_eq!(bidi_class(0x24), BidiClass::EuropeanTerminator);
+    assert_eq!(bidi_class(0x2c), BidiClass::CommonSeparator);
+    assert_eq!(bidi_class(0x300), BidiClass::NonspacingMark);
+    assert_eq!(bidi_class(0x200d), BidiClass::BoundaryNeutral);
+    assert_eq!(bidi_class(0x2029), BidiClass::ParagraphSeparator);
+    assert_eq!(bidi_class(0x09), BidiClass::SegmentSeparator);
+    assert_eq!(bidi_class(0x2028), BidiClass::WhiteSpace);
+    assert_eq!(bidi_class(0x2068), BidiClass::FirstStrongIsolate);
+    // Unassigned code points in the Hebrew and Arabic blocks
+    assert_eq!(bidi_class(0x5ff), BidiClass::RightToLeft);
+    assert_eq!(bidi_class(0x1ed00), BidiClass::ArabicLetter);
+    assert_eq!(bidi_class(0x378), BidiClass::LeftToRight);
+}
+
+#[test]
+fn test_bidi_bidi_mirroring_glyph_1() {
+    assert_eq!(bidi_mirroring_glyph(0x29), Some(0x28));
+    assert_eq!(bidi_mirroring_glyph(0x3c), Some(0x3e));
+    assert_eq!(bidi_mirroring_glyph(0x2264), Some(0x2265));
+    assert_eq!(bidi_mirroring_glyph(0xff62), Some(0xff63));
+    assert_eq!(bidi_mirroring_glyph(0x2f), None);
+}
+
+#[test]
+fn test_bidi_paragraph_level_1() {
+    let auto = ParagraphDirection::Auto;
+    assert_eq!(paragraph_level(code_points("hello"), auto), 0);
+    assert_eq!(paragraph_level(code_points("שלום"), auto), 1);
+    assert_eq!(paragraph_level(code_points("مرحبا"), auto), 1);
+    // No strong character
+    assert_eq!(paragraph_level(code_points("123 !"), auto), 0);
+    assert_eq!(paragraph_level(Vec::new(), auto), 0);
+    // The isolates are skipped
+    assert_eq!(
+        paragraph_level(code_points("\u{2066}abc\u{2069} שלום"), auto),
+        1
+    );
+    // Only the first paragraph
+    assert_eq!(paragraph_level(code_points("abc\u{2029}אבג"), auto), 0);
+    assert_eq!(
+        paragraph_level(code_points("abc"), ParagraphDirection::RightToLeft),
+        1
+    );
+}
+
+#[test]
+fn test_bidi_bidi_levels_1() {
+    let auto = ParagraphDirection::Auto;
+    assert_eq!(bidi_levels(code_points("hello"), auto), vec![0; 5]);
+    assert_eq!(bidi_levels(code_points("שלום"), auto), vec![1; 4]);
+    assert_eq!(
+        bidi_levels(code_points("abc"), ParagraphDirection::RightToLeft),
+        vec![2, 2, 2]
+    );
+    // The numbers in a right-to-left paragraph
+    assert_eq!(
+        bidi_levels(code_points("שלום 123 עולם"), auto),
+        vec![1, 1, 1, 1, 1, 2, 2, 2, 1, 1, 1, 1, 1]
+    );
+    assert_eq!(
+        bidi_levels(code_points("مرحبا ١٢٣"), auto),
+        vec![1, 1, 1, 1, 1, 1, 2, 2, 2]
+    );
+    assert_eq!(bidi_levels(Vec::new(), auto), Vec::<u8>::new());
+}
+
+#[test]
+fn test_bidi_bidi_levels_2() {
+    let ltr = ParagraphDirection::LeftToRight;
+    // Override and embedding (the formatting characters take the level of the character before them)
+    assert_eq!(
+        bidi_levels(code_points("a\u{202e}abc\u{202c}d"), ltr),
+        vec![0, 0, 1, 1, 1, 1, 0]
+    );
+    assert_eq!(
+        bidi_levels(code_points("a\u{202b}abc\u{202c}d"), ltr),
+        vec![0, 0, 2, 2, 2, 2, 0]
+    );
+    // Isolate
+    assert_eq!(
+        bidi_levels(
+            code_points("\u{2067}abc\u{2069} שלום"),
+            ParagraphDirection::Auto
+        ),
+        vec![1, 4, 4, 4, 1, 1, 1, 1, 1, 1]
+    );
+    // The embeddings deeper than the maximum depth are ignored
+    let mut v: Vec<u32> = vec![0x202b; 130];
+    v.push(0x61);
+    assert_eq!(*bidi_levels(&v, ltr).last().unwrap(), 126);
+}
+
+#[test]
+fn test_bidi_bidi_levels_3() {
+    // The brackets take the direction of their content
+    assert_eq!(
+        bidi_levels(code_points("a (b) c"), ParagraphDirection::RightToLeft),
+        vec![2; 7]
+    );
+    assert_eq!(
+        bidi_levels(code_points("א (ב) c"), ParagraphDirection::LeftToRight),
+        vec![1, 1, 1, 1, 1, 0, 0]
+    );
+    assert_eq!(
+        bidi_levels(
+            code_points("abc (אבג) 123"),
+            ParagraphDirection::LeftToRight
+        ),
+        vec![0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 2, 2, 2]
+    );
+}
+
+#[test]
+fn test_bidi_bidi_levels_4() {
+    // Several paragraphs, a CR LF pair is a single separator
+    assert_eq!(
+        bidi_levels(code_points("אב\r\ncd"), ParagraphDirection::Auto),
+        vec![1, 1, 1, 1, 0, 0]
+    );
+    // The segment separators and the whitespaces at the end of the line take the paragraph level
+    assert_eq!(
+        bidi_levels(code_points("אב\tגד"), ParagraphDirection::LeftToRight),
+        vec![1, 1, 0, 1, 1]
+    );
+    assert_eq!(
+        bidi_levels(code_points("abc  "), ParagraphDirection::RightToLeft),
+        vec![2, 2, 2, 1, 1]
+    );
+}
+
+#[test]
+fn test_bidi_visual_order_1() {
+    assert_eq!(visual_order(&[]), Vec::<usize>::new());
+    assert_eq!(visual_order(&[0, 0, 0]), vec![0, 1, 2]);
+    assert_eq!(visual_order(&[1, 1, 1]), vec![2, 1, 0]);
+    assert_eq!(visual_order(&[1, 1, 2, 2, 2, 1]), vec![5, 2, 3, 4, 1, 0]);
+    assert_eq!(
+        visual_order(&[0, 1, 2, 3, 2, 1, 0]),
+        vec![0, 5, 2, 3, 4, 1, 6]
+    );
+}
+
+#[test]
+fn test_bidi_reorder_1() {
+    let reordered = |s: &str, direction: ParagraphDirection| -> String {
+        reorder(code_points(s), direction)
+            .iter()
+            .map(|cp| char::from_u32(*cp).unwrap())
+            .collect()
+    };
+    assert_eq!(reordered("abc אבג", ParagraphDirection::Auto), "abc גבא");
+    assert_eq!(
+        reordered("שלום 123 עולם", ParagraphDirection::Auto),
+        "םלוע 123 םולש"
+    );
+    // The mirrored glyphs in the right-to-left runs
+    assert_eq!(
+        reordered("א<ב>ג [1]", ParagraphDirection::RightToLeft),
+        "[1] ג<ב>א"
+    );
+    // Every paragraph is reordered on its own
+    assert_eq!(reordered("אב\nab", ParagraphDirection::Auto), "בא\nab");
+    assert_eq!(reordered("אב\r\nגד", ParagraphDirection::Auto), "בא\r\nדג");
+}
+
+/// The code point of every class in the `BidiTest.txt` file.
+fn class_code_point(class: &str) -> u32 {
+    match class {
+        "L" => 0x61,
+        "R" => 0x5d0,
+        "AL" => 0x627,
+        "EN" => 0x30,
+        "ES" => 0x2b,
+        "ET" => 0x23,
+        "AN" => 0x660,
+        "CS" => 0x2c,
+        "NSM" => 0x300,
+        "BN" => 0xad,
+        "B" => 0x2029,
+        "S" => 0x09,
+        "WS" => 0x20,
+        "ON" => 0x21,
+        "LRE" => 0x202a,
+        "LRO" => 0x202d,
+        "RLE" => 0x202b,
+        "RLO" => 0x202e,
+        "PDF" => 0x202c,
+        "LRI" => 0x2066,
+        "RLI" => 0x2067,
+        "FSI" => 0x2068,
+        "PDI" => 0x2069,
+        class => panic!("Unknown bidirectional class {}", class),
+    }
+}
+
+/// Check the levels (`x` if the code point is removed by the rule X9) and the visual order, without the removed code points.
+fn check_bidi_test(v: &[u32], direction: ParagraphDirection, levels: &[&str], order: &[usize]) {
+    let found: Vec<u8> = bidi_levels(v.to_vec(), direction);
+    for (i, level) in levels.iter().enumerate() {
+        if *level != "x" {
+            assert_eq!(found[i].to_string(), *level, "{:x?} {:?}", v, direction);
+        }
+    }
+    let found: Vec<usize> = visual_order(&found)
+        .into_iter()
+        .filter(|i| levels[*i] != "x")
+        .collect();
+    assert_eq!(found, order, "{:x?} {:?}", v, direction);
+}
+
+#[test]
+#[ignore = "requires tests/data/BidiTest.txt, run `make test-data` to download it"]
+fn test_bidi_conformance() {
+    let path: String = format!("{}/tests/data/BidiTest.txt", env!("CARGO_MANIFEST_DIR"));
+    let data: String = std::fs::read_to_string(path).unwrap();
+    let mut levels: Vec<&str> = Vec::new();
+    let mut order: Vec<usize> = Vec::new();
+    for line in data.lines() {
+        let line: &str = line.split('#').next().unwrap().trim();
+        if let Some(l) = line.strip_prefix("@Levels:") {
+            levels = l.split_whitespace().collect();
+        } else if let Some(o) = line.strip_prefix("@Reorder:") {
+            order = o.split_whitespace().map(|i| i.parse().unwrap()).collect();
+        } else if let Some((classes, bitset)) = line.split_once(';') {
+            let classes: Vec<&str> = classes.split_whitespace().collect();
+            // The text is split into paragraphs after every paragraph separator (P1), the test data is not
+            if classes[..classes.len() - 1].contains(&"B") {
+                continue;
+            }
+            let v: Vec<u32> = classes.iter().map(|c| class_code_point(c)).collect();
+            let bitset: u8 = bitset.trim().parse().unwrap();
+            for (bit, direction) in [
+                (1, ParagraphDirection::Auto),
+                (2, ParagraphDirection::LeftToRight),
+                (4, ParagraphDirection::RightToLeft),
+            ] {
+                if bitset & bit != 0 {
+                    check_bidi_test(&v, direction, &levels, &order);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[ignore = "requires tests/data/BidiCharacterTest.txt, run `make test-data` to download it"]
+fn test_bidi_character_conformance() {
+    let path: String = format!(
+        "{}/tests/data/BidiCharacterTest.txt",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let data: String = std::fs::read_to_string(path).unwrap();
+    for line in data.lines() {
+        let line: &str = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').collect();
+        let v: Vec<u32> = fields[0]
+            .split_whitespace()
+            .map(|cp| u32::from_str_radix(cp, 16).unwrap())
+            .collect();
+        let direction: ParagraphDirection = match fields[1] {
+            "0" => ParagraphDirection::LeftToRight,
+            "1" => ParagraphDirection::RightToLeft,
+            _ => ParagraphDirection::Auto,
+        };
+        let level: u8 = fields[2].parse().unwrap();
+        assert_eq!(paragraph_level(&v, direction), level, "{}", line);
+        let levels: Vec<&str> = fields[3].split_whitespace().collect();
+        let order: Vec<usize> = fields[4]
+            .split_whitespace()
+            .map(|i| i.parse().unwrap())
+            .collect();
+        check_bidi_test(&v, direction, &levels, &order);
+    }
+}
@@ -0,0 +1,131 @@
+use ende::prelude::*;
+
+#[test]
+fn test_case_to_upper() {
+    let v: Vec<u32> = vec![0x61, 0xe9, 0xdf, 0x149, 0x1f0, 0xfb03]; // "aéßŉǰﬃ"
+    let upper: Vec<u32> = to_upper(&v);
+    assert_eq!(
+        upper,
+        vec![0x41, 0xc9, 0x53, 0x53, 0x2bc, 0x4e, 0x4a, 0x30c, 0x46, 0x46, 0x49]
+    );
+}
+
+#[test]
+fn test_case_to_lower() {
+    let v: Vec<u32> = vec![0x41, 0xc9, 0x1e9e, 0x130, 0x10400]; // "AÉẞİ𐐀"
+    let lower: Vec<u32> = to_lower(&v);
+    assert_eq!(lower, vec![0x61, 0xe9, 0xdf, 0x69, 0x307, 0x10428]);
+}
+
+#[test]
+fn test_case_final_sigma() {
+    // "ΣΑΣ ΣΑ. Σ"
+    let v: Vec<u32> = vec![0x3a3, 0x391, 0x3a3, 0x20, 0x3a3, 0x391, 0x2e, 0x20, 0x3a3];
+    let lower: Vec<u32> = to_lower(&v);
+    assert_eq!(
+        lower,
+        vec![0x3c3, 0x3b1, 0x3c2, 0x20, 0x3c3, 0x3b1, 0x2e, 0x20, 0x3c3]
+    );
+    // Case-ignorable code points between the letters
+    let v: Vec<u32> = vec![0x391, 0x3a3, 0x27, 0x301]; // "ΑΣ'́"
+    assert_eq!(to_lower(&v), vec![0x3b1, 0x3c2, 0x27, 0x301]);
+    let v: Vec<u32> = vec![0x391, 0x3a3, 0x27, 0x391]; // "ΑΣ'Α"
+    assert_eq!(to_lower(&v), vec![0x3b1, 0x3c3, 0x27, 0x3b1]);
+}
+
+#[test]
+fn test_case_to_title() {
+    let v: Vec<u32> = vec![
+        0x68, 0x45, 0x4c, 0x4c, 0x4f, 0x20, 0x77, 0x6f, 0x72, 0x6c, 0x64,
+    ]; // "hELLO world"
+    let title: Vec<u32> = to_title(&v);
+    assert_eq!(
+        title,
+        vec![0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x57, 0x6f, 0x72, 0x6c, 0x64]
+    );
+    let v: Vec<u32> = vec![0x6f, 0x27, 0x6e, 0x65, 0x69, 0x6c]; // "o'neil"
+    assert_eq!(to_title(&v), vec![0x4f, 0x27, 0x6e, 0x65, 0x69, 0x6c]);
+    let v: Vec<u32> = vec![0x1f3, 0x1f3, 0x20, 0xfb00]; // "ǳǳ ﬀ"
+    assert_eq!(to_title(&v), vec![0x1f2, 0x1f3, 0x20, 0x46, 0x66]);
+}
+
+#[test]
+fn test_case_to_title_word_boundaries() {
+    let v: Vec<u32> = vec![0x31, 0x73, 0x74, 0x20, 0x70, 0x6c, 0x61, 0x63, 0x65]; // "1st place"
+    assert_eq!(
+        to_title(&v),
+        vec![0x31, 0x73, 0x74, 0x20, 0x50, 0x6c, 0x61, 0x63, 0x65]
+    ); // "1st Place"
+    let v: Vec<u32> = vec![0x28, 0x61, 0x42, 0x29, 0x2e, 0x63, 0x44]; // "(aB).cD"
+    assert_eq!(to_title(&v), vec![0x28, 0x41, 0x62, 0x29, 0x2e, 0x43, 0x64]); // "(Ab).Cd"
+}
+
+#[test]
+fn test_case_case_fold() {
+    let v: Vec<u32> = vec![0x41, 0xdf, 0x1e9e, 0x3a3, 0x3c2, 0x13a0, 0xab70]; // "AßẞΣςᎠꭰ"
+    let folded: Vec<u32> = case_fold(&v);
+    assert_eq!(
+        folded,
+        vec![0x61, 0x73, 0x73, 0x73, 0x73, 0x3c3, 0x3c3, 0x13a0, 0x13a0]
+    );
+}
+
+#[test]
+fn test_case_turkish() {
+    let v: Vec<u32> = vec![0x49, 0x69, 0x130, 0x131]; // "Iiİı"
+    assert_eq!(
+        to_upper_locale(&v, CaseLocale::Turkish),
+        vec![0x49, 0x130, 0x130, 0x49]
+    );
+    assert_eq!(
+        to_lower_locale(&v, CaseLocale::Turkish),
+        vec![0x131, 0x69, 0x69, 0x131]
+    );
+    assert_eq!(
+        to_lower_locale(&v, CaseLocale::Azerbaijani),
+        vec![0x131, 0x69, 0x69, 0x131]
+    );
+    assert_eq!(
+        case_fold_locale(&v, CaseLocale::Turkish),
+        vec![0x131, 0x69, 0x69, 0x131]
+    );
+    // I followed by a dot above is lowercased to i, and the dot is removed
+    let v: Vec<u32> = vec![0x49, 0x316, 0x307];
+    assert_eq!(to_lower_locale(&v, CaseLocale::Turkish), vec![0x69, 0x316]);
+    assert_eq!(to_lower(&v), vec![0x69, 0x316, 0x307]);
+}
+
+#[test]
+fn test_case_lithuanian() {
+    // I keeps its dot before an accent above
+    let v: Vec<u32> = vec![0x49, 0x301, 0x4a, 0x12e, 0x300, 0x49];
+    assert_eq!(
+        to_lower_locale(&v, CaseLocale::Lithuanian),
+        vec![0x69, 0x307, 0x301, 0x6a, 0x12f, 0x307, 0x300, 0x69]
+    );
+    let v: Vec<u32> = vec![0xcc, 0xcd, 0x128];
+    assert_eq!(
+        to_lower_locale(&v, CaseLocale::Lithuanian),
+        vec![0x69, 0x307, 0x300, 0x69, 0x307, 0x301, 0x69, 0x307, 0x303]
+    );
+    // The dot above is removed after a soft dotted letter
+    let v: Vec<u32> = vec![0x69, 0x307, 0x301, 0x6a, 0x307];
+    assert_eq!(
+        to_upper_locale(&v, CaseLocale::Lithuanian),
+        vec![0x49, 0x301, 0x4a]
+    );
+    assert_eq!(to_upper(&v), vec![0x49, 0x307, 0x301, 0x4a, 0x307]);
+}
+
+#[test]
+fn test_case_caseless_eq() {
+    let a: Vec<u32> = vec![0x53, 0x54, 0x52, 0x41, 0x1e9e, 0x45]; // "STRAẞE"
+    let b: Vec<u32> = vec![0x73, 0x74, 0x72, 0x61, 0xdf, 0x65]; // "straße"
+    assert!(caseless_eq(&a, &b));
+    let a: Vec<u32> = vec![0x1e09]; // "ḉ"
+    let b: Vec<u32> = vec![0x43, 0x327, 0x301]; // "Ḉ" decomposed
+    assert!(caseless_eq(&a, &b));
+    let a: Vec<u32> = vec![0x61];
+    let b: Vec<u32> = vec![0xe1];
+    assert!(!caseless_eq(&a, &b));
+}
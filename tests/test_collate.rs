@@ -0,0 +1,247 @@
+mod common;
+
+use std::cmp::Ordering;
+
+use common::code_points;
+use ende::prelude::*;
+
+/// Compare two strings with the non-ignorable variable weighting.
+fn compare(a: &str, b: &str, strength: Strength) -> Ordering {
+    collate(
+        code_points(a),
+        code_points(b),
+        strength,
+        VariableWeighting::NonIgnorable,
+    )
+}
+
+#[test]
+fn test_collate_collate_1() {
+    assert_eq!(compare("a", "A", Strength::Primary), Ordering::Equal);
+    assert_eq!(compare("a", "á", Strength::Primary), Ordering::Equal);
+    assert_eq!(compare("a", "b", Strength::Primary), Ordering::Less);
+    assert_eq!(compare("e", "E", Strength::Secondary), Ordering::Equal);
+    assert_eq!(compare("e", "é", Strength::Secondary), Ordering::Less);
+    assert_eq!(compare("e", "E", Strength::Tertiary), Ordering::Less);
+    // The secondary difference wins over the tertiary one
+    assert_eq!(compare("role", "Role", Strength::Tertiary), Ordering::Less);
+    assert_eq!(compare("Role", "rôle", Strength::Tertiary), Ordering::Less);
+    // The identical level compares the code points of the NFD
+    assert_eq!(
+        compare("\u{fb01}", "fi", Strength::Tertiary),
+        Ordering::Greater
+    );
+    assert_eq!(
+        compare("é", "e\u{301}", Strength::Identical),
+        Ordering::Equal
+    );
+    assert_eq!(
+        compare("\u{212b}", "\u{c5}", Strength::Identical),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn test_collate_collate_2() {
+    let mut names: Vec<Vec<u32>> = ["Zoe", "Émile", "eve", "Adam", "émile", "Ærø", "adam", "Øre"]
+        .iter()
+        .map(|s| code_points(s))
+        .collect();
+    names.sort_by(|a, b| collate(a, b, Strength::Tertiary, VariableWeighting::NonIgnorable));
+    let names: Vec<String> = names
+        .iter()
+        .map(|v| v.iter().map(|cp| char::from_u32(*cp).unwrap()).collect())
+        .collect();
+    assert_eq!(
+        names,
+        vec!["adam", "Adam", "Ærø", "émile", "Émile", "eve", "Øre", "Zoe"]
+    );
+}
+
+#[test]
+fn test_collate_collate_3() {
+    let words: [&str; 3] = ["de luge", "de-luge", "deluge"];
+    for pair in words.windows(2) {
+        assert_eq!(
+            compare(pair[0], pair[1], Strength::Tertiary),
+            Ordering::Less
+        );
+    }
+    let shifted = |a: &str, b: &str, strength: Strength| {
+        collate(
+            code_points(a),
+            code_points(b),
+            strength,
+            VariableWeighting::Shifted,
+        )
+    };
+    // The variable characters are ignored up to the tertiary level
+    assert_eq!(
+        shifted("de luge", "deluge", Strength::Tertiary),
+        Ordering::Equal
+    );
+    assert_eq!(
+        shifted("de-luge", "deLuge", Strength::Tertiary),
+        Ordering::Less
+    );
+    for pair in words.windows(2) {
+        assert_eq!(
+            shifted(pair[0], pair[1], Strength::Quaternary),
+            Ordering::Less
+        );
+    }
+    // The combining marks after a variable character are ignored too
+    assert_eq!(
+        shifted("a-\u{301}b", "ab", Strength::Tertiary),
+        Ordering::Equal
+    );
+    assert_eq!(
+        shifted("a\u{301}b", "ab", Strength::Tertiary),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_collate_collate_4() {
+    // "й" is a letter of its own, also with a dot below between the letter and the breve
+    assert_eq!(compare("й", "и", Strength::Primary), Ordering::Greater);
+    assert_eq!(
+        compare("й", "и\u{306}", Strength::Identical),
+        Ordering::Equal
+    );
+    assert_eq!(
+        compare("и\u{323}\u{306}", "й", Strength::Primary),
+        Ordering::Equal
+    );
+    assert_eq!(
+        compare("и\u{306}\u{323}", "й", Strength::Primary),
+        Ordering::Equal
+    );
+    assert_eq!(
+        compare("и\u{323}\u{306}", "и", Strength::Primary),
+        Ordering::Greater
+    );
+    // A mark of the same class blocks the contraction
+    assert_eq!(
+        compare("и\u{301}\u{306}", "и", Strength::Primary),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn test_collate_collate_5() {
+    // Hangul syllables are compared by their jamo
+    assert_eq!(compare("가", "각", Strength::Primary), Ordering::Less);
+    assert_eq!(compare("각", "나", Strength::Primary), Ordering::Less);
+    assert_eq!(
+        compare("가", "\u{1100}\u{1161}", Strength::Identical),
+        Ordering::Equal
+    );
+    // The Han ideographs after the letters, by code point and block
+    assert_eq!(compare("z", "一", Strength::Primary), Ordering::Less);
+    assert_eq!(compare("一", "丁", Strength::Primary), Ordering::Less);
+    assert_eq!(
+        compare("\u{9fa5}", "\u{3400}", Strength::Primary),
+        Ordering::Less
+    );
+    assert_eq!(
+        compare("\u{3400}", "\u{20000}", Strength::Primary),
+        Ordering::Less
+    );
+    // The unassigned code points last
+    assert_eq!(
+        compare("\u{20000}", "\u{378}", Strength::Primary),
+        Ordering::Less
+    );
+    assert_eq!(
+        compare("\u{378}", "\u{379}", Strength::Primary),
+        Ordering::Less
+    );
+    // Tangut Supplement after Tangut
+    assert_eq!(
+        compare("\u{18aff}", "\u{18d00}", Strength::Primary),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn test_collate_sort_key_1() {
+    let key = |s: &str, strength: Strength, weighting: VariableWeighting| {
+        sort_key(code_points(s), strength, weighting)
+    };
+    let non_ignorable = VariableWeighting::NonIgnorable;
+    assert_eq!(key("", Strength::Tertiary, non_ignorable), vec![0, 0]);
+    assert_eq!(key("", Strength::Identical, non_ignorable), vec![0, 0, 0]);
+    assert_eq!(key("ab", Strength::Primary, non_ignorable).len(), 2);
+    assert_eq!(key("ab", Strength::Tertiary, non_ignorable).len(), 8);
+    // The quaternary level only with the shifted weighting
+    assert_eq!(key("ab", Strength::Quaternary, non_ignorable).len(), 8);
+    let shifted: Vec<u32> = key("a b", Strength::Quaternary, VariableWeighting::Shifted);
+    assert_eq!(shifted.len(), 12);
+    assert_eq!(shifted[9], 0xFFFF);
+    assert!(shifted[10] < 0xFFFF);
+    assert_eq!(shifted[11], 0xFFFF);
+    assert_eq!(
+        key("e\u{301}", Strength::Identical, non_ignorable)[7..],
+        [0, 0x65, 0x301]
+    );
+    assert_eq!(
+        key("é", Strength::Identical, non_ignorable),
+        key("e\u{301}", Strength::Identical, non_ignorable)
+    );
+}
+
+#[test]
+fn test_collate_sort_key_2() {
+    // Long runs of combining marks after a base letter or a contraction prefix (U+0F71) are matched in linear time
+    for (base, mark) in [(0x61, 0x301), (0x6c, 0x301), (0xf71, 0xf71)] {
+        let mut v: Vec<u32> = vec![base];
+        v.extend(vec![mark; 100_000]);
+        let key: Vec<u32> = sort_key(&v, Strength::Primary, VariableWeighting::NonIgnorable);
+        assert!(!key.is_empty());
+    }
+    // A discontiguous contraction: "и" + U+0323 + U+0306 has the primary weight of "й"
+    let a: Vec<u32> = vec![0x438, 0x323, 0x306];
+    let b: Vec<u32> = vec![0x439];
+    assert_eq!(
+        sort_key(&a, Strength::Primary, VariableWeighting::NonIgnorable),
+        sort_key(&b, Strength::Primary, VariableWeighting::NonIgnorable)
+    );
+}
+
+#[test]
+#[ignore = "requires tests/data/CollationTest_NON_IGNORABLE.txt, run `make test-data` to download it"]
+fn test_collate_conformance() {
+    let path: String = format!(
+        "{}/tests/data/CollationTest_NON_IGNORABLE.txt",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let data: String = std::fs::read_to_string(path).unwrap();
+    let mut previous: Option<(Vec<u32>, &str)> = None;
+    for line in data.lines() {
+        let fields: &str = line
+            .split('#')
+            .next()
+            .unwrap()
+            .split(';')
+            .next()
+            .unwrap()
+            .trim();
+        if fields.is_empty() {
+            continue;
+        }
+        let key: Vec<u32> = sort_key(
+            fields
+                .split_whitespace()
+                .map(|cp| u32::from_str_radix(cp, 16).unwrap())
+                .collect::<Vec<u32>>(),
+            Strength::Identical,
+            VariableWeighting::NonIgnorable,
+        );
+        // Every line is greater than or equal to the line before it
+        if let Some((previous_key, previous_line)) = &previous {
+            assert!(*previous_key <= key, "{} > {}", previous_line, line);
+        }
+        previous = Some((key, line));
+    }
+}
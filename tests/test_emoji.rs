@@ -0,0 +1,156 @@
+mod common;
+
+use common::code_points;
+use ende::prelude::*;
+
+#[test]
+fn test_emoji_is_emoji_1() {
+    assert!(is_emoji(0x1f600));
+    assert!(is_emoji(0x23)); // #
+    assert!(is_emoji(0x1f1ee)); // regional indicator
+    assert!(!is_emoji(0x61));
+    assert!(!is_emoji(0x200d));
+    assert!(!is_emoji(0x110000));
+}
+
+#[test]
+fn test_emoji_is_emoji_presentation_1() {
+    assert!(is_emoji_presentation(0x1f44d));
+    assert!(!is_emoji_presentation(0xa9)); // ©
+}
+
+#[test]
+fn test_emoji_is_emoji_modifier_1() {
+    assert!(is_emoji_modifier(0x1f3fb));
+    assert!(!is_emoji_modifier(0x1f3fa));
+}
+
+#[test]
+fn test_emoji_is_emoji_modifier_base_1() {
+    assert!(is_emoji_modifier_base(0x261d)); // ☝
+    assert!(!is_emoji_modifier_base(0x2764));
+}
+
+#[test]
+fn test_emoji_is_emoji_component_1() {
+    assert!(is_emoji_component(0xfe0f));
+    assert!(is_emoji_component(0xe0067));
+    assert!(is_emoji_component(0x30));
+    assert!(!is_emoji_component(0x2764));
+}
+
+#[test]
+fn test_emoji_is_extended_pictographic_1() {
+    assert!(is_extended_pictographic(0x2764));
+    assert!(!is_extended_pictographic(0x1f1ee));
+}
+
+#[test]
+fn test_emoji_emoji_sequences_1() {
+    let sequences = |s: &str| emoji_sequences(&code_points(s));
+    assert_eq!(sequences("😀"), vec![(0..1, EmojiSequence::Basic)]);
+    assert_eq!(
+        sequences("\u{2764}\u{fe0f}"),
+        vec![(0..2, EmojiSequence::Presentation)]
+    );
+    assert_eq!(
+        sequences("1\u{fe0f}\u{20e3}"),
+        vec![(0..3, EmojiSequence::Keycap)]
+    );
+    assert_eq!(sequences("#\u{20e3}"), vec![(0..2, EmojiSequence::Keycap)]);
+    assert_eq!(
+        sequences("\u{1f1ee}\u{1f1f9}"),
+        vec![(0..2, EmojiSequence::Flag)]
+    );
+    assert_eq!(
+        sequences("\u{1f44d}\u{1f3fd}"),
+        vec![(0..2, EmojiSequence::Modifier)]
+    );
+    assert_eq!(
+        sequences("\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}"),
+        vec![(0..7, EmojiSequence::Tag)]
+    );
+    assert_eq!(
+        sequences("\u{1f469}\u{200d}\u{1f4bb}"),
+        vec![(0..3, EmojiSequence::Zwj)]
+    );
+    // With modifiers and a text presentation element
+    assert_eq!(
+        sequences("\u{1f469}\u{1f3fe}\u{200d}\u{2695}\u{fe0f}"),
+        vec![(0..5, EmojiSequence::Zwj)]
+    );
+    assert_eq!(
+        sequences("\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}"),
+        vec![(0..4, EmojiSequence::Zwj)]
+    );
+}
+
+#[test]
+fn test_emoji_emoji_sequences_2() {
+    let sequences = |s: &str| emoji_sequences(&code_points(s));
+    assert_eq!(sequences(""), vec![]);
+    assert_eq!(sequences("Hello, world!"), vec![]);
+    // The characters with a text presentation by default
+    assert_eq!(sequences("© 2022 ☺"), vec![]);
+    assert_eq!(sequences("\u{2764}\u{fe0e}"), vec![]);
+    // A modifier or a regional indicator alone
+    assert_eq!(sequences("\u{1f3fb}"), vec![(0..1, EmojiSequence::Basic)]);
+    assert_eq!(sequences("\u{1f1ee}"), vec![(0..1, EmojiSequence::Basic)]);
+    // Three regional indicators are a flag and an indicator
+    assert_eq!(
+        sequences("\u{1f1ee}\u{1f1f9}\u{1f1eb}"),
+        vec![(0..2, EmojiSequence::Flag), (2..3, EmojiSequence::Basic)]
+    );
+    // A ZWJ which does not join two emoji
+    assert_eq!(
+        sequences("\u{1f469}\u{200d}a"),
+        vec![(0..1, EmojiSequence::Basic)]
+    );
+    // Tags without a cancel tag
+    assert_eq!(
+        sequences("\u{1f3f4}\u{e0067}\u{e0062}"),
+        vec![(0..1, EmojiSequence::Basic)]
+    );
+    assert_eq!(
+        sequences("a😀b👍🏽c"),
+        vec![
+            (1..2, EmojiSequence::Basic),
+            (3..5, EmojiSequence::Modifier)
+        ]
+    );
+}
+
+#[test]
+fn test_emoji_emoji_sequences_3() {
+    let sequences = |s: &str| emoji_sequences(&code_points(s));
+    // The digits, `#` and `*` are joined only with U+FE0F
+    assert_eq!(sequences("1\u{200d}2"), vec![]);
+    assert_eq!(
+        sequences("#\u{200d}\u{1f600}"),
+        vec![(2..3, EmojiSequence::Basic)]
+    );
+    assert_eq!(
+        sequences("\u{1f600}\u{200d}*"),
+        vec![(0..1, EmojiSequence::Basic)]
+    );
+    assert_eq!(
+        sequences("1\u{fe0f}\u{200d}2\u{fe0f}"),
+        vec![(0..5, EmojiSequence::Zwj)]
+    );
+    // A keycap is not an element of a ZWJ sequence
+    assert_eq!(
+        sequences("1\u{fe0f}\u{20e3}\u{200d}\u{1f600}"),
+        vec![(0..3, EmojiSequence::Keycap), (4..5, EmojiSequence::Basic)]
+    );
+}
+
+#[test]
+fn test_emoji_emoji_count_1() {
+    let count = |s: &str| emoji_count(&code_points(s));
+    assert_eq!(count(""), 0);
+    assert_eq!(count("no emoji here 123"), 0);
+    assert_eq!(count("👨‍👩‍👧‍👦"), 1);
+    assert_eq!(count("🇫🇷🇩🇪"), 2);
+    assert_eq!(count("👍🏿👍 ❤️ 1️⃣"), 4);
+    assert_eq!(count("1\u{200d}2"), 0);
+}
@@ -0,0 +1,153 @@
+use ende::prelude::*;
+
+#[test]
+fn test_escape_escape_json() {
+    let v: Vec<u32> = vec![0x41, 0x22, 0x5c, 0x0a, 0x01, 0xe9, 0x1f600];
+    let escaped: String = escape(v, EscapeDialect::Json);
+    assert_eq!(escaped, r#"A\"\\\n\u0001\u00e9\ud83d\ude00"#);
+}
+
+#[test]
+fn test_escape_unescape_json() {
+    let v: Vec<u32> =
+        unescape(r#"A\"\\\n\/\u0001\u00e9\ud83d\ude00"#, EscapeDialect::Json).unwrap();
+    assert_eq!(v, vec![0x41, 0x22, 0x5c, 0x0a, 0x2f, 0x01, 0xe9, 0x1f600]);
+}
+
+#[test]
+fn test_escape_escape_rust() {
+    let v: Vec<u32> = vec![0x41, 0x00, 0x7f, 0xe9, 0x1f600];
+    let escaped: String = escape(v, EscapeDialect::Rust);
+    assert_eq!(escaped, r"A\0\x7f\u{e9}\u{1f600}");
+}
+
+#[test]
+fn test_escape_unescape_rust() {
+    let v: Vec<u32> = unescape(r"A\0\x7f\'\u{E9}\u{1_F600}", EscapeDialect::Rust).unwrap();
+    assert_eq!(v, vec![0x41, 0x00, 0x7f, 0x27, 0xe9, 0x1f600]);
+}
+
+#[test]
+fn test_escape_escape_c() {
+    let v: Vec<u32> = vec![0x41, 0x07, 0x01, 0xe9, 0x1f600];
+    let escaped: String = escape(v, EscapeDialect::C);
+    assert_eq!(escaped, r"A\a\001\u00e9\U0001f600");
+}
+
+#[test]
+fn test_escape_escape_c_c1_controls() {
+    let v: Vec<u32> = vec![0x80, 0x85, 0x9f, 0xa0];
+    let escaped: String = escape(&v, EscapeDialect::C);
+    assert_eq!(escaped, r"\302\200\302\205\302\237\u00a0");
+    assert_eq!(unescape(&escaped, EscapeDialect::C).unwrap(), v);
+}
+
+#[test]
+fn test_escape_unescape_c() {
+    let v: Vec<u32> = unescape(r"A\a\1\101\x41\?\u00E9\U0001F600", EscapeDialect::C).unwrap();
+    assert_eq!(v, vec![0x41, 0x07, 0x01, 0x41, 0x41, 0x3f, 0xe9, 0x1f600]);
+}
+
+#[test]
+fn test_escape_escape_python() {
+    let v: Vec<u32> = vec![0x41, 0x0b, 0xe9, 0x3b1, 0x1f600];
+    let escaped: String = escape(v, EscapeDialect::Python);
+    assert_eq!(escaped, r"A\v\xe9\u03b1\U0001f600");
+}
+
+#[test]
+fn test_escape_unescape_python() {
+    let v: Vec<u32> = unescape(r"A\v\xe9\u03b1\U0001f600\101", EscapeDialect::Python).unwrap();
+    assert_eq!(v, vec![0x41, 0x0b, 0xe9, 0x3b1, 0x1f600, 0x41]);
+}
+
+#[test]
+fn test_escape_escape_javascript() {
+    let v: Vec<u32> = vec![0x41, 0x00, 0xe9, 0x1f600];
+    let escaped: String = escape(v, EscapeDialect::JavaScript);
+    assert_eq!(escaped, r"A\x00\u00e9\u{1f600}");
+}
+
+#[test]
+fn test_escape_unescape_javascript() {
+    let v: Vec<u32> = unescape(
+        r"A\0\x00\u00e9\u{1f600}\ud83d\ude00",
+        EscapeDialect::JavaScript,
+    )
+    .unwrap();
+    assert_eq!(v, vec![0x41, 0x00, 0x00, 0xe9, 0x1f600, 0x1f600]);
+}
+
+#[test]
+fn test_escape_roundtrip() {
+    let v: Vec<u32> = vec![
+        0x00, 0x09, 0x22, 0x27, 0x5c, 0x7f, 0x85, 0xa9, 0xffff, 0x10348, 0x10ffff,
+    ];
+    for dialect in [
+        EscapeDialect::Json,
+        EscapeDialect::Rust,
+        EscapeDialect::C,
+        EscapeDialect::Python,
+        EscapeDialect::JavaScript,
+    ] {
+        assert_eq!(unescape(&escape(&v, dialect), dialect).unwrap(), v);
+    }
+}
+
+#[test]
+fn test_escape_unescape_non_ascii() {
+    let v: Vec<u32> = unescape("é😀", EscapeDialect::Json).unwrap();
+    assert_eq!(v, vec![0xe9, 0x1f600]);
+}
+
+#[test]
+fn test_escape_unescape_unknown_escape() {
+    let err: EscapeError = unescape(r"ab\q", EscapeDialect::Json).unwrap_err();
+    assert_eq!(err.kind, EscapeErrorKind::UnknownEscape('q'));
+    assert_eq!(err.position, 2);
+}
+
+#[test]
+fn test_escape_unescape_truncated_escape() {
+    let err: EscapeError = unescape(r"é\u12", EscapeDialect::Json).unwrap_err();
+    assert_eq!(err.kind, EscapeErrorKind::TruncatedEscape);
+    assert_eq!(err.position, 2);
+
+    let err: EscapeError = unescape(r"\u{1f600", EscapeDialect::Rust).unwrap_err();
+    assert_eq!(err.kind, EscapeErrorKind::TruncatedEscape);
+    assert_eq!(err.position, 0);
+
+    let err: EscapeError = unescape("a\\", EscapeDialect::C).unwrap_err();
+    assert_eq!(err.kind, EscapeErrorKind::TruncatedEscape);
+    assert_eq!(err.position, 1);
+}
+
+#[test]
+fn test_escape_unescape_lone_surrogate() {
+    let err: EscapeError = unescape(r"\ud83dA", EscapeDialect::Json).unwrap_err();
+    assert_eq!(err.kind, EscapeErrorKind::LoneSurrogate(0xd83d));
+    assert_eq!(err.position, 0);
+
+    let err: EscapeError = unescape(r"A\ude00", EscapeDialect::JavaScript).unwrap_err();
+    assert_eq!(err.kind, EscapeErrorKind::LoneSurrogate(0xde00));
+    assert_eq!(err.position, 1);
+
+    let err: EscapeError = unescape(r"\u{d800}", EscapeDialect::Rust).unwrap_err();
+    assert_eq!(err.kind, EscapeErrorKind::LoneSurrogate(0xd800));
+}
+
+#[test]
+fn test_escape_unescape_invalid_code_point() {
+    let err: EscapeError = unescape(r"\U00110000", EscapeDialect::Python).unwrap_err();
+    assert_eq!(err.kind, EscapeErrorKind::InvalidCodePoint(0x110000));
+
+    let err: EscapeError = unescape(r"\xff", EscapeDialect::Rust).unwrap_err();
+    assert_eq!(err.kind, EscapeErrorKind::InvalidCodePoint(0xff));
+}
+
+#[test]
+fn test_escape_unescape_unsupported_escape() {
+    let err: EscapeError = unescape(r"\N{BULLET}", EscapeDialect::Python).unwrap_err();
+    assert_eq!(err.kind, EscapeErrorKind::UnsupportedEscape);
+    assert_eq!(err.position, 0);
+}
@@ -0,0 +1,147 @@
+use ende::prelude::*;
+
+#[test]
+fn test_gsm7_encode_in_gsm7_1() {
+    let v: Vec<u32> = vec![0x48, 0x65, 0x6c, 0x6c, 0x6f]; // "Hello"
+    let enc: Vec<u8> = encode_in_gsm7(v);
+    assert_eq!(enc, vec![0x48, 0x65, 0x6c, 0x6c, 0x6f]);
+}
+
+#[test]
+fn test_gsm7_decode_from_gsm7_1() {
+    let enc: Vec<u8> = vec![0x48, 0x65, 0x6c, 0x6c, 0x6f];
+    let v: Vec<u32> = decode_from_gsm7(enc);
+    assert_eq!(v, vec![0x48, 0x65, 0x6c, 0x6c, 0x6f]);
+}
+
+#[test]
+fn test_gsm7_encode_in_gsm7_2() {
+    let v: Vec<u32> = vec![0x40, 0xa3, 0x24, 0x394, 0x20ac, 0x7b]; // "@£$Δ€{"
+    let enc: Vec<u8> = encode_in_gsm7(v);
+    assert_eq!(enc, vec![0x00, 0x01, 0x02, 0x10, 0x1b, 0x65, 0x1b, 0x28]);
+}
+
+#[test]
+fn test_gsm7_decode_from_gsm7_2() {
+    let enc: Vec<u8> = vec![0x00, 0x01, 0x02, 0x10, 0x1b, 0x65, 0x1b, 0x28];
+    let v: Vec<u32> = decode_from_gsm7(enc);
+    assert_eq!(v, vec![0x40, 0xa3, 0x24, 0x394, 0x20ac, 0x7b]);
+}
+
+#[test]
+fn test_gsm7_decode_from_gsm7_unknown_extension() {
+    let enc: Vec<u8> = vec![0x1b, 0x41];
+    let v: Vec<u32> = decode_from_gsm7(enc);
+    assert_eq!(v, vec![0x41]);
+}
+
+#[test]
+#[should_panic]
+fn test_gsm7_encode_in_gsm7_invalid() {
+    let v: Vec<u32> = vec![0x3b1];
+    encode_in_gsm7(v);
+}
+
+#[test]
+#[should_panic]
+fn test_gsm7_decode_from_gsm7_trailing_escape() {
+    let enc: Vec<u8> = vec![0x41, 0x1b];
+    decode_from_gsm7(enc);
+}
+
+#[test]
+fn test_gsm7_pack_septets_1() {
+    let septets: Vec<u8> = vec![0x68, 0x65, 0x6c, 0x6c, 0x6f]; // "hello"
+    let packed: Vec<u8> = pack_septets(septets);
+    assert_eq!(packed, vec![0xe8, 0x32, 0x9b, 0xfd, 0x06]);
+}
+
+#[test]
+fn test_gsm7_unpack_septets_1() {
+    let packed: Vec<u8> = vec![0xe8, 0x32, 0x9b, 0xfd, 0x06];
+    let septets: Vec<u8> = unpack_septets(packed, 5);
+    assert_eq!(septets, vec![0x68, 0x65, 0x6c, 0x6c, 0x6f]);
+}
+
+#[test]
+fn test_gsm7_pack_septets_2() {
+    let septets: Vec<u8> = vec![0x7f; 8];
+    let packed: Vec<u8> = pack_septets(septets);
+    assert_eq!(packed, vec![0xff; 7]);
+}
+
+#[test]
+fn test_gsm7_unpack_septets_2() {
+    let packed: Vec<u8> = vec![0xff; 7];
+    assert_eq!(unpack_septets(&packed, 8), vec![0x7f; 8]);
+    assert_eq!(unpack_septets(&packed, 7), vec![0x7f; 7]);
+}
+
+#[test]
+fn test_gsm7_is_gsm7_encodable() {
+    assert!(is_gsm7_encodable(vec![0x48, 0x69, 0x5b, 0x5d]));
+    assert!(!is_gsm7_encodable(vec![0x48, 0x69, 0x1f600]));
+    assert!(!is_gsm7_encodable(vec![0x1b]));
+}
+
+#[test]
+fn test_gsm7_sms_info_gsm7() {
+    assert_eq!(
+        sms_info(vec![0x41; 160]),
+        SmsInfo {
+            encoding: SmsEncoding::Gsm7,
+            units: 160,
+            segments: 1
+        }
+    );
+    assert_eq!(sms_info(vec![0x41; 306]).segments, 2);
+    assert_eq!(sms_info(vec![0x41; 307]).segments, 3);
+}
+
+#[test]
+fn test_gsm7_sms_info_gsm7_escape_not_split() {
+    // 152 septets followed by "€" (two septets) do not fit in the first segment
+    let mut v: Vec<u32> = vec![0x41; 152];
+    v.push(0x20ac);
+    v.append(&mut vec![0x41; 152]);
+    let info: SmsInfo = sms_info(v);
+    assert_eq!(info.units, 306);
+    assert_eq!(info.segments, 3);
+}
+
+#[test]
+fn test_gsm7_sms_info_ucs2() {
+    assert_eq!(
+        sms_info(vec![0x3b1; 70]),
+        SmsInfo {
+            encoding: SmsEncoding::Ucs2,
+            units: 70,
+            segments: 1
+        }
+    );
+    assert_eq!(sms_info(vec![0x3b1; 71]).segments, 2);
+    assert_eq!(sms_info(vec![0x3b1; 135]).segments, 3);
+}
+
+#[test]
+fn test_gsm7_encode_sms() {
+    assert_eq!(
+        encode_sms(vec![0x48, 0x69, 0x20ac]),
+        SmsPayload::Gsm7(vec![0x48, 0x69, 0x1b, 0x65])
+    );
+    assert_eq!(
+        encode_sms(vec![0x48, 0x69, 0x3b1]),
+        SmsPayload::Ucs2(vec![0x48, 0x69, 0x3b1])
+    );
+}
+
+#[test]
+fn test_gsm7_encode_sms_emoji() {
+    // "hi 😀"
+    let v: Vec<u32> = vec![0x68, 0x69, 0x20, 0x1f600];
+    assert_eq!(sms_info(&v).units, 5);
+    assert_eq!(
+        encode_sms(&v),
+        SmsPayload::Ucs2(vec![0x68, 0x69, 0x20, 0xd83d, 0xde00])
+    );
+}
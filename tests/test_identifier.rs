@@ -0,0 +1,152 @@
+mod common;
+
+use common::code_points;
+use ende::prelude::*;
+
+#[test]
+fn test_identifier_is_xid_start_1() {
+    assert!(is_xid_start(0x41));
+    assert!(is_xid_start(0xe9)); // é
+    assert!(is_xid_start(0x5d0)); // א
+    assert!(is_xid_start(0x2160)); // Ⅰ
+    assert!(is_xid_start(0x20000)); // 𠀀
+    assert!(!is_xid_start(0x5f)); // _
+    assert!(!is_xid_start(0x301));
+    assert!(!is_xid_start(0x660)); // ٠
+    assert!(!is_xid_start(0x37a)); // ͺ, whose NFKC starts with a space
+    assert!(!is_xid_start(0x110000));
+}
+
+#[test]
+fn test_identifier_is_xid_continue_1() {
+    assert!(is_xid_continue(0x41));
+    assert!(is_xid_continue(0x5f)); // _
+    assert!(is_xid_continue(0x203f)); // ‿
+    assert!(is_xid_continue(0x660)); // ٠
+    assert!(is_xid_continue(0xb7)); // ·
+    assert!(is_xid_continue(0xe0100)); // variation selector
+    assert!(!is_xid_continue(0x20));
+    assert!(!is_xid_continue(0x24)); // $
+    assert!(!is_xid_continue(0x200d)); // ZERO WIDTH JOINER
+    assert!(!is_xid_continue(0x1f600));
+    // Every start code point is a continue code point
+    for code_point in 0..0x110000 {
+        assert!(!is_xid_start(code_point) || is_xid_continue(code_point));
+    }
+}
+
+#[test]
+fn test_identifier_is_valid_identifier_1() {
+    let valid = |s: &str| is_valid_identifier(&code_points(s));
+    assert_eq!(valid("x"), Ok(()));
+    assert_eq!(valid("snake_case_2"), Ok(()));
+    assert_eq!(valid("größe"), Ok(()));
+    assert_eq!(valid("переменная"), Ok(()));
+    assert_eq!(valid("変数"), Ok(()));
+    assert_eq!(valid("e\u{301}"), Ok(()));
+    let error =
+        |kind: IdentifierErrorKind, position: usize| Err(IdentifierError { kind, position });
+    assert_eq!(valid(""), error(IdentifierErrorKind::Empty, 0));
+    assert_eq!(
+        valid("_x"),
+        error(IdentifierErrorKind::InvalidStart(0x5f), 0)
+    );
+    assert_eq!(
+        valid("2x"),
+        error(IdentifierErrorKind::InvalidStart(0x32), 0)
+    );
+    assert_eq!(
+        valid("a b"),
+        error(IdentifierErrorKind::InvalidContinue(0x20), 1)
+    );
+    assert_eq!(
+        valid("café☕"),
+        error(IdentifierErrorKind::InvalidContinue(0x2615), 4)
+    );
+}
+
+#[test]
+fn test_identifier_validate_identifier_1() {
+    let validate = |s: &str| validate_identifier(&code_points(s), IdentifierProfile::Nfkc);
+    assert_eq!(validate("file"), Ok(()));
+    assert_eq!(validate("caf\u{e9}"), Ok(()));
+    let error =
+        |kind: IdentifierErrorKind, position: usize| Err(IdentifierError { kind, position });
+    assert_eq!(
+        validate("caf\u{e9}\u{ff41}"), // with a full-width "ａ"
+        error(IdentifierErrorKind::NotNfkc(0xff41), 4)
+    );
+    // Not composed
+    assert_eq!(
+        validate("cafe\u{301}"),
+        error(IdentifierErrorKind::NotNfkc(0x301), 4)
+    );
+    assert_eq!(
+        validate("x\u{210c}"), // ℌ
+        error(IdentifierErrorKind::NotNfkc(0x210c), 1)
+    );
+    // The syntax is checked first
+    assert_eq!(
+        validate("\u{ff41}-"),
+        error(IdentifierErrorKind::InvalidContinue(0x2d), 1)
+    );
+    // The NFKC of an identifier is still an identifier
+    for s in ["\u{fb01}le", "x\u{210c}", "\u{ff41}\u{ff10}", "\u{2160}"] {
+        assert!(is_valid_identifier(&code_points(s)).is_ok());
+        assert_eq!(
+            validate_identifier(&nfkc(code_points(s)), IdentifierProfile::Nfkc),
+            Ok(())
+        );
+    }
+}
+
+#[test]
+fn test_identifier_validate_identifier_2() {
+    // The first code point not in NFKC is found in a single pass
+    let mut v: Vec<u32> = vec![0x61; 100_000];
+    v.extend([0x65, 0x301]);
+    assert_eq!(
+        validate_identifier(&v, IdentifierProfile::Nfkc),
+        Err(IdentifierError {
+            kind: IdentifierErrorKind::NotNfkc(0x301),
+            position: 100_001
+        })
+    );
+    // A blocked mark does not compose: "e" + U+20D0 + U+0301 is in NFKC, but "a" + U+0301 is not
+    let v: Vec<u32> = vec![0x65, 0x20d0, 0x301, 0x61, 0x301];
+    assert_eq!(
+        validate_identifier(&v, IdentifierProfile::Nfkc),
+        Err(IdentifierError {
+            kind: IdentifierErrorKind::NotNfkc(0x301),
+            position: 4
+        })
+    );
+    // A mark which composes with the decomposition of a precomposed letter: "é" + U+0323 is U+1EB9 + U+0301
+    let v: Vec<u32> = vec![0xe9, 0x323, 0x62, 0x63];
+    assert_eq!(
+        validate_identifier(&v, IdentifierProfile::Nfkc),
+        Err(IdentifierError {
+            kind: IdentifierErrorKind::NotNfkc(0x323),
+            position: 1
+        })
+    );
+    let v: Vec<u32> = vec![0x61, 0x1ed9, 0x328, 0x300, 0x62];
+    assert_eq!(
+        validate_identifier(&v, IdentifierProfile::Nfkc),
+        Err(IdentifierError {
+            kind: IdentifierErrorKind::NotNfkc(0x328),
+            position: 2
+        })
+    );
+}
+
+#[test]
+fn test_identifier_is_valid_identifier_2() {
+    let err: IdentifierError = is_valid_identifier(&code_points("a-b")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid identifier character 2d at position 1"
+    );
+    let err: IdentifierError = is_valid_identifier(&[]).unwrap_err();
+    assert_eq!(err.to_string(), "Empty identifier at position 0");
+}
@@ -0,0 +1,125 @@
+mod common;
+
+use common::code_points;
+use ende::prelude::*;
+
+#[test]
+fn test_japanese_fold_fullwidth_1() {
+    assert_eq!(fold_fullwidth(code_points("ＡＢＣ")), code_points("ABC"));
+    assert_eq!(
+        fold_fullwidth(code_points("ｈｅｌｌｏ，　ｗｏｒｌｄ～")),
+        code_points("hello, world~")
+    );
+    assert_eq!(fold_fullwidth(code_points("￥１００")), code_points("¥100"));
+    assert_eq!(fold_fullwidth(code_points("￣")), vec![0xaf]);
+    assert_eq!(fold_fullwidth(code_points("｟｠")), vec![0x2985, 0x2986]);
+    // The half-width forms and the other characters are kept
+    assert_eq!(
+        fold_fullwidth(code_points("ｶﾅ abc 日本")),
+        code_points("ｶﾅ abc 日本")
+    );
+    // The same as the NFKC of the full-width ASCII
+    for code_point in 0xff01..=0xff5e {
+        assert_eq!(fold_fullwidth(vec![code_point]), nfkc(vec![code_point]));
+        assert_eq!(fold_fullwidth(vec![code_point]), vec![code_point - 0xfee0]);
+    }
+    assert_eq!(fold_fullwidth(Vec::new()), Vec::<u32>::new());
+}
+
+#[test]
+fn test_japanese_fold_halfwidth_1() {
+    assert_eq!(fold_halfwidth(code_points("ｶﾀｶﾅ")), code_points("カタカナ"));
+    assert_eq!(
+        fold_halfwidth(code_points("ｺﾝﾋﾟｭｰﾀｰ")),
+        code_points("コンピューター")
+    );
+    assert_eq!(
+        fold_halfwidth(code_points("ｳﾞｧｲｵﾘﾝ")),
+        code_points("ヴァイオリン")
+    );
+    assert_eq!(fold_halfwidth(code_points("ﾜﾞ")), code_points("ヷ"));
+    assert_eq!(
+        fold_halfwidth(code_points("｢ﾊﾟﾝ｣､｡･")),
+        code_points("「パン」、。・")
+    );
+    assert_eq!(fold_halfwidth(code_points("￩￫")), code_points("←→"));
+    // The sound marks which cannot be composed
+    assert_eq!(fold_halfwidth(code_points("ﾞ")), code_points("゛"));
+    assert_eq!(fold_halfwidth(code_points("ｱﾞ")), code_points("ア゛"));
+    assert_eq!(fold_halfwidth(code_points("ｶﾟ")), code_points("カ゜"));
+    assert_eq!(fold_halfwidth(code_points("ﾊﾞﾟ")), code_points("バ゜"));
+    // The sound marks are composed with the full-width kana too
+    assert_eq!(fold_halfwidth(code_points("かﾞ")), code_points("が"));
+    // The full-width forms and the other characters are kept
+    assert_eq!(fold_halfwidth(code_points("ＡＢＣ")), code_points("ＡＢＣ"));
+    assert_eq!(fold_halfwidth(code_points("ﾡ")), code_points("ﾡ"));
+    // The same as the NFC of the NFKC of the half-width katakana
+    for code_point in 0xff61..=0xff9d {
+        assert_eq!(fold_halfwidth(vec![code_point]), nfkc(vec![code_point]));
+        for mark in [0xff9e, 0xff9f] {
+            let nfkc: Vec<u32> = nfkc(vec![code_point, mark]);
+            if nfkc.len() == 1 {
+                assert_eq!(fold_halfwidth(vec![code_point, mark]), nfkc);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_japanese_fold_width_1() {
+    assert_eq!(
+        fold_width(code_points("ＡＢＣ ｶﾞｯｺｳ １２３")),
+        code_points("ABC ガッコウ 123")
+    );
+    let v: Vec<u32> = code_points("ＡｶﾞＢ");
+    assert_eq!(fold_width(&v), fold_halfwidth(fold_fullwidth(&v)));
+    assert_eq!(fold_width(&v), fold_fullwidth(fold_halfwidth(&v)));
+}
+
+#[test]
+fn test_japanese_hiragana_to_katakana_1() {
+    assert_eq!(
+        hiragana_to_katakana(code_points("ひらがな")),
+        code_points("ヒラガナ")
+    );
+    assert_eq!(
+        hiragana_to_katakana(code_points("ぁゔゕゖゝゞ")),
+        code_points("ァヴヵヶヽヾ")
+    );
+    assert_eq!(
+        hiragana_to_katakana(code_points("日本語のテキスト、abc")),
+        code_points("日本語ノテキスト、abc")
+    );
+    // The spacing sound marks and the digraph ゟ are kept
+    assert_eq!(
+        hiragana_to_katakana(code_points("゛゜ゟ")),
+        code_points("゛゜ゟ")
+    );
+}
+
+#[test]
+fn test_japanese_katakana_to_hiragana_1() {
+    assert_eq!(
+        katakana_to_hiragana(code_points("カタカナ")),
+        code_points("かたかな")
+    );
+    assert_eq!(
+        katakana_to_hiragana(code_points("ァヴヵヶヽヾ")),
+        code_points("ぁゔゕゖゝゞ")
+    );
+    assert_eq!(
+        katakana_to_hiragana(code_points("ヷヸヹヺ")),
+        code_points("わ\u{3099}ゐ\u{3099}ゑ\u{3099}を\u{3099}")
+    );
+    // The prolonged sound mark, the middle dot, the digraph ヿ and the half-width katakana are kept
+    assert_eq!(
+        katakana_to_hiragana(code_points("ー・ヿｶﾅ")),
+        code_points("ー・ヿｶﾅ")
+    );
+    // Round trip
+    for code_point in (0x3041..=0x3096).chain(0x309d..=0x309e) {
+        let katakana: Vec<u32> = hiragana_to_katakana(vec![code_point]);
+        assert_eq!(script(katakana[0]), Script::Katakana);
+        assert_eq!(katakana_to_hiragana(katakana), vec![code_point]);
+    }
+}
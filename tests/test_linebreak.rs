@@ -0,0 +1,204 @@
+mod common;
+
+use common::{code_points, parse_break_test};
+use ende::prelude::*;
+
+/// Collect the positions of the line breaking opportunities of a string, in code points.
+fn breaks(s: &str) -> Vec<usize> {
+    let v: Vec<u32> = code_points(s);
+    line_breaks(&v).map(|(i, _)| i).collect()
+}
+
+/// Wrap a string and collect the lines as strings.
+fn wrap_str(s: &str, width: usize) -> Vec<String> {
+    let v: Vec<u32> = code_points(s);
+    wrap(&v, width)
+        .iter()
+        .map(|line| line.iter().map(|cp| char::from_u32(*cp).unwrap()).collect())
+        .collect()
+}
+
+#[test]
+fn test_linebreak_line_breaks_spaces() {
+    assert_eq!(breaks("The quick  brown fox"), vec![4, 11, 17, 20]);
+    // Spaces before a closing punctuation or an exclamation do not allow a break
+    assert_eq!(breaks("Hello !"), vec![7]);
+    assert_eq!(breaks(""), Vec::<usize>::new());
+}
+
+#[test]
+fn test_linebreak_line_breaks_mandatory() {
+    let v: Vec<u32> = code_points("a\r\nb\nc\u{2028}d");
+    let opportunities: Vec<(usize, BreakOpportunity)> = line_breaks(&v).collect();
+    assert_eq!(
+        opportunities,
+        vec![
+            (3, BreakOpportunity::Mandatory),
+            (5, BreakOpportunity::Mandatory),
+            (7, BreakOpportunity::Mandatory),
+            (8, BreakOpportunity::Mandatory),
+        ]
+    );
+}
+
+#[test]
+fn test_linebreak_line_breaks_hyphens() {
+    assert_eq!(breaks("well-known"), vec![5, 10]);
+    // A hyphen before a number is a sign
+    assert_eq!(breaks("x -5"), vec![2, 4]);
+    // No break around a no-break space or a word joiner
+    assert_eq!(breaks("10\u{a0}km a\u{2060}b"), vec![6, 9]);
+}
+
+#[test]
+fn test_linebreak_line_breaks_numbers() {
+    assert_eq!(breaks("$(12.50) 3,000%"), vec![9, 15]);
+    assert_eq!(breaks("v1.2/3"), vec![6]);
+    // An infix separator is not part of a number which does not precede it (Example 7 of LB25)
+    assert_eq!(breaks("x.5"), vec![2, 3]);
+    assert_eq!(breaks("a .5"), vec![3, 4]);
+}
+
+#[test]
+fn test_linebreak_line_breaks_ideographic() {
+    // Breaks between ideographs, but not before a full stop or after an opening bracket
+    assert_eq!(breaks("漢字。「東京」"), vec![1, 3, 5, 7]);
+    // Small kana are treated as nonstarters
+    assert_eq!(breaks("ちょっと"), vec![3, 4]);
+}
+
+#[test]
+fn test_linebreak_line_breaks_emoji() {
+    // Flags, emoji modifiers and ZWJ sequences are not broken
+    assert_eq!(breaks("🇮🇹🇫🇷"), vec![2, 4]);
+    assert_eq!(breaks("👍🏽👩\u{200d}💻"), vec![2, 5]);
+}
+
+#[test]
+fn test_linebreak_line_breaks_combining_marks() {
+    // A combining mark takes the class of its base, and an isolated one is an alphabetic
+    assert_eq!(breaks("e\u{301} \u{301}x"), vec![3, 5]);
+}
+
+#[test]
+fn test_linebreak_line_breaks_utf8() {
+    let s: &str = "Ünïcödé text, 漢字";
+    let lines: Vec<&str> = {
+        let mut start: usize = 0;
+        line_breaks_utf8(s.as_bytes())
+            .map(|(i, _)| {
+                let line: &str = &s[start..i];
+                start = i;
+                line
+            })
+            .collect()
+    };
+    assert_eq!(lines, vec!["Ünïcödé ", "text, ", "漢", "字"]);
+}
+
+#[test]
+fn test_linebreak_line_breaks_utf16() {
+    let s: &str = "a 😀b\nc";
+    let utf16_cp: Vec<u16> = s.encode_utf16().collect();
+    let opportunities: Vec<(usize, BreakOpportunity)> = line_breaks_utf16(&utf16_cp).collect();
+    assert_eq!(
+        opportunities,
+        vec![
+            (2, BreakOpportunity::Allowed),
+            (4, BreakOpportunity::Allowed),
+            (6, BreakOpportunity::Mandatory),
+            (7, BreakOpportunity::Mandatory),
+        ]
+    );
+}
+
+#[test]
+fn test_linebreak_line_break() {
+    assert_eq!(line_break(0x61), LineBreak::Alphabetic);
+    assert_eq!(line_break(0x20), LineBreak::Space);
+    assert_eq!(line_break(0x0a), LineBreak::LineFeed);
+    assert_eq!(line_break(0x2d), LineBreak::Hyphen);
+    assert_eq!(line_break(0x28), LineBreak::OpenPunctuation);
+    assert_eq!(line_break(0x35), LineBreak::Numeric);
+    assert_eq!(line_break(0x3002), LineBreak::ClosePunctuation);
+    assert_eq!(line_break(0x6f22), LineBreak::Ideographic);
+    assert_eq!(line_break(0x3063), LineBreak::ConditionalJapaneseStarter);
+    assert_eq!(line_break(0xac00), LineBreak::H2);
+    assert_eq!(line_break(0xe01), LineBreak::ComplexContext);
+    assert_eq!(line_break(0x1f1ee), LineBreak::RegionalIndicator);
+    assert_eq!(line_break(0x1f44d), LineBreak::EBase);
+    assert_eq!(line_break(0xe0000), LineBreak::Unknown);
+}
+
+#[test]
+fn test_linebreak_wrap_1() {
+    assert_eq!(
+        wrap_str("The quick brown fox jumps over the lazy dog", 10),
+        vec!["The quick", "brown fox", "jumps over", "the lazy", "dog"]
+    );
+    assert_eq!(wrap_str("", 10), Vec::<String>::new());
+}
+
+#[test]
+fn test_linebreak_wrap_2() {
+    // Mandatory breaks always end a line, and empty lines are kept
+    assert_eq!(
+        wrap_str("first line\n\nsecond line", 20),
+        vec!["first line", "", "second line"]
+    );
+}
+
+#[test]
+fn test_linebreak_wrap_3() {
+    // Words longer than the width are broken between grapheme clusters
+    assert_eq!(
+        wrap_str("abcdefghij xy", 4),
+        vec!["abcd", "efgh", "ij", "xy"]
+    );
+    assert_eq!(
+        wrap_str("e\u{301}e\u{301}e\u{301}", 2),
+        vec!["e\u{301}e\u{301}", "e\u{301}"]
+    );
+}
+
+#[test]
+fn test_linebreak_wrap_4() {
+    // Wide characters take two columns
+    assert_eq!(
+        wrap_str("日本語のテキスト", 6),
+        vec!["日本語", "のテキ", "スト"]
+    );
+}
+
+#[test]
+#[ignore = "requires tests/data/LineBreakTest.txt, run `make test-data` to download it"]
+fn test_linebreak_conformance() {
+    let path: String = format!(
+        "{}/tests/data/LineBreakTest.txt",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let data: String = std::fs::read_to_string(path).unwrap();
+    for line in data.lines() {
+        let line: &str = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (code_points, boundaries) = parse_break_test(line);
+        let found: Vec<usize> = line_breaks(&code_points).map(|(i, _)| i).collect();
+        assert_eq!(found, boundaries, "{}", line);
+        // The same opportunities in UTF-8 and UTF-16, counted in code points (the surrogates cannot be encoded)
+        if code_points.iter().any(|cp| (0xd800..=0xdfff).contains(cp)) {
+            continue;
+        }
+        let utf8_cp: Vec<u8> = encode_in_utf8(&code_points);
+        let utf8_found: Vec<usize> = line_breaks_utf8(&utf8_cp)
+            .map(|(i, _)| decode_from_utf8(utf8_cp[..i].to_vec()).len())
+            .collect();
+        assert_eq!(utf8_found, boundaries, "UTF-8 {}", line);
+        let utf16_cp: Vec<u16> = encode_in_utf16(&code_points);
+        let utf16_found: Vec<usize> = line_breaks_utf16(&utf16_cp)
+            .map(|(i, _)| decode_from_utf16(utf16_cp[..i].to_vec()).len())
+            .collect();
+        assert_eq!(utf16_found, boundaries, "UTF-16 {}", line);
+    }
+}
@@ -0,0 +1,172 @@
+use ende::prelude::*;
+
+#[test]
+fn test_mime_decode_encoded_word_b() {
+    let v: Vec<u32> = decode_encoded_word("=?UTF-8?B?8JCNiA==?=").unwrap();
+    assert_eq!(v, vec![0x10348]);
+}
+
+#[test]
+fn test_mime_decode_encoded_word_q() {
+    let v: Vec<u32> = decode_encoded_word("=?iso-8859-1?q?Andr=E9_Pirard?=").unwrap();
+    assert_eq!(
+        v,
+        vec![0x41, 0x6e, 0x64, 0x72, 0xe9, 0x20, 0x50, 0x69, 0x72, 0x61, 0x72, 0x64]
+    );
+}
+
+#[test]
+fn test_mime_decode_encoded_word_utf16() {
+    let v: Vec<u32> = decode_encoded_word("=?UTF-16?B?//4A2EjfQQA=?=").unwrap();
+    assert_eq!(v, vec![0x10348, 0x41]);
+
+    let v: Vec<u32> = decode_encoded_word("=?UTF-16BE?Q?=D8=00=DC=01?=").unwrap();
+    assert_eq!(v, vec![0x10001]);
+
+    let v: Vec<u32> = decode_encoded_word("=?UCS-2?Q?=FF=EE?=").unwrap();
+    assert_eq!(v, vec![0xffee]);
+}
+
+#[test]
+fn test_mime_decode_encoded_word_language() {
+    let v: Vec<u32> = decode_encoded_word("=?US-ASCII*EN?Q?Keith_Moore?=").unwrap();
+    assert_eq!(
+        v,
+        vec![0x4b, 0x65, 0x69, 0x74, 0x68, 0x20, 0x4d, 0x6f, 0x6f, 0x72, 0x65]
+    );
+}
+
+#[test]
+fn test_mime_decode_encoded_word_errors() {
+    let kind = |word: &str| decode_encoded_word(word).unwrap_err().kind;
+    assert_eq!(
+        kind("=?UTF-8?B?w6k=?= "),
+        MimeErrorKind::MalformedEncodedWord
+    );
+    assert_eq!(kind("=?KOI8-R?B?w6k=?="), MimeErrorKind::UnsupportedCharset);
+    assert_eq!(kind("=?UTF-8?X?w6k=?="), MimeErrorKind::UnsupportedEncoding);
+    assert_eq!(kind("=?UTF-8?B?w6k*?="), MimeErrorKind::InvalidBase64);
+    assert_eq!(
+        kind("=?UTF-8?Q?=C3=A?="),
+        MimeErrorKind::InvalidQuotedPrintable
+    );
+    assert_eq!(kind("=?UTF-8?Q?=C3?="), MimeErrorKind::InvalidCharsetData);
+    assert_eq!(
+        kind("=?US-ASCII?Q?=E9?="),
+        MimeErrorKind::InvalidCharsetData
+    );
+    assert_eq!(
+        kind("=?UTF-16BE?Q?=D8=00?="),
+        MimeErrorKind::InvalidCharsetData
+    );
+}
+
+#[test]
+fn test_mime_decode_mime_header() {
+    // RFC 2047, section 8
+    let dec = |text: &str| decode_mime_header(text).unwrap();
+    assert_eq!(dec("(=?ISO-8859-1?Q?a?=)"), vec![0x28, 0x61, 0x29]);
+    assert_eq!(
+        dec("(=?ISO-8859-1?Q?a?= b)"),
+        vec![0x28, 0x61, 0x20, 0x62, 0x29]
+    );
+    assert_eq!(
+        dec("(=?ISO-8859-1?Q?a?= =?ISO-8859-1?Q?b?=)"),
+        vec![0x28, 0x61, 0x62, 0x29]
+    );
+    assert_eq!(
+        dec("(=?ISO-8859-1?Q?a?=\r\n    =?ISO-8859-1?Q?b?=)"),
+        vec![0x28, 0x61, 0x62, 0x29]
+    );
+    assert_eq!(
+        dec("(=?ISO-8859-1?Q?a_b?=)"),
+        vec![0x28, 0x61, 0x20, 0x62, 0x29]
+    );
+    assert_eq!(
+        dec("(=?ISO-8859-1?Q?a?= =?ISO-8859-1?Q?_b?=)"),
+        vec![0x28, 0x61, 0x20, 0x62, 0x29]
+    );
+}
+
+#[test]
+fn test_mime_decode_mime_header_error() {
+    let err: MimeError = decode_mime_header("Hi =?UTF-8?B?w6k*?=").unwrap_err();
+    assert_eq!(err.kind, MimeErrorKind::InvalidBase64);
+    assert_eq!(err.position, 3);
+}
+
+#[test]
+fn test_mime_encode_mime_header_b() {
+    let v: Vec<u32> = vec![0x10348, 0x41];
+    assert_eq!(
+        encode_mime_header(v, MimeEncoding::B),
+        "=?UTF-8?B?8JCNiEE=?="
+    );
+}
+
+#[test]
+fn test_mime_encode_mime_header_q() {
+    let v: Vec<u32> = vec![0x61, 0x20, 0x3f, 0x5f, 0x3d, 0x2b];
+    assert_eq!(
+        encode_mime_header(v, MimeEncoding::Q),
+        "=?UTF-8?Q?a_=3F=5F=3D+?="
+    );
+}
+
+#[test]
+fn test_mime_encode_mime_header_folding() {
+    let v: Vec<u32> = [0x61]
+        .repeat(30)
+        .into_iter()
+        .chain([0x10348].repeat(20))
+        .collect();
+    for encoding in [MimeEncoding::B, MimeEncoding::Q] {
+        let enc: String = encode_mime_header(&v, encoding);
+        let words: Vec<&str> = enc.split("\r\n ").collect();
+        assert!(words.len() > 1);
+        for word in &words {
+            assert!(word.len() <= 75);
+            // Every encoded-word contains complete UTF-8 code points
+            assert!(decode_encoded_word(word).is_ok());
+        }
+        assert_eq!(decode_mime_header(&enc).unwrap(), v);
+    }
+}
+
+#[test]
+fn test_mime_encode_quoted_printable() {
+    let v: Vec<u32> = vec![0x61, 0x09, 0x0d, 0x0a, 0x3d, 0xe9, 0x20];
+    assert_eq!(encode_quoted_printable(v), "a=09\r\n=3D=C3=A9=20");
+}
+
+#[test]
+fn test_mime_encode_quoted_printable_soft_line_breaks() {
+    let v: Vec<u32> = [0x3d]
+        .repeat(30)
+        .into_iter()
+        .chain([0x61].repeat(100))
+        .collect();
+    let enc: String = encode_quoted_printable(&v);
+    for line in enc.split("\r\n") {
+        assert!(line.len() <= 76);
+    }
+    assert!(enc.starts_with(&format!("{}=\r\n", "=3D".repeat(25))));
+    assert_eq!(decode_quoted_printable(&enc, "UTF-8").unwrap(), v);
+}
+
+#[test]
+fn test_mime_decode_quoted_printable() {
+    let v: Vec<u32> = decode_quoted_printable("a=09  \r\n=3d=E9=\n b", "ISO-8859-1").unwrap();
+    assert_eq!(v, vec![0x61, 0x09, 0x0d, 0x0a, 0x3d, 0xe9, 0x20, 0x62]);
+}
+
+#[test]
+fn test_mime_decode_quoted_printable_errors() {
+    let err: MimeError = decode_quoted_printable("ab\r\nc=4", "UTF-8").unwrap_err();
+    assert_eq!(err.kind, MimeErrorKind::InvalidQuotedPrintable);
+    assert_eq!(err.position, 5);
+
+    let err: MimeError = decode_quoted_printable("ab\r\nc=C3=28", "UTF-8").unwrap_err();
+    assert_eq!(err.kind, MimeErrorKind::InvalidCharsetData);
+    assert_eq!(err.position, 5);
+}
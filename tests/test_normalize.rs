@@ -0,0 +1,256 @@
+use ende::prelude::*;
+
+#[test]
+fn test_normalize_nfd() {
+    let v: Vec<u32> = vec![0xe9, 0x1e09, 0x212b]; // "éḉÅ"
+    let norm: Vec<u32> = nfd(&v);
+    assert_eq!(norm, vec![0x65, 0x301, 0x63, 0x327, 0x301, 0x41, 0x30a]);
+}
+
+#[test]
+fn test_normalize_nfc() {
+    let v: Vec<u32> = vec![0x65, 0x301, 0x63, 0x327, 0x301, 0x212b]; // "éḉÅ" decomposed
+    let norm: Vec<u32> = nfc(&v);
+    assert_eq!(norm, vec![0xe9, 0x1e09, 0xc5]);
+}
+
+#[test]
+fn test_normalize_nfkd() {
+    let v: Vec<u32> = vec![0xfb01, 0x2075, 0x1e9b, 0x323]; // "ﬁ⁵ẛ̣"
+    let norm: Vec<u32> = nfkd(&v);
+    assert_eq!(norm, vec![0x66, 0x69, 0x35, 0x73, 0x323, 0x307]);
+}
+
+#[test]
+fn test_normalize_nfkc() {
+    let v: Vec<u32> = vec![0xfb01, 0x2075, 0x1e9b, 0x323]; // "ﬁ⁵ẛ̣"
+    let norm: Vec<u32> = nfkc(&v);
+    assert_eq!(norm, vec![0x66, 0x69, 0x35, 0x1e69]);
+}
+
+#[test]
+fn test_normalize_canonical_ordering() {
+    // Dot below (220) is ordered before dot above (230), acute and grave (230) keep their order
+    let v: Vec<u32> = vec![0x61, 0x307, 0x323, 0x301, 0x300];
+    assert_eq!(nfd(&v), vec![0x61, 0x323, 0x307, 0x301, 0x300]);
+    assert_eq!(nfc(&v), vec![0x1ea1, 0x307, 0x301, 0x300]);
+}
+
+#[test]
+fn test_normalize_blocked() {
+    // The second acute is blocked by the first one
+    let v: Vec<u32> = vec![0x61, 0x301, 0x301];
+    assert_eq!(nfc(&v), vec![0xe1, 0x301]);
+    // A starter between the base and the mark blocks the composition
+    let v: Vec<u32> = vec![0x61, 0x63, 0x301];
+    assert_eq!(nfc(&v), vec![0x61, 0x107]);
+    // A non-starter at the beginning is kept
+    let v: Vec<u32> = vec![0x301, 0x65];
+    assert_eq!(nfc(&v), vec![0x301, 0x65]);
+}
+
+#[test]
+fn test_normalize_exclusions() {
+    // Singletons and composition exclusions are never composed
+    assert_eq!(nfc(vec![0x2126]), vec![0x3a9]);
+    assert_eq!(nfc(vec![0x958]), vec![0x915, 0x93c]);
+    assert_eq!(nfc(vec![0x344]), vec![0x308, 0x301]);
+}
+
+#[test]
+fn test_normalize_hangul() {
+    let v: Vec<u32> = vec![0xd4db, 0xac00]; // "퓛가"
+    let dec: Vec<u32> = nfd(&v);
+    assert_eq!(dec, vec![0x1111, 0x1171, 0x11b6, 0x1100, 0x1161]);
+    assert_eq!(nfc(&dec), v);
+    // LV syllable followed by a trailing consonant
+    assert_eq!(nfc(vec![0xac00, 0x11a8]), vec![0xac01]);
+    // Not a trailing consonant
+    assert_eq!(nfc(vec![0xac00, 0x11a7]), vec![0xac00, 0x11a7]);
+}
+
+#[test]
+fn test_normalize_quick_check() {
+    assert_eq!(
+        quick_check(vec![0x61, 0xe9], NormalizationForm::Nfc),
+        IsNormalized::Yes
+    );
+    assert_eq!(
+        quick_check(vec![0x61, 0xe9], NormalizationForm::Nfd),
+        IsNormalized::No
+    );
+    assert_eq!(
+        quick_check(vec![0x61, 0x301], NormalizationForm::Nfc),
+        IsNormalized::Maybe
+    );
+    assert_eq!(
+        quick_check(vec![0x61, 0x301, 0x323], NormalizationForm::Nfd),
+        IsNormalized::No
+    );
+    assert_eq!(
+        quick_check(vec![0xfb01], NormalizationForm::Nfkc),
+        IsNormalized::No
+    );
+}
+
+#[test]
+fn test_normalize_is_normalized() {
+    assert!(is_nfc(vec![0x61, 0xe9]));
+    assert!(is_nfc(vec![0x62, 0x301]));
+    assert!(!is_nfc(vec![0x61, 0x301]));
+    assert!(is_nfd(vec![0x61, 0x301]));
+    assert!(!is_nfd(vec![0x61, 0x301, 0x323]));
+    assert!(is_nfkc(vec![0x66, 0x69]));
+    assert!(!is_nfkd(vec![0x2075]));
+    assert!(is_nfc(Vec::<u32>::new()));
+}
+
+#[test]
+fn test_normalize_canonical_combining_class() {
+    assert_eq!(canonical_combining_class(0x41), 0);
+    assert_eq!(canonical_combining_class(0x301), 230);
+    assert_eq!(canonical_combining_class(0x323), 220);
+    assert_eq!(canonical_combining_class(0x334), 1);
+    assert_eq!(canonical_combining_class(0x1d16d), 226);
+}
+
+/// Parse a field of NormalizationTest.txt.
+fn parse_field(field: &str) -> Vec<u32> {
+    field
+        .split_whitespace()
+        .map(|cp| u32::from_str_radix(cp, 16).unwrap())
+        .collect()
+}
+
+#[test]
+#[ignore = "requires tests/data/NormalizationTest.txt, run `make test-data` to download it"]
+fn test_normalize_conformance() {
+    let path: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/data/NormalizationTest.txt"
+    );
+    let data: String = std::fs::read_to_string(path).unwrap();
+    let mut part1: Vec<u32> = Vec::new();
+    let mut in_part1: bool = false;
+    for line in data.lines() {
+        let line: &str = line.split('#').next().unwrap().trim();
+        if line.starts_with('@') {
+            in_part1 = line.starts_with("@Part1");
+            continue;
+        }
+        if line.is_empty() {
+            continue;
+        }
+        let c: Vec<Vec<u32>> = line.split(';').take(5).map(parse_field).collect();
+        // c2 == NFC(c1) == NFC(c2) == NFC(c3), c4 == NFC(c4) == NFC(c5)
+        for (source, expected) in [(0, 1), (1, 1), (2, 1), (3, 3), (4, 3)] {
+            assert_eq!(nfc(&c[source]), c[expected], "NFC {}", line);
+        }
+        // c3 == NFD(c1) == NFD(c2) == NFD(c3), c5 == NFD(c4) == NFD(c5)
+        for (source, expected) in [(0, 2), (1, 2), (2, 2), (3, 4), (4, 4)] {
+            assert_eq!(nfd(&c[source]), c[expected], "NFD {}", line);
+        }
+        // c4 == NFKC(c1..c5), c5 == NFKD(c1..c5)
+        for source in 0..5 {
+            assert_eq!(nfkc(&c[source]), c[3], "NFKC {}", line);
+            assert_eq!(nfkd(&c[source]), c[4], "NFKD {}", line);
+        }
+        assert!(is_nfc(&c[1]) && is_nfd(&c[2]) && is_nfkc(&c[3]) && is_nfkd(&c[4]));
+        if in_part1 {
+            part1.push(c[0][0]);
+        }
+    }
+    // Every other code point is not changed by the normalization
+    part1.sort_unstable();
+    for cp in (0..0x110000).filter(|cp| !(0xd800..=0xdfff).contains(cp)) {
+        if part1.binary_search(&cp).is_err() {
+            let v: Vec<u32> = vec![cp];
+            assert_eq!(nfc(&v), v);
+            assert_eq!(nfd(&v), v);
+            assert_eq!(nfkc(&v), v);
+            assert_eq!(nfkd(&v), v);
+        }
+    }
+}
+
+#[test]
+fn test_normalize_normalize_iter_1() {
+    let v: Vec<u32> = vec![0x65, 0x301, 0x63, 0x327, 0x301, 0x212b, 0xfb01];
+    for form in [
+        NormalizationForm::Nfc,
+        NormalizationForm::Nfd,
+        NormalizationForm::Nfkc,
+        NormalizationForm::Nfkd,
+    ] {
+        let norm: Vec<u32> = normalize_iter(v.clone(), form).collect();
+        assert_eq!(norm, normalize(&v, form));
+    }
+}
+
+#[test]
+fn test_normalize_normalize_iter_2() {
+    // Starters composed with the previous starter (Hangul jamos, Oriya vowel signs)
+    let v: Vec<u32> = vec![0x1100, 0x1161, 0x11a8, 0xb47, 0xb3e, 0x41];
+    let norm: Vec<u32> = normalize_iter(v, NormalizationForm::Nfc).collect();
+    assert_eq!(norm, vec![0xac01, 0xb4b, 0x41]);
+}
+
+#[test]
+fn test_normalize_normalize_iter_3() {
+    // A code point decomposed in several segments
+    let v: Vec<u32> = vec![0x3300, 0x301];
+    let norm: Vec<u32> = normalize_iter(v.clone(), NormalizationForm::Nfkc).collect();
+    assert_eq!(norm, nfkc(&v));
+    assert!(normalize_iter(Vec::new(), NormalizationForm::Nfc)
+        .next()
+        .is_none());
+}
+
+#[test]
+fn test_normalize_normalize_iter_stream_safe() {
+    // A COMBINING GRAPHEME JOINER is inserted after 30 non-starters
+    let mut v: Vec<u32> = vec![0x61];
+    v.extend([0x301; 40]);
+    let norm: Vec<u32> = normalize_iter(v, NormalizationForm::Nfc).collect();
+    let mut expected: Vec<u32> = vec![0xe1];
+    expected.extend([0x301; 29]);
+    expected.push(0x34f);
+    expected.extend([0x301; 10]);
+    assert_eq!(norm, expected);
+}
+
+#[test]
+fn test_normalize_normalize_iter_unbounded() {
+    // The buffer is bounded, so an endless sequence of non-starters is normalized lazily
+    let iter = std::iter::once(0x61).chain(std::iter::repeat(0x301));
+    let norm: Vec<u32> = normalize_iter(iter, NormalizationForm::Nfd)
+        .take(100)
+        .collect();
+    assert_eq!(norm.len(), 100);
+    assert_eq!(norm[0], 0x61);
+    assert_eq!(norm[31], 0x34f);
+}
+
+#[test]
+fn test_normalize_normalize_iter_unbounded_starters() {
+    // Starters composing with the previous starter are not boundaries, but only the last one is buffered
+    for starter in [0x1161, 0xb3e] {
+        let read: std::cell::Cell<usize> = std::cell::Cell::new(0);
+        let iter = std::iter::repeat(starter).inspect(|_| read.set(read.get() + 1));
+        let mut norm = normalize_iter(iter, NormalizationForm::Nfc);
+        assert_eq!(norm.next(), Some(starter));
+        assert!(read.get() <= 2);
+        assert_eq!(norm.take(1000).count(), 1000);
+        assert!(read.get() <= 1002);
+    }
+}
+
+#[test]
+fn test_normalize_normalize_iter_utf8() {
+    let v: Vec<u8> = vec![0x41, 0xcc, 0x8a, 0x20, 0xe1, 0x84, 0x80, 0xe1, 0x85, 0xa1]; // "A\u{30a} 가"
+    let norm: Vec<u32> = normalize_iter(decode_from_utf8(&v), NormalizationForm::Nfc).collect();
+    assert_eq!(
+        encode_in_utf8(norm),
+        vec![0xc3, 0x85, 0x20, 0xea, 0xb0, 0x80]
+    );
+}
@@ -0,0 +1,101 @@
+use ende::prelude::*;
+
+#[test]
+fn test_percent_percent_encode_path() {
+    let v: Vec<u32> = vec![0x2f, 0x61, 0x20, 0x62, 0x3f, 0x40, 0x7e, 0x10348]; // "/a b?@~𐍈"
+    let enc: String = percent_encode(&v, PercentEncodeSet::Path);
+    assert_eq!(enc, "/a%20b%3F@~%F0%90%8D%88");
+}
+
+#[test]
+fn test_percent_percent_encode_query() {
+    let v: Vec<u32> = vec![0x61, 0x3d, 0x31, 0x26, 0x62, 0x3f, 0x23]; // "a=1&b?#"
+    let enc: String = percent_encode(&v, PercentEncodeSet::Query);
+    assert_eq!(enc, "a=1&b?%23");
+}
+
+#[test]
+fn test_percent_percent_encode_fragment() {
+    let v: Vec<u32> = vec![0x2f, 0x3f, 0x23, 0x5b]; // "/?#["
+    let enc: String = percent_encode(&v, PercentEncodeSet::Fragment);
+    assert_eq!(enc, "/?%23%5B");
+}
+
+#[test]
+fn test_percent_percent_encode_userinfo() {
+    let v: Vec<u32> = vec![0x75, 0x3a, 0x70, 0x40, 0x2f]; // "u:p@/"
+    let enc: String = percent_encode(&v, PercentEncodeSet::Userinfo);
+    assert_eq!(enc, "u:p%40%2F");
+}
+
+#[test]
+fn test_percent_percent_encode_form() {
+    let v: Vec<u32> = vec![0x61, 0x20, 0x2b, 0x7e, 0x2a, 0xe9]; // "a +~*é"
+    let enc: String = percent_encode(&v, PercentEncodeSet::Form);
+    assert_eq!(enc, "a+%2B%7E*%C3%A9");
+}
+
+#[test]
+fn test_percent_percent_decode_1() {
+    let v: Vec<u32> = percent_decode("/a%20b%3f@~%F0%90%8D%88+").unwrap();
+    assert_eq!(
+        v,
+        vec![0x2f, 0x61, 0x20, 0x62, 0x3f, 0x40, 0x7e, 0x10348, 0x2b]
+    );
+}
+
+#[test]
+fn test_percent_percent_decode_non_ascii() {
+    let v: Vec<u32> = percent_decode("é%C3%A9").unwrap();
+    assert_eq!(v, vec![0xe9, 0xe9]);
+}
+
+#[test]
+fn test_percent_percent_decode_form() {
+    let v: Vec<u32> = percent_decode_form("a+%2B%7E*%C3%A9").unwrap();
+    assert_eq!(v, vec![0x61, 0x20, 0x2b, 0x7e, 0x2a, 0xe9]);
+}
+
+#[test]
+fn test_percent_percent_decode_invalid_escape() {
+    let err: PercentDecodeError = percent_decode("ab%2").unwrap_err();
+    assert_eq!(err.kind, PercentDecodeErrorKind::InvalidEscape);
+    assert_eq!(err.position, 2);
+
+    let err: PercentDecodeError = percent_decode("%zz").unwrap_err();
+    assert_eq!(err.kind, PercentDecodeErrorKind::InvalidEscape);
+    assert_eq!(err.position, 0);
+}
+
+#[test]
+fn test_percent_percent_decode_invalid_utf8() {
+    // Truncated two bytes code point
+    let err: PercentDecodeError = percent_decode("abc%C3").unwrap_err();
+    assert_eq!(err.kind, PercentDecodeErrorKind::InvalidUtf8);
+    assert_eq!(err.position, 3);
+
+    // Overlong encoding of "/"
+    let err: PercentDecodeError = percent_decode("a/%C0%AF").unwrap_err();
+    assert_eq!(err.kind, PercentDecodeErrorKind::InvalidUtf8);
+    assert_eq!(err.position, 2);
+
+    // Surrogate
+    let err: PercentDecodeError = percent_decode("é%ED%A0%80").unwrap_err();
+    assert_eq!(err.kind, PercentDecodeErrorKind::InvalidUtf8);
+    assert_eq!(err.position, 2);
+}
+
+#[test]
+fn test_percent_roundtrip() {
+    let v: Vec<u32> = vec![0x00, 0x20, 0x25, 0x2b, 0x7f, 0xe9, 0xffff, 0x10ffff];
+    for set in [
+        PercentEncodeSet::Path,
+        PercentEncodeSet::Query,
+        PercentEncodeSet::Fragment,
+        PercentEncodeSet::Userinfo,
+    ] {
+        assert_eq!(percent_decode(&percent_encode(&v, set)).unwrap(), v);
+    }
+    let enc: String = percent_encode(&v, PercentEncodeSet::Form);
+    assert_eq!(percent_decode_form(&enc).unwrap(), v);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::code_points;
+use ende::prelude::*;
+
+#[test]
+fn test_security_is_identifier_allowed_1() {
+    assert!(is_identifier_allowed(0x41));
+    assert!(is_identifier_allowed(0x5f)); // _
+    assert!(is_identifier_allowed(0xe9)); // é
+    assert!(is_identifier_allowed(0x4e00));
+    assert!(!is_identifier_allowed(0x20));
+    assert!(!is_identifier_allowed(0x2160)); // Ⅰ
+    assert!(!is_identifier_allowed(0xff21)); // Ａ
+    assert!(!is_identifier_allowed(0x1d400)); // 𝐀
+    assert!(!is_identifier_allowed(0x110000));
+}
+
+#[test]
+fn test_security_skeleton_1() {
+    assert_eq!(skeleton(&code_points("m")), code_points("rn"));
+    assert_eq!(skeleton(&code_points("0")), code_points("O"));
+    assert_eq!(skeleton(&code_points("\u{1c9}")), code_points("lj"));
+    assert_eq!(
+        skeleton(&code_points("\u{430}\u{435}\u{43e}")),
+        code_points("aeo")
+    );
+    // The skeleton is normalized
+    assert_eq!(
+        skeleton(&code_points("\u{e9}")),
+        skeleton(&code_points("e\u{301}"))
+    );
+    assert_eq!(skeleton(&[]), Vec::<u32>::new());
+    // A part of a buffer
+    let v: Vec<u32> = code_points(" m ");
+    assert_eq!(skeleton(trim(&v)), code_points("rn"));
+}
+
+#[test]
+fn test_security_is_confusable_1() {
+    let confusable = |a: &str, b: &str| is_confusable(&code_points(a), &code_points(b));
+    assert!(confusable("paypal", "p\u{430}ypal"));
+    assert!(confusable("paypal", "paypa1"));
+    assert!(confusable("scope", "ѕсоре"));
+    assert!(confusable("ＡＢＣ", "ABC"));
+    assert!(confusable("𝐇𝐞𝐥𝐥𝐨", "Hello"));
+    assert!(!confusable("paypal", "paypai"));
+    assert!(!confusable("hello", "world"));
+}
+
+#[test]
+fn test_security_restriction_level_1() {
+    let level = |s: &str| restriction_level(&code_points(s));
+    assert_eq!(level(""), RestrictionLevel::AsciiOnly);
+    assert_eq!(level("user_42"), RestrictionLevel::AsciiOnly);
+    assert_eq!(level("café"), RestrictionLevel::SingleScript);
+    assert_eq!(level("Ελληνικά"), RestrictionLevel::SingleScript);
+    assert_eq!(
+        level("\u{410}\u{440}\u{440}\u{4cf}\u{435}"),
+        RestrictionLevel::SingleScript
+    );
+    assert_eq!(level("日本語のテキスト"), RestrictionLevel::SingleScript);
+    assert_eq!(level("hello world"), RestrictionLevel::Unrestricted);
+    assert_eq!(level("ok😀"), RestrictionLevel::Unrestricted);
+}
+
+#[test]
+fn test_security_restriction_level_2() {
+    let level = |s: &str| restriction_level(&code_points(s));
+    // Latin with Japanese, Chinese or Korean
+    assert_eq!(
+        level("Tokyoの東京タワー"),
+        RestrictionLevel::HighlyRestrictive
+    );
+    assert_eq!(level("abcㄅㄆ中文"), RestrictionLevel::HighlyRestrictive);
+    assert_eq!(level("abc한국어漢字"), RestrictionLevel::HighlyRestrictive);
+    // Latin with another recommended script
+    assert_eq!(level("abcअनुच्छेद"), RestrictionLevel::ModeratelyRestrictive);
+    assert_eq!(level("abcשלום"), RestrictionLevel::ModeratelyRestrictive);
+    // Latin with Cyrillic or Greek, and the other mixes
+    assert_eq!(
+        level("p\u{430}ypal"),
+        RestrictionLevel::MinimallyRestrictive
+    );
+    assert_eq!(level("Teχ"), RestrictionLevel::MinimallyRestrictive);
+    assert_eq!(level("abcअनुשלום"), RestrictionLevel::MinimallyRestrictive);
+    assert_eq!(
+        level("ひらがな한국어"),
+        RestrictionLevel::MinimallyRestrictive
+    );
+    assert!(level("Teχ") > RestrictionLevel::ModeratelyRestrictive);
+}
@@ -0,0 +1,300 @@
+mod common;
+
+use common::parse_break_test;
+use ende::prelude::*;
+use std::ops::Range;
+
+#[test]
+fn test_segment_graphemes_combining_marks() {
+    let v: Vec<u32> = vec![0x65, 0x301, 0x323, 0x61, 0x915, 0x94d, 0x937, 0x93f]; // "ệaक्षि"
+    let clusters: Vec<Range<usize>> = graphemes(&v).collect();
+    assert_eq!(clusters, vec![0..3, 3..4, 4..6, 6..8]);
+    // A combining mark at the beginning is a cluster on its own
+    let v: Vec<u32> = vec![0x301, 0x61];
+    assert_eq!(graphemes(&v).collect::<Vec<_>>(), vec![0..1, 1..2]);
+}
+
+#[test]
+fn test_segment_graphemes_controls() {
+    let v: Vec<u32> = vec![0x61, 0x0d, 0x0a, 0x0a, 0x0d, 0x301]; // "a\r\n\n\r\u{301}"
+    let clusters: Vec<Range<usize>> = graphemes(&v).collect();
+    assert_eq!(clusters, vec![0..1, 1..3, 3..4, 4..5, 5..6]);
+}
+
+#[test]
+fn test_segment_graphemes_hangul() {
+    // Jamos, a LV syllable followed by a trailing consonant, and a LVT syllable
+    let v: Vec<u32> = vec![0x1100, 0x1161, 0x11a8, 0xac00, 0x11a8, 0xac01, 0x1161];
+    let clusters: Vec<Range<usize>> = graphemes(&v).collect();
+    assert_eq!(clusters, vec![0..3, 3..5, 5..6, 6..7]);
+}
+
+#[test]
+fn test_segment_graphemes_regional_indicators() {
+    // Three flags and a lone regional indicator: 🇮🇹🇫🇷🇯🇵🇺
+    let v: Vec<u32> = vec![
+        0x1f1ee, 0x1f1f9, 0x1f1eb, 0x1f1f7, 0x1f1ef, 0x1f1f5, 0x1f1fa,
+    ];
+    let clusters: Vec<Range<usize>> = graphemes(&v).collect();
+    assert_eq!(clusters, vec![0..2, 2..4, 4..6, 6..7]);
+}
+
+#[test]
+fn test_segment_graphemes_emoji() {
+    // 👩‍❤️‍👨 (ZWJ sequence with a variation selector), 👍🏽 (skin tone), a‍👩 (no ZWJ sequence)
+    let v: Vec<u32> = vec![
+        0x1f469, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f44d, 0x1f3fd, 0x61, 0x200d, 0x1f469,
+    ];
+    let clusters: Vec<Range<usize>> = graphemes(&v).collect();
+    assert_eq!(clusters, vec![0..6, 6..8, 8..10, 10..11]);
+}
+
+#[test]
+fn test_segment_graphemes_prepend_spacing_mark() {
+    // Prepend (U+0600) and spacing mark (U+0903)
+    let v: Vec<u32> = vec![0x600, 0x661, 0x915, 0x903, 0x600];
+    let clusters: Vec<Range<usize>> = graphemes(&v).collect();
+    assert_eq!(clusters, vec![0..2, 2..4, 4..5]);
+}
+
+#[test]
+fn test_segment_graphemes_utf8() {
+    let s: &str = "e\u{301}🇮🇹👨‍👩‍👧‍👦!";
+    let clusters: Vec<&str> = graphemes_utf8(s.as_bytes()).map(|r| &s[r]).collect();
+    assert_eq!(clusters, vec!["e\u{301}", "🇮🇹", "👨‍👩‍👧‍👦", "!"]);
+    assert_eq!(graphemes_utf8(&[]).next(), None);
+}
+
+#[test]
+fn test_segment_graphemes_utf16() {
+    let s: &str = "e\u{301}🇮🇹👨‍👩‍👧‍👦!";
+    let v: Vec<u16> = s.encode_utf16().collect();
+    let clusters: Vec<Range<usize>> = graphemes_utf16(&v).collect();
+    assert_eq!(clusters, vec![0..2, 2..6, 6..17, 17..18]);
+}
+
+#[test]
+fn test_segment_grapheme_cluster_break() {
+    assert_eq!(grapheme_cluster_break(0x0d), GraphemeClusterBreak::Cr);
+    assert_eq!(grapheme_cluster_break(0x200d), GraphemeClusterBreak::Zwj);
+    assert_eq!(grapheme_cluster_break(0xac01), GraphemeClusterBreak::Lvt);
+    assert_eq!(
+        grapheme_cluster_break(0x1f3fd),
+        GraphemeClusterBreak::Extend
+    );
+    assert_eq!(grapheme_cluster_break(0x1f600), GraphemeClusterBreak::Other);
+}
+
+#[test]
+fn test_segment_words_1() {
+    let s: &str = "The quick (\"brown\") fox can't jump 32.3 feet, right?";
+    let segments: Vec<&str> = words_utf8(s.as_bytes()).map(|r| &s[r]).collect();
+    assert_eq!(
+        segments,
+        vec![
+            "The", " ", "quick", " ", "(", "\"", "brown", "\"", ")", " ", "fox", " ", "can't", " ",
+            "jump", " ", "32.3", " ", "feet", ",", " ", "right", "?"
+        ]
+    );
+}
+
+#[test]
+fn test_segment_words_2() {
+    // Letters and digits joined by connector punctuation, a trailing full stop is not part of the word
+    let s: &str = "foo_bar e.g. 1,000.5 a1b2";
+    let segments: Vec<&str> = words_utf8(s.as_bytes()).map(|r| &s[r]).collect();
+    assert_eq!(
+        segments,
+        vec!["foo_bar", " ", "e.g", ".", " ", "1,000.5", " ", "a1b2"]
+    );
+    // Horizontal spaces are kept together, line breaks are not
+    let s: &str = "a  \u{3000}b\r\n\nc";
+    let segments: Vec<&str> = words_utf8(s.as_bytes()).map(|r| &s[r]).collect();
+    assert_eq!(segments, vec!["a", "  \u{3000}", "b", "\r\n", "\n", "c"]);
+}
+
+#[test]
+fn test_segment_words_extend() {
+    // Combining marks, format characters and ZWJ are kept with the previous code point (WB4)
+    let v: Vec<u32> = vec![0x61, 0x301, 0xad, 0x62, 0x2e, 0x301, 0x63, 0x20, 0x301];
+    let segments: Vec<Range<usize>> = words(&v).collect();
+    assert_eq!(segments, vec![0..7, 7..9]);
+}
+
+#[test]
+fn test_segment_words_hebrew_katakana() {
+    // Hebrew letters with a gershayim (double quote) and a geresh (single quote)
+    let v: Vec<u32> = vec![0x5d0, 0x22, 0x5d1, 0x20, 0x5d2, 0x27];
+    let segments: Vec<Range<usize>> = words(&v).collect();
+    assert_eq!(segments, vec![0..3, 3..4, 4..6]);
+    // A run of Katakana, then Hiragana (no rule joins them)
+    let v: Vec<u32> = vec![0x30ab, 0x30bf, 0x30ab, 0x30ca, 0x3072, 0x3089];
+    let segments: Vec<Range<usize>> = words(&v).collect();
+    assert_eq!(segments, vec![0..4, 4..5, 5..6]);
+}
+
+#[test]
+fn test_segment_words_emoji() {
+    // Flags, and an emoji ZWJ sequence after a letter
+    let v: Vec<u32> = vec![
+        0x1f1ee, 0x1f1f9, 0x1f1eb, 0x1f1f7, 0x1f1fa, 0x61, 0x200d, 0x1f469,
+    ];
+    let segments: Vec<Range<usize>> = words(&v).collect();
+    assert_eq!(segments, vec![0..2, 2..4, 4..5, 5..8]);
+}
+
+#[test]
+fn test_segment_words_utf16() {
+    let s: &str = "ab 👍🏽 c.d";
+    let v: Vec<u16> = s.encode_utf16().collect();
+    let segments: Vec<Range<usize>> = words_utf16(&v).collect();
+    assert_eq!(segments, vec![0..2, 2..3, 3..7, 7..8, 8..11]);
+    assert_eq!(words_utf16(&[]).next(), None);
+}
+
+#[test]
+fn test_segment_word_break() {
+    assert_eq!(word_break(0x5d0), WordBreak::HebrewLetter);
+    assert_eq!(word_break(0x2e), WordBreak::MidNumLet);
+    assert_eq!(word_break(0x2c), WordBreak::MidNum);
+    assert_eq!(word_break(0x5f), WordBreak::ExtendNumLet);
+    assert_eq!(word_break(0x85), WordBreak::Newline);
+    assert_eq!(word_break(0x3000), WordBreak::WSegSpace);
+}
+
+#[test]
+fn test_segment_sentences_1() {
+    let s: &str = "Mr. Smith paid $3.50 (e.g. a coffee). \"Fine!\" He left.\nNew line";
+    let segments: Vec<&str> = sentences_utf8(s.as_bytes()).map(|r| &s[r]).collect();
+    assert_eq!(
+        segments,
+        vec![
+            "Mr. ",
+            "Smith paid $3.50 (e.g. a coffee). ",
+            "\"Fine!\" ",
+            "He left.\n",
+            "New line"
+        ]
+    );
+}
+
+#[test]
+fn test_segment_sentences_2() {
+    // Upper case abbreviations, terminators followed by a separator, closing punctuation after the spaces
+    let s: &str = "U.S.A. is big?! Yes.\u{2029}(No.) \"X";
+    let segments: Vec<&str> = sentences_utf8(s.as_bytes()).map(|r| &s[r]).collect();
+    assert_eq!(
+        segments,
+        vec!["U.S.A. is big?! ", "Yes.\u{2029}", "(No.) ", "\"X"]
+    );
+    // A full stop followed by a comma does not end the sentence
+    let s: &str = "etc., and so on. done";
+    let segments: Vec<&str> = sentences_utf8(s.as_bytes()).map(|r| &s[r]).collect();
+    assert_eq!(segments, vec!["etc., and so on. done"]);
+}
+
+#[test]
+fn test_segment_sentences_3() {
+    // A long sequence of spaces or closing punctuation after a full stop is scanned once (SB8)
+    for filler in [0x20, 0x29] {
+        let mut v: Vec<u32> = vec![0x78, 0x2e];
+        v.extend(vec![filler; 100_000]);
+        v.push(0x41);
+        assert_eq!(
+            sentences(&v).collect::<Vec<_>>(),
+            vec![0..100_002, 100_002..100_003]
+        );
+        *v.last_mut().unwrap() = 0x61;
+        assert_eq!(sentences(&v).collect::<Vec<_>>(), vec![0..100_003]);
+    }
+}
+
+#[test]
+fn test_segment_sentences_utf16() {
+    let s: &str = "Go! 👍 Yes.\r\nEnd";
+    let v: Vec<u16> = s.encode_utf16().collect();
+    let segments: Vec<Range<usize>> = sentences_utf16(&v).collect();
+    assert_eq!(segments, vec![0..4, 4..13, 13..16]);
+    let v: Vec<u32> = vec![0x2e, 0x2e];
+    assert_eq!(sentences(&v).collect::<Vec<_>>(), vec![0..2]);
+}
+
+#[test]
+fn test_segment_sentence_break() {
+    assert_eq!(sentence_break(0x2e), SentenceBreak::ATerm);
+    assert_eq!(sentence_break(0x3002), SentenceBreak::STerm);
+    assert_eq!(sentence_break(0x2c), SentenceBreak::SContinue);
+    assert_eq!(sentence_break(0x29), SentenceBreak::Close);
+    assert_eq!(sentence_break(0x41), SentenceBreak::Upper);
+    assert_eq!(sentence_break(0x5d0), SentenceBreak::OLetter);
+}
+
+/// Check the segments of every test of a break test file, in code points, UTF-8 and UTF-16.
+fn check_break_test(
+    file: &str,
+    segments: fn(&[u32]) -> Vec<Range<usize>>,
+    segments_utf8: fn(&[u8]) -> Vec<Range<usize>>,
+    segments_utf16: fn(&[u16]) -> Vec<Range<usize>>,
+) {
+    let path: String = format!("{}/tests/data/{}", env!("CARGO_MANIFEST_DIR"), file);
+    let data: String = std::fs::read_to_string(path).unwrap();
+    for line in data.lines() {
+        let line: &str = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (code_points, boundaries) = parse_break_test(line);
+        let mut found: Vec<usize> = vec![0];
+        found.extend(segments(&code_points).iter().map(|r| r.end));
+        assert_eq!(found, boundaries, "{}", line);
+        // The same boundaries in UTF-8 and UTF-16, counted in code points (the surrogates cannot be encoded)
+        if code_points.iter().any(|cp| (0xd800..=0xdfff).contains(cp)) {
+            continue;
+        }
+        let utf8_cp: Vec<u8> = encode_in_utf8(&code_points);
+        let utf8_ends: Vec<usize> = segments_utf8(&utf8_cp)
+            .iter()
+            .map(|r| decode_from_utf8(utf8_cp[..r.end].to_vec()).len())
+            .collect();
+        assert_eq!(utf8_ends, boundaries[1..], "UTF-8 {}", line);
+        let utf16_cp: Vec<u16> = encode_in_utf16(&code_points);
+        let utf16_ends: Vec<usize> = segments_utf16(&utf16_cp)
+            .iter()
+            .map(|r| decode_from_utf16(utf16_cp[..r.end].to_vec()).len())
+            .collect();
+        assert_eq!(utf16_ends, boundaries[1..], "UTF-16 {}", line);
+    }
+}
+
+#[test]
+#[ignore = "requires tests/data/GraphemeBreakTest.txt, run `make test-data` to download it"]
+fn test_segment_grapheme_conformance() {
+    check_break_test(
+        "GraphemeBreakTest.txt",
+        |v| graphemes(v).collect(),
+        |v| graphemes_utf8(v).collect(),
+        |v| graphemes_utf16(v).collect(),
+    );
+}
+
+#[test]
+#[ignore = "requires tests/data/WordBreakTest.txt, run `make test-data` to download it"]
+fn test_segment_word_conformance() {
+    check_break_test(
+        "WordBreakTest.txt",
+        |v| words(v).collect(),
+        |v| words_utf8(v).collect(),
+        |v| words_utf16(v).collect(),
+    );
+}
+
+#[test]
+#[ignore = "requires tests/data/SentenceBreakTest.txt, run `make test-data` to download it"]
+fn test_segment_sentence_conformance() {
+    check_break_test(
+        "SentenceBreakTest.txt",
+        |v| sentences(v).collect(),
+        |v| sentences_utf8(v).collect(),
+        |v| sentences_utf16(v).collect(),
+    );
+}
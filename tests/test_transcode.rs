@@ -0,0 +1,88 @@
+use ende::prelude::*;
+
+#[test]
+fn test_transcode_utf8_to_utf16_1() {
+    let v: Vec<u8> = vec![0x24, 0xd0, 0x98, 0xe2, 0x82, 0xac, 0xf0, 0x90, 0x8d, 0x88];
+    let enc: Vec<u16> = utf8_to_utf16(&v);
+    assert_eq!(enc, vec![0x24, 0x418, 0x20ac, 0xd800, 0xdf48]);
+    assert_eq!(enc.capacity(), enc.len());
+}
+
+#[test]
+fn test_transcode_utf16_to_utf8_1() {
+    let v: Vec<u16> = vec![0x24, 0x418, 0x20ac, 0xd800, 0xdf48];
+    let enc: Vec<u8> = utf16_to_utf8(&v);
+    assert_eq!(
+        enc,
+        vec![0x24, 0xd0, 0x98, 0xe2, 0x82, 0xac, 0xf0, 0x90, 0x8d, 0x88]
+    );
+    assert_eq!(enc.capacity(), enc.len());
+}
+
+#[test]
+fn test_transcode_roundtrip() {
+    let v: Vec<u32> = vec![
+        0x00, 0x7f, 0x80, 0x7ff, 0x800, 0xd7ff, 0xe000, 0xffff, 0x10000, 0x10ffff,
+    ];
+    let utf8_vec: Vec<u8> = encode_in_utf8(&v);
+    let utf16_vec: Vec<u16> = encode_in_utf16(&v);
+    assert_eq!(utf8_to_utf16(&utf8_vec), utf16_vec);
+    assert_eq!(utf16_to_utf8(&utf16_vec), utf8_vec);
+}
+
+#[test]
+fn test_transcode_utf8_to_ucs2_1() {
+    let v: Vec<u8> = vec![0x24, 0xd0, 0x98, 0xef, 0xbf, 0xae];
+    let enc: Vec<u16> = utf8_to_ucs2(&v);
+    assert_eq!(enc, vec![0x24, 0x418, 0xffee]);
+    assert_eq!(enc.capacity(), enc.len());
+}
+
+#[test]
+fn test_transcode_ucs2_to_utf8_1() {
+    let v: Vec<u16> = vec![0x24, 0x418, 0xffee];
+    let enc: Vec<u8> = ucs2_to_utf8(&v);
+    assert_eq!(enc, vec![0x24, 0xd0, 0x98, 0xef, 0xbf, 0xae]);
+}
+
+#[test]
+fn test_transcode_empty() {
+    assert_eq!(utf8_to_utf16(&[]), Vec::<u16>::new());
+    assert_eq!(utf16_to_utf8(&[]), Vec::<u8>::new());
+}
+
+#[test]
+#[should_panic]
+fn test_transcode_utf8_to_utf16_invalid_continuation_byte() {
+    utf8_to_utf16(&[0x41, 0xe2, 0x82]);
+}
+
+#[test]
+#[should_panic]
+fn test_transcode_utf8_to_utf16_surrogate() {
+    utf8_to_utf16(&[0xed, 0xa0, 0x80]);
+}
+
+#[test]
+#[should_panic]
+fn test_transcode_utf16_to_utf8_lone_high_surrogate() {
+    utf16_to_utf8(&[0xd800, 0x41]);
+}
+
+#[test]
+#[should_panic]
+fn test_transcode_utf16_to_utf8_lone_low_surrogate() {
+    utf16_to_utf8(&[0xdc00]);
+}
+
+#[test]
+#[should_panic]
+fn test_transcode_utf8_to_ucs2_supplementary() {
+    utf8_to_ucs2(&[0xf0, 0x90, 0x8d, 0x88]);
+}
+
+#[test]
+#[should_panic]
+fn test_transcode_ucs2_to_utf8_surrogate() {
+    ucs2_to_utf8(&[0xd800, 0xdf48]);
+}
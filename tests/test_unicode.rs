@@ -0,0 +1,509 @@
+mod common;
+
+use common::code_points;
+use ende::prelude::*;
+
+#[test]
+fn test_unicode_name_1() {
+    assert_eq!(name(0x20), Some(String::from("SPACE")));
+    assert_eq!(name(0x41), Some(String::from("LATIN CAPITAL LETTER A")));
+    assert_eq!(
+        name(0xe9),
+        Some(String::from("LATIN SMALL LETTER E WITH ACUTE"))
+    );
+    assert_eq!(name(0x20ac), Some(String::from("EURO SIGN")));
+    assert_eq!(name(0x1f600), Some(String::from("GRINNING FACE")));
+    assert_eq!(name(0xe01ef), Some(String::from("VARIATION SELECTOR-256")));
+    assert_eq!(name(0xf0f), Some(String::from("TIBETAN MARK TSHEG SHAD")));
+}
+
+#[test]
+fn test_unicode_name_2() {
+    // Names derived from the code point (NR2)
+    assert_eq!(
+        name(0x4e00),
+        Some(String::from("CJK UNIFIED IDEOGRAPH-4E00"))
+    );
+    assert_eq!(
+        name(0x2a700),
+        Some(String::from("CJK UNIFIED IDEOGRAPH-2A700"))
+    );
+    assert_eq!(
+        name(0xf900),
+        Some(String::from("CJK COMPATIBILITY IDEOGRAPH-F900"))
+    );
+    assert_eq!(name(0x17000), Some(String::from("TANGUT IDEOGRAPH-17000")));
+    assert_eq!(name(0x18d08), Some(String::from("TANGUT IDEOGRAPH-18D08")));
+    assert_eq!(name(0x1b170), Some(String::from("NUSHU CHARACTER-1B170")));
+    assert_eq!(
+        name(0x18b00),
+        Some(String::from("KHITAN SMALL SCRIPT CHARACTER-18B00"))
+    );
+}
+
+#[test]
+fn test_unicode_name_3() {
+    // Hangul syllables (NR1)
+    assert_eq!(name(0xac00), Some(String::from("HANGUL SYLLABLE GA")));
+    assert_eq!(name(0xd4db), Some(String::from("HANGUL SYLLABLE PWILH")));
+    assert_eq!(name(0xc544), Some(String::from("HANGUL SYLLABLE A")));
+    assert_eq!(name(0xd7a3), Some(String::from("HANGUL SYLLABLE HIH")));
+}
+
+#[test]
+fn test_unicode_name_4() {
+    assert_eq!(name(0x00), None);
+    assert_eq!(name(0x85), None);
+    assert_eq!(name(0x0378), None);
+    assert_eq!(name(0xd800), None);
+    assert_eq!(name(0xe000), None);
+    assert_eq!(name(0xfdd0), None);
+    assert_eq!(name(0xffff), None);
+    assert_eq!(name(0x110000), None);
+}
+
+#[test]
+fn test_unicode_general_category() {
+    assert_eq!(general_category(0x41), GeneralCategory::UppercaseLetter);
+    assert_eq!(general_category(0x61), GeneralCategory::LowercaseLetter);
+    assert_eq!(general_category(0x1c5), GeneralCategory::TitlecaseLetter);
+    assert_eq!(general_category(0x4e00), GeneralCategory::OtherLetter);
+    assert_eq!(general_category(0x301), GeneralCategory::NonspacingMark);
+    assert_eq!(general_category(0x39), GeneralCategory::DecimalNumber);
+    assert_eq!(general_category(0x2028), GeneralCategory::LineSeparator);
+    assert_eq!(general_category(0x0a), GeneralCategory::Control);
+    assert_eq!(general_category(0xad), GeneralCategory::Format);
+    assert_eq!(general_category(0xdc00), GeneralCategory::Surrogate);
+    assert_eq!(general_category(0x10fffd), GeneralCategory::PrivateUse);
+    assert_eq!(general_category(0xfffe), GeneralCategory::Unassigned);
+    assert_eq!(general_category(0x110000), GeneralCategory::Unassigned);
+    assert_eq!(general_category(0x2212).abbreviation(), "Sm");
+}
+
+#[test]
+fn test_unicode_age() {
+    assert_eq!(age(0x41), Some((1, 1)));
+    assert_eq!(age(0x20ac), Some((2, 1)));
+    assert_eq!(age(0x1f600), Some((6, 1)));
+    assert_eq!(age(0x1fae0), Some((14, 0)));
+    assert_eq!(age(0xffff), Some((1, 1)));
+    assert_eq!(age(0x0378), None);
+}
+
+#[test]
+fn test_unicode_block() {
+    assert_eq!(block(0x41), Some("Basic Latin"));
+    assert_eq!(block(0xe9), Some("Latin-1 Supplement"));
+    assert_eq!(block(0xac00), Some("Hangul Syllables"));
+    assert_eq!(block(0x10ffff), Some("Supplementary Private Use Area-B"));
+    assert_eq!(block(0x2fe0), None);
+}
+
+#[test]
+fn test_unicode_east_asian_width() {
+    assert_eq!(east_asian_width(0x41), EastAsianWidth::Narrow);
+    assert_eq!(east_asian_width(0xa1), EastAsianWidth::Ambiguous);
+    assert_eq!(east_asian_width(0x3042), EastAsianWidth::Wide);
+    assert_eq!(east_asian_width(0xff21), EastAsianWidth::Fullwidth);
+    assert_eq!(east_asian_width(0xff71), EastAsianWidth::Halfwidth);
+    assert_eq!(east_asian_width(0x1f600), EastAsianWidth::Wide);
+    assert_eq!(east_asian_width(0x3400), EastAsianWidth::Wide);
+    assert_eq!(east_asian_width(0x0), EastAsianWidth::Neutral);
+    assert_eq!(east_asian_width(0x5d0), EastAsianWidth::Neutral);
+}
+
+#[test]
+fn test_unicode_script() {
+    assert_eq!(script(0x41), Script::Latin);
+    assert_eq!(script(0x3b1), Script::Greek);
+    assert_eq!(script(0x5d0), Script::Hebrew);
+    assert_eq!(script(0x3042), Script::Hiragana);
+    assert_eq!(script(0x4e00), Script::Han);
+    assert_eq!(script(0x1f600), Script::Common);
+    assert_eq!(script(0x64b), Script::Inherited);
+    assert_eq!(script(0xe000), Script::Unknown);
+    assert_eq!(script(0x110000), Script::Unknown);
+    assert_eq!(Script::Han.abbreviation(), "Hani");
+    assert_eq!(Script::Unknown.abbreviation(), "Zzzz");
+    assert_eq!(Script::Japanese.abbreviation(), "Jpan");
+}
+
+#[test]
+fn test_unicode_script_extensions() {
+    assert_eq!(script_extensions(0x430), vec![Script::Cyrillic]);
+    assert_eq!(script_extensions(0x20), vec![Script::Common]);
+    assert_eq!(
+        script_extensions(0x64b),
+        vec![Script::Arabic, Script::Syriac]
+    );
+    assert_eq!(
+        script_extensions(0x3001),
+        vec![
+            Script::Bopomofo,
+            Script::Hangul,
+            Script::Han,
+            Script::Hiragana,
+            Script::Katakana,
+            Script::Yi
+        ]
+    );
+}
+
+#[test]
+fn test_unicode_resolved_script_set_1() {
+    let resolved = |s: &str| resolved_script_set(&code_points(s));
+    assert_eq!(resolved("abc123"), ScriptSet::Scripts(vec![Script::Latin]));
+    assert_eq!(
+        resolved("e\u{301}"),
+        ScriptSet::Scripts(vec![Script::Latin])
+    );
+    assert_eq!(
+        resolved("Ελληνικά"),
+        ScriptSet::Scripts(vec![Script::Greek])
+    );
+    // Only characters used with all the scripts
+    assert_eq!(resolved("123 !"), ScriptSet::All);
+    assert_eq!(resolved(""), ScriptSet::All);
+    // Latin and Cyrillic
+    assert!(resolved("p\u{430}ypal").is_empty());
+    assert!(resolved("\u{410}pple").is_empty());
+    assert!(!resolved("\u{410}\u{440}\u{440}\u{4cf}\u{435}").is_empty());
+}
+
+#[test]
+fn test_unicode_resolved_script_set_2() {
+    let resolved = |s: &str| resolved_script_set(&code_points(s));
+    // The Han characters are also Japanese and Korean
+    assert_eq!(
+        resolved("漢字"),
+        ScriptSet::Scripts(vec![
+            Script::Han,
+            Script::HanWithBopomofo,
+            Script::Japanese,
+            Script::Korean
+        ])
+    );
+    assert_eq!(
+        resolved("ひらがなとカタカナと漢字"),
+        ScriptSet::Scripts(vec![Script::Japanese])
+    );
+    assert_eq!(
+        resolved("한국어 漢字"),
+        ScriptSet::Scripts(vec![Script::Korean])
+    );
+    assert_eq!(
+        resolved("注音ㄅㄆㄇ"),
+        ScriptSet::Scripts(vec![Script::HanWithBopomofo])
+    );
+    assert!(resolved("カタカナ・한국어").is_empty());
+    // The script extensions
+    assert_eq!(
+        resolved("カ・カ"),
+        ScriptSet::Scripts(vec![Script::Katakana, Script::Japanese])
+    );
+    assert!(resolved("ab・c").is_empty());
+    assert!(ScriptSet::All.contains(Script::Latin));
+}
+
+#[test]
+fn test_unicode_display_width_1() {
+    let width = |s: &str| display_width(&code_points(s));
+    assert_eq!(width(""), 0);
+    assert_eq!(width("Hello, world!"), 13);
+    assert_eq!(width("こんにちは"), 10);
+    assert_eq!(width("ＡＢＣ ｱｲｳ"), 10);
+    assert_eq!(width("한국어"), 6);
+    // Conjoining jamos make a single syllable
+    assert_eq!(width("\u{1100}\u{1161}\u{11a8}"), 2);
+}
+
+#[test]
+fn test_unicode_display_width_2() {
+    let width = |s: &str| display_width(&code_points(s));
+    // Combining marks, format characters and controls take no column
+    assert_eq!(width("e\u{301}\u{323}"), 1);
+    assert_eq!(width("\u{301}"), 0);
+    assert_eq!(width("a\u{200b}b\u{ad}"), 2);
+    assert_eq!(width("a\tb\r\n"), 2);
+}
+
+#[test]
+fn test_unicode_display_width_3() {
+    let width = |s: &str| display_width(&code_points(s));
+    // Emoji presentation, ZWJ sequences, skin tones, flags and keycaps
+    assert_eq!(width("😀"), 2);
+    assert_eq!(width("👨\u{200d}👩\u{200d}👧\u{200d}👦"), 2);
+    assert_eq!(width("👍🏽"), 2);
+    assert_eq!(width("🇮🇹🇯🇵"), 4);
+    assert_eq!(width("1\u{fe0f}\u{20e3}"), 2);
+    // Text presentation by default, emoji presentation with VS16
+    assert_eq!(width("\u{2764}"), 1);
+    assert_eq!(width("\u{2764}\u{fe0f}"), 2);
+}
+
+#[test]
+fn test_unicode_display_width_ambiguous() {
+    let v: Vec<u32> = code_points("Ωμέγα ①─┐");
+    assert_eq!(display_width_ambiguous(&v, AmbiguousWidth::Narrow), 9);
+    assert_eq!(display_width_ambiguous(&v, AmbiguousWidth::Wide), 16);
+    assert_eq!(display_width(&v), 9);
+}
+
+#[test]
+fn test_unicode_lookup_name_1() {
+    assert_eq!(lookup_name("GREEK SMALL LETTER ALPHA"), Some(0x3b1));
+    assert_eq!(lookup_name("LATIN CAPITAL LETTER A"), Some(0x41));
+    assert_eq!(lookup_name("GOTHIC LETTER HWAIR"), Some(0x10348));
+    assert_eq!(lookup_name("VARIATION SELECTOR-256"), Some(0xe01ef));
+    assert_eq!(lookup_name("NOT A CHARACTER NAME"), None);
+    assert_eq!(lookup_name(""), None);
+}
+
+#[test]
+fn test_unicode_lookup_name_2() {
+    // Loose matching (UAX44-LM2)
+    assert_eq!(lookup_name("greek small letter alpha"), Some(0x3b1));
+    assert_eq!(lookup_name("Greek_Small_Letter_Alpha"), Some(0x3b1));
+    assert_eq!(lookup_name("GREEKSMALLLETTERALPHA"), Some(0x3b1));
+    assert_eq!(lookup_name("hyphen minus"), Some(0x2d));
+    assert_eq!(lookup_name("TIBETAN LETTER A"), Some(0xf68));
+    assert_eq!(lookup_name("TIBETAN LETTER -A"), Some(0xf60));
+    assert_eq!(lookup_name("HANGUL JUNGSEONG OE"), Some(0x116c));
+    assert_eq!(lookup_name("HANGUL JUNGSEONG O-E"), Some(0x1180));
+    assert_eq!(lookup_name("hangul jungseong o-e"), Some(0x1180));
+}
+
+#[test]
+fn test_unicode_lookup_name_3() {
+    // Names derived by algorithm
+    assert_eq!(lookup_name("CJK UNIFIED IDEOGRAPH-4E00"), Some(0x4e00));
+    assert_eq!(lookup_name("cjk unified ideograph-2a700"), Some(0x2a700));
+    assert_eq!(
+        lookup_name("CJK COMPATIBILITY IDEOGRAPH-F900"),
+        Some(0xf900)
+    );
+    assert_eq!(lookup_name("TANGUT IDEOGRAPH-18D08"), Some(0x18d08));
+    assert_eq!(lookup_name("CJK UNIFIED IDEOGRAPH-0041"), None);
+    assert_eq!(lookup_name("HANGUL SYLLABLE GAG"), Some(0xac01));
+    assert_eq!(lookup_name("hangul syllable pwilh"), Some(0xd4db));
+    assert_eq!(lookup_name("HANGUL SYLLABLE A"), Some(0xc544));
+}
+
+#[test]
+fn test_unicode_lookup_name_6() {
+    // Only the canonical spelling of the derived names
+    assert_eq!(lookup_name("CJK UNIFIED IDEOGRAPH-04E00"), None);
+    assert_eq!(lookup_name("CJK UNIFIED IDEOGRAPH-004E00"), None);
+    assert_eq!(lookup_name("CJK UNIFIED IDEOGRAPH-+4E00"), None);
+    assert_eq!(lookup_name("HANGUL SYLLABLE"), None);
+    assert_eq!(lookup_name("HANGUL SYLLABLE GAGX"), None);
+    assert_eq!(lookup_name("HANGUL SYLLABLE GG"), None);
+    for code_point in 0xac00..=0xd7a3 {
+        assert_eq!(lookup_name(&name(code_point).unwrap()), Some(code_point));
+    }
+}
+
+#[test]
+fn test_unicode_lookup_name_4() {
+    // Aliases
+    assert_eq!(lookup_name("LINE FEED"), Some(0x0a));
+    assert_eq!(lookup_name("LF"), Some(0x0a));
+    assert_eq!(lookup_name("NBSP"), Some(0xa0));
+    assert_eq!(lookup_name("BYTE ORDER MARK"), Some(0xfeff));
+    assert_eq!(lookup_name("LATIN CAPITAL LETTER GHA"), Some(0x1a2));
+    assert_eq!(lookup_name("LATIN CAPITAL LETTER OI"), Some(0x1a2));
+}
+
+#[test]
+fn test_unicode_lookup_name_5() {
+    for code_point in [0x20, 0xe9, 0x3b1, 0x1180, 0x20ac, 0x1f600, 0x10348, 0xac01] {
+        assert_eq!(lookup_name(&name(code_point).unwrap()), Some(code_point));
+    }
+}
+
+#[test]
+fn test_unicode_search_names_1() {
+    let candidates: Vec<(u32, String)> = search_names("greek small letter alpha", 3);
+    assert_eq!(candidates.len(), 3);
+    assert_eq!(
+        candidates[0],
+        (0x3b1, String::from("GREEK SMALL LETTER ALPHA"))
+    );
+    assert!(candidates[1..]
+        .iter()
+        .all(|(_, n)| n.starts_with("GREEK SMALL LETTER ALPHA ")));
+}
+
+#[test]
+fn test_unicode_search_names_2() {
+    let candidates: Vec<(u32, String)> = search_names("hwair", 10);
+    assert_eq!(
+        candidates[0],
+        (0x10348, String::from("GOTHIC LETTER HWAIR"))
+    );
+    assert!(candidates.iter().all(|(_, n)| n.contains("HWAIR")));
+
+    let candidates: Vec<(u32, String)> = search_names("euro", 5);
+    assert_eq!(candidates[0], (0x20ac, String::from("EURO SIGN")));
+
+    let candidates: Vec<(u32, String)> = search_names("cjk unified ideograph-4e00", 5);
+    assert_eq!(
+        candidates[0],
+        (0x4e00, String::from("CJK UNIFIED IDEOGRAPH-4E00"))
+    );
+}
+
+#[test]
+fn test_unicode_search_names_3() {
+    let candidates: Vec<(u32, String)> = search_names("alpha tonos", 5);
+    assert!(candidates.contains(&(0x3ac, String::from("GREEK SMALL LETTER ALPHA WITH TONOS"))));
+    assert!(search_names("", 5).is_empty());
+    assert!(search_names("alpha", 0).is_empty());
+    assert!(search_names("qqqqqq", 5).is_empty());
+}
+
+#[test]
+fn test_unicode_jamo_short_name() {
+    assert_eq!(jamo_short_name(0x1100), Some("G"));
+    assert_eq!(jamo_short_name(0x1112), Some("H"));
+    assert_eq!(jamo_short_name(0x110b), Some(""));
+    assert_eq!(jamo_short_name(0x1175), Some("I"));
+    assert_eq!(jamo_short_name(0x11a8), Some("G"));
+    assert_eq!(jamo_short_name(0x11c2), Some("H"));
+    assert_eq!(jamo_short_name(0x1113), None);
+    assert_eq!(jamo_short_name(0x11a7), None);
+    assert_eq!(jamo_short_name(0xac00), None);
+    // The names of the syllables are derived from the short names
+    for code_point in [0xac00, 0xd55c, 0xd7a3] {
+        let jamos: String = decompose_hangul(vec![code_point])
+            .iter()
+            .map(|cp| jamo_short_name(*cp).unwrap())
+            .collect();
+        assert_eq!(name(code_point), Some(format!("HANGUL SYLLABLE {}", jamos)));
+    }
+}
+
+#[test]
+fn test_unicode_compose_hangul() {
+    assert_eq!(compose_hangul(vec![0x1100, 0x1161]), vec![0xac00]);
+    assert_eq!(compose_hangul(vec![0x1100, 0x1161, 0x11a8]), vec![0xac01]);
+    assert_eq!(compose_hangul(vec![0x1112, 0x1175, 0x11c2]), vec![0xd7a3]);
+    assert_eq!(compose_hangul(vec![0xac00, 0x11a8]), vec![0xac01]);
+    // An LVT syllable does not take another trailing consonant
+    assert_eq!(compose_hangul(vec![0xac01, 0x11a8]), vec![0xac01, 0x11a8]);
+    // The jamos which are not part of a syllable
+    assert_eq!(compose_hangul(vec![0x1161, 0x11a8]), vec![0x1161, 0x11a8]);
+    assert_eq!(
+        compose_hangul(vec![0x1100, 0x41, 0x1161]),
+        vec![0x1100, 0x41, 0x1161]
+    );
+    // The old jamos are not composed
+    assert_eq!(compose_hangul(vec![0x1113, 0x1161]), vec![0x1113, 0x1161]);
+    assert_eq!(compose_hangul(vec![0x1100, 0x1176]), vec![0x1100, 0x1176]);
+    assert_eq!(compose_hangul(Vec::new()), Vec::<u32>::new());
+}
+
+#[test]
+fn test_unicode_decompose_hangul() {
+    assert_eq!(decompose_hangul(vec![0xac00]), vec![0x1100, 0x1161]);
+    assert_eq!(decompose_hangul(vec![0xac01]), vec![0x1100, 0x1161, 0x11a8]);
+    assert_eq!(decompose_hangul(vec![0xd7a3]), vec![0x1112, 0x1175, 0x11c2]);
+    assert_eq!(decompose_hangul(vec![0xd7a4, 0xe9]), vec![0xd7a4, 0xe9]);
+    // Round trip, and the same jamos as the NFD
+    for code_point in 0xac00..=0xd7a3 {
+        let jamos: Vec<u32> = decompose_hangul(vec![code_point]);
+        assert_eq!(jamos, nfd(vec![code_point]));
+        assert_eq!(compose_hangul(&jamos), vec![code_point]);
+    }
+}
+
+#[test]
+fn test_unicode_numeric_type() {
+    assert_eq!(numeric_type(0x30), Some(NumericType::Decimal));
+    assert_eq!(numeric_type(0x6f9), Some(NumericType::Decimal)); // ۹
+    assert_eq!(numeric_type(0xff10), Some(NumericType::Decimal)); // ０
+    assert_eq!(numeric_type(0x1d7ce), Some(NumericType::Decimal)); // 𝟎
+    assert_eq!(numeric_type(0x2460), Some(NumericType::Digit)); // ①
+    assert_eq!(numeric_type(0x2160), Some(NumericType::Numeric)); // Ⅰ
+    assert_eq!(numeric_type(0x4e00), Some(NumericType::Numeric)); // 一
+    assert_eq!(numeric_type(0x4e01), None);
+    assert_eq!(numeric_type(0x110000), None);
+}
+
+#[test]
+fn test_unicode_numeric_value() {
+    assert_eq!(numeric_value(0x30), Some(0.0));
+    assert_eq!(numeric_value(0x96f), Some(9.0)); // ९
+    assert_eq!(numeric_value(0xff19), Some(9.0)); // ９
+    assert_eq!(numeric_value(0xbc), Some(0.25)); // ¼
+    assert_eq!(numeric_value(0x2153), Some(1.0 / 3.0)); // ⅓
+    assert_eq!(numeric_value(0xf33), Some(-0.5)); // ༳
+    assert_eq!(numeric_value(0x2164), Some(5.0)); // Ⅴ
+    assert_eq!(numeric_value(0x217f), Some(1000.0)); // ⅿ
+    assert_eq!(numeric_value(0x2188), Some(100000.0)); // ↈ
+    assert_eq!(numeric_value(0x5146), Some(1e12)); // 兆
+    assert_eq!(numeric_value(0x2469), Some(10.0)); // ⑩
+    assert_eq!(numeric_value(0x41), None);
+    // Every decimal digit is in a run from 0 to 9
+    for code_point in 0..0x110000 {
+        if numeric_type(code_point) == Some(NumericType::Decimal) {
+            let digit: u32 = numeric_value(code_point).unwrap() as u32;
+            for (i, cp) in (code_point - digit..code_point - digit + 10).enumerate() {
+                assert_eq!(numeric_type(cp), Some(NumericType::Decimal));
+                assert_eq!(numeric_value(cp), Some(i as f64));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_unicode_parse_decimal() {
+    let parse = |s: &str| parse_decimal(&code_points(s));
+    assert_eq!(parse("0"), Ok(0));
+    assert_eq!(parse("007"), Ok(7));
+    assert_eq!(parse("18446744073709551615"), Ok(u64::MAX));
+    assert_eq!(parse("۱۲۳۴"), Ok(1234)); // Extended Arabic-Indic digits
+    assert_eq!(parse("४२"), Ok(42)); // Devanagari digits
+    assert_eq!(parse("𝟗𝟗"), Ok(99)); // Mathematical bold digits
+    let error =
+        |kind: ParseDecimalErrorKind, position: usize| Err(ParseDecimalError { kind, position });
+    assert_eq!(parse(""), error(ParseDecimalErrorKind::Empty, 0));
+    assert_eq!(
+        parse("12a"),
+        error(ParseDecimalErrorKind::InvalidDigit(0x61), 2)
+    );
+    assert_eq!(
+        parse("-1"),
+        error(ParseDecimalErrorKind::InvalidDigit(0x2d), 0)
+    );
+    // Not decimal digits
+    assert_eq!(
+        parse("1²"),
+        error(ParseDecimalErrorKind::InvalidDigit(0xb2), 1)
+    );
+    assert_eq!(
+        parse("Ⅻ"),
+        error(ParseDecimalErrorKind::InvalidDigit(0x216b), 0)
+    );
+    // Mixed digits, even in the same script
+    assert_eq!(
+        parse("١۲"),
+        error(ParseDecimalErrorKind::MixedDigits(0x6f2), 1)
+    );
+    assert_eq!(
+        parse("12３"),
+        error(ParseDecimalErrorKind::MixedDigits(0xff13), 2)
+    );
+    assert_eq!(
+        parse("𝟗𝟡"),
+        error(ParseDecimalErrorKind::MixedDigits(0x1d7e1), 1)
+    );
+    assert_eq!(
+        parse("18446744073709551616"),
+        error(ParseDecimalErrorKind::Overflow, 19)
+    );
+    assert_eq!(
+        parse("1 2").unwrap_err().to_string(),
+        "Invalid digit 20 at position 1"
+    );
+}
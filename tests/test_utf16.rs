@@ -55,3 +55,18 @@ fn test_utf16_decode_from_utf16_4() {
     let v: Vec<u32> = decode_from_utf16(enc);
     assert_eq!(v, vec![0x10001, 0x23456]);
 }
+
+#[test]
+fn test_utf16_encode_in_utf16_5() {
+    let v: Vec<u32> = vec![0xffff, 0x10000];
+    let enc: Vec<u16> = encode_in_utf16(v);
+    assert_eq!(enc, vec![0xffff, 0xd800, 0xdc00]);
+}
+
+#[test]
+fn test_utf16_validate_utf16() {
+    assert_eq!(validate_utf16(vec![0x41, 0xd800, 0xdc01, 0xffff]), Ok(()));
+    assert_eq!(validate_utf16(vec![0x41, 0xd800]), Err(1));
+    assert_eq!(validate_utf16(vec![0xd800, 0x41]), Err(0));
+    assert_eq!(validate_utf16(vec![0x41, 0x42, 0xdc01]), Err(2));
+}
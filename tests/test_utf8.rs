@@ -60,3 +60,18 @@ fn test_utf8_decode_from_utf8_4() {
     let v: Vec<u32> = decode_from_utf8(enc);
     assert_eq!(v, vec![0x10001, 0x23456]);
 }
+
+#[test]
+fn test_utf8_validate_utf8() {
+    assert_eq!(
+        validate_utf8(vec![0x24, 0xd0, 0x98, 0xe2, 0x82, 0xac]),
+        Ok(())
+    );
+    assert_eq!(validate_utf8(vec![0xf4, 0x8f, 0xbf, 0xbf]), Ok(()));
+    assert_eq!(validate_utf8(vec![0x24, 0x80]), Err(1));
+    assert_eq!(validate_utf8(vec![0xc1, 0xbf]), Err(0));
+    assert_eq!(validate_utf8(vec![0x24, 0xe0, 0x80, 0x80]), Err(1));
+    assert_eq!(validate_utf8(vec![0xed, 0xa0, 0x80]), Err(0));
+    assert_eq!(validate_utf8(vec![0xf4, 0x90, 0x80, 0x80]), Err(0));
+    assert_eq!(validate_utf8(vec![0x24, 0xf0, 0x90, 0x80]), Err(1));
+}
@@ -0,0 +1,198 @@
+mod common;
+
+use common::code_points;
+use ende::prelude::*;
+
+#[test]
+fn test_whitespace_is_white_space_1() {
+    for c in [
+        ' ', '\t', '\n', '\r', '\u{b}', '\u{c}', '\u{85}', '\u{a0}', '\u{1680}',
+    ] {
+        assert!(is_white_space(c as u32));
+    }
+    for c in [
+        '\u{2000}', '\u{200a}', '\u{2028}', '\u{2029}', '\u{202f}', '\u{3000}',
+    ] {
+        assert!(is_white_space(c as u32));
+    }
+    for c in ['a', '\0', '\u{1c}', '\u{180e}', '\u{200b}', '\u{feff}'] {
+        assert!(!is_white_space(c as u32));
+    }
+    // The same as the standard library
+    for code_point in 0..0x110000 {
+        if let Some(c) = char::from_u32(code_point) {
+            assert_eq!(is_white_space(code_point), c.is_whitespace());
+        }
+    }
+}
+
+#[test]
+fn test_whitespace_is_pattern_white_space_1() {
+    for code_point in [0x09, 0x0a, 0x0d, 0x20, 0x85, 0x200e, 0x200f, 0x2028, 0x2029] {
+        assert!(is_pattern_white_space(code_point));
+    }
+    for code_point in [0xa0, 0x1680, 0x2000, 0x3000, 0x200b] {
+        assert!(!is_pattern_white_space(code_point));
+    }
+}
+
+#[test]
+fn test_whitespace_is_line_terminator_1() {
+    for code_point in [0x0a, 0x0b, 0x0c, 0x0d, 0x85, 0x2028, 0x2029] {
+        assert!(is_line_terminator(code_point));
+        assert!(is_white_space(code_point));
+    }
+    for code_point in [0x09, 0x20, 0x1c, 0x1e, 0xa0, 0x3000] {
+        assert!(!is_line_terminator(code_point));
+    }
+}
+
+#[test]
+fn test_whitespace_is_control_1() {
+    // The same as the general category Cc
+    for code_point in 0..0x110000 {
+        assert_eq!(
+            is_control(code_point),
+            general_category(code_point) == GeneralCategory::Control
+        );
+    }
+}
+
+#[test]
+fn test_whitespace_is_default_ignorable_1() {
+    for code_point in [
+        0xad, 0x34f, 0x200b, 0x200d, 0x2060, 0xfe0f, 0xfeff, 0xe0001, 0xe0fff,
+    ] {
+        assert!(is_default_ignorable(code_point));
+    }
+    for code_point in [0x20, 0x0a, 0x85, 0x2028, 0xfff9, 0xe1000] {
+        assert!(!is_default_ignorable(code_point));
+    }
+}
+
+#[test]
+fn test_whitespace_trim_1() {
+    let v: Vec<u32> = code_points("\u{3000}\t a b \u{85}\u{2028}\r\n");
+    assert_eq!(trim(&v), &code_points("a b")[..]);
+    let v: Vec<u32> = code_points("\u{200b}x\u{200b}");
+    assert_eq!(trim(&v), &v[..]);
+    assert_eq!(trim(&code_points(" \u{a0}\n ")), &[] as &[u32]);
+    assert_eq!(trim(&[]), &[] as &[u32]);
+}
+
+#[test]
+fn test_whitespace_trim_utf8_1() {
+    for s in [
+        "  hello \u{2029}",
+        "\u{a0}héllo",
+        "hello",
+        "\u{85}\u{3000}hello\u{1680}",
+    ] {
+        assert_eq!(trim_utf8(s.as_bytes()), s.trim().as_bytes());
+    }
+    assert_eq!(
+        trim_utf8("\u{2003}ça va 👍\u{2003}".as_bytes()),
+        "ça va 👍".as_bytes()
+    );
+    assert_eq!(trim_utf8(b" \r\n "), b"");
+}
+
+#[test]
+#[should_panic]
+fn test_whitespace_trim_utf8_2() {
+    trim_utf8(&[0x20, 0xff, 0x20]);
+}
+
+#[test]
+fn test_whitespace_trim_utf16_1() {
+    for s in [" \u{2028}x y\u{3000}", "😀", "\u{85}", ""] {
+        let v: Vec<u16> = s.encode_utf16().collect();
+        let expected: Vec<u16> = s.trim().encode_utf16().collect();
+        assert_eq!(trim_utf16(&v), &expected[..]);
+    }
+}
+
+#[test]
+fn test_whitespace_split_lines_1() {
+    let lines = |s: &str| -> Vec<Vec<u32>> {
+        let v: Vec<u32> = code_points(s);
+        split_lines(&v).map(|r| v[r].to_vec()).collect()
+    };
+    assert_eq!(
+        lines("a\nb\r\nc\rd\u{85}e\u{2028}f\u{2029}g\u{b}h\u{c}i"),
+        ["a", "b", "c", "d", "e", "f", "g", "h", "i"].map(code_points)
+    );
+    // No empty last line after a final terminator
+    assert_eq!(lines("a\n"), vec![code_points("a")]);
+    assert_eq!(lines("\n"), vec![Vec::new()]);
+    assert_eq!(lines(""), Vec::<Vec<u32>>::new());
+    assert_eq!(lines("a\n\nb"), ["a", "", "b"].map(code_points));
+    // CR followed by LF is a single terminator, but not LF followed by CR
+    assert_eq!(lines("a\r\n\r\nb"), ["a", "", "b"].map(code_points));
+    assert_eq!(lines("a\n\rb"), ["a", "", "b"].map(code_points));
+    assert_eq!(lines("a\r\r\n"), ["a", ""].map(code_points));
+    // The other whitespace does not split
+    assert_eq!(lines("a\tb\u{a0}c "), vec![code_points("a\tb\u{a0}c ")]);
+}
+
+#[test]
+fn test_whitespace_split_lines_utf8_1() {
+    let s: &str = "première\r\nseconde\u{2028}\u{85}😀\n";
+    let lines: Vec<&str> = split_lines_utf8(s.as_bytes()).map(|r| &s[r]).collect();
+    assert_eq!(lines, vec!["première", "seconde", "", "😀"]);
+    // The same as the standard library with LF and CRLF
+    for s in ["a\nb", "a\r\nb\r\n", "\n\n", "x", "a\n\r\nb"] {
+        let lines: Vec<&str> = split_lines_utf8(s.as_bytes()).map(|r| &s[r]).collect();
+        assert_eq!(lines, s.lines().collect::<Vec<&str>>());
+    }
+}
+
+#[test]
+fn test_whitespace_split_lines_utf16_1() {
+    let s: &str = "a\u{2029}😀\r\n\u{85}b";
+    let v: Vec<u16> = s.encode_utf16().collect();
+    let lines: Vec<String> = split_lines_utf16(&v)
+        .map(|r| String::from_utf16(&v[r]).unwrap())
+        .collect();
+    assert_eq!(lines, vec!["a", "😀", "", "b"]);
+}
+
+#[test]
+fn test_whitespace_normalize_newlines_1() {
+    assert_eq!(
+        normalize_newlines(code_points(
+            "a\r\nb\rc\u{85}d\u{2028}e\u{2029}f\u{b}g\u{c}h\n"
+        )),
+        code_points("a\nb\nc\nd\ne\nf\ng\nh\n")
+    );
+    assert_eq!(
+        normalize_newlines(code_points("\r\r\n\n\r")),
+        code_points("\n\n\n\n")
+    );
+    assert_eq!(
+        normalize_newlines(code_points("no\tbreak\u{a0}here")),
+        code_points("no\tbreak\u{a0}here")
+    );
+    assert_eq!(normalize_newlines(Vec::new()), Vec::<u32>::new());
+}
+
+#[test]
+fn test_whitespace_normalize_newlines_utf8_1() {
+    let s: &str = "ligne\u{2028}über\r\n😀\u{85}";
+    assert_eq!(
+        normalize_newlines_utf8(s.as_bytes().to_vec()),
+        "ligne\nüber\n😀\n".as_bytes()
+    );
+    // The lines are the same once normalized
+    let normalized: String =
+        String::from_utf8(normalize_newlines_utf8(s.as_bytes().to_vec())).unwrap();
+    let lines: Vec<&str> = split_lines_utf8(s.as_bytes()).map(|r| &s[r]).collect();
+    assert_eq!(normalized.lines().collect::<Vec<&str>>(), lines);
+}
+
+#[test]
+fn test_whitespace_normalize_newlines_utf16_1() {
+    let v: Vec<u16> = "a\r\n𝄞\u{2029}b\r".encode_utf16().collect();
+    let expected: Vec<u16> = "a\n𝄞\nb\n".encode_utf16().collect();
+    assert_eq!(normalize_newlines_utf16(&v), expected);
+}